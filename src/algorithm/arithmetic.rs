@@ -0,0 +1,423 @@
+//! Number theoretic procedures on [unsigned integers](`Unsigned`).
+//!
+//! Notably, modular arithmetic which never overflows regardless of the
+//! magnitude of inputs, as required by rolling hashes.
+
+/// Unsigned integer types supported by procedures within this module.
+///
+/// This only exposes the (checked) operations these procedures require, it is
+/// not intended to be a general purpose numeric abstraction.
+pub trait Unsigned: Copy + Ord + core::ops::BitAnd<Output = Self> {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Add `rhs`, yielding [`None`] upon overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtract `rhs`, yielding [`None`] upon underflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Multiply by `rhs`, yielding [`None`] upon overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Divide by `rhs`, yielding [`None`] if `rhs` is zero.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    /// Remainder of division by `rhs`, yielding [`None`] if `rhs` is zero.
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+
+    /// Shift right by `rhs` bits, yielding [`None`] if `rhs` exceeds the width.
+    fn checked_shr(self, rhs: u32) -> Option<Self>;
+}
+
+/// Implement [`Unsigned`] for primitive types by their inherent methods.
+macro_rules! unsigned {
+    ($($primitive:ty),*) => {
+        $(
+            impl Unsigned for $primitive {
+                const ZERO: Self = 0;
+
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$primitive>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$primitive>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$primitive>::checked_mul(self, rhs)
+                }
+
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$primitive>::checked_div(self, rhs)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$primitive>::checked_rem(self, rhs)
+                }
+
+                fn checked_shr(self, rhs: u32) -> Option<Self> {
+                    <$primitive>::checked_shr(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// Compute the greatest common divisor of `first` and `second`.
+///
+/// This is implemented via the [Euclidean algorithm][euclid]. By convention,
+/// the greatest common divisor of zero and zero is zero.
+///
+/// # Performance
+/// This method takes O(log min(first, second)) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::arithmetic::gcd;
+///
+/// assert_eq!(gcd(48_u32, 18), 6);
+/// assert_eq!(gcd(0_u32, 7), 7);
+/// ```
+///
+/// [euclid]: https://en.wikipedia.org/wiki/Euclidean_algorithm
+pub fn gcd<T: Unsigned>(mut first: T, mut second: T) -> T {
+    while second != T::ZERO {
+        let Some(remainder) = first.checked_rem(second) else {
+            unreachable!("divisor is non-zero");
+        };
+
+        first = second;
+        second = remainder;
+    }
+
+    first
+}
+
+/// Compute the least common multiple of `first` and `second`.
+///
+/// By convention, the least common multiple of zero and anything is zero.
+///
+/// # Errors
+/// Yields [`None`] if the result cannot be represented by `T`.
+///
+/// # Performance
+/// This method takes O(log min(first, second)) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::arithmetic::lcm;
+///
+/// assert_eq!(lcm(4_u32, 6), Some(12));
+/// assert_eq!(lcm(u8::MAX, 2), None);
+/// ```
+pub fn lcm<T: Unsigned>(first: T, second: T) -> Option<T> {
+    if first == T::ZERO || second == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    let Some(quotient) = first.checked_div(gcd(first, second)) else {
+        unreachable!("divisor of non-zero inputs is non-zero");
+    };
+
+    quotient.checked_mul(second)
+}
+
+/// Compute `first * second` modulo `modulus` without overflowing.
+///
+/// If the product can be represented by `T` it is computed directly,
+/// otherwise via [repeated doubling][doubling] which never exceeds `modulus`.
+///
+/// # Panics
+/// This method has the precondition that `modulus` is non-zero.
+///
+/// # Performance
+/// This method takes O(log second) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::arithmetic::mod_mul;
+///
+/// assert_eq!(mod_mul(7_u64, 8, 10), 6);
+/// assert_eq!(mod_mul(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+/// ```
+///
+/// [doubling]: https://en.wikipedia.org/wiki/Modular_arithmetic#Example_implementations
+pub fn mod_mul<T: Unsigned>(first: T, second: T, modulus: T) -> T {
+    assert!(modulus != T::ZERO, "modulus must be non-zero");
+
+    let Some(mut first) = first.checked_rem(modulus) else {
+        unreachable!("modulus is non-zero");
+    };
+
+    let Some(mut second) = second.checked_rem(modulus) else {
+        unreachable!("modulus is non-zero");
+    };
+
+    if let Some(product) = first.checked_mul(second) {
+        let Some(product) = product.checked_rem(modulus) else {
+            unreachable!("modulus is non-zero");
+        };
+
+        return product;
+    }
+
+    let mut product = T::ZERO;
+
+    while second != T::ZERO {
+        if second & T::ONE == T::ONE {
+            product = mod_add(product, first, modulus);
+        }
+
+        first = mod_add(first, first, modulus);
+
+        let Some(halved) = second.checked_shr(1) else {
+            unreachable!("every integer type has more than one bit");
+        };
+
+        second = halved;
+    }
+
+    product
+}
+
+/// Compute `base` raised to the power of `exponent` modulo `modulus`.
+///
+/// This is implemented via [exponentiation by squaring][squaring] with each
+/// multiplication computed by [`mod_mul`], hence it never overflows.
+///
+/// # Panics
+/// This method has the precondition that `modulus` is non-zero.
+///
+/// # Performance
+/// This method takes O(log exponent * log modulus) time and consumes O(1)
+/// memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::arithmetic::mod_pow;
+///
+/// assert_eq!(mod_pow(2_u32, 10, 1000), 24);
+/// assert_eq!(mod_pow(3_u32, 0, 7), 1);
+/// ```
+///
+/// [squaring]: https://en.wikipedia.org/wiki/Modular_exponentiation
+pub fn mod_pow<T: Unsigned>(base: T, mut exponent: T, modulus: T) -> T {
+    assert!(modulus != T::ZERO, "modulus must be non-zero");
+
+    let Some(mut base) = base.checked_rem(modulus) else {
+        unreachable!("modulus is non-zero");
+    };
+
+    let Some(mut result) = T::ONE.checked_rem(modulus) else {
+        unreachable!("modulus is non-zero");
+    };
+
+    while exponent != T::ZERO {
+        if exponent & T::ONE == T::ONE {
+            result = mod_mul(result, base, modulus);
+        }
+
+        base = mod_mul(base, base, modulus);
+
+        let Some(halved) = exponent.checked_shr(1) else {
+            unreachable!("every integer type has more than one bit");
+        };
+
+        exponent = halved;
+    }
+
+    result
+}
+
+/// Compute `first + second` modulo `modulus` without overflowing.
+///
+/// This has the precondition that both `first` and `second` are less than
+/// `modulus`, which is maintained by [`mod_mul`].
+fn mod_add<T: Unsigned>(first: T, second: T, modulus: T) -> T {
+    let Some(remaining) = modulus.checked_sub(first) else {
+        unreachable!("first is less than modulus");
+    };
+
+    if second >= remaining {
+        let Some(sum) = second.checked_sub(remaining) else {
+            unreachable!("second is at least remaining");
+        };
+
+        sum
+    } else {
+        let Some(sum) = first.checked_add(second) else {
+            unreachable!("sum is less than modulus");
+        };
+
+        sum
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod gcd {
+        use super::*;
+
+        #[test]
+        fn known_values() {
+            assert_eq!(gcd(48_u32, 18), 6);
+            assert_eq!(gcd(18_u32, 48), 6);
+            assert_eq!(gcd(17_u32, 5), 1);
+            assert_eq!(gcd(270_u64, 192), 6);
+        }
+
+        #[test]
+        fn when_one_is_zero() {
+            assert_eq!(gcd(0_u32, 7), 7);
+            assert_eq!(gcd(7_u32, 0), 7);
+        }
+
+        #[test]
+        fn when_both_are_zero() {
+            assert_eq!(gcd(0_u32, 0), 0);
+        }
+
+        #[test]
+        fn when_equal() {
+            assert_eq!(gcd(u64::MAX, u64::MAX), u64::MAX);
+        }
+    }
+
+    mod lcm {
+        use super::*;
+
+        #[test]
+        fn known_values() {
+            assert_eq!(lcm(4_u32, 6), Some(12));
+            assert_eq!(lcm(21_u32, 6), Some(42));
+            assert_eq!(lcm(7_u32, 5), Some(35));
+        }
+
+        #[test]
+        fn when_zero() {
+            assert_eq!(lcm(0_u32, 7), Some(0));
+            assert_eq!(lcm(7_u32, 0), Some(0));
+        }
+
+        #[test]
+        fn when_overflows() {
+            assert_eq!(lcm(u8::MAX, 2), None);
+            assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        }
+
+        #[test]
+        fn when_does_not_overflow_despite_product() {
+            assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        }
+    }
+
+    mod mod_mul {
+        use super::*;
+
+        #[test]
+        fn known_values() {
+            assert_eq!(mod_mul(7_u64, 8, 10), 6);
+            assert_eq!(mod_mul(123_u64, 456, 789), 69);
+        }
+
+        #[test]
+        fn reduces_inputs_greater_than_modulus() {
+            assert_eq!(mod_mul(17_u8, 23, 5), 1);
+        }
+
+        #[test]
+        fn when_modulus_is_one() {
+            assert_eq!(mod_mul(u64::MAX, u64::MAX, 1), 0);
+        }
+
+        #[test]
+        fn does_not_overflow_near_maximum() {
+            assert_eq!(mod_mul(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+            assert_eq!(mod_mul(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+        }
+
+        #[test]
+        fn agrees_with_wider_type() {
+            let modulus = u64::MAX - 58;
+
+            for (first, second) in [
+                (u64::MAX, u64::MAX),
+                (u64::MAX - 1, 2),
+                (1 << 63, 1 << 63),
+                (0xDEAD_BEEF_DEAD_BEEF, 0xCAFE_BABE_CAFE_BABE),
+            ] {
+                let Some(expected) =
+                    (u128::from(first) * u128::from(second)).checked_rem(u128::from(modulus))
+                else {
+                    unreachable!("modulus is non-zero");
+                };
+
+                let Ok(expected) = u64::try_from(expected) else {
+                    unreachable!("remainder is less than modulus");
+                };
+
+                assert_eq!(mod_mul(first, second, modulus), expected);
+            }
+        }
+
+        #[test]
+        fn does_not_overflow_widest_type() {
+            assert_eq!(mod_mul(u128::MAX - 1, u128::MAX - 1, u128::MAX), 1);
+        }
+
+        #[test]
+        #[should_panic = "modulus must be non-zero"]
+        fn panics_when_modulus_is_zero() {
+            _ = mod_mul(1_u64, 1, 0);
+        }
+    }
+
+    mod mod_pow {
+        use super::*;
+
+        #[test]
+        fn known_values() {
+            assert_eq!(mod_pow(2_u32, 10, 1000), 24);
+            assert_eq!(mod_pow(4_u32, 13, 497), 445);
+            assert_eq!(mod_pow(3_u32, 200, 13), 9);
+        }
+
+        #[test]
+        fn when_exponent_is_zero() {
+            assert_eq!(mod_pow(3_u32, 0, 7), 1);
+        }
+
+        #[test]
+        fn when_modulus_is_one() {
+            assert_eq!(mod_pow(3_u32, 0, 1), 0);
+            assert_eq!(mod_pow(3_u32, 5, 1), 0);
+        }
+
+        #[test]
+        fn does_not_overflow_near_maximum() {
+            // Largest prime representable by `u64`.
+            let prime = 18_446_744_073_709_551_557_u64;
+
+            // Fermat's little theorem.
+            assert_eq!(mod_pow(2, prime - 1, prime), 1);
+            assert_eq!(mod_pow(u64::MAX, prime - 1, prime), 1);
+        }
+
+        #[test]
+        #[should_panic = "modulus must be non-zero"]
+        fn panics_when_modulus_is_zero() {
+            _ = mod_pow(2_u64, 2, 0);
+        }
+    }
+}
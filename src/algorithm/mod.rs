@@ -1,4 +1,5 @@
 //! Procedures on data.
 
+pub mod arithmetic;
 pub mod merge;
 pub mod sort;
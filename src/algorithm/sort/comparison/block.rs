@@ -0,0 +1,1098 @@
+//! Implementations of [Block Sort](https://en.wikipedia.org/wiki/Block_sort).
+
+use super::insertion;
+use super::INSERTION_CUTOFF;
+
+/// Sort `elements` via block merge sort using an internal buffer.
+///
+/// Up to 2 * sqrt(N) distinct elements are first extracted to the front of
+/// the slice. Some tag the blocks of each run to be merged, whereas the
+/// remainder are an internal buffer into which elements are swapped (rather
+/// than copied) whilst merging. Runs double in size until only one remains:
+/// each pair of runs is divided into blocks of sqrt(N) elements, these
+/// are reordered via selection sort by their first element (using the tags
+/// to break ties in favour of the left run), and then adjacent blocks are
+/// merged locally via the buffer. Finally, the extracted elements are sorted
+/// and merged back in with the rest.
+///
+/// This is a stable sort, elements which are equal will remain in the same
+/// order relative to each other. This makes it suitable where merge sort
+/// would be used but allocating a buffer is undesirable. If there are too
+/// few distinct elements for a complete buffer, smaller blocks (or only
+/// rotations) are used, which is efficient precisely because there are few
+/// distinct elements.
+///
+/// # Performance
+/// This method takes O(N * log N) time and consumes O(1) memory.
+///
+/// # See Also
+/// ```text
+/// @misc{
+///     author = {Astrelin, Andrey},
+///     title  = {GrailSort},
+///     year   = {2013},
+///     url    = {https://github.com/Mrrl/GrailSort},
+/// }
+/// ```
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::block::block_sort;
+///
+/// let mut elements = [0, 5, 2, 3, 1, 4];
+///
+/// block_sort(&mut elements);
+///
+/// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+/// ```
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn block_sort<T: Ord>(elements: &mut [T]) {
+    let length = elements.len();
+
+    if length <= INSERTION_CUTOFF {
+        insertion::iterative(elements);
+        return;
+    }
+
+    // The smallest power of two which is at least the square root.
+    let mut size: usize = 1;
+
+    while size.saturating_mul(size) < length {
+        size *= 2;
+    }
+
+    let mut tags = (length - 1) / size + 1;
+
+    let found = extract_keys(elements, tags + size);
+
+    let buffered = found == tags + size;
+
+    if !buffered {
+        if found < 4 {
+            lazy_sort(elements);
+            return;
+        }
+
+        // Use every key as the buffer whilst building runs.
+        tags = size;
+
+        while tags > found {
+            tags /= 2;
+        }
+
+        size = 0;
+    }
+
+    let start = size + tags;
+    let mut run = if buffered { size } else { tags };
+
+    build_runs(elements, start, run);
+
+    loop {
+        run *= 2;
+
+        if length - start <= run {
+            break;
+        }
+
+        let (block, buffer) = if buffered {
+            (size, true)
+        } else if tags > 4 && tags / 8 * tags >= run {
+            // Enough keys to split between tags and a smaller buffer.
+            (tags / 2, true)
+        } else {
+            // As few blocks as the keys allow, merged by rotation.
+            let mut blocks = 1;
+            let mut remaining = (run / 2).saturating_mul(found);
+
+            while blocks < tags && remaining != 0 {
+                blocks *= 2;
+                remaining /= 8;
+            }
+
+            (2 * run / blocks, false)
+        };
+
+        combine_runs(elements, start, run, block, buffer);
+    }
+
+    insertion::iterative(&mut elements[..start]);
+
+    merge_in_place(elements, 0, start, length - start);
+}
+
+/// Sort `elements` via block merge sort using symmetric merging.
+///
+/// Blocks of [`INSERTION_CUTOFF`] elements are first individually sorted via
+/// [`insertion::iterative`], then adjacent sorted blocks are merged pairwise
+/// doubling in size until all elements are sorted. Unlike traditional merge
+/// sort, blocks are merged in place without an auxiliary buffer by the
+/// `SymMerge` algorithm which recursively rotates the section of the left
+/// block that belongs after some section of the right block.
+///
+/// This is a stable sort, elements which are equal will remain in the same
+/// order relative to each other. This makes it suitable where merge sort
+/// would be used but allocating a buffer is undesirable, at the cost of
+/// additional moves. See [`block_sort`] for the O(N * log N) time variant
+/// which extracts an internal buffer.
+///
+/// # Performance
+/// This method takes O(N * log<sup>2</sup> N) time and consumes O(log N)
+/// memory for the recursion, without allocating.
+///
+/// # See Also
+/// ```text
+/// @inproceedings{
+///     author    = {Kim, Pok-Son and Kutzner, Arne},
+///     title     = {Stable Minimum Storage Merging by Symmetric Comparisons},
+///     booktitle = {Algorithms -- ESA 2004},
+///     year      = {2004},
+///     publisher = {Springer Berlin Heidelberg},
+///     pages     = {714--723},
+///     isbn      = {978-3-540-30140-0},
+/// }
+/// ```
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::block::sym_merge;
+///
+/// let mut elements = [0, 5, 2, 3, 1, 4];
+///
+/// sym_merge(&mut elements);
+///
+/// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+/// ```
+pub fn sym_merge<T: Ord>(elements: &mut [T]) {
+    /// How many elements each block contains before merging.
+    const BLOCK: usize = INSERTION_CUTOFF;
+
+    for block in elements.chunks_mut(BLOCK) {
+        insertion::iterative(block);
+    }
+
+    let mut size = BLOCK;
+
+    while size < elements.len() {
+        let Some(width) = size.checked_mul(2) else {
+            // Merging the last pair of blocks would be the only merge.
+            merge(elements, size);
+            return;
+        };
+
+        for pair in elements.chunks_mut(width) {
+            if pair.len() > size {
+                merge(pair, size);
+            }
+        }
+
+        size = width;
+    }
+}
+
+/// Stable merge of sorted `elements[..middle]` and `elements[middle..]`.
+///
+/// # Performance
+/// This method takes O(N * log N) time and consumes O(log N) memory.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn merge<T: Ord>(elements: &mut [T], middle: usize) {
+    let length = elements.len();
+
+    if middle == 0 || middle == length {
+        return;
+    }
+
+    // The left section is a single element, insert it into position.
+    if middle == 1 {
+        let position = elements[1..].partition_point(|element| element < &elements[0]);
+
+        elements[..=position].rotate_left(1);
+
+        return;
+    }
+
+    // The right section is a single element, insert it into position.
+    if length - middle == 1 {
+        let position = elements[..middle].partition_point(|element| element <= &elements[middle]);
+
+        elements[position..].rotate_right(1);
+
+        return;
+    }
+
+    // Symmetrically search around the center for the sections to exchange.
+    let center = length / 2;
+    let sum = center + middle;
+
+    let (mut start, mut bound) = if middle > center {
+        (sum - length, center)
+    } else {
+        (0, middle)
+    };
+
+    while start < bound {
+        let current = start + (bound - start) / 2;
+
+        if elements[sum - 1 - current] < elements[current] {
+            bound = current;
+        } else {
+            start = current + 1;
+        }
+    }
+
+    let end = sum - start;
+
+    // Exchange `[start..middle]` with `[middle..end]` via rotation.
+    if start < middle && middle < end {
+        elements[start..end].rotate_left(middle - start);
+    }
+
+    let (left, right) = elements.split_at_mut(center);
+
+    merge(&mut left[..], start);
+    merge(&mut right[..], end - center);
+}
+
+/// Move up to `wanted` distinct elements to the front, in sorted order.
+///
+/// The first occurrence of each value is extracted, so the relative order of
+/// the elements which are not extracted remains stable. Yields how many
+/// distinct elements were extracted.
+///
+/// # Performance
+/// This method takes O(N + wanted<sup>2</sup>) time and consumes O(1) memory.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn extract_keys<T: Ord>(elements: &mut [T], wanted: usize) -> usize {
+    // The first element is always distinct from those before it.
+    let mut first = 0;
+    let mut found = 1;
+
+    for current in 1..elements.len() {
+        if found == wanted {
+            break;
+        }
+
+        let keys = &elements[first..first + found];
+        let position = keys.partition_point(|key| key < &elements[current]);
+
+        if position == found || elements[current].cmp(&elements[first + position]).is_ne() {
+            // Move the keys to be adjacent to the new key.
+            elements[first..current].rotate_left(found);
+            first = current - found;
+
+            // Insert the new key into sorted position.
+            elements[first + position..=current].rotate_right(1);
+            found += 1;
+        }
+    }
+
+    elements[..first + found].rotate_left(first);
+
+    found
+}
+
+/// Sort `elements` via merge sort, merging only via rotations.
+///
+/// # Performance
+/// This method takes O(N * log N * K) time and consumes O(1) memory where K
+/// is the number of distinct elements.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn lazy_sort<T: Ord>(elements: &mut [T]) {
+    let length = elements.len();
+
+    for index in (1..length).step_by(2) {
+        if elements[index - 1] > elements[index] {
+            elements.swap(index - 1, index);
+        }
+    }
+
+    let mut run = 2;
+
+    while run < length {
+        let mut start = 0;
+
+        while start + 2 * run <= length {
+            merge_in_place(elements, start, run, run);
+            start += 2 * run;
+        }
+
+        if length - start > run {
+            merge_in_place(elements, start, run, length - start - run);
+        }
+
+        run *= 2;
+    }
+}
+
+/// Sort runs of `2 * buffer` elements after `start` via the preceding buffer.
+///
+/// The `buffer` elements before `start` are used as the internal buffer,
+/// hence their order is not maintained, but they are at the same position
+/// once the runs are sorted. The last run may contain fewer elements.
+///
+/// # Performance
+/// This method takes O(N * log buffer) time and consumes O(1) memory.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn build_runs<T: Ord>(elements: &mut [T], start: usize, buffer: usize) {
+    let length = elements.len() - start;
+
+    // Sort pairs whilst moving them into the last two buffer elements.
+    for index in (start + 1..start + length).step_by(2) {
+        let swap = usize::from(elements[index - 1] > elements[index]);
+
+        elements.swap(index - 3, index - 1 + swap);
+        elements.swap(index - 2, index - swap);
+    }
+
+    if length % 2 == 1 {
+        elements.swap(start + length - 1, start + length - 3);
+    }
+
+    let mut offset = start - 2;
+    let mut run = 2;
+
+    // Merge runs into the buffer before them, moving it after them.
+    while run < buffer {
+        let mut position = 0;
+
+        while position + 2 * run <= length {
+            let left = offset + position;
+            merge_left(elements, left, run, run, left - run);
+            position += 2 * run;
+        }
+
+        if length - position > run {
+            let left = offset + position;
+            merge_left(elements, left, run, length - position - run, left - run);
+        } else {
+            for index in offset + position..offset + length {
+                elements.swap(index, index - run);
+            }
+        }
+
+        offset -= run;
+        run *= 2;
+    }
+
+    // Merge runs into the buffer after them, moving it back before them.
+    let remainder = length % (2 * buffer);
+    let mut position = length - remainder;
+
+    if remainder <= buffer {
+        let left = offset + position;
+        elements[left..left + remainder + buffer].rotate_left(remainder);
+    } else {
+        merge_right(
+            elements,
+            offset + position,
+            buffer,
+            remainder - buffer,
+            buffer,
+        );
+    }
+
+    while position > 0 {
+        position -= 2 * buffer;
+        merge_right(elements, offset + position, buffer, buffer, buffer);
+    }
+}
+
+/// Merge pairs of sorted runs with `run` elements which follow `start`.
+///
+/// The run tags are the keys at the front of `elements`, and the preceding
+/// `block` elements are the internal buffer if `buffered`. If the last run
+/// contains no more than `run` elements, it is left in place.
+///
+/// # Performance
+/// This method takes O(N) time and consumes O(1) memory.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn combine_runs<T: Ord>(
+    elements: &mut [T],
+    start: usize,
+    run: usize,
+    block: usize,
+    buffered: bool,
+) {
+    let mut length = elements.len() - start;
+    let pairs = length / (2 * run);
+    let mut remainder = length % (2 * run);
+
+    if remainder <= run {
+        length -= remainder;
+        remainder = 0;
+    }
+
+    for pair in 0..=pairs {
+        let last = pair == pairs;
+
+        if last && remainder == 0 {
+            break;
+        }
+
+        let first = start + pair * 2 * run;
+        let blocks = if last { remainder } else { 2 * run } / block;
+
+        // Tags less than the middle tag are of blocks from the left run.
+        insertion::iterative(&mut elements[..blocks + usize::from(last)]);
+        let mut middle = run / block;
+
+        // Selection sort blocks by their first element, then by their tag.
+        for sorted in 1..blocks {
+            let mut minimum = sorted - 1;
+
+            for candidate in sorted..blocks {
+                let ordering =
+                    elements[first + minimum * block].cmp(&elements[first + candidate * block]);
+
+                if ordering.is_gt() || (ordering.is_eq() && elements[minimum] > elements[candidate])
+                {
+                    minimum = candidate;
+                }
+            }
+
+            if minimum != sorted - 1 {
+                let previous = sorted - 1;
+
+                swap_blocks(
+                    elements,
+                    first + previous * block,
+                    first + minimum * block,
+                    block,
+                );
+                elements.swap(previous, minimum);
+
+                middle = if middle == previous {
+                    minimum
+                } else if middle == minimum {
+                    previous
+                } else {
+                    middle
+                };
+            }
+        }
+
+        // Left run blocks which belong after the incomplete last block.
+        let mut trailing = 0;
+        let incomplete = if last { remainder % block } else { 0 };
+
+        if incomplete != 0 {
+            while trailing < blocks
+                && elements[first + blocks * block]
+                    < elements[first + (blocks - trailing - 1) * block]
+            {
+                trailing += 1;
+            }
+        }
+
+        merge_blocks(
+            elements,
+            middle,
+            first,
+            (blocks - trailing, block),
+            buffered,
+            (trailing, incomplete),
+        );
+    }
+
+    // Move the buffer from after the runs back to before them.
+    if buffered {
+        for index in (start..start + length).rev() {
+            elements.swap(index, index - block);
+        }
+    }
+}
+
+/// Locally merge the sorted blocks which follow `start`.
+///
+/// The `count` blocks of `size` elements are tagged by the keys at the front
+/// of `elements` such that those less than the key at index `middle` are of
+/// the left run. They are followed by `trailing` blocks of the left run then
+/// `incomplete` elements of the right run. If `buffered`, the internal
+/// buffer precedes `start` and will follow the merged elements.
+///
+/// # Performance
+/// This method takes O(N) time and consumes O(1) memory.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn merge_blocks<T: Ord>(
+    elements: &mut [T],
+    middle: usize,
+    start: usize,
+    (count, size): (usize, usize),
+    buffered: bool,
+    (trailing, incomplete): (usize, usize),
+) {
+    if count == 0 {
+        let length = trailing * size;
+
+        if buffered {
+            merge_left(elements, start, length, incomplete, start - size);
+        } else {
+            merge_in_place(elements, start, length, incomplete);
+        }
+
+        return;
+    }
+
+    // The yet to be merged section before `position`, and its origin.
+    let mut rest = size;
+    let mut from_left = elements[0] < elements[middle];
+    let mut position = start + size;
+
+    for block in 1..count {
+        let previous = position - rest;
+        let next_from_left = elements[block] < elements[middle];
+
+        if next_from_left == from_left {
+            if buffered {
+                swap_blocks(elements, previous - size, previous, rest);
+            }
+
+            rest = size;
+        } else if buffered {
+            rest = merge_rest_buffered(elements, previous, (rest, &mut from_left), size);
+        } else {
+            rest = merge_rest_in_place(elements, previous, (rest, &mut from_left), size);
+        }
+
+        position += size;
+    }
+
+    let mut previous = position - rest;
+
+    if incomplete == 0 {
+        if buffered {
+            swap_blocks(elements, previous, previous - size, rest);
+        }
+
+        return;
+    }
+
+    if from_left {
+        rest += size * trailing;
+    } else {
+        if buffered {
+            swap_blocks(elements, previous - size, previous, rest);
+        }
+
+        previous = position;
+        rest = size * trailing;
+    }
+
+    if buffered {
+        merge_left(elements, previous, rest, incomplete, previous - size);
+    } else {
+        merge_in_place(elements, previous, rest, incomplete);
+    }
+}
+
+/// If `left` precedes `right` where `left` is from the left run if `from_left`.
+fn precedes<T: Ord>(left: &T, right: &T, from_left: bool) -> bool {
+    if from_left {
+        left <= right
+    } else {
+        left < right
+    }
+}
+
+/// Merge the rest of the previous block with the next via the buffer.
+///
+/// The `rest` is `(length, from_left)` of the section which starts at
+/// `start`, followed by `length` elements of the other run. The buffer of at
+/// least `length` elements precedes `start`. Merged elements are moved into
+/// the buffer until either section is exhausted, the remainder of which is
+/// moved to the end and becomes the new rest, whose length is yielded.
+///
+/// # Performance
+/// This method takes O(N) time and consumes O(1) memory.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn merge_rest_buffered<T: Ord>(
+    elements: &mut [T],
+    start: usize,
+    (rest, from_left): (usize, &mut bool),
+    length: usize,
+) -> usize {
+    let mut output = start - length;
+    let mut left = start;
+    let mut right = start + rest;
+    let left_end = right;
+    let mut right_end = right + length;
+
+    while left < left_end && right < right_end {
+        if precedes(&elements[left], &elements[right], *from_left) {
+            elements.swap(output, left);
+            left += 1;
+        } else {
+            elements.swap(output, right);
+            right += 1;
+        }
+
+        output += 1;
+    }
+
+    if left < left_end {
+        let remaining = left_end - left;
+
+        for index in (left..left_end).rev() {
+            right_end -= 1;
+            elements.swap(index, right_end);
+        }
+
+        remaining
+    } else {
+        *from_left = !*from_left;
+
+        right_end - right
+    }
+}
+
+/// Merge the rest of the previous block with the next via rotations.
+///
+/// The `rest` is `(length, from_left)` of the section which starts at
+/// `start`, followed by `length` elements of the other run. Yields the
+/// length of the new rest, which ends the merged section.
+///
+/// # Performance
+/// This method takes O(N * K) time and consumes O(1) memory where K is the
+/// number of distinct elements.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn merge_rest_in_place<T: Ord>(
+    elements: &mut [T],
+    mut start: usize,
+    (mut rest, from_left): (usize, &mut bool),
+    mut length: usize,
+) -> usize {
+    if length == 0 {
+        return rest;
+    }
+
+    if rest != 0
+        && !precedes(
+            &elements[start + rest - 1],
+            &elements[start + rest],
+            *from_left,
+        )
+    {
+        while rest != 0 {
+            let head = &elements[start];
+            let other = &elements[start + rest..start + rest + length];
+            let before = other.partition_point(|element| !precedes(head, element, *from_left));
+
+            if before != 0 {
+                elements[start..start + rest + before].rotate_left(rest);
+                start += before;
+                length -= before;
+            }
+
+            if length == 0 {
+                return rest;
+            }
+
+            loop {
+                start += 1;
+                rest -= 1;
+
+                if rest == 0 || !precedes(&elements[start], &elements[start + rest], *from_left) {
+                    break;
+                }
+            }
+        }
+    }
+
+    *from_left = !*from_left;
+
+    length
+}
+
+/// Stably merge `left` elements after `start` with the `right` after them.
+///
+/// Sections are merged into the buffer of at least `right` elements which
+/// starts at `buffer` before `start`, which is moved after the result.
+///
+/// # Performance
+/// This method takes O(N) time and consumes O(1) memory.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn merge_left<T: Ord>(elements: &mut [T], start: usize, left: usize, right: usize, buffer: usize) {
+    let left_end = start + left;
+    let end = left_end + right;
+
+    let mut output = buffer;
+    let mut current_left = start;
+    let mut current_right = left_end;
+
+    while current_right < end {
+        if current_left == left_end || elements[current_left] > elements[current_right] {
+            elements.swap(output, current_right);
+            current_right += 1;
+        } else {
+            elements.swap(output, current_left);
+            current_left += 1;
+        }
+
+        output += 1;
+    }
+
+    if output != current_left {
+        swap_blocks(elements, output, current_left, left_end - current_left);
+    }
+}
+
+/// Stably merge `left` elements after `start` with the `right` after them.
+///
+/// Sections are merged into the `buffer` elements after them, which is
+/// moved before the result.
+///
+/// # Performance
+/// This method takes O(N) time and consumes O(1) memory.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn merge_right<T: Ord>(elements: &mut [T], start: usize, left: usize, right: usize, buffer: usize) {
+    let left_end = start + left;
+
+    let mut current_left = left_end;
+    let mut current_right = left_end + right;
+    let mut output = current_right + buffer;
+
+    while current_left > start {
+        output -= 1;
+
+        if current_right == left_end || elements[current_left - 1] > elements[current_right - 1] {
+            current_left -= 1;
+            elements.swap(output, current_left);
+        } else {
+            current_right -= 1;
+            elements.swap(output, current_right);
+        }
+    }
+
+    if current_right != output {
+        while current_right > left_end {
+            output -= 1;
+            current_right -= 1;
+            elements.swap(output, current_right);
+        }
+    }
+}
+
+/// Stably merge `left` elements after `start` with the `right` after them.
+///
+/// # Performance
+/// This method takes O(min(left, right) * N) time and consumes O(1) memory,
+/// but only O(K * N) time where K is the number of distinct elements.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn merge_in_place<T: Ord>(elements: &mut [T], mut start: usize, mut left: usize, mut right: usize) {
+    if left < right {
+        while left != 0 {
+            let head = &elements[start];
+            let other = &elements[start + left..start + left + right];
+            let before = other.partition_point(|element| element < head);
+
+            if before != 0 {
+                elements[start..start + left + before].rotate_left(left);
+                start += before;
+                right -= before;
+            }
+
+            if right == 0 {
+                break;
+            }
+
+            loop {
+                start += 1;
+                left -= 1;
+
+                if left == 0 || elements[start] > elements[start + left] {
+                    break;
+                }
+            }
+        }
+    } else {
+        while right != 0 {
+            let tail = &elements[start + left + right - 1];
+            let before = elements[start..start + left].partition_point(|element| element <= tail);
+
+            if before != left {
+                elements[start + before..start + left + right].rotate_left(left - before);
+                left = before;
+            }
+
+            if left == 0 {
+                break;
+            }
+
+            loop {
+                right -= 1;
+
+                if right == 0 || elements[start + left - 1] > elements[start + left + right - 1] {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Swap the `count` elements starting at `left` with those at `right`.
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+fn swap_blocks<T>(elements: &mut [T], left: usize, right: usize, count: usize) {
+    for offset in 0..count {
+        elements.swap(left + offset, right + offset);
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::undocumented_unsafe_blocks,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::assertions_on_result_states,
+    clippy::indexing_slicing
+)]
+mod test {
+    use super::*;
+    use crate::algorithm::sort::test_support::{assert_sorts_correctly, pseudo_random};
+
+    mod block_sort {
+        use super::*;
+
+        /// Element ordered only by `key`, identified by `index`.
+        #[derive(Debug, Clone, Copy)]
+        struct Keyed {
+            /// The value which defines the ordering.
+            key: usize,
+
+            /// The original position, which does not affect ordering.
+            index: usize,
+        }
+
+        impl PartialEq for Keyed {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+
+        impl Eq for Keyed {}
+
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        /// Assert the output is that of [`slice::sort`], which is stable.
+        fn assert_stable(keys: impl Iterator<Item = usize>) {
+            let mut elements: Vec<_> = keys
+                .enumerate()
+                .map(|(index, key)| Keyed { key, index })
+                .collect();
+
+            let mut expected = elements.clone();
+            expected.sort();
+
+            block_sort(&mut elements);
+
+            assert!(
+                elements
+                    .iter()
+                    .map(|element| element.index)
+                    .eq(expected.iter().map(|element| element.index)),
+                "length {} was not stably sorted",
+                elements.len()
+            );
+        }
+
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(block_sort);
+        }
+
+        #[test]
+        fn empty() {
+            let mut elements: [usize; 0] = [];
+
+            block_sort(&mut elements);
+
+            assert_eq!(elements, []);
+        }
+
+        #[test]
+        fn single_element() {
+            let mut elements = [0];
+
+            block_sort(&mut elements);
+
+            assert_eq!(elements, [0]);
+        }
+
+        #[test]
+        fn already_sorted() {
+            let mut elements: [usize; 1000] = core::array::from_fn(|index| index);
+
+            block_sort(&mut elements);
+
+            assert!(elements.iter().copied().eq(0..1000));
+        }
+
+        #[test]
+        fn reversed() {
+            let mut elements: [usize; 1000] = core::array::from_fn(|index| 999 - index);
+
+            block_sort(&mut elements);
+
+            assert!(elements.iter().copied().eq(0..1000));
+        }
+
+        #[test]
+        fn is_stable_with_complete_buffer() {
+            // Every element is distinct, except for those with equal keys.
+            for length in [17, 64, 100, 257, 1000, 4097] {
+                assert_stable(pseudo_random(length).map(|key| key * 7 % 1000));
+            }
+        }
+
+        #[test]
+        fn is_stable_with_incomplete_buffer() {
+            // Too few distinct elements for a complete buffer.
+            for distinct in [4, 5, 8, 16, 31, 64] {
+                for length in [17, 100, 257, 1000, 4097] {
+                    assert_stable(pseudo_random(length).map(|key| key % distinct));
+                }
+            }
+        }
+
+        #[test]
+        fn is_stable_with_few_distinct() {
+            // Too few distinct elements to use a buffer at all.
+            for distinct in [1, 2, 3] {
+                for length in [17, 100, 257, 1000] {
+                    assert_stable(pseudo_random(length).map(|key| key % distinct));
+                }
+            }
+        }
+
+        #[test]
+        fn large_pseudo_random_input() {
+            let mut elements: Vec<_> = pseudo_random(10_000).collect();
+
+            let mut expected = elements.clone();
+            expected.sort_unstable();
+
+            block_sort(&mut elements);
+
+            assert_eq!(elements, expected);
+        }
+    }
+
+    mod sym_merge {
+        use super::*;
+
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(sym_merge);
+        }
+
+        #[test]
+        fn empty() {
+            let mut elements: [usize; 0] = [];
+
+            sym_merge(&mut elements);
+
+            assert_eq!(elements, []);
+        }
+
+        #[test]
+        fn single_element() {
+            let mut elements = [0];
+
+            sym_merge(&mut elements);
+
+            assert_eq!(elements, [0]);
+        }
+
+        #[test]
+        fn already_sorted() {
+            let mut elements = [0, 1, 2, 3, 4, 5];
+
+            sym_merge(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn must_swap() {
+            let mut elements = [1, 0];
+
+            sym_merge(&mut elements);
+
+            assert_eq!(elements, [0, 1]);
+        }
+
+        #[test]
+        fn odd_length() {
+            let mut elements = [2, 1, 0];
+
+            sym_merge(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2]);
+        }
+
+        #[test]
+        fn multiple_swaps() {
+            let mut elements = [2, 0, 3, 1];
+
+            sym_merge(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3]);
+        }
+
+        #[test]
+        fn multiple_blocks() {
+            let mut elements: [usize; 1000] = core::array::from_fn(|index| 999 - index);
+
+            sym_merge(&mut elements);
+
+            assert!(elements.iter().copied().eq(0..1000));
+        }
+
+        #[test]
+        fn is_stable() {
+            // Few distinct keys across many blocks, tagged by original index.
+            let mut elements: [(usize, usize); 1000] =
+                core::array::from_fn(|index| (index.wrapping_mul(7) % 5, index));
+
+            sym_merge(&mut elements);
+
+            for pair in elements.windows(2) {
+                assert!(pair[0].0 < pair[1].0 || (pair[0].0 == pair[1].0 && pair[0].1 < pair[1].1));
+            }
+        }
+
+        #[test]
+        fn large_pseudo_random_input() {
+            let mut elements: Vec<_> = pseudo_random(1000).collect();
+
+            let mut expected = elements.clone();
+            expected.sort_unstable();
+
+            sym_merge(&mut elements);
+
+            assert_eq!(elements, expected);
+        }
+    }
+}
@@ -44,10 +44,13 @@ pub fn naive<T: Ord>(elements: &mut [T]) {
 /// Fundamentally the same as the [`naive`] implementation, but takes advantage
 /// of the fact that if no swap occurs past some index, then the elements
 /// after that index were proven to be sorted by that iteration hence they do
-/// not need to be compared against in future iteration.
+/// not need to be compared against in future iteration. Moreover, a pass
+/// without any swaps proves all elements are sorted, so an already sorted
+/// input is only scanned once.
 ///
 /// # Performance
-/// This method takes O(N<sup>2</sup>) time and consumes O(1) memory.
+/// This method takes O(N<sup>2</sup>) time and consumes O(1) memory, but
+/// only O(N) time for an already sorted input.
 ///
 /// # Examples
 /// ```
@@ -299,10 +302,16 @@ pub fn comb<T: Ord>(elements: &mut [T]) {
 )]
 mod test {
     use super::*;
+    use crate::algorithm::sort::test_support::{assert_sorts_correctly, comparisons};
 
     mod naive {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(naive);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -361,6 +370,11 @@ mod test {
     mod optimized {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(optimized);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -414,11 +428,56 @@ mod test {
 
             assert_eq!(elements, [0, 1, 2, 3]);
         }
+
+        #[test]
+        fn shuffled() {
+            // Multiplying by a value coprime to the length yields a permutation.
+            let values: [usize; 256] = core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+
+            _ = comparisons(&values, |elements| optimized(elements));
+        }
+
+        #[test]
+        fn reversed() {
+            let values: [usize; 256] = core::array::from_fn(|index| 255 - index);
+
+            _ = comparisons(&values, |elements| optimized(elements));
+        }
+
+        #[test]
+        fn sorted_input_is_scanned_once() {
+            let values: [usize; 256] = core::array::from_fn(|index| index);
+
+            assert_eq!(comparisons(&values, |elements| optimized(elements)), 255);
+        }
+
+        #[test]
+        fn does_not_compare_sorted_suffix() {
+            // Only the first pair is out of order, so one swap then one pass.
+            let values = [1, 0, 2, 3, 4, 5];
+
+            assert_eq!(comparisons(&values, |elements| optimized(elements)), 5);
+        }
+
+        #[test]
+        fn fewer_comparisons_than_naive() {
+            let values: [usize; 256] = core::array::from_fn(|index| index);
+
+            let naive = comparisons(&values, |elements| naive(elements));
+            let optimized = comparisons(&values, |elements| optimized(elements));
+
+            assert!(optimized < naive);
+        }
     }
 
     mod bidirectional {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(bidirectional);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -477,6 +536,11 @@ mod test {
     mod parallel {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(parallel);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -535,6 +599,11 @@ mod test {
     mod comb {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(comb);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
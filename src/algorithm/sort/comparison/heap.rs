@@ -1,4 +1,11 @@
 //! Implementations of [Heap Sort](https://en.wikipedia.org/wiki/Heapsort).
+//!
+//! Alongside the sorting algorithms, the building blocks used to construct
+//! and maintain a binary max-heap within a slice are exposed such that other
+//! heap-based structures can reuse them. Within a slice, the children of the
+//! node at index `i` are located at indexes `2i + 1` and `2i + 2`.
+
+use core::cmp::Ordering;
 
 /// Sort `elements` via bottom-up heap sort.
 ///
@@ -22,10 +29,33 @@
 /// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
 /// ```
 pub fn bottom_up<T: Ord>(elements: &mut [T]) {
+    heapsort_by(elements, T::cmp);
+}
+
+/// Sort `elements` via bottom-up heap sort ordered by `compare`.
+///
+/// This is the same as [`bottom_up`] except elements are ordered by `compare`
+/// rather than [`Ord`], hence the largest element is that which compares
+/// [`Ordering::Greater`] than all others.
+///
+/// # Performance
+/// This method takes O(N * log N) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::heap::heapsort_by;
+///
+/// let mut elements = [0, 5, 2, 3, 1, 4];
+///
+/// heapsort_by(&mut elements, |left, right| right.cmp(left));
+///
+/// assert_eq!(elements, [5, 4, 3, 2, 1, 0]);
+/// ```
+pub fn heapsort_by<T>(elements: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) {
     // Order `elements` in max-heap order, hence `elements[0]` is the greatest.
-    construct_heap::bottom_up(elements);
+    construct_heap::bottom_up(elements, &mut compare);
 
-    for sorted in (0..elements.len()).rev() {
+    for sorted in (1..elements.len()).rev() {
         // Place the greatest element not yet sorted into sorted order.
         elements.swap(0, sorted);
 
@@ -34,7 +64,7 @@ pub fn bottom_up<T: Ord>(elements: &mut [T]) {
         };
 
         // Sift down the leaf into the max-heap (excluding sorted elements).
-        sift_down::bottom_up(heap);
+        sift_down::bottom_up(heap, 0, &mut compare);
     }
 }
 
@@ -91,11 +121,11 @@ pub fn inline<T: Ord>(elements: &mut [T]) {
             elements.swap(remaining_unsorted, 0);
         }
 
-        let Some(heap) = elements.get_mut(root..remaining_unsorted) else {
-            unreachable!("both bounds are less than the number of elements");
+        let Some(heap) = elements.get_mut(..remaining_unsorted) else {
+            unreachable!("the bound is less than the number of elements");
         };
 
-        sift_down::top_down(heap);
+        sift_down::top_down(heap, root, &mut T::cmp);
     }
 }
 
@@ -122,9 +152,9 @@ pub fn inline<T: Ord>(elements: &mut [T]) {
 /// ```
 pub fn top_down<T: Ord>(elements: &mut [T]) {
     // Order `elements` in max-heap order, hence `elements[0]` is the greatest.
-    construct_heap::top_down(elements);
+    construct_heap::top_down(elements, &mut T::cmp);
 
-    for sorted in (0..elements.len()).rev() {
+    for sorted in (1..elements.len()).rev() {
         // Place the greatest element not yet sorted into sorted order.
         elements.swap(0, sorted);
 
@@ -133,10 +163,146 @@ pub fn top_down<T: Ord>(elements: &mut [T]) {
         };
 
         // Sift down the leaf into the max-heap (excluding sorted elements).
-        sift_down::top_down(heap);
+        sift_down::top_down(heap, 0, &mut T::cmp);
     }
 }
 
+/// Arrange `elements` into max-heap (children less than parent) order.
+///
+/// This is Floyd's method which sifts down each parent starting from the last
+/// such that both children are already the root of a valid max-heap.
+///
+/// # Performance
+/// This method takes O(N) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::heap::heapify;
+///
+/// let mut elements = [0, 1, 2, 3, 4, 5];
+///
+/// heapify(&mut elements);
+///
+/// assert_eq!(elements[0], 5);
+/// assert!(elements[1] > elements[3] && elements[1] > elements[4]);
+/// assert!(elements[2] > elements[5]);
+/// ```
+pub fn heapify<T: Ord>(elements: &mut [T]) {
+    construct_heap::bottom_up(elements, &mut T::cmp);
+}
+
+/// Sift the element at `index` of a `max_heap` up to the correct position.
+///
+/// Swap the element with its parent until the parent is not less than it,
+/// thereby repairing a max-heap whose element at `index` may be too large.
+/// For example, an element can be inserted into a max-heap by appending it
+/// as the last leaf and then sifting it up.
+///
+/// # Panics
+/// This method has the precondition that `index` is within bounds.
+///
+/// # Performance
+/// This method takes O(log N) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::heap::sift_up;
+///
+/// let mut elements = [5, 4, 3, 2, 1, 6];
+///
+/// sift_up(&mut elements, 5);
+///
+/// assert_eq!(elements, [6, 4, 5, 2, 1, 3]);
+/// ```
+pub fn sift_up<T: Ord>(max_heap: &mut [T], index: usize) {
+    assert!(index < max_heap.len(), "index out of bounds");
+
+    sift_up_by(max_heap, index, &mut T::cmp);
+}
+
+/// Sift the element at `index` of a `max_heap` down to the correct position.
+///
+/// Swap the element with the greatest child until neither child is greater,
+/// thereby repairing a max-heap whose element at `index` may be too small.
+/// For example, the greatest element can be removed from a max-heap by
+/// swapping it with the last leaf, excluding it, and sifting down the root.
+///
+/// # Panics
+/// This method has the precondition that `index` is within bounds.
+///
+/// # Performance
+/// This method takes O(log N) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::heap::sift_down;
+///
+/// let mut elements = [0, 4, 5, 2, 1, 3];
+///
+/// sift_down(&mut elements, 0);
+///
+/// assert_eq!(elements, [5, 4, 3, 2, 1, 0]);
+/// ```
+pub fn sift_down<T: Ord>(max_heap: &mut [T], index: usize) {
+    assert!(index < max_heap.len(), "index out of bounds");
+
+    sift_down::top_down(max_heap, index, &mut T::cmp);
+}
+
+/// Arrange `elements` into max-heap order as defined by `compare`.
+///
+/// This is the same as [`heapify`] except elements are ordered by `compare`
+/// rather than [`Ord`], hence reversing the comparison yields a min-heap.
+///
+/// # Performance
+/// This method takes O(N) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::heap::heapify_by;
+///
+/// let mut elements = [5, 4, 3, 2, 1, 0];
+///
+/// heapify_by(&mut elements, |left, right| right.cmp(left));
+///
+/// assert_eq!(elements[0], 0);
+/// ```
+pub fn heapify_by<T>(elements: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) {
+    construct_heap::bottom_up(elements, &mut compare);
+}
+
+/// Sift the element at `index` of a `max_heap` ordered by `compare` down.
+///
+/// This is the same as [`sift_down`] except elements are ordered by `compare`
+/// rather than [`Ord`], hence the heap must have been constructed by the
+/// same comparison, for example via [`heapify_by`].
+///
+/// # Panics
+/// This method has the precondition that `index` is within bounds.
+///
+/// # Performance
+/// This method takes O(log N) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::heap::sift_down_by;
+///
+/// let mut elements = [5, 1, 0, 2, 3, 4];
+///
+/// sift_down_by(&mut elements, 0, |left, right| right.cmp(left));
+///
+/// assert_eq!(elements, [0, 1, 4, 2, 3, 5]);
+/// ```
+pub fn sift_down_by<T>(
+    max_heap: &mut [T],
+    index: usize,
+    mut compare: impl FnMut(&T, &T) -> Ordering,
+) {
+    assert!(index < max_heap.len(), "index out of bounds");
+
+    sift_down::top_down(max_heap, index, &mut compare);
+}
+
 /// Index of the left child of the node at `root` in a binary heap.
 ///
 /// # Performance
@@ -164,17 +330,14 @@ fn parent(child: usize) -> Option<usize> {
     child.checked_sub(1).map(|index| index / 2)
 }
 
-/// Sift the last leaf of a `max_heap` up to the correct position.
+/// Sift the element at `index` of a `max_heap` up to the correct position.
 ///
-/// Swap the leaf with its parent until the parent is greater.
+/// Swap the element with its parent until the parent is not less.
 ///
 /// # Performance
 /// This method takes O(log N) time and consumes O(1) memory.
-fn sift_up<T: Ord>(max_heap: &mut [T]) {
-    let Some(mut current_index) = max_heap.len().checked_sub(1) else {
-        debug_assert_eq!(max_heap.len(), 0, "only condition its none");
-        return;
-    };
+fn sift_up_by<T>(max_heap: &mut [T], index: usize, compare: &mut impl FnMut(&T, &T) -> Ordering) {
+    let mut current_index = index;
 
     while current_index > 0 {
         let Some(current_element) = max_heap.get(current_index) else {
@@ -189,7 +352,7 @@ fn sift_up<T: Ord>(max_heap: &mut [T]) {
             unreachable!("parent is between zero and current, thus in bounds");
         };
 
-        if parent_element < current_element {
+        if compare(parent_element, current_element) == Ordering::Less {
             max_heap.swap(current_index, parent_index);
             current_index = parent_index;
         } else {
@@ -203,16 +366,21 @@ mod sift_down {
     use super::left_child;
     use super::parent;
     use super::right_child;
+    use super::Ordering;
 
-    /// Sift the root of a binary `max_heap` down to the correct position.
+    /// Sift the element at `root` of a binary `max_heap` down to its position.
     ///
     /// Swap the current root with the greatest child until both children are
     /// less than that root, thereby repairing a max-heap with invalid root.
     ///
     /// # Performance
     /// This method takes O(log N) time and consumes O(1) memory.
-    pub(super) fn top_down<T: Ord>(max_heap: &mut [T]) {
-        let mut root_index = 0;
+    pub(super) fn top_down<T>(
+        max_heap: &mut [T],
+        root: usize,
+        compare: &mut impl FnMut(&T, &T) -> Ordering,
+    ) {
+        let mut root_index = root;
 
         loop {
             let (Some(left_child), Some(right_child)) =
@@ -223,7 +391,7 @@ mod sift_down {
 
             let child_index = match (max_heap.get(left_child), max_heap.get(right_child)) {
                 (Some(left), Some(right)) => {
-                    if left < right {
+                    if compare(left, right) == Ordering::Less {
                         right_child
                     } else {
                         left_child
@@ -240,7 +408,7 @@ mod sift_down {
                 unreachable!("in the loop => child exists => root exists");
             };
 
-            if root_element < child_element {
+            if compare(root_element, child_element) == Ordering::Less {
                 max_heap.swap(root_index, child_index);
                 root_index = child_index;
             } else {
@@ -249,7 +417,7 @@ mod sift_down {
         }
     }
 
-    /// Sift the root of a binary `max_heap` down to the correct position.
+    /// Sift the element at `root` of a binary `max_heap` down to its position.
     ///
     /// Traverse the heap down to the leaves (this is presumably where the
     /// current root value came from), and then traverse upward a node is found
@@ -260,8 +428,12 @@ mod sift_down {
     ///
     /// # Performance
     /// This method takes O(log N) time and consumes O(1) memory.
-    pub(super) fn bottom_up<T: Ord>(max_heap: &mut [T]) {
-        let mut current = 0;
+    pub(super) fn bottom_up<T>(
+        max_heap: &mut [T],
+        root: usize,
+        compare: &mut impl FnMut(&T, &T) -> Ordering,
+    ) {
+        let mut current = root;
 
         // Traverse down to leaf where the smallest possible value goes.
         loop {
@@ -272,7 +444,7 @@ mod sift_down {
 
             current = match (max_heap.get(left_child), max_heap.get(right_child)) {
                 (Some(left), Some(right)) => {
-                    if right > left {
+                    if compare(right, left) == Ordering::Greater {
                         right_child
                     } else {
                         left_child
@@ -286,7 +458,7 @@ mod sift_down {
 
         // Traverse upwards from that leaf to find where root should go.
         loop {
-            let Some(root) = max_heap.first() else {
+            let Some(root_element) = max_heap.get(root) else {
                 return;
             };
 
@@ -294,9 +466,9 @@ mod sift_down {
                 unreachable!("above loop will ensure within bounds");
             };
 
-            if root > element {
+            if compare(root_element, element) == Ordering::Greater {
                 let Some(parent) = parent(current) else {
-                    unreachable!("loop exits before `root == 0`");
+                    unreachable!("loop exits before reaching the root");
                 };
 
                 current = parent;
@@ -306,11 +478,11 @@ mod sift_down {
         }
 
         // Swap root into that position and propagate upwards.
-        while current > 0 {
-            max_heap.swap(0, current);
+        while current > root {
+            max_heap.swap(root, current);
 
             let Some(parent) = parent(current) else {
-                unreachable!("loop exits upon `root == 0`");
+                unreachable!("loop exits upon reaching the root");
             };
 
             current = parent;
@@ -321,23 +493,20 @@ mod sift_down {
 /// Construct a binary max-heap (also known as heapify).
 mod construct_heap {
     use super::sift_down;
-    use super::sift_up;
+    use super::sift_up_by;
+    use super::Ordering;
 
     /// Arrange `element` into max-heap (children less than parent) order.
     ///
     /// # Performance
     /// This method takes O(N) time and consumes O(1) memory.
-    pub(super) fn bottom_up<T: Ord>(elements: &mut [T]) {
+    pub(super) fn bottom_up<T>(elements: &mut [T], compare: &mut impl FnMut(&T, &T) -> Ordering) {
         // All leaves will be ordered when their parent is sifted down.
         let last_parent = elements.len() / 2;
 
-        for parent in (0..=last_parent).rev() {
-            let Some(heap) = elements.get_mut(parent..) else {
-                unreachable!("loop condition ensures in bounds");
-            };
-
-            // The children of `node` are already heap ordered, so sift down.
-            sift_down::top_down(heap);
+        for parent in (0..last_parent).rev() {
+            // The children of `parent` are already heap ordered, so sift down.
+            sift_down::top_down(elements, parent, compare);
         }
     }
 
@@ -345,14 +514,10 @@ mod construct_heap {
     ///
     /// # Performance
     /// This method takes O(N * log N) time and consumes O(1) memory.
-    pub(super) fn top_down<T: Ord>(elements: &mut [T]) {
-        for leaf in 1..=elements.len() {
-            let Some(heap) = elements.get_mut(..leaf) else {
-                unreachable!("loop condition ensures in bounds");
-            };
-
+    pub(super) fn top_down<T>(elements: &mut [T], compare: &mut impl FnMut(&T, &T) -> Ordering) {
+        for leaf in 1..elements.len() {
             // The ancestors of `leaf` are already heap ordered, so sift up.
-            sift_up(heap);
+            sift_up_by(elements, leaf, compare);
         }
     }
 }
@@ -367,10 +532,22 @@ mod construct_heap {
 )]
 mod test {
     use super::*;
+    use crate::algorithm::sort::test_support::{assert_sorts_correctly, pseudo_random};
+
+    /// Query if every parent within `elements` is not less than its children.
+    fn is_max_heap<T: Ord>(elements: &[T]) -> bool {
+        (1..elements.len())
+            .all(|child| parent(child).is_some_and(|parent| elements[parent] >= elements[child]))
+    }
 
     mod bottom_up {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(bottom_up);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -429,6 +606,11 @@ mod test {
     mod inline {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(inline);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -487,6 +669,11 @@ mod test {
     mod top_down {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(top_down);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -541,4 +728,243 @@ mod test {
             assert_eq!(elements, [0, 1, 2, 3]);
         }
     }
+
+    mod heapsort_by {
+        use super::*;
+
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(|elements| heapsort_by(elements, Ord::cmp));
+        }
+
+        #[test]
+        fn empty() {
+            let mut elements: [usize; 0] = [];
+
+            heapsort_by(&mut elements, usize::cmp);
+
+            assert_eq!(elements, []);
+        }
+
+        #[test]
+        fn descending_order() {
+            let mut elements = [2, 0, 5, 3, 1, 4];
+
+            heapsort_by(&mut elements, |left, right| right.cmp(left));
+
+            assert_eq!(elements, [5, 4, 3, 2, 1, 0]);
+        }
+
+        #[test]
+        fn by_key() {
+            let mut elements = [(2, 'a'), (0, 'b'), (1, 'c')];
+
+            heapsort_by(&mut elements, |left, right| left.0.cmp(&right.0));
+
+            assert_eq!(elements, [(0, 'b'), (1, 'c'), (2, 'a')]);
+        }
+    }
+
+    mod heapify {
+        use super::*;
+
+        #[test]
+        fn empty() {
+            let mut elements: [usize; 0] = [];
+
+            heapify(&mut elements);
+
+            assert_eq!(elements, []);
+        }
+
+        #[test]
+        fn single_element() {
+            let mut elements = [0];
+
+            heapify(&mut elements);
+
+            assert_eq!(elements, [0]);
+        }
+
+        #[test]
+        fn constructs_max_heap() {
+            for count in 0..128 {
+                let mut elements: [usize; 128] = [0; 128];
+                elements
+                    .iter_mut()
+                    .zip(pseudo_random(count))
+                    .for_each(|(actual, element)| *actual = element);
+
+                let elements = &mut elements[..count];
+
+                heapify(elements);
+
+                assert!(is_max_heap(elements));
+            }
+        }
+
+        #[test]
+        fn linear_comparisons() {
+            const ELEMENTS: usize = 1024;
+
+            let mut comparisons = 0_usize;
+
+            // Ascending order is the worst case, every parent sifts to a leaf.
+            let mut elements: [usize; ELEMENTS] = core::array::from_fn(|index| index);
+
+            construct_heap::bottom_up(&mut elements, &mut |left: &usize, right: &usize| {
+                comparisons = comparisons.wrapping_add(1);
+                left.cmp(right)
+            });
+
+            assert!(is_max_heap(&elements));
+            assert!(comparisons <= ELEMENTS.wrapping_mul(2));
+        }
+    }
+
+    mod sift_up {
+        use super::*;
+
+        #[test]
+        fn repairs_appended_leaf() {
+            let mut elements = [5, 3, 4, 1, 2, 6];
+
+            sift_up(&mut elements, 5);
+
+            assert!(is_max_heap(&elements));
+            assert_eq!(elements[0], 6);
+        }
+
+        #[test]
+        fn does_not_move_smaller_leaf() {
+            let mut elements = [5, 3, 4, 1, 2, 0];
+
+            sift_up(&mut elements, 5);
+
+            assert_eq!(elements, [5, 3, 4, 1, 2, 0]);
+        }
+
+        #[test]
+        fn constructs_heap_one_leaf_at_a_time() {
+            let mut elements = [0, 1, 2, 3, 4, 5];
+
+            for leaf in 0..elements.len() {
+                sift_up(&mut elements, leaf);
+            }
+
+            assert!(is_max_heap(&elements));
+        }
+
+        #[test]
+        #[should_panic = "index out of bounds"]
+        fn panics_when_index_out_of_bounds() {
+            let mut elements = [0, 1, 2];
+
+            sift_up(&mut elements, 3);
+        }
+    }
+
+    mod sift_down {
+        use super::*;
+
+        #[test]
+        fn repairs_root() {
+            let mut elements = [0, 4, 5, 2, 1, 3];
+
+            sift_down(&mut elements, 0);
+
+            assert!(is_max_heap(&elements));
+        }
+
+        #[test]
+        fn repairs_non_root() {
+            let mut elements = [9, 0, 8, 4, 5, 7, 6];
+
+            sift_down(&mut elements, 1);
+
+            assert_eq!(elements, [9, 5, 8, 4, 0, 7, 6]);
+        }
+
+        #[test]
+        fn does_not_move_greater_element() {
+            let mut elements = [5, 4, 3, 2, 1, 0];
+
+            sift_down(&mut elements, 0);
+
+            assert_eq!(elements, [5, 4, 3, 2, 1, 0]);
+        }
+
+        #[test]
+        #[should_panic = "index out of bounds"]
+        fn panics_when_index_out_of_bounds() {
+            let mut elements = [0, 1, 2];
+
+            sift_down(&mut elements, 3);
+        }
+    }
+
+    mod heapify_by {
+        use super::*;
+
+        #[test]
+        fn empty() {
+            let mut elements: [usize; 0] = [];
+
+            heapify_by(&mut elements, Ord::cmp);
+
+            assert_eq!(elements, []);
+        }
+
+        #[test]
+        fn constructs_max_heap_by_comparison() {
+            let mut elements: Vec<_> = pseudo_random(100).collect();
+
+            heapify_by(&mut elements, Ord::cmp);
+
+            assert!(is_max_heap(&elements));
+        }
+
+        #[test]
+        fn reversed_comparison_constructs_min_heap() {
+            let mut elements: Vec<_> = pseudo_random(100).collect();
+
+            heapify_by(&mut elements, |left, right| right.cmp(left));
+
+            let reversed: Vec<_> = elements.into_iter().map(core::cmp::Reverse).collect();
+
+            assert!(is_max_heap(&reversed));
+        }
+    }
+
+    mod sift_down_by {
+        use super::*;
+
+        #[test]
+        fn repairs_root() {
+            let mut elements = [5, 1, 0, 2, 3, 4];
+
+            sift_down_by(&mut elements, 0, |left, right| right.cmp(left));
+
+            assert_eq!(elements, [0, 1, 4, 2, 3, 5]);
+        }
+
+        #[test]
+        fn matches_sift_down_for_ord() {
+            let mut expected = [0, 4, 5, 2, 1, 3];
+            let mut actual = expected;
+
+            sift_down(&mut expected, 0);
+            sift_down_by(&mut actual, 0, Ord::cmp);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        #[should_panic = "index out of bounds"]
+        fn panics_when_index_out_of_bounds() {
+            let mut elements = [0, 1, 2];
+
+            sift_down_by(&mut elements, 3, Ord::cmp);
+        }
+    }
 }
@@ -93,14 +93,16 @@ pub fn recursive<T: Ord>(elements: &mut [T]) {
     }
 }
 
-/// Sort `elements` using recursive insertion sort.
+/// Sort `elements` using binary insertion sort.
 ///
 /// Note that this is non-stable meaning the order of equivalent elements is
 /// not preserved.
 ///
 /// Similar to [`iterative`] except binary search is used to locate the index
 /// within the already sorted section the next unsorted element should go,
-/// thereby making fewer comparisons.
+/// thereby making O(N * log N) comparisons rather than O(N<sup>2</sup>).
+/// Elements must still be shifted one at a time to make room, hence this is
+/// only beneficial when comparisons are expensive relative to moves.
 ///
 /// # Performance
 /// This method takes O(N<sup>2</sup>) time and consumes O(1) memory.
@@ -266,10 +268,16 @@ pub fn shell<T: Ord>(elements: &mut [T]) {
 )]
 mod test {
     use super::*;
+    use crate::algorithm::sort::test_support::{assert_sorts_correctly, comparisons};
 
     mod iterative {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(iterative);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -328,6 +336,11 @@ mod test {
     mod recursive {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(recursive);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -386,6 +399,11 @@ mod test {
     mod binary {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(binary);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -439,11 +457,36 @@ mod test {
 
             assert_eq!(elements, [0, 1, 2, 3]);
         }
+
+        #[test]
+        fn fewer_comparisons_than_iterative() {
+            // Multiplying by a value coprime to the length yields a permutation.
+            let values: [usize; 256] = core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+
+            let binary = comparisons(&values, |elements| binary(elements));
+            let iterative = comparisons(&values, |elements| iterative(elements));
+
+            assert!(binary < iterative);
+        }
+
+        #[test]
+        fn logarithmic_comparisons_per_element() {
+            // Multiplying by a value coprime to the length yields a permutation.
+            let values: [usize; 256] = core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+
+            // Each of the 256 elements is found within at most 8 comparisons.
+            assert!(comparisons(&values, |elements| binary(elements)) <= 256 * 8);
+        }
     }
 
     mod gnome {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(gnome);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -502,6 +545,11 @@ mod test {
     mod shell {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(shell);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -1,14 +1,18 @@
 //! Implementations of [Merge Sort](https://en.wikipedia.org/wiki/Merge_sort).
 
 use super::super::super::merge;
+use super::insertion;
+use super::INSERTION_CUTOFF;
 
 /// Sort `elements` via top-down merge sort.
 ///
-/// Recursively divide `elements` into two halves until each contains only
-/// a single element and is therefore in sorted order. Then merge both
-/// independently sorted halves together thereby sorting them into one
-/// larger sorted section which can be passed up the call stack to be merged
-/// with another.
+/// Recursively divide `elements` into two halves until each contains at
+/// most [`INSERTION_CUTOFF`] elements which are then sorted via
+/// [`insertion::iterative`]. Then merge both independently sorted halves
+/// together thereby sorting them into one larger sorted section which can be
+/// passed up the call stack to be merged with another.
+///
+/// See [`top_down_with_cutoff`] to use a different cutoff.
 ///
 /// # Panics
 /// This method has the precondition that `auxiliary` is a clone of `elements`.
@@ -28,9 +32,39 @@ use super::super::super::merge;
 /// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
 /// ```
 pub fn top_down<T: Ord>(elements: &mut [T], auxiliary: &mut [T]) {
+    top_down_with_cutoff(elements, auxiliary, INSERTION_CUTOFF);
+}
+
+/// Sort `elements` via top-down merge sort, switching to insertion sort for
+/// sections with at most `cutoff` elements.
+///
+/// See [`top_down`] for details. A `cutoff` of zero or one means sections
+/// are divided until they contain a single element, hence never uses
+/// insertion sort, whereas a `cutoff` of at least the number of elements
+/// means the entire input is sorted via insertion sort.
+///
+/// # Panics
+/// This method has the precondition that `auxiliary` is a clone of `elements`.
+///
+/// # Performance
+/// This method takes O(N * (log N + C)) time and consumes O(log N) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::merge::top_down_with_cutoff;
+///
+/// let mut elements = [0, 5, 2, 3, 1, 4];
+/// let mut auxiliary = elements.clone();
+///
+/// top_down_with_cutoff(&mut elements, &mut auxiliary, 2);
+///
+/// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+/// ```
+pub fn top_down_with_cutoff<T: Ord>(elements: &mut [T], auxiliary: &mut [T], cutoff: usize) {
     debug_assert!(elements == auxiliary, "auxiliary must be clone of elements");
 
-    if elements.len() <= 1 {
+    if elements.len() <= cutoff.max(1) {
+        insertion::iterative(elements);
         return;
     }
 
@@ -38,8 +72,8 @@ pub fn top_down<T: Ord>(elements: &mut [T], auxiliary: &mut [T]) {
     let (left_auxiliary, right_auxiliary) = auxiliary.split_at_mut(auxiliary.len() / 2);
 
     // Alternating input/auxiliary ensures top-level caller merges into output.
-    top_down(left_auxiliary, left_input);
-    top_down(right_auxiliary, right_input);
+    top_down_with_cutoff(left_auxiliary, left_input, cutoff);
+    top_down_with_cutoff(right_auxiliary, right_input, cutoff);
 
     merge::iterative(left_auxiliary, right_auxiliary, elements);
 }
@@ -143,7 +177,8 @@ pub fn bottom_up<T: Ord>(elements: &mut [T], auxiliary: &mut [T]) {
         let auxiliary = auxiliary.chunks_mut(length);
 
         for (input, output) in elements.zip(auxiliary) {
-            let (left, right) = input.split_at_mut(length / 2);
+            // The last chunk might not contain a right section to merge.
+            let (left, right) = input.split_at_mut((length / 2).min(input.len()));
 
             merge::iterative(left, right, output);
 
@@ -207,10 +242,13 @@ pub fn in_place<T: Ord>(elements: &mut [T]) {
         second: core::ops::Range<usize>,
         output: usize,
     ) {
+        // Only as many elements as will be merged are output.
+        let end = output + first.len() + second.len();
+
         let mut first = first.peekable();
         let mut second = second.peekable();
 
-        for output_index in output..elements.len() {
+        for output_index in output..end {
             let input_index = match (first.peek(), second.peek()) {
                 (Some(first_index), Some(second_index)) => {
                     if elements[*first_index] < elements[*second_index] {
@@ -241,6 +279,8 @@ pub fn in_place<T: Ord>(elements: &mut [T]) {
             in_place(left);
             in_place(right);
 
+            let middle = range.start + middle;
+
             merge(elements, range.start..middle, middle..range.end, output);
         } else {
             elements.swap(output, range.start);
@@ -266,7 +306,7 @@ pub fn in_place<T: Ord>(elements: &mut [T]) {
         // Unsorted: [..output]
         // To be sorted [output..split]
         // Already sorted: [split..]
-        output = (split + 1) / 2;
+        output = split.div_ceil(2);
 
         // Sort [output..split] into [..output]
         sort_into(elements, output..split, 0);
@@ -298,10 +338,53 @@ pub fn in_place<T: Ord>(elements: &mut [T]) {
 )]
 mod test {
     use super::*;
+    use crate::algorithm::sort::test_support::{assert_sorts_correctly, comparisons, Counted};
+
+    /// Count how many comparisons `sort` makes to sort `N` reversed elements.
+    fn reversed<const N: usize>(
+        sort: impl for<'a> FnOnce(&mut [Counted<'a>], &mut [Counted<'a>]),
+    ) -> usize {
+        let values: [usize; N] =
+            core::array::from_fn(|index| N.wrapping_sub(1).wrapping_sub(index));
+
+        comparisons(&values, |elements| {
+            let mut auxiliary = elements.to_vec();
+
+            sort(elements, &mut auxiliary);
+        })
+    }
 
     mod top_down {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(|elements| {
+                let mut auxiliary = elements.to_vec();
+
+                top_down(elements, &mut auxiliary);
+            });
+        }
+
+        #[test]
+        fn small_input_uses_insertion_sort() {
+            let expected =
+                reversed::<INSERTION_CUTOFF>(|elements, _| insertion::iterative(elements));
+            let actual = reversed::<INSERTION_CUTOFF>(|elements, auxiliary| {
+                top_down(elements, auxiliary);
+            });
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn large_input_is_merged() {
+            let insertion = reversed::<256>(|elements, _| insertion::iterative(elements));
+            let merge = reversed::<256>(|elements, auxiliary| top_down(elements, auxiliary));
+
+            assert!(merge < insertion);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -381,9 +464,134 @@ mod test {
         }
     }
 
+    mod top_down_with_cutoff {
+        use super::*;
+
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(|elements| {
+                for cutoff in [0, 1, 2, INSERTION_CUTOFF, usize::MAX] {
+                    let mut auxiliary = elements.to_vec();
+
+                    top_down_with_cutoff(elements, &mut auxiliary, cutoff);
+                }
+            });
+        }
+
+        #[test]
+        fn default_cutoff() {
+            let expected = reversed::<256>(|elements, auxiliary| top_down(elements, auxiliary));
+            let actual = reversed::<256>(|elements, auxiliary| {
+                top_down_with_cutoff(elements, auxiliary, INSERTION_CUTOFF);
+            });
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn raised_cutoff_uses_insertion_sort() {
+            let expected = reversed::<64>(|elements, _| insertion::iterative(elements));
+            let default = reversed::<64>(|elements, auxiliary| top_down(elements, auxiliary));
+            let raised = reversed::<64>(|elements, auxiliary| {
+                top_down_with_cutoff(elements, auxiliary, 64);
+            });
+
+            assert_ne!(default, expected);
+            assert_eq!(raised, expected);
+        }
+
+        #[test]
+        fn zero_cutoff_never_uses_insertion_sort() {
+            let zero = reversed::<64>(|elements, auxiliary| {
+                top_down_with_cutoff(elements, auxiliary, 0);
+            });
+
+            let one = reversed::<64>(|elements, auxiliary| {
+                top_down_with_cutoff(elements, auxiliary, 1);
+            });
+
+            // Merging reversed halves takes only as many comparisons as the
+            // length of the left half, hence (N / 2) * log N in total.
+            assert_eq!(zero, 32 * 6);
+            assert_eq!(one, zero);
+        }
+
+        #[test]
+        fn is_correct_for_any_cutoff() {
+            for cutoff in [0, 1, 2, 3, 7, 16, 33, 100] {
+                let mut elements: [usize; 100] =
+                    core::array::from_fn(|index| index.wrapping_mul(37) % 100);
+                let mut auxiliary = elements;
+
+                top_down_with_cutoff(&mut elements, &mut auxiliary, cutoff);
+
+                assert!(elements.iter().copied().eq(0..100));
+            }
+        }
+
+        #[test]
+        fn is_stable_for_any_cutoff() {
+            /// Element ordered only by `key`, identified by `index`.
+            #[derive(Debug, Clone, Copy)]
+            struct Keyed {
+                /// The value which defines the ordering.
+                key: usize,
+
+                /// The original position, which does not affect ordering.
+                index: usize,
+            }
+
+            impl PartialEq for Keyed {
+                fn eq(&self, other: &Self) -> bool {
+                    self.key == other.key && self.index == other.index
+                }
+            }
+
+            impl Eq for Keyed {}
+
+            impl PartialOrd for Keyed {
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for Keyed {
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.key.cmp(&other.key)
+                }
+            }
+
+            for cutoff in [0, 1, 4, 16, 64] {
+                let mut elements: [Keyed; 64] = core::array::from_fn(|index| Keyed {
+                    key: index.wrapping_mul(7) % 3,
+                    index,
+                });
+                let mut auxiliary = elements;
+
+                top_down_with_cutoff(&mut elements, &mut auxiliary, cutoff);
+
+                for pair in elements.windows(2) {
+                    assert!(
+                        pair[0].key < pair[1].key
+                            || (pair[0].key == pair[1].key && pair[0].index < pair[1].index)
+                    );
+                }
+            }
+        }
+    }
+
     mod natural {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(|elements| {
+                let mut auxiliary = elements.to_vec();
+
+                natural(elements, &mut auxiliary);
+            });
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -466,6 +674,15 @@ mod test {
     mod bottom_up {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(|elements| {
+                let mut auxiliary = elements.to_vec();
+
+                bottom_up(elements, &mut auxiliary);
+            });
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
@@ -548,6 +765,11 @@ mod test {
     mod in_place {
         use super::*;
 
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(in_place);
+        }
+
         #[test]
         fn empty() {
             let mut elements: [usize; 0] = [];
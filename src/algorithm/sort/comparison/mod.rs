@@ -1,6 +1,16 @@
 //! Sorting relying on comparison ([`Ord`] types) rather than tricks.
 
+pub mod block;
 pub mod bubble;
 pub mod heap;
 pub mod insertion;
 pub mod merge;
+pub mod selection;
+
+/// Sections with at most this many elements are sorted via insertion sort.
+///
+/// Divide-and-conquer algorithms recurse until sections are small, at which
+/// point the overhead of further recursion outweighs the quadratic time of
+/// [`insertion::iterative`], hence they instead switch to it. Algorithms
+/// which do so provide a `_with_cutoff` variant to override this per call.
+pub const INSERTION_CUTOFF: usize = 16;
@@ -0,0 +1,330 @@
+//! Implementations of [Selection Sort](https://en.wikipedia.org/wiki/Selection_sort).
+
+/// Sort `elements` using naive selection sort.
+///
+/// Iteratively search the yet to be sorted section for the smallest element
+/// and swap it to the front of that section thereby placing it into sorted
+/// position until all elements have been selected.
+///
+/// Note that this is non-stable meaning the order of equivalent elements is
+/// not preserved.
+///
+/// # Performance
+/// This method takes O(N<sup>2</sup>) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::selection::naive;
+///
+/// let mut elements = [0, 5, 2, 3, 1, 4];
+///
+/// naive(&mut elements);
+///
+/// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+/// ```
+pub fn naive<T: Ord>(elements: &mut [T]) {
+    for sorted_position in 0..elements.len() {
+        let Some(unsorted) = elements.get(sorted_position..) else {
+            unreachable!("loop ensures within bounds");
+        };
+
+        let Some((smallest, _)) = unsorted
+            .iter()
+            .enumerate()
+            .min_by(|&(_, left), &(_, right)| left.cmp(right))
+        else {
+            unreachable!("loop ensures there is at least one element");
+        };
+
+        let Some(smallest) = sorted_position.checked_add(smallest) else {
+            unreachable!("index of an element within the slice");
+        };
+
+        elements.swap(sorted_position, smallest);
+    }
+}
+
+/// Sort `elements` using double-ended (min-max) selection sort.
+///
+/// Similar to [`naive`] except both the smallest and largest element of the
+/// yet to be sorted section are searched for in each pass, swapping them to
+/// the front and back of that section respectively. This means only about
+/// half as many passes are required. Furthermore, elements are considered in
+/// pairs such that only the smaller of each pair is compared against the
+/// smallest so far and only the larger against the largest so far, thereby
+/// requiring about three comparisons for every two elements rather than four.
+///
+/// Note that this is non-stable meaning the order of equivalent elements is
+/// not preserved.
+///
+/// # Performance
+/// This method takes O(N<sup>2</sup>) time and consumes O(1) memory.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::comparison::selection::min_max;
+///
+/// let mut elements = [0, 5, 2, 3, 1, 4];
+///
+/// min_max(&mut elements);
+///
+/// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+/// ```
+#[allow(clippy::indexing_slicing)]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn min_max<T: Ord>(elements: &mut [T]) {
+    let mut unsorted = 0..elements.len();
+
+    while unsorted.len() > 1 {
+        let first = unsorted.start;
+        let last = unsorted.end - 1;
+
+        // The indexes of the smallest and largest elements found so far.
+        let (mut smallest, mut largest) = if elements[last] < elements[first] {
+            (last, first)
+        } else {
+            (first, last)
+        };
+
+        let mut current = first + 1;
+
+        while current < last {
+            let next = current + 1;
+
+            if next < last {
+                let (smaller, larger) = if elements[next] < elements[current] {
+                    (next, current)
+                } else {
+                    (current, next)
+                };
+
+                if elements[smaller] < elements[smallest] {
+                    smallest = smaller;
+                }
+
+                if elements[larger] > elements[largest] {
+                    largest = larger;
+                }
+
+                current += 2;
+            } else {
+                // An odd number of elements leaves one without a pair.
+                if elements[current] < elements[smallest] {
+                    smallest = current;
+                }
+
+                if elements[current] > elements[largest] {
+                    largest = current;
+                }
+
+                current += 1;
+            }
+        }
+
+        elements.swap(first, smallest);
+
+        // If the largest was at the front, it was just swapped away.
+        if largest == first {
+            largest = smallest;
+        }
+
+        elements.swap(last, largest);
+
+        unsorted = (first + 1)..last;
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::undocumented_unsafe_blocks,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::assertions_on_result_states,
+    clippy::indexing_slicing
+)]
+mod test {
+    use super::*;
+    use crate::algorithm::sort::test_support::{assert_sorts_correctly, comparisons};
+
+    mod naive {
+        use super::*;
+
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(naive);
+        }
+
+        #[test]
+        fn empty() {
+            let mut elements: [usize; 0] = [];
+
+            naive(&mut elements);
+
+            assert_eq!(elements, []);
+        }
+
+        #[test]
+        fn single_element() {
+            let mut elements = [0];
+
+            naive(&mut elements);
+
+            assert_eq!(elements, [0]);
+        }
+
+        #[test]
+        fn already_sorted() {
+            let mut elements = [0, 1, 2, 3, 4, 5];
+
+            naive(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn must_swap() {
+            let mut elements = [1, 0];
+
+            naive(&mut elements);
+
+            assert_eq!(elements, [0, 1]);
+        }
+
+        #[test]
+        fn odd_length() {
+            let mut elements = [2, 1, 0];
+
+            naive(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2]);
+        }
+
+        #[test]
+        fn multiple_swaps() {
+            let mut elements = [2, 0, 3, 1];
+
+            naive(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3]);
+        }
+    }
+
+    mod min_max {
+        use super::*;
+
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(min_max);
+        }
+
+        #[test]
+        fn empty() {
+            let mut elements: [usize; 0] = [];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, []);
+        }
+
+        #[test]
+        fn single_element() {
+            let mut elements = [0];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0]);
+        }
+
+        #[test]
+        fn already_sorted() {
+            let mut elements = [0, 1, 2, 3, 4, 5];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn must_swap() {
+            let mut elements = [1, 0];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0, 1]);
+        }
+
+        #[test]
+        fn odd_length() {
+            let mut elements = [2, 1, 0];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2]);
+        }
+
+        #[test]
+        fn multiple_swaps() {
+            let mut elements = [2, 0, 3, 1];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3]);
+        }
+
+        #[test]
+        fn reverse_order() {
+            let mut elements = [7, 6, 5, 4, 3, 2, 1, 0];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3, 4, 5, 6, 7]);
+        }
+
+        #[test]
+        fn largest_where_smallest_goes() {
+            let mut elements = [5, 1, 0, 3, 2, 4];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn smallest_where_largest_goes() {
+            let mut elements = [3, 1, 5, 2, 4, 0];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn duplicates() {
+            let mut elements = [2, 0, 2, 1, 0, 1, 2];
+
+            min_max(&mut elements);
+
+            assert_eq!(elements, [0, 0, 1, 1, 2, 2, 2]);
+        }
+
+        #[test]
+        fn shuffled() {
+            // Multiplying by a value coprime to the length yields a permutation.
+            let values: [usize; 256] = core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+            _ = comparisons(&values, |elements| min_max(elements));
+        }
+
+        #[test]
+        fn fewer_comparisons_than_naive() {
+            // Multiplying by a value coprime to the length yields a permutation.
+            let values: [usize; 256] = core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+
+            let naive = comparisons(&values, |elements| naive(elements));
+            let min_max = comparisons(&values, |elements| min_max(elements));
+
+            // Three comparisons per two elements, over half as many passes,
+            // hence approaching three-quarters as many comparisons.
+            assert!(min_max.wrapping_mul(5) <= naive.wrapping_mul(4));
+        }
+    }
+}
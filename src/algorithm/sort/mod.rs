@@ -1,3 +1,315 @@
 //! Produce an ordered list of items from a collection.
 
 pub mod comparison;
+
+#[cfg(test)]
+mod test_support;
+
+use comparison::{block, heap, insertion, INSERTION_CUTOFF};
+
+/// Inputs with at most one descent per this many elements are partially ordered.
+pub const PARTIALLY_ORDERED_RATIO: usize = 16;
+
+/// Sort `elements` by choosing an algorithm suitable for the input.
+///
+/// Inputs with at most [`INSERTION_CUTOFF`] elements are sorted via
+/// [`insertion::iterative`] which has the least overhead for small inputs.
+/// Otherwise, the input is scanned once to count descents (adjacent elements
+/// which are out of order), returning immediately if there are none. Inputs
+/// with at most one descent per [`PARTIALLY_ORDERED_RATIO`] elements are
+/// considered partially ordered and sorted via [`block::sym_merge`] which
+/// does little work merging already ordered blocks. All other inputs are
+/// sorted via [`heap::bottom_up`] which guarantees O(N * log N) time.
+///
+/// See [`sort_with_cutoff`] to use a different cutoff.
+///
+/// Note that this is non-stable, since which algorithm is chosen depends on
+/// the input, the order of equivalent elements is not preserved.
+///
+/// # Performance
+/// This method takes O(N * log<sup>2</sup> N) time and consumes O(log N)
+/// memory, without allocating.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::sort;
+///
+/// let mut elements = [0, 5, 2, 3, 1, 4];
+///
+/// sort(&mut elements);
+///
+/// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+/// ```
+pub fn sort<T: Ord>(elements: &mut [T]) {
+    sort_with_cutoff(elements, INSERTION_CUTOFF);
+}
+
+/// Sort `elements` by choosing an algorithm suitable for the input, using
+/// insertion sort for inputs with at most `cutoff` elements.
+///
+/// See [`sort`] for details.
+///
+/// # Performance
+/// This method takes O(N * log<sup>2</sup> N + C<sup>2</sup>) time and
+/// consumes O(log N) memory, without allocating.
+///
+/// # Examples
+/// ```
+/// use rust::algorithm::sort::sort_with_cutoff;
+///
+/// let mut elements = [0, 5, 2, 3, 1, 4];
+///
+/// sort_with_cutoff(&mut elements, 2);
+///
+/// assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+/// ```
+pub fn sort_with_cutoff<T: Ord>(elements: &mut [T], cutoff: usize) {
+    if elements.len() <= cutoff {
+        insertion::iterative(elements);
+        return;
+    }
+
+    let descents = elements
+        .windows(2)
+        .filter(|pair| matches!(pair, [previous, current] if current < previous))
+        .count();
+
+    if descents == 0 {
+        return;
+    }
+
+    if descents <= elements.len() / PARTIALLY_ORDERED_RATIO {
+        block::sym_merge(elements);
+    } else {
+        heap::bottom_up(elements);
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::undocumented_unsafe_blocks,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::assertions_on_result_states,
+    clippy::indexing_slicing
+)]
+mod test {
+    use super::*;
+    use crate::algorithm::sort::test_support::{assert_sorts_correctly, comparisons};
+
+    mod sort {
+        use super::*;
+
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(sort);
+        }
+
+        #[test]
+        fn empty() {
+            let mut elements: [usize; 0] = [];
+
+            sort(&mut elements);
+
+            assert_eq!(elements, []);
+        }
+
+        #[test]
+        fn single_element() {
+            let mut elements = [0];
+
+            sort(&mut elements);
+
+            assert_eq!(elements, [0]);
+        }
+
+        #[test]
+        fn already_sorted() {
+            let mut elements = [0, 1, 2, 3, 4, 5];
+
+            sort(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn must_swap() {
+            let mut elements = [1, 0];
+
+            sort(&mut elements);
+
+            assert_eq!(elements, [0, 1]);
+        }
+
+        #[test]
+        fn odd_length() {
+            let mut elements = [2, 1, 0];
+
+            sort(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2]);
+        }
+
+        #[test]
+        fn multiple_swaps() {
+            let mut elements = [2, 0, 3, 1];
+
+            sort(&mut elements);
+
+            assert_eq!(elements, [0, 1, 2, 3]);
+        }
+
+        #[test]
+        fn shuffled() {
+            // Multiplying by a value coprime to the length yields a permutation.
+            let mut elements: [usize; 256] =
+                core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+
+            sort(&mut elements);
+
+            assert!(elements.iter().copied().eq(0..256));
+        }
+
+        #[test]
+        fn reversed() {
+            let mut elements: [usize; 256] = core::array::from_fn(|index| 255 - index);
+
+            sort(&mut elements);
+
+            assert!(elements.iter().copied().eq(0..256));
+        }
+
+        #[test]
+        fn partially_ordered() {
+            let mut elements: [usize; 256] = core::array::from_fn(|index| index);
+
+            elements.swap(10, 200);
+            elements.swap(50, 100);
+
+            sort(&mut elements);
+
+            assert!(elements.iter().copied().eq(0..256));
+        }
+
+        #[test]
+        fn duplicates() {
+            let mut elements: [usize; 256] = core::array::from_fn(|index| index % 3);
+
+            sort(&mut elements);
+
+            assert!(elements.is_sorted());
+        }
+
+        #[test]
+        fn small_input_uses_insertion_sort() {
+            let values = [7, 6, 5, 4, 3, 2, 1, 0];
+
+            let expected = comparisons(&values, |elements| insertion::iterative(elements));
+            let actual = comparisons(&values, |elements| sort(elements));
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn cutoff_length_uses_insertion_sort() {
+            let values: [usize; INSERTION_CUTOFF] =
+                core::array::from_fn(|index| INSERTION_CUTOFF - 1 - index);
+
+            let expected = comparisons(&values, |elements| insertion::iterative(elements));
+            let actual = comparisons(&values, |elements| sort(elements));
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn sorted_input_is_only_scanned() {
+            let values: [usize; 256] = core::array::from_fn(|index| index);
+
+            assert_eq!(comparisons(&values, |elements| sort(elements)), 255);
+        }
+
+        #[test]
+        fn partially_ordered_uses_block_sort() {
+            let mut values: [usize; 256] = core::array::from_fn(|index| index);
+
+            values.swap(10, 200);
+
+            let scan = values.len() - 1;
+            let expected = comparisons(&values, |elements| block::sym_merge(elements));
+            let actual = comparisons(&values, |elements| sort(elements));
+
+            assert_eq!(actual, scan + expected);
+        }
+
+        #[test]
+        fn unordered_uses_heap_sort() {
+            let values: [usize; 256] = core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+
+            let scan = values.len() - 1;
+            let expected = comparisons(&values, |elements| heap::bottom_up(elements));
+            let actual = comparisons(&values, |elements| sort(elements));
+
+            assert_eq!(actual, scan + expected);
+        }
+    }
+
+    mod sort_with_cutoff {
+        use super::*;
+
+        #[test]
+        fn sorts_correctly() {
+            assert_sorts_correctly(|elements| {
+                for cutoff in [0, 1, 2, INSERTION_CUTOFF, usize::MAX] {
+                    sort_with_cutoff(elements, cutoff);
+                }
+            });
+        }
+
+        #[test]
+        fn default_cutoff() {
+            let values: [usize; 256] = core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+
+            let expected = comparisons(&values, |elements| sort(elements));
+            let actual = comparisons(&values, |elements| {
+                sort_with_cutoff(elements, INSERTION_CUTOFF);
+            });
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn raised_cutoff_uses_insertion_sort() {
+            let values: [usize; 64] = core::array::from_fn(|index| index.wrapping_mul(37) % 64);
+
+            let expected = comparisons(&values, |elements| insertion::iterative(elements));
+            let default = comparisons(&values, |elements| sort(elements));
+            let raised = comparisons(&values, |elements| sort_with_cutoff(elements, 64));
+
+            assert_ne!(default, expected);
+            assert_eq!(raised, expected);
+        }
+
+        #[test]
+        fn lowered_cutoff_uses_heap_sort() {
+            let values = [7, 6, 5, 4, 3, 2, 1, 0];
+
+            let scan = values.len() - 1;
+            let expected = comparisons(&values, |elements| heap::bottom_up(elements));
+            let actual = comparisons(&values, |elements| sort_with_cutoff(elements, 0));
+
+            assert_eq!(actual, scan + expected);
+        }
+
+        #[test]
+        fn is_correct_for_any_cutoff() {
+            for cutoff in [0, 1, 2, 16, 100, 256, usize::MAX] {
+                let mut elements: [usize; 256] =
+                    core::array::from_fn(|index| index.wrapping_mul(97) % 256);
+
+                sort_with_cutoff(&mut elements, cutoff);
+
+                assert!(elements.iter().copied().eq(0..256));
+            }
+        }
+    }
+}
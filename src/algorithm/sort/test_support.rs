@@ -0,0 +1,122 @@
+//! Utilities shared by the tests of sorting algorithms.
+
+/// The lengths of input to sort, around boundaries algorithms special-case.
+const LENGTHS: [usize; 11] = [0, 1, 2, 3, 7, 15, 16, 17, 64, 100, 257];
+
+/// Assert `sort` orders every kind of input into a permutation of itself.
+///
+/// Inputs of each length within [`LENGTHS`] are generated which are
+/// pseudo-random, already sorted, reversed, all equal, or contain only a few
+/// distinct values. The output of `sort` must be ordered, and must equal
+/// that of [`slice::sort_unstable`] which implies it contains exactly the same
+/// elements as the input.
+pub(crate) fn assert_sorts_correctly(mut sort: impl FnMut(&mut [usize])) {
+    for length in LENGTHS {
+        for (kind, input) in inputs(length) {
+            let mut expected = input.clone();
+            expected.sort_unstable();
+
+            let mut actual = input;
+            sort(&mut actual);
+
+            assert!(
+                actual.is_sorted(),
+                "{kind} input of length {length} was not sorted: {actual:?}"
+            );
+
+            assert_eq!(
+                actual, expected,
+                "{kind} input of length {length} was not a permutation"
+            );
+        }
+    }
+}
+
+/// Each kind of input with `length` many elements, alongside its name.
+fn inputs(length: usize) -> [(&'static str, Vec<usize>); 5] {
+    [
+        ("pseudo-random", pseudo_random(length).collect()),
+        ("sorted", (0..length).collect()),
+        ("reversed", (0..length).rev().collect()),
+        ("all equal", core::iter::repeat_n(7, length).collect()),
+        (
+            "few distinct",
+            pseudo_random(length).map(|element| element % 4).collect(),
+        ),
+    ]
+}
+
+/// Pseudo-random elements with no discernible order and some duplicates.
+pub(crate) fn pseudo_random(count: usize) -> impl Iterator<Item = usize> {
+    let mut state: u64 = 0;
+
+    (0..count).map(move |_| {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+
+        let Ok(element) = usize::try_from((state >> 33) % 1000) else {
+            unreachable!("less than one thousand");
+        };
+
+        element
+    })
+}
+
+/// Element which counts how many times it has been compared.
+#[derive(Debug, Clone)]
+pub(crate) struct Counted<'a> {
+    /// The underlying value which defines the ordering.
+    pub(crate) value: usize,
+
+    /// The number of comparisons between any [`Counted`] sharing this.
+    comparisons: &'a core::cell::Cell<usize>,
+}
+
+impl PartialEq for Counted<'_> {
+    /// Not counted, so debug assertions do not affect the result.
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Counted<'_> {}
+
+impl PartialOrd for Counted<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Counted<'_> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.comparisons.set(self.comparisons.get().wrapping_add(1));
+
+        self.value.cmp(&other.value)
+    }
+}
+
+/// Count how many comparisons `sort` makes to sort `values`.
+///
+/// # Panics
+/// If `sort` does not order the elements.
+pub(crate) fn comparisons(values: &[usize], sort: impl FnOnce(&mut [Counted<'_>])) -> usize {
+    let comparisons = core::cell::Cell::new(0);
+
+    let mut elements: Vec<_> = values
+        .iter()
+        .map(|&value| Counted {
+            value,
+            comparisons: &comparisons,
+        })
+        .collect();
+
+    sort(&mut elements);
+
+    assert!(
+        elements.iter().map(|element| element.value).is_sorted(),
+        "elements were not sorted"
+    );
+
+    comparisons.get()
+}
@@ -0,0 +1,693 @@
+//! Implementation of [`BitSet`].
+
+use super::linear::array::Dynamic;
+use super::Collection;
+use super::Linear;
+
+/// Booleans packed together such that each occupies a single bit.
+///
+/// Unlike a [`Dynamic<bool>`] which (typically) occupies an entire byte for
+/// each boolean, this stores 64 booleans within each [`u64`] word thereby
+/// consuming approximately one-eighth of the memory. Each boolean is referred
+/// to as a bit which is said to be 'set' if it is `true`, otherwise it is
+/// 'clear' if it is `false`. The bit at some index is located within the word
+/// at `index / 64` offset by `index % 64` bits from the least significant bit
+/// as visualized below:
+///
+/// ```text
+/// +--------------------+----------------------+-----+
+/// | word 0: bits 0..64 | word 1: bits 64..128 | ... |
+/// +--------------------+----------------------+-----+
+/// ```
+///
+/// Any bits within the last word which are past the end are always clear,
+/// hence entire words can be operated on at once.
+///
+/// See also: [Wikipedia](https://en.wikipedia.org/wiki/Bit_array).
+#[derive(Clone, Default)]
+pub struct BitSet {
+    /// The underlying words each containing [`Self::BITS`] many bits.
+    words: Dynamic<u64>,
+
+    /// The number of bits contained.
+    count: usize,
+}
+
+impl BitSet {
+    /// The number of bits contained within each word.
+    const BITS: usize = u64::BITS as usize;
+
+    /// Construct an instance containing `count` many clear bits.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Collection;
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let instance = BitSet::new(256);
+    ///
+    /// assert_eq!(instance.count(), 256);
+    /// assert_eq!(instance.count_ones(), 0);
+    /// ```
+    #[must_use]
+    pub fn new(count: usize) -> Self {
+        Self {
+            words: core::iter::repeat_n(0, count.div_ceil(Self::BITS)).collect(),
+            count,
+        }
+    }
+
+    /// Query if the bit at `index` is set, bounds checked.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(256);
+    ///
+    /// _ = instance.set(128);
+    ///
+    /// assert_eq!(instance.get(127), Some(false));
+    /// assert_eq!(instance.get(128), Some(true));
+    /// assert_eq!(instance.get(256), None);
+    /// ```
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index >= self.count {
+            return None;
+        }
+
+        let (word, mask) = Self::locate(index);
+
+        self.words.at(word).map(|word| word & mask != 0)
+    }
+
+    /// Set the bit at `index`, yielding if it was set beforehand.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(256);
+    ///
+    /// assert_eq!(instance.set(128), Some(false));
+    /// assert_eq!(instance.set(128), Some(true));
+    /// assert_eq!(instance.set(256), None);
+    /// ```
+    pub fn set(&mut self, index: usize) -> Option<bool> {
+        self.modify(index, |word, mask| *word |= mask)
+    }
+
+    /// Clear the bit at `index`, yielding if it was set beforehand.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(256);
+    ///
+    /// _ = instance.set(128);
+    ///
+    /// assert_eq!(instance.clear(128), Some(true));
+    /// assert_eq!(instance.clear(128), Some(false));
+    /// assert_eq!(instance.clear(256), None);
+    /// ```
+    #[allow(clippy::same_name_method)] // `Collection::clear` removes every bit.
+    pub fn clear(&mut self, index: usize) -> Option<bool> {
+        self.modify(index, |word, mask| *word &= !mask)
+    }
+
+    /// Query how many bits are set.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(256);
+    ///
+    /// _ = instance.set(0);
+    /// _ = instance.set(64);
+    /// _ = instance.set(255);
+    ///
+    /// assert_eq!(instance.count_ones(), 3);
+    /// ```
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Set every bit which is set within `other`.
+    ///
+    /// # Panics
+    /// This method has the precondition that both contain the same number of
+    /// bits.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(4);
+    /// _ = instance.set(0);
+    /// _ = instance.set(1);
+    ///
+    /// let mut other = BitSet::new(4);
+    /// _ = other.set(1);
+    /// _ = other.set(2);
+    ///
+    /// instance.union(&other);
+    ///
+    /// assert_eq!(instance.get(0), Some(true));
+    /// assert_eq!(instance.get(1), Some(true));
+    /// assert_eq!(instance.get(2), Some(true));
+    /// assert_eq!(instance.get(3), Some(false));
+    /// ```
+    pub fn union(&mut self, other: &Self) {
+        self.combine(other, |word, other| *word |= other);
+    }
+
+    /// Clear every bit which is clear within `other`.
+    ///
+    /// # Panics
+    /// This method has the precondition that both contain the same number of
+    /// bits.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(4);
+    /// _ = instance.set(0);
+    /// _ = instance.set(1);
+    ///
+    /// let mut other = BitSet::new(4);
+    /// _ = other.set(1);
+    /// _ = other.set(2);
+    ///
+    /// instance.intersection(&other);
+    ///
+    /// assert_eq!(instance.get(0), Some(false));
+    /// assert_eq!(instance.get(1), Some(true));
+    /// assert_eq!(instance.get(2), Some(false));
+    /// assert_eq!(instance.get(3), Some(false));
+    /// ```
+    pub fn intersection(&mut self, other: &Self) {
+        self.combine(other, |word, other| *word &= other);
+    }
+
+    /// Clear every bit which is set within `other`.
+    ///
+    /// # Panics
+    /// This method has the precondition that both contain the same number of
+    /// bits.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(4);
+    /// _ = instance.set(0);
+    /// _ = instance.set(1);
+    ///
+    /// let mut other = BitSet::new(4);
+    /// _ = other.set(1);
+    /// _ = other.set(2);
+    ///
+    /// instance.difference(&other);
+    ///
+    /// assert_eq!(instance.get(0), Some(true));
+    /// assert_eq!(instance.get(1), Some(false));
+    /// assert_eq!(instance.get(2), Some(false));
+    /// assert_eq!(instance.get(3), Some(false));
+    /// ```
+    pub fn difference(&mut self, other: &Self) {
+        self.combine(other, |word, other| *word &= !other);
+    }
+
+    /// Query which word contains the bit at `index`, and the mask for said bit.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    fn locate(index: usize) -> (usize, u64) {
+        let Ok(offset) = u32::try_from(index % Self::BITS) else {
+            unreachable!("offset is less than the number of bits in a word");
+        };
+
+        let Some(mask) = 1_u64.checked_shl(offset) else {
+            unreachable!("offset is less than the number of bits in a word");
+        };
+
+        (index / Self::BITS, mask)
+    }
+
+    /// Apply `operation` to the word containing the bit at `index`.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    fn modify(&mut self, index: usize, operation: impl FnOnce(&mut u64, u64)) -> Option<bool> {
+        if index >= self.count {
+            return None;
+        }
+
+        let (word, mask) = Self::locate(index);
+
+        let word = self.words.at_mut(word)?;
+
+        let previous = *word & mask != 0;
+
+        operation(word, mask);
+
+        Some(previous)
+    }
+
+    /// Apply `operation` to each word alongside the corresponding other word.
+    ///
+    /// # Panics
+    /// This method has the precondition that both contain the same number of
+    /// bits.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    fn combine(&mut self, other: &Self, mut operation: impl FnMut(&mut u64, u64)) {
+        assert_eq!(self.count, other.count, "sets must contain the same count");
+
+        for (word, operand) in self.words.iter_mut().zip(other.words.iter()) {
+            operation(word, *operand);
+        }
+    }
+}
+
+impl core::fmt::Debug for BitSet {
+    /// List the bits as booleans in order.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(3);
+    ///
+    /// _ = instance.set(1);
+    ///
+    /// assert_eq!(format!("{instance:?}"), "[false, true, false]");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries((0..self.count).filter_map(|index| self.get(index)))
+            .finish()
+    }
+}
+
+impl Collection for BitSet {
+    type Element = bool;
+
+    /// Query how many bits are contained, irrespective of if they are set.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Collection;
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let instance = BitSet::new(256);
+    ///
+    /// assert_eq!(instance.count(), 256);
+    /// ```
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Drop all bits, retaining the allocation.
+    ///
+    /// Unlike [`BitSet::clear`] which clears the bit at some index, this
+    /// removes every bit such that none are contained.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Collection;
+    /// use rust::structure::collection::BitSet;
+    ///
+    /// let mut instance = BitSet::new(256);
+    ///
+    /// Collection::clear(&mut instance);
+    ///
+    /// assert_eq!(instance.count(), 0);
+    /// assert_eq!(instance.get(0), None);
+    /// ```
+    fn clear(&mut self) {
+        self.words.clear();
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::undocumented_unsafe_blocks,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::assertions_on_result_states,
+    clippy::indexing_slicing
+)]
+mod test {
+    use super::super::linear::Array;
+    use super::*;
+
+    /// Construct an instance with bits set according to `bits`.
+    fn from_bools(bits: &[bool]) -> BitSet {
+        let mut instance = BitSet::new(bits.len());
+
+        for (index, bit) in bits.iter().enumerate() {
+            if *bit {
+                _ = instance.set(index).expect("within bounds");
+            }
+        }
+
+        instance
+    }
+
+    /// Pseudo-random booleans with no discernible pattern.
+    fn pseudo_random(count: usize, seed: u64) -> Dynamic<bool> {
+        let mut state = seed;
+
+        (0..count)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+
+                state >> 63 == 1
+            })
+            .collect()
+    }
+
+    mod method {
+        use super::*;
+
+        mod new {
+            use super::*;
+
+            #[test]
+            fn all_bits_clear() {
+                let actual = BitSet::new(256);
+
+                assert!((0..256).all(|index| actual.get(index) == Some(false)));
+            }
+
+            #[test]
+            fn allocates_enough_words() {
+                assert_eq!(BitSet::new(0).words.len(), 0);
+                assert_eq!(BitSet::new(1).words.len(), 1);
+                assert_eq!(BitSet::new(64).words.len(), 1);
+                assert_eq!(BitSet::new(65).words.len(), 2);
+            }
+        }
+
+        mod get {
+            use super::*;
+
+            #[test]
+            fn none_when_out_of_bounds() {
+                let actual = BitSet::new(65);
+
+                assert_eq!(actual.get(65), None);
+                assert_eq!(actual.get(128), None);
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let actual = BitSet::default();
+
+                assert_eq!(actual.get(0), None);
+            }
+        }
+
+        mod set {
+            use super::*;
+
+            #[test]
+            fn round_trips() {
+                let mut actual = BitSet::new(256);
+
+                for index in 0..256 {
+                    assert_eq!(actual.set(index), Some(false));
+                    assert_eq!(actual.get(index), Some(true));
+                }
+            }
+
+            #[test]
+            fn does_not_modify_other_bits() {
+                let mut actual = BitSet::new(256);
+
+                _ = actual.set(100).expect("within bounds");
+
+                assert_eq!(actual.count_ones(), 1);
+            }
+
+            #[test]
+            fn none_when_out_of_bounds() {
+                let mut actual = BitSet::new(65);
+
+                assert_eq!(actual.set(65), None);
+                assert_eq!(actual.count_ones(), 0);
+            }
+        }
+
+        mod clear {
+            use super::*;
+
+            #[test]
+            fn round_trips() {
+                let mut actual = from_bools(&[true; 256]);
+
+                for index in 0..256 {
+                    assert_eq!(actual.clear(index), Some(true));
+                    assert_eq!(actual.get(index), Some(false));
+                }
+            }
+
+            #[test]
+            fn does_not_modify_other_bits() {
+                let mut actual = from_bools(&[true; 256]);
+
+                _ = actual.clear(100).expect("within bounds");
+
+                assert_eq!(actual.count_ones(), 255);
+            }
+
+            #[test]
+            fn none_when_out_of_bounds() {
+                let mut actual = from_bools(&[true; 65]);
+
+                assert_eq!(actual.clear(65), None);
+                assert_eq!(actual.count_ones(), 65);
+            }
+        }
+
+        mod count_ones {
+            use super::*;
+
+            #[test]
+            fn zero_when_empty() {
+                let actual = BitSet::default();
+
+                assert_eq!(actual.count_ones(), 0);
+            }
+
+            #[test]
+            fn matches_number_of_set_bits() {
+                let expected = pseudo_random(1000, 0);
+
+                let actual = from_bools(expected.as_slice());
+
+                assert_eq!(
+                    actual.count_ones(),
+                    expected.iter().filter(|bit| **bit).count()
+                );
+            }
+        }
+
+        mod union {
+            use super::*;
+
+            #[test]
+            fn matches_boolean_or() {
+                let left = pseudo_random(1000, 1);
+                let right = pseudo_random(1000, 2);
+
+                let mut actual = from_bools(left.as_slice());
+                actual.union(&from_bools(right.as_slice()));
+
+                for (index, (first, second)) in left.iter().zip(right.iter()).enumerate() {
+                    assert_eq!(actual.get(index), Some(*first || *second));
+                }
+            }
+
+            #[test]
+            #[should_panic = "sets must contain the same count"]
+            fn panics_when_counts_differ() {
+                let mut actual = BitSet::new(64);
+
+                actual.union(&BitSet::new(65));
+            }
+        }
+
+        mod intersection {
+            use super::*;
+
+            #[test]
+            fn matches_boolean_and() {
+                let left = pseudo_random(1000, 3);
+                let right = pseudo_random(1000, 4);
+
+                let mut actual = from_bools(left.as_slice());
+                actual.intersection(&from_bools(right.as_slice()));
+
+                for (index, (first, second)) in left.iter().zip(right.iter()).enumerate() {
+                    assert_eq!(actual.get(index), Some(*first && *second));
+                }
+            }
+
+            #[test]
+            #[should_panic = "sets must contain the same count"]
+            fn panics_when_counts_differ() {
+                let mut actual = BitSet::new(64);
+
+                actual.intersection(&BitSet::new(65));
+            }
+        }
+
+        mod difference {
+            use super::*;
+
+            #[test]
+            fn matches_boolean_and_not() {
+                let left = pseudo_random(1000, 5);
+                let right = pseudo_random(1000, 6);
+
+                let mut actual = from_bools(left.as_slice());
+                actual.difference(&from_bools(right.as_slice()));
+
+                for (index, (first, second)) in left.iter().zip(right.iter()).enumerate() {
+                    assert_eq!(actual.get(index), Some(*first && !*second));
+                }
+            }
+
+            #[test]
+            #[should_panic = "sets must contain the same count"]
+            fn panics_when_counts_differ() {
+                let mut actual = BitSet::new(64);
+
+                actual.difference(&BitSet::new(65));
+            }
+        }
+    }
+
+    mod fmt {
+        use super::*;
+
+        mod debug {
+            use super::*;
+
+            #[test]
+            fn lists_bits_in_order() {
+                let expected = [true, false, false, true, true];
+
+                let actual = from_bools(&expected);
+
+                assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+            }
+        }
+    }
+
+    mod collection {
+        use super::*;
+
+        mod count {
+            use super::*;
+
+            #[test]
+            fn number_of_bits_not_words() {
+                let actual = BitSet::new(65);
+
+                assert_eq!(actual.count(), 65);
+            }
+
+            #[test]
+            fn irrespective_of_set_bits() {
+                let actual = from_bools(&[true, false, true]);
+
+                assert_eq!(actual.count(), 3);
+            }
+        }
+
+        mod clear {
+            use super::*;
+
+            #[test]
+            fn contains_no_bits() {
+                let mut actual = from_bools(&[true, false, true, true]);
+
+                Collection::clear(&mut actual);
+
+                assert_eq!(actual.count(), 0);
+                assert_eq!(actual.count_ones(), 0);
+                assert_eq!(actual.get(0), None);
+            }
+
+            #[test]
+            fn keeps_allocation() {
+                let mut actual = BitSet::new(256);
+
+                Collection::clear(&mut actual);
+
+                assert_eq!(actual.words.count(), 0);
+                assert_eq!(actual.words.capacity(), 4);
+            }
+
+            #[test]
+            fn when_already_empty() {
+                let mut actual = BitSet::default();
+
+                Collection::clear(&mut actual);
+
+                assert_eq!(actual.count(), 0);
+            }
+        }
+    }
+}
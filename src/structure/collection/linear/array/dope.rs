@@ -2,7 +2,9 @@
 
 use super::Array;
 use super::Collection;
+use super::Dynamic;
 use super::Linear;
+use super::RandomAccess;
 
 use core::ptr::NonNull;
 
@@ -22,7 +24,7 @@ use core::ptr::NonNull;
 ///
 /// [span]: https://en.cppreference.com/w/cpp/container/span
 /// [string_view]: https://en.cppreference.com/w/cpp/string/basic_string_view
-#[derive(Clone, Copy, Hash)]
+#[derive(Clone, Copy)]
 pub struct Dope<'a, T> {
     /// Pointer to the start of the array.
     ptr: NonNull<T>,
@@ -101,6 +103,43 @@ impl<'a, T: 'a> From<&'a mut [T]> for Dope<'a, T> {
     }
 }
 
+impl<'a, T: 'a> From<&'a mut Dynamic<T>> for Dope<'a, T> {
+    /// Construct a view over the initialized elements of a [`Dynamic`].
+    ///
+    /// The resulting [`Dope`] covers exactly the initialized elements, not
+    /// any allocated capacity before or after them. The mutable borrow ensures
+    /// the view cannot outlive the [`Dynamic`], nor can the [`Dynamic`] be
+    /// modified (thereby potentially reallocating) while the view exists.
+    ///
+    /// Note that no constructor from `&Dynamic<T>` is provided since [`Dope`]
+    /// is strictly a mutable view, see [`Array::as_slice`] instead.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::linear::array::Dope;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut expected = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut clone = expected.clone();
+    ///
+    /// let actual = Dope::from(&mut clone);
+    ///
+    /// assert!(actual.iter().eq(expected.iter()));
+    /// ```
+    fn from(dynamic: &'a mut Dynamic<T>) -> Self {
+        // No allocation to point to => view over no elements.
+        if dynamic.len() == 0 {
+            return Self::from(<&mut [T]>::default());
+        }
+
+        Self::from(dynamic.as_mut_slice())
+    }
+}
+
 impl<'a, T: 'a> core::ops::Index<usize> for Dope<'a, T> {
     type Output = T;
 
@@ -206,6 +245,19 @@ impl<'a, T: 'a + PartialEq> PartialEq for Dope<'a, T> {
 
 impl<'a, T: 'a + Eq> Eq for Dope<'a, T> {}
 
+impl<'a, T: 'a + core::hash::Hash> core::hash::Hash for Dope<'a, T> {
+    /// Feed the elements referenced to/contained into the `state`.
+    ///
+    /// This is consistent with [`PartialEq`] in that equal instances (those
+    /// which contain the same elements) produce the same hash.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(1) memory.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
 impl<'a, T: 'a + core::fmt::Debug> core::fmt::Debug for Dope<'a, T> {
     /// List the elements referenced to/contained.
     ///
@@ -249,6 +301,32 @@ impl<'a, T: 'a> Collection for Dope<'a, T> {
     fn count(&self) -> usize {
         self.count
     }
+
+    /// Stop referencing any elements, such that this becomes an empty view.
+    ///
+    /// The elements are _NOT_ owned, hence they are _NOT_ dropped and remain
+    /// within the underlying buffer which is left unmodified.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::array::Dope;
+    ///
+    /// let mut underlying = [0, 1, 2, 3, 4, 5];
+    ///
+    /// let mut actual = Dope::from(underlying.as_mut_slice());
+    ///
+    /// actual.clear();
+    ///
+    /// assert_eq!(actual.count(), 0);
+    /// assert_eq!(underlying, [0, 1, 2, 3, 4, 5]);
+    /// ```
+    fn clear(&mut self) {
+        self.count = 0;
+    }
 }
 
 impl<'a, T: 'a> Linear for Dope<'a, T> {
@@ -361,6 +439,81 @@ impl<'a, T: 'a> Array for Dope<'a, T> {
     }
 }
 
+impl<'a, T: 'a> RandomAccess for Dope<'a, T> {
+    /// Obtain an immutable reference to the `index` element, bounds checked.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Dope;
+    ///
+    /// let mut underlying = [0, 1, 2, 3, 4, 5];
+    /// let instance = Dope::from(underlying.as_mut_slice());
+    ///
+    /// assert_eq!(instance.get(0), Some(&0));
+    /// assert_eq!(instance.get(5), Some(&5));
+    /// assert_eq!(instance.get(6), None);
+    /// ```
+    fn get(&self, index: usize) -> Option<&Self::Element> {
+        self.at(index)
+    }
+
+    /// Obtain a mutable reference to the `index` element, bounds checked.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Dope;
+    ///
+    /// let mut underlying = [0, 1, 2, 3, 4, 5];
+    /// let mut instance = Dope::from(underlying.as_mut_slice());
+    ///
+    /// assert_eq!(instance.get_mut(0), Some(&mut 0));
+    /// assert_eq!(instance.get_mut(5), Some(&mut 5));
+    /// assert_eq!(instance.get_mut(6), None);
+    /// ```
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Element> {
+        self.at_mut(index)
+    }
+
+    /// Swap the element at index `first` with the element at index `second`.
+    ///
+    /// # Panics
+    /// If either `first` or `second` is out of bounds.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Dope;
+    ///
+    /// let mut underlying = [0, 1, 2, 3, 4, 5];
+    /// let mut instance = Dope::from(underlying.as_mut_slice());
+    ///
+    /// instance.swap(0, 5);
+    ///
+    /// assert!(instance.iter().eq([5, 1, 2, 3, 4, 0].iter()));
+    /// ```
+    fn swap(&mut self, first: usize, second: usize) {
+        let count = self.count();
+
+        assert!(first < count && second < count, "index out of bounds");
+
+        self.as_mut_slice().swap(first, second);
+    }
+}
+
 #[cfg(test)]
 #[allow(
     clippy::undocumented_unsafe_blocks,
@@ -428,6 +581,62 @@ mod test {
                 assert_eq!(actual.ptr.as_ptr(), expected.as_mut_ptr());
             }
         }
+
+        mod dynamic {
+            use super::*;
+
+            #[test]
+            fn correct_size() {
+                let mut expected = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let actual = Dope::from(&mut expected);
+
+                assert_eq!(actual.count, 6);
+            }
+
+            #[test]
+            fn correct_pointer() {
+                let mut expected = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                let ptr = expected.as_mut_ptr();
+
+                let actual = Dope::from(&mut expected);
+
+                assert_eq!(actual.ptr.as_ptr(), ptr);
+            }
+
+            #[test]
+            fn excludes_capacity() {
+                let mut expected = Dynamic::with_capacity(256).unwrap();
+                expected.extend([0, 1, 2, 3, 4, 5]);
+                _ = expected.reserve_front(64).unwrap();
+
+                let actual = Dope::from(&mut expected);
+
+                assert!(actual.iter().eq([0, 1, 2, 3, 4, 5].iter()));
+            }
+
+            #[test]
+            fn is_mutable() {
+                let mut expected = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let mut actual = Dope::from(&mut expected);
+
+                for element in actual.iter_mut() {
+                    *element = 0;
+                }
+
+                assert!(expected.into_iter().eq([0, 0, 0, 0, 0, 0]));
+            }
+
+            #[test]
+            fn empty_when_unallocated() {
+                let mut expected = Dynamic::<i32>::default();
+
+                let actual = Dope::from(&mut expected);
+
+                assert_eq!(actual.iter().next(), None);
+            }
+        }
     }
 
     mod index {
@@ -632,6 +841,51 @@ mod test {
         }
     }
 
+    mod hash {
+        use super::*;
+
+        use core::hash::BuildHasher;
+
+        #[test]
+        fn eq_when_same_elements_in_different_buffers() {
+            let mut first = [0, 1, 2, 3, 4, 5];
+            let first = Dope::from(first.as_mut_slice());
+
+            let mut second = [0, 1, 2, 3, 4, 5];
+            let second = Dope::from(second.as_mut_slice());
+
+            let hasher = std::hash::RandomState::new();
+
+            assert_eq!(first, second);
+            assert_eq!(hasher.hash_one(first), hasher.hash_one(second));
+        }
+
+        #[test]
+        fn eq_to_hash_of_slice() {
+            let mut expected = [0, 1, 2, 3, 4, 5];
+
+            let hasher = std::hash::RandomState::new();
+            let expected_hash = hasher.hash_one(expected.as_slice());
+
+            let actual = Dope::from(expected.as_mut_slice());
+
+            assert_eq!(hasher.hash_one(actual), expected_hash);
+        }
+
+        #[test]
+        fn ne_when_different_elements() {
+            let mut zero = [0, 1, 2];
+            let zero = Dope::from(zero.as_mut_slice());
+
+            let mut one = [0, 1, 3];
+            let one = Dope::from(one.as_mut_slice());
+
+            let hasher = std::hash::RandomState::new();
+
+            assert_ne!(hasher.hash_one(zero), hasher.hash_one(one));
+        }
+    }
+
     mod fmt {
         use super::*;
 
@@ -680,6 +934,32 @@ mod test {
                 assert_eq!(Collection::count(&actual), 0);
             }
         }
+
+        mod clear {
+            use super::*;
+
+            #[test]
+            fn references_no_elements() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = Dope::from(underlying.as_mut_slice());
+
+                actual.clear();
+
+                assert_eq!(actual.count(), 0);
+                assert!(actual.iter().next().is_none());
+            }
+
+            #[test]
+            fn does_not_modify_underlying_buffer() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let mut underlying = expected;
+
+                Dope::from(underlying.as_mut_slice()).clear();
+
+                assert_eq!(underlying, expected);
+            }
+        }
     }
 
     mod linear {
@@ -991,4 +1271,120 @@ mod test {
             }
         }
     }
+
+    mod random_access {
+        use super::*;
+
+        mod get {
+            use super::*;
+
+            #[test]
+            fn correct_element() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let actual = Dope::from(underlying.as_mut_slice());
+
+                for index in 0..6 {
+                    assert_eq!(actual.get(index), Some(&index));
+                }
+            }
+
+            #[test]
+            fn none_when_index_out_of_bounds() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let actual = Dope::from(underlying.as_mut_slice());
+
+                assert_eq!(actual.get(6), None);
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let mut underlying: [usize; 0] = [];
+                let actual = Dope::from(underlying.as_mut_slice());
+
+                assert_eq!(actual.get(0), None);
+            }
+        }
+
+        mod get_mut {
+            use super::*;
+
+            #[test]
+            fn correct_element() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dope::from(underlying.as_mut_slice());
+
+                for mut index in 0..6 {
+                    assert_eq!(actual.get_mut(index), Some(&mut index));
+                }
+            }
+
+            #[test]
+            fn is_mutable() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dope::from(underlying.as_mut_slice());
+
+                *actual.get_mut(0).expect("within bounds") = 5;
+
+                assert_eq!(actual.get(0), Some(&5));
+            }
+
+            #[test]
+            fn none_when_index_out_of_bounds() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dope::from(underlying.as_mut_slice());
+
+                assert_eq!(actual.get_mut(6), None);
+            }
+        }
+
+        mod swap {
+            use super::*;
+
+            #[test]
+            fn exchanges_elements() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dope::from(underlying.as_mut_slice());
+
+                actual.swap(1, 4);
+
+                assert!(actual.iter().eq([0, 4, 2, 3, 1, 5].iter()));
+            }
+
+            #[test]
+            fn same_index_is_unmodified() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dope::from(underlying.as_mut_slice());
+
+                actual.swap(2, 2);
+
+                assert!(actual.iter().eq([0, 1, 2, 3, 4, 5].iter()));
+            }
+
+            #[test]
+            #[should_panic = "index out of bounds"]
+            fn panics_when_index_out_of_bounds() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dope::from(underlying.as_mut_slice());
+
+                actual.swap(0, 6);
+            }
+        }
+
+        mod generic {
+            use super::*;
+
+            /// Obtain the last element via only [`RandomAccess`] methods.
+            fn last(collection: &impl RandomAccess<Element = usize>) -> Option<&usize> {
+                collection.get(collection.count().checked_sub(1)?)
+            }
+
+            #[test]
+            fn accepted_by_generic_functions() {
+                let mut underlying = [0, 1, 2, 3, 4, 5];
+                let actual = Dope::from(underlying.as_mut_slice());
+
+                assert_eq!(last(&actual), Some(&5));
+            }
+        }
+    }
 }
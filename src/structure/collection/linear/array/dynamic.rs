@@ -6,6 +6,10 @@ use super::super::List;
 use super::Array;
 use super::Collection;
 use super::Linear;
+use super::RandomAccess;
+
+use crate::algorithm::sort::comparison::heap;
+use crate::algorithm::sort::comparison::merge;
 
 use core::mem::MaybeUninit;
 use core::ptr::NonNull;
@@ -94,6 +98,151 @@ impl<T> Dynamic<T> {
         }
     }
 
+    /// Construct an instance by flattening `iters` in order.
+    ///
+    /// Unlike collecting [`Iterator::flatten`], this sums the lower bound of
+    /// the size hint of every inner iterator to allocate once upfront, hence
+    /// exactly one allocation occurs when the inner iterators are of exact
+    /// size.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_nested([0..3, 3..6]);
+    ///
+    /// assert_eq!(instance.capacity(), 0);
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    pub fn from_nested<I, J>(iters: I) -> Self
+    where
+        I: IntoIterator<Item = J>,
+        J: IntoIterator<Item = T>,
+    {
+        let iters: Dynamic<J::IntoIter> = iters.into_iter().map(IntoIterator::into_iter).collect();
+
+        // The upper bound might vastly over-allocate, so use the lower.
+        let count = iters
+            .iter()
+            .map(|iter| iter.size_hint().0)
+            .fold(0, usize::saturating_add);
+
+        let mut instance = Dynamic::<T>::default();
+
+        // Appending will allocate should reserving fail, so this can be ignored.
+        drop(instance.reserve_back(count));
+
+        // Unlike `extend`, this does not reserve each upper bound.
+        for element in iters.into_iter().flatten() {
+            assert!(instance.append(element).is_ok(), "allocation failed");
+        }
+
+        instance
+    }
+
+    /// Construct an instance containing `count` clones of `value`.
+    ///
+    /// Equivalent to `vec![value; count]`, exactly `count` elements are
+    /// allocated upfront. The last element is `value` itself rather than a
+    /// clone, hence only `count - 1` clones occur.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_elem(7, 4);
+    ///
+    /// assert_eq!(instance.capacity(), 0);
+    /// assert!(instance.into_iter().eq([7, 7, 7, 7]));
+    /// ```
+    pub fn from_elem(value: T, count: usize) -> Self
+    where
+        T: Clone,
+    {
+        core::iter::repeat_n(value, count).collect()
+    }
+
+    /// Construct a new instance containing the elements cloned `n` times.
+    ///
+    /// Just like [`slice::repeat`], the result is `self` concatenated `n`
+    /// times in order. Exactly enough memory for every element is allocated
+    /// upfront.
+    ///
+    /// # Panics
+    /// Panics if the number of elements would exceed `usize::MAX`. The Rust
+    /// runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This methods takes O(N * M) time and consumes O(N * M) memory for the
+    /// result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0, 1]);
+    ///
+    /// assert!(instance.repeat(3).into_iter().eq([0, 1, 0, 1, 0, 1]));
+    /// ```
+    #[must_use]
+    pub fn repeat(&self, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let Some(count) = self.initialized.checked_mul(n) else {
+            panic!("capacity overflow");
+        };
+
+        let mut instance = Dynamic::<T>::default();
+
+        if count == 0 {
+            return instance;
+        }
+
+        // The exact number of elements is known, so allocation is upfront.
+        assert!(instance.reserve_back(count).is_ok(), "allocation failed");
+
+        for _ in 0..n {
+            instance.extend(self.iter());
+        }
+
+        instance
+    }
+
+    /// Query how many elements are contained.
+    ///
+    /// This is the same as [`Collection::count`], provided such that callers
+    /// need not import the trait.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.len(), 6);
+    /// ```
+    #[must_use]
+    #[allow(clippy::len_without_is_empty)] // Provided by `Collection::is_empty`.
+    pub fn len(&self) -> usize {
+        self.initialized
+    }
+
     /// Query how many elements could be added without reallocation.
     ///
     /// Note that adding this many elements might still require rearranging the
@@ -131,10 +280,7 @@ impl<T> Dynamic<T> {
     pub fn capacity(&self) -> usize {
         self.front_capacity
             .checked_add(self.back_capacity)
-            .map_or_else(
-                || unreachable!("allocated more than `isize::MAX` bytes"),
-                |capacity| capacity,
-            )
+            .unwrap_or_else(|| unreachable!("allocated more than `isize::MAX` bytes"))
     }
 
     /// How many elements can [`Self::prepend`] in without reallocation.
@@ -248,7 +394,8 @@ impl<T> Dynamic<T> {
     /// The Rust runtime might panic or otherwise abort if allocation fails.
     ///
     /// # Errors
-    /// Yields [`FailedAllocation`] when memory (re)allocation fails.
+    /// Yields [`FailedAllocation`] when memory (re)allocation fails, which
+    /// includes when the total allocation would exceed `isize::MAX` bytes.
     ///
     /// # Performance
     /// This methods takes O(N) time and consumes O(N) memory.
@@ -278,6 +425,8 @@ impl<T> Dynamic<T> {
     /// assert_eq!(instance.as_ptr(), ptr);
     /// ```
     pub fn reserve(&mut self, capacity: usize) -> Result<&mut Self, FailedAllocation> {
+        self.reclaim_front_capacity_if_empty();
+
         // Reclaim any front capacity.
         if self.initialized > 0 {
             let Ok(offset) = isize::try_from(self.front_capacity) else {
@@ -324,7 +473,8 @@ impl<T> Dynamic<T> {
     /// The Rust runtime might panic or otherwise abort if allocation fails.
     ///
     /// # Errors
-    /// Yields [`FailedAllocation`] when memory (re)allocation fails.
+    /// Yields [`FailedAllocation`] when memory (re)allocation fails, which
+    /// includes when the total allocation would exceed `isize::MAX` bytes.
     ///
     /// # Performance
     /// This methods takes O(N) time and consumes O(N) memory.
@@ -356,7 +506,7 @@ impl<T> Dynamic<T> {
 
         let capacity = isize::try_from(capacity).map_err(|_| FailedAllocation)?;
 
-        _ = self.resize(capacity)?;
+        _ = self.reallocate(capacity)?;
 
         if self.initialized > 0 {
             let Ok(_) = self.shift(capacity) else {
@@ -377,7 +527,8 @@ impl<T> Dynamic<T> {
     /// The Rust runtime might panic or otherwise abort if allocation fails.
     ///
     /// # Errors
-    /// Yields [`FailedAllocation`] when memory (re)allocation fails.
+    /// Yields [`FailedAllocation`] when memory (re)allocation fails, which
+    /// includes when the total allocation would exceed `isize::MAX` bytes.
     ///
     /// # Performance
     /// This methods takes O(N) time and consumes O(N) memory.
@@ -409,7 +560,7 @@ impl<T> Dynamic<T> {
 
         let capacity = isize::try_from(capacity).map_err(|_| FailedAllocation)?;
 
-        self.resize(capacity)
+        self.reallocate(capacity)
     }
 
     /// Attempt to reduce capacity to exactly `capacity`, or none/zero.
@@ -532,7 +683,7 @@ impl<T> Dynamic<T> {
             };
         }
 
-        self.resize(extra)
+        self.reallocate(extra)
     }
 
     /// Reallocate to reduce back capacity to exactly `capacity` elements.
@@ -586,3260 +737,10437 @@ impl<T> Dynamic<T> {
             unreachable!("extra capacity is negative");
         };
 
-        self.resize(extra)
+        self.reallocate(extra)
     }
 
-    /// Shift the initialized elements `offset` positions within the buffer.
+    /// Reallocate to reduce front capacity to exactly `front` elements and
+    /// back capacity to exactly `back` elements.
     ///
-    /// This method  maintains the order of initialized elements, but shifts
-    /// them thereby converting some portion of the capacity from front to
-    /// back, or vice versa.
+    /// Unlike [`Self::shrink`], which moves all capacity to the back, this
+    /// retains capacity at both ends. Capacity freed from the front may be
+    /// retained at the back, but this will never allocate more memory.
+    ///
+    /// # Panics
+    /// The Rust runtime might panic or otherwise `abort` if allocation fails.
     ///
     /// # Errors
-    /// Yields [`OutOfBounds`] is there is not enough capacity to shift into.
+    /// Yields [`FailedAllocation`] when memory (re)allocation fails.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(1) memory.
+    /// This methods takes O(N) time and consumes O(N) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
-    ///
-    /// // Fill with elements.
-    /// instance.extend(0..256);
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// // Allocate capacity at both ends.
     /// instance.reserve_front(256).expect("successful allocation");
     /// instance.reserve_back(256).expect("successful allocation");
     ///
-    /// // Shift initialized elements to the front of the buffer.
-    /// instance.shift(-256).expect("offset <= capacity_front()");
-    /// instance.shift(-1).expect_err("offset out of bounds");
-    /// assert_eq!(instance.capacity_front(), 0);
-    /// assert_eq!(instance.capacity_back(), 512);
+    /// instance.shrink_keeping(Some(8), Some(8)).expect("successful reallocation");
+    /// assert_eq!(instance.capacity_front(), 8);
+    /// assert_eq!(instance.capacity_back(), 8);
     ///
-    /// // Shift initialized elements to the end of the buffer.
-    /// instance.shift(512).expect("offset <= capacity_back()");
-    /// instance.shift(1).expect_err("offset out of bounds");
-    /// assert_eq!(instance.capacity_front(), 512);
-    /// assert_eq!(instance.capacity_back(), 0);
+    /// instance.shrink_keeping(None, None).expect("successful reallocation");
+    /// assert_eq!(instance.capacity(), 0);
     /// ```
-    pub fn shift(&mut self, offset: isize) -> Result<&mut Self, OutOfBounds> {
-        match offset.cmp(&0) {
-            core::cmp::Ordering::Less => {
-                if let Some(capacity) = self.front_capacity.checked_sub(offset.unsigned_abs()) {
-                    self.front_capacity = capacity;
-                } else {
-                    debug_assert!(
-                        offset.unsigned_abs() > self.front_capacity,
-                        "not enough capacity to shift into"
-                    );
-
-                    return Err(OutOfBounds);
-                }
-
-                if let Some(capacity) = self.back_capacity.checked_add(offset.unsigned_abs()) {
-                    self.back_capacity = capacity;
-                } else {
-                    unreachable!("allocated more than `isize::MAX` bytes");
-                }
-            }
-            core::cmp::Ordering::Greater => {
-                if let Some(capacity) = self.back_capacity.checked_sub(offset.unsigned_abs()) {
-                    self.back_capacity = capacity;
-                } else {
-                    debug_assert!(
-                        offset.unsigned_abs() > self.back_capacity,
-                        "not enough capacity to shift into"
-                    );
+    pub fn shrink_keeping(
+        &mut self,
+        front: Option<usize>,
+        back: Option<usize>,
+    ) -> Result<&mut Self, FailedAllocation> {
+        if let Some(extra) = self.front_capacity.checked_sub(front.unwrap_or(0)) {
+            let Ok(extra) = isize::try_from(extra) else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            };
 
-                    return Err(OutOfBounds);
-                }
+            let Some(extra) = extra.checked_neg() else {
+                unreachable!("negative extra capacity");
+            };
 
-                if let Some(capacity) = self.front_capacity.checked_add(offset.unsigned_abs()) {
-                    self.front_capacity = capacity;
-                } else {
-                    unreachable!("allocated more than `isize::MAX` bytes");
-                }
+            if self.shift(extra).is_err() {
+                unreachable!("not enough front capacity to shift into");
             }
-            core::cmp::Ordering::Equal => return Ok(self),
         }
 
-        let destination = self.as_mut_ptr();
-
-        let Some(offset) = offset.checked_neg() else {
-            unreachable!("offset out of bounds");
-        };
-
-        // SAFETY: offset is in bounds => aligned within the allocated object.
-        let source = unsafe { destination.offset(offset) };
-
-        // SAFETY:
-        // * owned memory => source/destination valid for read/writes.
-        // * no aliasing restrictions => source and destination can overlap.
-        // * underlying buffer is aligned => both pointers are aligned.
-        unsafe {
-            core::ptr::copy(source, destination, self.initialized);
-        }
-
-        Ok(self)
+        self.shrink_back(back)
     }
 
-    /// Remove an element by swapping it with the first element.
+    /// Consolidate all capacity at the back of the buffer.
     ///
-    /// In contrast to [`Self::remove`], this method takes constant time and
-    /// does _NOT_ preserve order.
+    /// Removing elements (for example via [`List::drain`], [`List::withdraw`],
+    /// or [`List::retain`]) may leave capacity at the front, which appending
+    /// cannot use without first shifting. This moves the initialized elements
+    /// to the start of the buffer such that all capacity is at the back,
+    /// hence appending up to [`Self::capacity_back`] elements will never
+    /// reallocate. Unlike [`Self::shrink`], this never (re)allocates.
+    ///
+    /// Note that elements are moved within the buffer, hence any pointers
+    /// obtained via [`Self::as_ptr`] or [`Self::as_mut_ptr`] (or derived
+    /// thereof) are invalidated if there was any front capacity.
     ///
     /// # Performance
-    /// This method takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
+    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
     /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.remove_via_front(3), Some(3));
-    /// assert_eq!(instance.capacity_front(), 1);
-    /// assert_eq!(instance[2], 0);
+    /// drop(instance.drain(1..3));
+    ///
+    /// instance.compact_back();
+    /// assert_eq!(instance.capacity_front(), 0);
+    /// assert_eq!(instance.capacity_back(), 2);
     /// ```
-    #[must_use]
-    pub fn remove_via_front(&mut self, index: usize) -> Option<T> {
-        if index >= self.initialized {
-            return None;
-        }
-
-        let front = self.as_mut_ptr();
-
-        // SAFETY: index in bounds => aligned within the allocated object.
-        let index = unsafe { front.add(index) };
-
-        // SAFETY:
-        // * both pointers are valid for reads and write.
-        // * both pointers are aligned.
-        // * no aliasing restrictions.
-        unsafe {
-            core::ptr::swap(front, index);
-        }
-
-        // SAFETY:
-        // * owned memory => pointer is valid for reads.
-        // * Underlying `T` is initialized.
-        // * This takes ownership (moved out of the buffer).
-        let element = unsafe { front.read() };
+    pub fn compact_back(&mut self) -> &mut Self {
+        let Ok(offset) = isize::try_from(self.front_capacity) else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        };
 
-        if let Some(decremented) = self.initialized.checked_sub(1) {
-            self.initialized = decremented;
-        } else {
-            unreachable!("no initialized element to remove");
-        }
+        let Some(offset) = offset.checked_neg() else {
+            unreachable!("negative front capacity");
+        };
 
-        if let Some(incremented) = self.front_capacity.checked_add(1) {
-            self.front_capacity = incremented;
-        } else {
-            unreachable!("allocated more that `isize::MAX` bytes");
+        if self.shift(offset).is_err() {
+            unreachable!("not enough front capacity to shift into");
         }
 
-        Some(element)
+        self
     }
 
-    /// Remove an element by swapping it with the last element.
+    /// Modify the number of elements to be exactly `len`, cloning `value`
+    /// into new elements if more are needed.
     ///
-    /// In contrast to [`Self::remove`], this method takes constant time and
-    /// does _NOT_ preserve order.
+    /// See [`Self::resize_with`] for details.
+    ///
+    /// # Panics
+    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    ///
+    /// # Errors
+    /// Yields [`FailedAllocation`] when memory (re)allocation fails, in which
+    /// case no elements are added.
     ///
     /// # Performance
-    /// This method takes O(1) time and consumes O(1) memory.
+    /// This methods takes O(N) time and consumes O(N) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut instance = Dynamic::from_iter([0, 1, 2]);
     ///
-    /// assert_eq!(instance.remove_via_back(3), Some(3));
-    /// assert_eq!(instance.capacity_back(), 1);
-    /// assert_eq!(instance[3], 5);
+    /// instance.resize(5, 7).expect("successful allocation");
+    /// assert_eq!(instance.len(), 5);
+    ///
+    /// instance.resize(2, 7).expect("does not allocate");
+    /// assert!(instance.into_iter().eq([0, 1]));
     /// ```
-    #[must_use]
-    pub fn remove_via_back(&mut self, index: usize) -> Option<T> {
-        if index >= self.initialized {
-            return None;
-        }
-
-        let ptr = self.as_mut_ptr();
+    pub fn resize(&mut self, len: usize, value: T) -> Result<&mut Self, FailedAllocation>
+    where
+        T: Clone,
+    {
+        self.resize_with(len, || value.clone())
+    }
 
-        let last = {
-            let Some(offset) = self.initialized.checked_sub(1) else {
-                unreachable!("no initialized element to remove");
-            };
-
-            // SAFETY: points to the final element contained.
-            unsafe { ptr.add(offset) }
-        };
-
-        // SAFETY: index is in bounds => aligned within the allocated object.
-        let index = unsafe { ptr.add(index) };
-
-        // SAFETY:
-        // * both pointers are valid for reads and write.
-        // * both pointers are aligned.
-        // * no aliasing restrictions.
-        unsafe {
-            core::ptr::swap(last, index);
-        }
-
-        // SAFETY:
-        // * owned memory => pointer is valid for reads.
-        // * Underlying `T` is initialized.
-        // * This takes ownership (moved out of the buffer).
-        let element = unsafe { last.read() };
-
-        if let Some(decremented) = self.initialized.checked_sub(1) {
-            self.initialized = decremented;
-        } else {
-            unreachable!("no initialized element to remove");
-        }
-
-        if let Some(incremented) = self.back_capacity.checked_add(1) {
-            self.back_capacity = incremented;
-        } else {
-            unreachable!("allocated more that `isize::MAX` bytes");
-        }
-
-        Some(element)
-    }
-
-    /// Exactly how much back capacity to allocate to apply amortized analysis.
-    ///
-    /// See also: [amortized analysis][amortized] and [dynamic array application][dynamic].
-    ///
-    /// # Performance
-    /// This method takes O(1) time and consumes O(1) memory.
-    ///
-    /// [amortized]: https://en.wikipedia.org/wiki/Amortized_analysis
-    /// [dynamic]: https://en.wikipedia.org/wiki/Dynamic_array#Geometric_expansion_and_amortized_cost
-    #[must_use]
-    fn amortized(&self, capacity: usize) -> Option<usize> {
-        let Some(retained) = self.front_capacity.checked_add(self.initialized) else {
-            unreachable!("allocated more the `isize::MAX` bytes");
-        };
-
-        let total = retained.checked_add(capacity)?;
-
-        let total = total.checked_next_power_of_two()?;
-
-        total.checked_sub(retained)
-    }
-
-    /// Shift the elements within `range` left or right by `offset`.
+    /// Modify the number of elements to be exactly `len`, constructing new
+    /// elements via `constructor` if more are needed.
     ///
-    /// Note this does _NOT_ modify internal capacity state.
-    ///
-    /// # Safety
-    /// The `range` must be within bounds, even when shifted by `offset`.
+    /// Growing consumes [`Self::capacity_back`] first, allocating only if
+    /// there is not enough. Hence, following [`Self::reserve_back`] this will
+    /// not reallocate, and capacity at the back decreases by exactly the
+    /// number of elements appended. Shrinking drops the excess elements from
+    /// the back, crediting that memory to [`Self::capacity_back`] instead.
+    /// In both cases, [`Self::capacity_front`] is unchanged.
     ///
     /// # Panics
-    /// This method has the precondition the start bound is before the end.
+    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    ///
+    /// # Errors
+    /// Yields [`FailedAllocation`] when memory (re)allocation fails, in which
+    /// case no elements are added.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(1) memory.
-    #[inline]
-    unsafe fn shift_range(&mut self, range: impl core::ops::RangeBounds<usize>, offset: isize) {
-        let start = match range.start_bound() {
-            core::ops::Bound::Unbounded => 0,
-            core::ops::Bound::Included(start) => *start,
-            core::ops::Bound::Excluded(start) => start.saturating_add(1),
-        };
-
-        let end = match range.end_bound() {
-            core::ops::Bound::Unbounded => self.len(),
-            core::ops::Bound::Included(end) => end.saturating_add(1),
-            core::ops::Bound::Excluded(end) => *end,
-        };
-
-        let Some(elements) = end.checked_sub(start) else {
-            panic!("range had end index before start index")
-        };
-
-        // SAFETY: points to the where the first initialized element goes.
-        let ptr = unsafe { self.buffer.as_ptr().add(self.front_capacity) };
-
-        // SAFETY: caller promises this will stay in bounds.
-        let source = unsafe { ptr.add(start) };
-
-        // SAFETY: caller promises this will stay in bounds.
-        let destination = unsafe { source.offset(offset) };
-
-        // SAFETY:
-        // * start/end in bounds => source/destination valid for read/write.
-        // * ranges can overlap => no aliasing restrictions.
-        unsafe {
-            std::ptr::copy(source, destination, elements);
-        }
-    }
-
-    /// (Re)allocate the buffer to modify back capacity by `capacity`.
+    /// This methods takes O(N) time and consumes O(N) memory, or O(K) time
+    /// and O(1) memory if there is enough back capacity for the K elements.
     ///
-    /// This method will increase back capacity by `capacity` if positive,
-    /// and decrease by `capacity` if negative, (re)allocating if necessary.
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Array;
+    /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// Note that failed allocation will _NOT_ modify the underlying buffer.
+    /// let mut instance = Dynamic::from_iter([0, 1, 2]);
     ///
-    /// # Panics
-    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    /// instance.reserve_back(3).expect("successful allocation");
+    /// let ptr = instance.as_ptr();
     ///
-    /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory.
-    #[inline]
-    fn resize(&mut self, capacity: isize) -> Result<&mut Self, FailedAllocation> {
-        let capacity = self
-            .capacity_back()
-            .checked_add_signed(capacity)
-            .ok_or(FailedAllocation)?;
-
-        // Zero-size types do _NOT_ occupy memory, so no (re/de)allocation.
-        if core::mem::size_of::<T>() == 0 {
-            // Global allocator API limits allocation to `isize:MAX` bytes.
-            if capacity > isize::MAX as usize {
-                return Err(FailedAllocation);
-            }
-
-            self.back_capacity = capacity;
+    /// let mut next = 3;
+    /// instance.resize_with(6, || { next += 1; next - 1 }).expect("enough capacity");
+    ///
+    /// assert_eq!(instance.as_ptr(), ptr);
+    /// assert_eq!(instance.capacity_back(), 0);
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    pub fn resize_with(
+        &mut self,
+        len: usize,
+        mut constructor: impl FnMut() -> T,
+    ) -> Result<&mut Self, FailedAllocation> {
+        let Some(additional) = len.checked_sub(self.initialized) else {
+            drop(self.drain(len..));
 
             return Ok(self);
-        }
-
-        let Some(unchanged) = self.front_capacity.checked_add(self.initialized) else {
-            unreachable!("allocated more than `isize::MAX` bytes");
         };
 
-        let new = {
-            let total = unchanged.checked_add(capacity).ok_or(FailedAllocation)?;
+        self.reclaim_front_capacity_if_empty();
 
-            match core::alloc::Layout::array::<T>(total) {
-                Ok(layout) => layout,
-                Err(_) => return Err(FailedAllocation),
-            }
-        };
+        _ = self.reserve_back(additional)?;
 
-        let Some(total) = unchanged.checked_add(self.back_capacity) else {
-            unreachable!("allocated more than `isize::MAX` bytes");
-        };
+        for _ in 0..additional {
+            let element = constructor();
 
-        let ptr = {
-            // No previous allocation exists, so create one.
-            if total == 0 {
-                if new.size() > 0 {
-                    // SAFETY: layout has non-zero size.
-                    unsafe { alloc::alloc::alloc(new) }.cast::<T>()
-                } else {
-                    debug_assert_eq!(capacity, 0, "otherwise occupies memory");
+            let Some(offset) = self.front_capacity.checked_add(self.initialized) else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            };
 
-                    // empty => The pointer will _NOT_ be read/written to.
-                    NonNull::<T>::dangling().as_ptr()
-                }
-            }
-            // Modify an existing buffer allocation.
-            else {
-                let Ok(existing) = core::alloc::Layout::array::<T>(total) else {
-                    return Err(FailedAllocation);
-                };
+            // SAFETY: the first uninitialized element in the back capacity.
+            let ptr = unsafe { self.buffer.as_ptr().add(offset) };
 
-                let ptr = self.buffer.as_ptr().cast::<u8>();
+            // SAFETY: the `MaybeUninit<T>` is initialized even if the `T` isn't.
+            let uninit_element = unsafe { &mut *ptr };
 
-                // Deallocate.
-                if unchanged == 0 && capacity == 0 {
-                    // SAFETY:
-                    // * allocated using the corresponding allocator.
-                    // * `existing_layout` is currently allocated.
-                    // * `new_layout` has non-zero size.
-                    // * `Layout` => `new.size() <= isize::MAX`.
-                    unsafe {
-                        alloc::alloc::dealloc(ptr, existing);
-                    }
+            _ = uninit_element.write(element);
 
-                    // empty state => will _NOT_ be read/written to.
-                    NonNull::<T>::dangling().as_ptr()
-                }
-                // Reallocate.
-                else {
-                    // SAFETY:
-                    // * allocated using the corresponding allocator.
-                    // * `existing_layout` is currently allocated.
-                    // * `new_layout` has non-zero size.
-                    // * `Layout` => `new.size() <= isize::MAX`.
-                    unsafe { alloc::alloc::realloc(ptr, existing, new.size()) }.cast::<T>()
-                }
+            if let Some(decremented) = self.back_capacity.checked_sub(1) {
+                self.back_capacity = decremented;
+            } else {
+                unreachable!("reserved enough back capacity");
             }
-        };
-
-        // `MaybeUninit<T>` has the same layout as `T`.
-        let ptr = ptr.cast::<MaybeUninit<T>>();
-
-        self.buffer = match NonNull::new(ptr) {
-            Some(ptr) => ptr,
-            None => return Err(FailedAllocation),
-        };
 
-        self.back_capacity = capacity;
+            if let Some(incremented) = self.initialized.checked_add(1) {
+                self.initialized = incremented;
+            } else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            }
+        }
 
         Ok(self)
     }
-}
 
-impl<T> Drop for Dynamic<T> {
-    /// Drops the elements that are initialized and deallocates memory.
+    /// Insert an `element` at `index`, distinguishing why it could not be.
+    ///
+    /// This is equivalent to [`List::insert`] except the error states if
+    /// the `index` was invalid or if memory allocation failed, hence being
+    /// suitable where the caller must recover from the latter. In either
+    /// case, the rejected `element` is yielded back and the instance is left
+    /// unmodified.
+    ///
+    /// # Errors
+    /// Yields [`TryInsertError::OutOfBounds`] if `index > len()`, or
+    /// [`TryInsertError::FailedAllocation`] if memory (re)allocation fails.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(1) memory.
+    /// This methods takes O(N) time and consumes O(N) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
+    /// use rust::structure::collection::linear::array::dynamic::TryInsertError;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut instance = Dynamic::from_iter([0, 2]);
     ///
-    /// instance.next();      // Consumes the element with value `0`.
-    /// instance.next_back(); // Consumes the element with value `5`.
+    /// assert_eq!(instance.try_insert(1, 1), Ok(&mut 1));
+    /// assert_eq!(instance.try_insert(4, 4), Err(TryInsertError::OutOfBounds(4)));
     ///
-    /// core::mem::drop(instance); // Drops the elements with values `[1, 2, 3, 4]`.
+    /// assert!(instance.into_iter().eq([0, 1, 2]));
     /// ```
-    fn drop(&mut self) {
-        for index in 0..self.initialized {
-            let ptr = self.buffer.as_ptr();
-
-            // SAFETY: stays aligned within the allocated object.
-            let ptr = unsafe { ptr.add(self.front_capacity) };
-
-            // SAFETY: index is within bounds, so within allocated object.
-            let ptr = unsafe { ptr.add(index) };
-
-            // SAFETY: the `MaybeUninit<T>` is initialized.
-            let element = unsafe { &mut *ptr };
-
-            // SAFETY: The `T` is initialized => safe drop.
-            unsafe {
-                element.assume_init_drop();
-            }
-        }
-
-        if let Some(capacity) = self.back_capacity.checked_add(self.initialized) {
-            self.back_capacity = capacity;
-            self.initialized = 0;
-        } else {
-            unreachable!("allocated more than `isize::MAX` bytes");
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<&mut T, TryInsertError<T>> {
+        if index > self.initialized {
+            return Err(TryInsertError::OutOfBounds(element));
         }
 
-        let Ok(_) = self.shrink(None) else {
-            unreachable!("deallocation failure");
-        };
+        self.insert(index, element)
+            .map_err(TryInsertError::FailedAllocation)
     }
-}
-
-impl<'a, T: 'a + Clone> TryFrom<&'a [T]> for Dynamic<T> {
-    type Error = FailedAllocation;
 
-    /// Construct by cloning elements from an existing slice.
+    /// Insert an `element` such that it becomes the first.
     ///
-    /// # Panics
-    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    /// See [`Self::try_insert`] for details.
+    ///
+    /// # Errors
+    /// Yields [`TryInsertError::FailedAllocation`] if memory (re)allocation
+    /// fails, in which case the instance is left unmodified.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory for the result.
+    /// This methods takes O(N) time and consumes O(N) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let expected = [0, 1, 2, 3, 4, 5];
-    /// let actual = Dynamic::try_from(expected.as_slice()).expect("successful allocation");
+    /// let mut instance = Dynamic::from_iter([1, 2]);
+    ///
+    /// instance.try_prepend(0).expect("successful allocation");
     ///
-    /// assert!(actual.eq(expected));
+    /// assert!(instance.into_iter().eq([0, 1, 2]));
     /// ```
-    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
-        let mut instance = Self::with_capacity(slice.len())?;
+    pub fn try_prepend(&mut self, element: T) -> Result<&mut T, TryInsertError<T>> {
+        self.try_insert(0, element)
+    }
 
-        instance.extend(slice.iter().cloned());
-
-        Ok(instance)
+    /// Insert an `element` such that it becomes the last.
+    ///
+    /// See [`Self::try_insert`] for details.
+    ///
+    /// # Errors
+    /// Yields [`TryInsertError::FailedAllocation`] if memory (re)allocation
+    /// fails, in which case the instance is left unmodified.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1]);
+    ///
+    /// instance.try_append(2).expect("successful allocation");
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2]));
+    /// ```
+    pub fn try_append(&mut self, element: T) -> Result<&mut T, TryInsertError<T>> {
+        self.try_insert(self.initialized, element)
     }
-}
-
-impl<T> core::ops::Index<usize> for Dynamic<T> {
-    type Output = T;
 
-    /// Query the initialized element `index` positions from the start.
+    /// Shift the initialized elements `offset` positions within the buffer.
     ///
-    /// # Panics
-    /// This method has the precondition that the `index` is within bounds.
+    /// This method  maintains the order of initialized elements, but shifts
+    /// them thereby converting some portion of the capacity from front to
+    /// back, or vice versa.
+    ///
+    /// # Errors
+    /// Yields [`OutOfBounds`] is there is not enough capacity to shift into.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
+    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let expected = [0, 1, 2, 3, 4, 5];
-    /// let actual = Dynamic::from_iter(expected.iter().copied());
+    /// let mut instance = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
     ///
-    /// for index in 0..expected.len() {
-    ///     use core::ops::Index;
-    ///     assert_eq!(actual.index(index), expected.index(index));
-    /// }
+    /// // Fill with elements.
+    /// instance.extend(0..256);
+    ///
+    /// // Allocate capacity at both ends.
+    /// instance.reserve_front(256).expect("successful allocation");
+    /// instance.reserve_back(256).expect("successful allocation");
+    ///
+    /// // Shift initialized elements to the front of the buffer.
+    /// instance.shift(-256).expect("offset <= capacity_front()");
+    /// instance.shift(-1).expect_err("offset out of bounds");
+    /// assert_eq!(instance.capacity_front(), 0);
+    /// assert_eq!(instance.capacity_back(), 512);
+    ///
+    /// // Shift initialized elements to the end of the buffer.
+    /// instance.shift(512).expect("offset <= capacity_back()");
+    /// instance.shift(1).expect_err("offset out of bounds");
+    /// assert_eq!(instance.capacity_front(), 512);
+    /// assert_eq!(instance.capacity_back(), 0);
     /// ```
-    fn index(&self, index: usize) -> &Self::Output {
-        assert!(index < self.initialized, "index out of bounds");
+    pub fn shift(&mut self, offset: isize) -> Result<&mut Self, OutOfBounds> {
+        match offset.cmp(&0) {
+            core::cmp::Ordering::Less => {
+                if let Some(capacity) = self.front_capacity.checked_sub(offset.unsigned_abs()) {
+                    self.front_capacity = capacity;
+                } else {
+                    debug_assert!(
+                        offset.unsigned_abs() > self.front_capacity,
+                        "not enough capacity to shift into"
+                    );
 
-        let ptr = self.as_ptr();
+                    return Err(OutOfBounds);
+                }
 
-        // SAFETY: index within bounds => stays within the allocated object.
-        let ptr = unsafe { ptr.add(index) };
+                if let Some(capacity) = self.back_capacity.checked_add(offset.unsigned_abs()) {
+                    self.back_capacity = capacity;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+            }
+            core::cmp::Ordering::Greater => {
+                if let Some(capacity) = self.back_capacity.checked_sub(offset.unsigned_abs()) {
+                    self.back_capacity = capacity;
+                } else {
+                    debug_assert!(
+                        offset.unsigned_abs() > self.back_capacity,
+                        "not enough capacity to shift into"
+                    );
+
+                    return Err(OutOfBounds);
+                }
+
+                if let Some(capacity) = self.front_capacity.checked_add(offset.unsigned_abs()) {
+                    self.front_capacity = capacity;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+            }
+            core::cmp::Ordering::Equal => return Ok(self),
+        }
+
+        let destination = self.as_mut_ptr();
+
+        let Some(offset) = offset.checked_neg() else {
+            unreachable!("offset out of bounds");
+        };
+
+        // SAFETY: offset is in bounds => aligned within the allocated object.
+        let source = unsafe { destination.offset(offset) };
 
         // SAFETY:
-        // * the underlying `T` is initialized.
-        // * lifetime bound to self => valid lifetime to return.
-        unsafe { &*ptr }
+        // * owned memory => source/destination valid for read/writes.
+        // * no aliasing restrictions => source and destination can overlap.
+        // * underlying buffer is aligned => both pointers are aligned.
+        unsafe {
+            core::ptr::copy(source, destination, self.initialized);
+        }
+
+        Ok(self)
     }
-}
 
-impl<T> core::ops::IndexMut<usize> for Dynamic<T> {
-    /// Obtain a reference to the element `index` positions from the start.
+    /// Move the elements within `range` left or right by `offset` positions.
     ///
-    /// # Panics
-    /// This method has the precondition that the `index` is within bounds.
+    /// The elements displaced by the shift fill the positions vacated by
+    /// `range` whilst retaining their relative order, which is equivalent to
+    /// rotating the section spanned by both the original and shifted range.
+    /// This is a safe alternative to manually moving elements because it only
+    /// moves initialized elements amongst themselves, it does _NOT_ move
+    /// elements into capacity hence never modifies capacity at either end.
+    ///
+    /// # Errors
+    /// Yields [`OutOfBounds`] if `range` is either malformed, or not within
+    /// the initialized elements either before or after being shifted.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(K + |offset|) time and consumes O(1) memory for
+    /// the K elements within `range`.
     ///
     /// # Examples
     /// ```
+    /// use rust::structure::collection::linear::Linear;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut expected = [0, 1, 2, 3, 4, 5];
-    /// let mut actual = Dynamic::from_iter(expected.iter().copied());
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// for index in 0..expected.len() {
-    ///     use core::ops::IndexMut;
-    ///     assert_eq!(actual.index_mut(index), expected.index_mut(index));
-    /// }
+    /// instance.shift_elements(1..3, 2).expect("in bounds");
+    /// assert!(instance.iter().eq([0, 3, 4, 1, 2, 5].iter()));
+    ///
+    /// instance.shift_elements(3..5, -3).expect("in bounds");
+    /// assert!(instance.into_iter().eq([1, 2, 0, 3, 4, 5]));
     /// ```
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        assert!(index < self.initialized, "index out of bounds");
+    #[allow(clippy::indexing_slicing)]
+    pub fn shift_elements(
+        &mut self,
+        range: impl core::ops::RangeBounds<usize>,
+        offset: isize,
+    ) -> Result<&mut Self, OutOfBounds> {
+        let start = match range.start_bound() {
+            core::ops::Bound::Unbounded => 0,
+            core::ops::Bound::Included(start) => *start,
+            core::ops::Bound::Excluded(start) => start.checked_add(1).ok_or(OutOfBounds)?,
+        };
 
-        let ptr = self.as_mut_ptr();
+        let end = match range.end_bound() {
+            core::ops::Bound::Unbounded => self.initialized,
+            core::ops::Bound::Included(end) => end.checked_add(1).ok_or(OutOfBounds)?,
+            core::ops::Bound::Excluded(end) => *end,
+        };
 
-        // SAFETY: index within bounds => stays within the allocated object.
-        let ptr = unsafe { ptr.add(index) };
+        if start > end || end > self.initialized {
+            return Err(OutOfBounds);
+        }
 
-        // SAFETY:
-        // * the underlying `T` is initialized.
-        // * lifetime bound to self => valid lifetime to return.
-        unsafe { &mut *ptr }
-    }
-}
+        let shifted_start = start.checked_add_signed(offset).ok_or(OutOfBounds)?;
+        let shifted_end = end.checked_add_signed(offset).ok_or(OutOfBounds)?;
 
-impl<T> Iterator for Dynamic<T> {
-    type Item = T;
+        if shifted_end > self.initialized {
+            return Err(OutOfBounds);
+        }
 
-    /// Obtain the first initialized element.
+        // Either nothing to move, or nowhere to move it.
+        if offset == 0 || start == end {
+            return Ok(self);
+        }
+
+        let elements = self.as_mut_slice();
+
+        if offset > 0 {
+            elements[start..shifted_end].rotate_right(offset.unsigned_abs());
+        } else {
+            elements[shifted_start..end].rotate_left(offset.unsigned_abs());
+        }
+
+        Ok(self)
+    }
+
+    /// Swap every element with the corresponding element of `other`.
+    ///
+    /// This is equivalent to [`slice::swap_with_slice`], offered directly for
+    /// discoverability rather than via [`Array::as_mut_slice`].
+    ///
+    /// # Panics
+    /// This method has the precondition that `other` contains the same
+    /// number of elements.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]).into_iter();
+    /// let mut instance = Dynamic::from_iter([0, 1, 2]);
+    /// let mut other = [3, 4, 5];
     ///
-    /// assert_eq!(instance.next(), Some(0));
-    /// assert_eq!(instance.next(), Some(1));
-    /// assert_eq!(instance.next(), Some(2));
-    /// assert_eq!(instance.next(), Some(3));
-    /// assert_eq!(instance.next(), Some(4));
-    /// assert_eq!(instance.next(), Some(5));
-    /// assert_eq!(instance.next(), None);
+    /// instance.swap_with_slice(&mut other);
+    ///
+    /// assert_eq!(other, [0, 1, 2]);
+    /// assert!(instance.into_iter().eq([3, 4, 5]));
     /// ```
-    fn next(&mut self) -> Option<Self::Item> {
-        (self.initialized > 0).then(|| {
-            let element = self.as_mut_ptr();
-
-            if let Some(decremented) = self.initialized.checked_sub(1) {
-                self.initialized = decremented;
-            } else {
-                unreachable!("no initialized element to remove");
-            };
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        assert_eq!(self.initialized, other.len(), "lengths must be equal");
 
-            if let Some(incremented) = self.front_capacity.checked_add(1) {
-                self.front_capacity = incremented;
-            } else {
-                unreachable!("allocated more than `isize::MAX` bytes");
-            };
+        // No allocation to point to => no elements to swap.
+        if self.initialized == 0 {
+            return;
+        }
 
-            // SAFETY:
-            // * owned memory => pointer is valid for reads.
-            // * Underlying `T` is initialized.
-            // * This takes ownership (moved out of the buffer).
-            unsafe { element.read() }
-        })
+        self.as_mut_slice().swap_with_slice(other);
     }
 
-    /// Query how many elements have yet to be yielded.
+    /// Copy the elements within `source` such that they start at `destination`.
+    ///
+    /// This is equivalent to [`slice::copy_within`], offered directly for
+    /// discoverability rather than via [`Array::as_mut_slice`]. The ranges
+    /// may overlap since elements are copied via [`core::ptr::copy`] which
+    /// behaves as if through an intermediate buffer.
+    ///
+    /// # Panics
+    /// This method has the precondition that `source` is well-formed and
+    /// both it and the `destination` range are within bounds.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(K) time and consumes O(1) memory for the K
+    /// elements within `source`.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]).into_iter();
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.size_hint(), (6, Some(6)));
+    /// instance.copy_within(0..3, 2);
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 0, 1, 2, 5]));
     /// ```
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.initialized, Some(self.initialized))
+    pub fn copy_within(&mut self, source: impl core::ops::RangeBounds<usize>, destination: usize)
+    where
+        T: Copy,
+    {
+        // No allocation to point to, but bounds must still be checked.
+        let elements: &mut [T] = if self.initialized == 0 {
+            &mut []
+        } else {
+            self.as_mut_slice()
+        };
+
+        elements.copy_within(source, destination);
     }
-}
 
-impl<T> DoubleEndedIterator for Dynamic<T> {
-    /// Obtain the last initialized element.
+    /// Replace the elements within `range` with copies of `replacement`.
+    ///
+    /// The elements are copied in bulk rather than individually via an
+    /// iterator, and those after (or before) the range are shifted at most
+    /// once to accommodate a replacement of different length. A longer
+    /// replacement will first consume back capacity, then front capacity,
+    /// and only reallocate if neither end has enough capacity. A shorter
+    /// replacement converts the difference into back capacity.
+    ///
+    /// # Panics
+    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    ///
+    /// # Errors
+    /// Yields [`FailedAllocation`] when memory (re)allocation fails, in which
+    /// case no elements are modified.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N + K) time and consumes O(K) memory for the K
+    /// elements within `replacement`.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]).into_iter();
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.next_back(), Some(5));
-    /// assert_eq!(instance.next_back(), Some(4));
-    /// assert_eq!(instance.next_back(), Some(3));
-    /// assert_eq!(instance.next_back(), Some(2));
-    /// assert_eq!(instance.next_back(), Some(1));
-    /// assert_eq!(instance.next_back(), Some(0));
-    /// assert_eq!(instance.next_back(), None);
+    /// instance.splice_slice(1..3, &[7, 8, 9]).expect("successful allocation");
+    ///
+    /// assert!(instance.into_iter().eq([0, 7, 8, 9, 3, 4, 5]));
     /// ```
-    fn next_back(&mut self) -> Option<Self::Item> {
-        (self.initialized > 0).then(|| {
-            if let Some(decremented) = self.initialized.checked_sub(1) {
-                self.initialized = decremented;
-            } else {
-                unreachable!("no initialized element to remove");
-            }
+    pub fn splice_slice(
+        &mut self,
+        range: impl core::ops::RangeBounds<usize>,
+        replacement: &[T],
+    ) -> Result<&mut Self, FailedAllocation>
+    where
+        T: Copy,
+    {
+        let end = match range.end_bound() {
+            core::ops::Bound::Included(end) => end.saturating_add(1),
+            core::ops::Bound::Excluded(end) => *end,
+            core::ops::Bound::Unbounded => self.initialized,
+        }
+        .min(self.initialized);
 
-            if let Some(incremented) = self.back_capacity.checked_add(1) {
-                self.back_capacity = incremented;
-            } else {
-                unreachable!("allocated more than `isize::MAX` bytes");
-            };
+        let start = match range.start_bound() {
+            core::ops::Bound::Included(start) => *start,
+            core::ops::Bound::Excluded(start) => start.saturating_add(1),
+            core::ops::Bound::Unbounded => 0,
+        }
+        .min(end);
 
-            let ptr = self.as_mut_ptr();
+        let Some(removed) = end.checked_sub(start) else {
+            unreachable!("start is clamped to be before end");
+        };
 
-            // SAFETY: final initialized element in the allocated object.
-            let element = unsafe { ptr.add(self.initialized) };
+        match replacement.len().cmp(&removed) {
+            core::cmp::Ordering::Greater => {
+                let Some(extra) = replacement.len().checked_sub(removed) else {
+                    unreachable!("replacement is longer than the range");
+                };
 
-            // SAFETY:
-            // * owned memory => pointer is valid for reads.
-            // * Underlying `T` is initialized.
-            // * This takes ownership (moved out of the buffer).
-            unsafe { element.read() }
-        })
-    }
-}
+                let offset = isize::try_from(extra).map_err(|_| FailedAllocation)?;
 
-impl<T> ExactSizeIterator for Dynamic<T> {}
+                if self.back_capacity < extra && self.front_capacity >= extra {
+                    let Some(offset) = offset.checked_neg() else {
+                        unreachable!("offset is positive");
+                    };
 
-impl<T> core::iter::FusedIterator for Dynamic<T> {}
+                    // SAFETY: there is front capacity to shift into.
+                    unsafe {
+                        self.shift_range(..start, offset);
+                    }
 
-impl<'a, T: 'a> FromIterator<T> for Dynamic<T> {
-    /// Construct by moving elements from an iterator.
-    ///
-    /// # Panics
-    /// The Rust runtime might abort if allocation fails, panics otherwise.
-    ///
-    /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory for the result.
-    ///
-    /// # Examples
-    /// ```
-    /// use rust::structure::collection::linear::array::Dynamic;
-    ///
-    /// let expected = [0, 1, 2, 3, 4, 5];
-    ///
-    /// let actual: Dynamic<_> = expected.clone().into_iter().collect();
-    ///
-    /// assert!(actual.eq(expected))
-    /// ```
-    fn from_iter<Iter: IntoIterator<Item = T>>(iter: Iter) -> Self {
-        let iter = iter.into_iter();
+                    if let Some(decremented) = self.front_capacity.checked_sub(extra) {
+                        self.front_capacity = decremented;
+                    } else {
+                        unreachable!("not enough front capacity to shift into");
+                    }
+                } else {
+                    _ = self.reserve(extra)?;
 
-        let mut instance = Self::default();
+                    // SAFETY: there is back capacity to shift into.
+                    unsafe {
+                        self.shift_range(end..self.initialized, offset);
+                    }
 
-        instance.extend(iter);
+                    if let Some(decremented) = self.back_capacity.checked_sub(extra) {
+                        self.back_capacity = decremented;
+                    } else {
+                        unreachable!("not enough back capacity to shift into");
+                    }
+                }
+            }
+            core::cmp::Ordering::Less => {
+                let Some(fewer) = removed.checked_sub(replacement.len()) else {
+                    unreachable!("replacement is shorter than the range");
+                };
 
-        instance
+                let Ok(offset) = isize::try_from(fewer) else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                };
+
+                let Some(offset) = offset.checked_neg() else {
+                    unreachable!("offset is positive");
+                };
+
+                // SAFETY: shifts into the elements being replaced.
+                unsafe {
+                    self.shift_range(end..self.initialized, offset);
+                }
+
+                if let Some(incremented) = self.back_capacity.checked_add(fewer) {
+                    self.back_capacity = incremented;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+            }
+            core::cmp::Ordering::Equal => {}
+        }
+
+        if let Some(count) = self
+            .initialized
+            .checked_sub(removed)
+            .and_then(|retained| retained.checked_add(replacement.len()))
+        {
+            self.initialized = count;
+        } else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        }
+
+        // SAFETY: points to the where the first initialized element goes.
+        let ptr = unsafe { self.buffer.as_ptr().add(self.front_capacity) };
+
+        // SAFETY: the range has been resized to exactly fit the replacement.
+        let destination = unsafe { ptr.add(start).cast::<T>() };
+
+        // SAFETY:
+        // * `T: Copy` => replaced elements need not be dropped.
+        // * owned memory => destination is valid for writes.
+        // * borrowed `replacement` => cannot overlap owned memory.
+        unsafe {
+            core::ptr::copy_nonoverlapping(replacement.as_ptr(), destination, replacement.len());
+        }
+
+        Ok(self)
     }
-}
 
-impl<T> Extend<T> for Dynamic<T> {
-    /// Append elements of an iterator in order.
+    /// Rotate the elements such that the first `count` become the last.
+    ///
+    /// If there is enough capacity, this will move the fewest possible
+    /// elements from one end into the capacity at the other end thereby
+    /// converting some portion of the capacity from one end to the other.
+    /// Otherwise this will rotate within the initialized elements via three
+    /// reversals, thereby retaining the capacity at each end.
     ///
     /// # Panics
-    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    /// This method has the precondition that `count` is not greater than the
+    /// number of elements.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory.
+    /// This method takes O(min(K, N - K)) time if there is enough capacity to
+    /// move the K rotated elements (or N-K unrotated elements) into, otherwise
+    /// O(N) time. In both cases, it consumes O(1) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let expected = [0, 1, 2, 3, 4, 5];
-    ///
-    /// let mut instance = Dynamic::<i32>::default();
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// instance.extend(expected.iter().cloned());
+    /// instance.rotate_left(2);
     ///
-    /// assert!(instance.eq(expected))
+    /// assert!(instance.into_iter().eq([2, 3, 4, 5, 0, 1]));
     /// ```
-    fn extend<Iter: IntoIterator<Item = T>>(&mut self, iter: Iter) {
-        let iter = iter.into_iter();
-
-        // `size_hint` can _NOT_ be trusted to exact size.
-        let count = {
-            let (min, max) = iter.size_hint();
-            max.unwrap_or(min)
+    pub fn rotate_left(&mut self, count: usize) {
+        let Some(remaining) = self.initialized.checked_sub(count) else {
+            panic!("rotation out of bounds");
         };
 
-        // Append will allocate for each realized element reserve if fails.
-        drop(self.reserve_back(count));
+        if count == 0 || remaining == 0 {
+            return;
+        }
 
-        for element in iter {
-            assert!(self.append(element).is_ok(), "allocation failed");
+        let to_back = (count <= self.back_capacity).then_some(count);
+        let to_front = (remaining <= self.front_capacity).then_some(remaining);
+
+        match (to_back, to_front) {
+            (Some(to_back), Some(to_front)) if to_front < to_back => {
+                self.relocate_to_front(to_front);
+            }
+            (Some(to_back), _) => self.relocate_to_back(to_back),
+            (None, Some(to_front)) => self.relocate_to_front(to_front),
+            (None, None) => {
+                let elements = self.as_mut_slice();
+
+                let (rotated, unrotated) = elements.split_at_mut(count);
+                rotated.reverse();
+                unrotated.reverse();
+
+                elements.reverse();
+            }
         }
     }
-}
 
-impl<T> Default for Dynamic<T> {
-    /// Construct an instance with no elements and no capacity/allocation.
+    /// Rotate the elements such that the last `count` become the first.
+    ///
+    /// See [`Self::rotate_left`] for details.
+    ///
+    /// # Panics
+    /// This method has the precondition that `count` is not greater than the
+    /// number of elements.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(min(K, N - K)) time if there is enough capacity to
+    /// move the K rotated elements (or N-K unrotated elements) into, otherwise
+    /// O(N) time. In both cases, it consumes O(1) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let instance = Dynamic::<()>::default();
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.len(), 0);
-    /// assert_eq!(instance.capacity(), 0);
+    /// instance.rotate_right(2);
+    ///
+    /// assert!(instance.into_iter().eq([4, 5, 0, 1, 2, 3]));
     /// ```
-    fn default() -> Self {
-        Self {
-            buffer: NonNull::dangling(),
-            front_capacity: 0,
-            initialized: 0,
-            back_capacity: 0,
-        }
+    pub fn rotate_right(&mut self, count: usize) {
+        let Some(remaining) = self.initialized.checked_sub(count) else {
+            panic!("rotation out of bounds");
+        };
+
+        self.rotate_left(remaining);
     }
-}
 
-impl<T: Clone> Clone for Dynamic<T> {
-    /// Construct an instance with no elements and no capacity/allocation.
+    /// Rearrange the elements such that the element at index `i` moves to
+    /// index `permutation[i]`.
+    ///
+    /// The `permutation` is first validated to contain every index exactly
+    /// once, then each cycle of the permutation is followed by repeatedly
+    /// swapping the element at the start of the cycle into its destination,
+    /// thereby performing O(N) swaps. Which indexes have been placed are
+    /// tracked by a [`BitSet`](`crate::structure::collection::BitSet`).
     ///
     /// # Panics
-    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    /// This method has the precondition that `permutation` contains every
+    /// index in `0..len()` exactly once. The Rust runtime might abort if
+    /// allocation fails, panics otherwise.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory for the result.
+    /// This method takes O(N) time and consumes O(N / 64) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let expected = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut instance = Dynamic::from_iter(['a', 'b', 'c', 'd']);
     ///
-    /// assert_eq!(expected.clone(), expected)
+    /// instance.apply_permutation(&[2, 0, 3, 1]);
+    ///
+    /// assert!(instance.into_iter().eq(['b', 'd', 'a', 'c']));
     /// ```
-    fn clone(&self) -> Self {
-        let mut clone = Self::default();
+    #[allow(clippy::indexing_slicing)]
+    pub fn apply_permutation(&mut self, permutation: &[usize]) {
+        assert_eq!(
+            permutation.len(),
+            self.initialized,
+            "permutation must have an index for each element"
+        );
 
-        clone.extend(self.iter().cloned());
+        let mut unplaced = crate::structure::collection::BitSet::new(self.initialized);
 
-        clone
+        for &destination in permutation {
+            match unplaced.set(destination) {
+                Some(false) => {}
+                Some(true) => panic!("permutation contains a duplicate index"),
+                None => panic!("permutation contains an out of bounds index"),
+            }
+        }
+
+        if self.initialized == 0 {
+            return;
+        }
+
+        let elements = self.as_mut_slice();
+
+        for start in 0..elements.len() {
+            if unplaced.clear(start) != Some(true) {
+                continue;
+            }
+
+            // The element at `start` is always the next in the cycle to place.
+            let mut destination = permutation[start];
+
+            while destination != start {
+                elements.swap(start, destination);
+
+                _ = unplaced.clear(destination);
+
+                destination = permutation[destination];
+            }
+        }
     }
-}
 
-impl<T: PartialEq> PartialEq for Dynamic<T> {
-    /// Query if the elements contained are the same as `other`.
+    /// Remove an element by swapping it with the first element.
+    ///
+    /// In contrast to [`Self::remove`], this method takes constant time and
+    /// does _NOT_ preserve order.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let left = [0, 1, 2, 3, 4, 5];
-    /// let right = left.clone();
-    ///
-    /// let left = Dynamic::from_iter(left);
-    /// let right = Dynamic::from_iter(right);
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(left, right);
+    /// assert_eq!(instance.remove_via_front(3), Some(3));
+    /// assert_eq!(instance.capacity_front(), 1);
+    /// assert_eq!(instance[2], 0);
     /// ```
-    fn eq(&self, other: &Self) -> bool {
-        self.iter().eq(other.iter())
-    }
-}
+    #[must_use]
+    pub fn remove_via_front(&mut self, index: usize) -> Option<T> {
+        if index >= self.initialized {
+            return None;
+        }
 
-impl<T: Eq> Eq for Dynamic<T> {}
+        let front = self.as_mut_ptr();
 
-impl<T: core::fmt::Debug> core::fmt::Debug for Dynamic<T> {
-    /// List the elements contained.
+        // SAFETY: index in bounds => aligned within the allocated object.
+        let index = unsafe { front.add(index) };
+
+        // SAFETY:
+        // * both pointers are valid for reads and write.
+        // * both pointers are aligned.
+        // * no aliasing restrictions.
+        unsafe {
+            core::ptr::swap(front, index);
+        }
+
+        // SAFETY:
+        // * owned memory => pointer is valid for reads.
+        // * Underlying `T` is initialized.
+        // * This takes ownership (moved out of the buffer).
+        let element = unsafe { front.read() };
+
+        if let Some(decremented) = self.initialized.checked_sub(1) {
+            self.initialized = decremented;
+        } else {
+            unreachable!("no initialized element to remove");
+        }
+
+        if let Some(incremented) = self.front_capacity.checked_add(1) {
+            self.front_capacity = incremented;
+        } else {
+            unreachable!("allocated more that `isize::MAX` bytes");
+        }
+
+        Some(element)
+    }
+
+    /// Remove an element by swapping it with the last element.
+    ///
+    /// In contrast to [`Self::remove`], this method takes constant time and
+    /// does _NOT_ preserve order.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut expected = [0, 1, 2, 3, 4, 5];
-    /// let actual = Dynamic::from_iter(expected.iter());
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+    /// assert_eq!(instance.remove_via_back(3), Some(3));
+    /// assert_eq!(instance.capacity_back(), 1);
+    /// assert_eq!(instance[3], 5);
     /// ```
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_list().entries(self.iter()).finish()
-    }
-}
+    #[must_use]
+    pub fn remove_via_back(&mut self, index: usize) -> Option<T> {
+        if index >= self.initialized {
+            return None;
+        }
 
-impl<'a, T: 'a> Collection for Dynamic<T> {
-    type Element = T;
+        let ptr = self.as_mut_ptr();
 
-    /// Query the number of initialized elements contained.
+        let last = {
+            let Some(offset) = self.initialized.checked_sub(1) else {
+                unreachable!("no initialized element to remove");
+            };
+
+            // SAFETY: points to the final element contained.
+            unsafe { ptr.add(offset) }
+        };
+
+        // SAFETY: index is in bounds => aligned within the allocated object.
+        let index = unsafe { ptr.add(index) };
+
+        // SAFETY:
+        // * both pointers are valid for reads and write.
+        // * both pointers are aligned.
+        // * no aliasing restrictions.
+        unsafe {
+            core::ptr::swap(last, index);
+        }
+
+        // SAFETY:
+        // * owned memory => pointer is valid for reads.
+        // * Underlying `T` is initialized.
+        // * This takes ownership (moved out of the buffer).
+        let element = unsafe { last.read() };
+
+        if let Some(decremented) = self.initialized.checked_sub(1) {
+            self.initialized = decremented;
+        } else {
+            unreachable!("no initialized element to remove");
+        }
+
+        if let Some(incremented) = self.back_capacity.checked_add(1) {
+            self.back_capacity = incremented;
+        } else {
+            unreachable!("allocated more that `isize::MAX` bytes");
+        }
+
+        Some(element)
+    }
+
+    /// Remove every `step`-th element within `range`, starting with the first.
+    ///
+    /// This is akin to [`List::drain`] combined with [`Iterator::step_by`]
+    /// such that the elements in `range` between those removed are instead
+    /// retained, for example when decimating a signal. The `range` is
+    /// clamped to within bounds. Removed elements are yielded in order, and
+    /// retained elements are shifted to remain contiguous, even if the
+    /// iterator is dropped before being exhausted.
+    ///
+    /// See also: [`List::withdraw`] to remove elements by some predicate.
+    ///
+    /// # Panics
+    /// This method has the precondition that `step` is not zero.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::Collection;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let expected = [0, 1, 2, 3, 4, 5];
-    /// let instance = Dynamic::from_iter(expected.clone());
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
     ///
-    /// assert_eq!(Collection::count(&instance), expected.len());
+    /// assert!(instance.drain_step_by(.., 2).eq([0, 2, 4, 6, 8]));
+    /// assert!(instance.into_iter().eq([1, 3, 5, 7, 9]));
     /// ```
-    fn count(&self) -> usize {
-        self.initialized
+    pub fn drain_step_by(
+        &mut self,
+        range: impl core::ops::RangeBounds<usize>,
+        step: usize,
+    ) -> impl Iterator<Item = T> + '_ {
+        assert!(step != 0, "step must be non-zero");
+
+        let start = match range.start_bound() {
+            core::ops::Bound::Included(start) => *start,
+            core::ops::Bound::Excluded(start) => start.saturating_add(1),
+            core::ops::Bound::Unbounded => 0,
+        }
+        .min(self.initialized);
+
+        let end = match range.end_bound() {
+            core::ops::Bound::Included(end) => end.saturating_add(1),
+            core::ops::Bound::Excluded(end) => *end,
+            core::ops::Bound::Unbounded => self.initialized,
+        }
+        .min(self.initialized);
+
+        // The predicate is called once per element in order from the front.
+        let mut index = 0;
+
+        self.withdraw(move |_| {
+            let withdrawn = (start..end).contains(&index)
+                && index
+                    .checked_sub(start)
+                    .and_then(|offset| offset.checked_rem(step))
+                    == Some(0);
+
+            if let Some(incremented) = index.checked_add(1) {
+                index = incremented;
+            } else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            }
+
+            withdrawn
+        })
     }
-}
 
-impl<T> Linear for Dynamic<T> {
-    /// Create an immutable iterator over the initialized elements.
+    /// Remove the elements within `range` which match some `predicate`.
+    ///
+    /// This is akin to [`List::drain`] combined with [`List::withdraw`] such
+    /// that only elements within `range` are given to the `predicate`, which
+    /// may mutate them. The `range` is clamped to within bounds. Elements for
+    /// which the `predicate` is true are yielded in order, whereas those for
+    /// which it is false (and all those outside `range`) are retained in
+    /// order. Elements are tested only as the iterator is advanced, hence
+    /// should it be dropped before being exhausted, elements yet to be tested
+    /// are retained.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory. Exhausting the
+    /// iterator takes O(N) time.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::Linear;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let expected = [0, 1, 2, 3, 4, 5];
-    /// let actual = Dynamic::from_iter(expected.clone());
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
     ///
-    /// for (actual, expected) in actual.iter().zip(expected.iter()) {
-    ///     assert_eq!(actual, expected);
-    /// }
+    /// assert!(instance.extract_if(2..8, |element| *element % 2 == 0).eq([2, 4, 6]));
+    /// assert!(instance.into_iter().eq([0, 1, 3, 5, 7, 8, 9]));
     /// ```
-    fn iter(
-        &self,
-    ) -> impl DoubleEndedIterator<Item = &Self::Element> + ExactSizeIterator + core::iter::FusedIterator
-    {
-        let ptr = if self.initialized > 0 {
-            // The pointer will only ever be read, no written to.
-            let ptr = self.as_ptr().cast_mut();
+    pub fn extract_if<'a>(
+        &'a mut self,
+        range: impl core::ops::RangeBounds<usize>,
+        predicate: impl FnMut(&mut T) -> bool + 'a,
+    ) -> impl core::iter::FusedIterator<Item = T> + 'a {
+        let end = match range.end_bound() {
+            core::ops::Bound::Included(end) => end.saturating_add(1),
+            core::ops::Bound::Excluded(end) => *end,
+            core::ops::Bound::Unbounded => self.initialized,
+        }
+        .min(self.initialized);
 
-            // SAFETY: initialized elements => `ptr` is non-null
-            unsafe { NonNull::new_unchecked(ptr) }
-        } else {
-            debug_assert_eq!(self.initialized, 0, "initialized elements");
+        let start = match range.start_bound() {
+            core::ops::Bound::Included(start) => *start,
+            core::ops::Bound::Excluded(start) => start.saturating_add(1),
+            core::ops::Bound::Unbounded => 0,
+        }
+        .min(end);
 
-            // no initialized elements => The pointer will not be read.
-            NonNull::dangling()
+        let original = self.initialized;
+
+        let Some(suffix) = original.checked_sub(start) else {
+            unreachable!("start is clamped to within bounds");
         };
 
-        // SAFETY: `ptr` is dangling if and only if no elements have been
-        // initialized, in which case the pointer will not be read.
-        unsafe { super::Iter::new(ptr, self.initialized) }
+        // Should the iterator be leaked, only elements before `range` remain
+        // initialized and the rest are leaked, but capacity stays consistent.
+        self.initialized = start;
+
+        if let Some(capacity) = self.back_capacity.checked_add(suffix) {
+            self.back_capacity = capacity;
+        } else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        }
+
+        ExtractIf {
+            underlying: self,
+            predicate,
+            next: start,
+            end,
+            original,
+            removed: 0,
+        }
     }
 
-    /// Create a mutable iterator over the initialized elements.
+    /// Remove every element, yielding them in ascending order.
+    ///
+    /// The elements are first arranged into a min-heap in place such that
+    /// each element yielded only requires sifting down the root, hence only
+    /// consuming a few elements is cheaper than sorting. When dropped, any
+    /// elements yet to be yielded are dropped, leaving [`Self`] empty but
+    /// retaining the capacity.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(1) memory. Each element
+    /// yielded takes O(log N) time.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::Linear;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut expected = [0, 1, 2, 3, 4, 5];
-    /// let mut actual = Dynamic::from_iter(expected.clone());
+    /// let mut instance = Dynamic::from_iter([3, 1, 2]);
     ///
-    /// for (actual, expected) in actual.iter_mut().zip(expected.iter_mut()) {
-    ///     assert_eq!(actual, expected);
-    /// }
+    /// assert!(instance.drain_sorted().eq([1, 2, 3]));
+    ///
+    /// assert_eq!(instance.len(), 0);
+    /// assert_eq!(instance.capacity(), 3);
     /// ```
-    fn iter_mut(
+    pub fn drain_sorted(
         &mut self,
-    ) -> impl DoubleEndedIterator<Item = &mut Self::Element>
-           + ExactSizeIterator
-           + core::iter::FusedIterator {
-        let ptr = if self.initialized > 0 {
-            let ptr = self.as_mut_ptr();
-
-            // SAFETY: initialized elements => `ptr` is non-null
-            unsafe { NonNull::new_unchecked(ptr) }
-        } else {
-            debug_assert_eq!(self.initialized, 0, "initialized elements");
-
-            // no initialized elements => The pointer will not be read.
-            NonNull::dangling()
-        };
+    ) -> impl ExactSizeIterator<Item = T> + core::iter::FusedIterator + '_
+    where
+        T: Ord,
+    {
+        if self.initialized != 0 {
+            heap::heapify_by(self.as_mut_slice(), |left, right| right.cmp(left));
+        }
 
-        // SAFETY: `ptr` is dangling if and only if no elements have been
-        // initialized, in which case the pointer will not be read.
-        unsafe { super::IterMut::new(ptr, self.initialized) }
+        DrainSorted { underlying: self }
     }
-}
 
-impl<T> Array for Dynamic<T> {
-    /// Obtain an immutable pointer to the underlying contigious memory buffer.
-    ///
-    /// The pointer starts at the first initialized element.
+    /// Replace each element with the result of `transform` applied to it.
     ///
-    /// # Safety
-    /// * `self` must outlive the pointer.
-    /// * The pointer must never be written to.
-    /// * Modifying `self` might invalidate the pointer.
+    /// Because the output type is the same as the input, each result is
+    /// written back into the slot the element was moved out of, hence the
+    /// existing allocation (and therefore capacity) is reused.
     ///
-    /// # Panics
-    /// This method has the precondition that an underlying allocation exists
-    /// to point to. Note that a dangling (but nevertheless valid) pointer will
-    /// be yielded for zero-size types despite not occupying memory.
+    /// Should `transform` panic, the element it was given is dropped by
+    /// `transform`'s unwinding, and all other elements (transformed or not)
+    /// are dropped before unwinding further.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::Array;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// let expected = core::ptr::from_ref(&instance[0]);
-    /// let actual = unsafe { instance.as_ptr() };
+    /// let actual = instance.map_in_place(|element| element * 2);
     ///
-    /// assert_eq!(actual, expected);
+    /// assert!(actual.into_iter().eq([0, 2, 4, 6, 8, 10]));
     /// ```
-    #[allow(clippy::arithmetic_side_effects)]
-    fn as_ptr(&self) -> *const Self::Element {
-        assert!(
-            self.front_capacity + self.initialized + self.back_capacity > 0,
-            "no allocation to point to"
-        );
+    #[must_use]
+    pub fn map_in_place(mut self, mut transform: impl FnMut(T) -> T) -> Self {
+        /// Drop all elements except the one moved out if `transform` panics.
+        struct Guard<'a, T> {
+            /// The instance whose elements are being transformed.
+            underlying: &'a mut Dynamic<T>,
+
+            /// The index of the element currently moved out of the buffer.
+            current: usize,
+        }
 
-        // `MaybeUninit<T>` has the same layout as `T`.
-        let ptr = self.buffer.cast::<T>().as_ptr().cast_const();
+        impl<T> Drop for Guard<'_, T> {
+            /// Drop elements other than `current` when unwinding.
+            ///
+            /// # Performance
+            /// This method takes O(N) time and consumes O(1) memory.
+            fn drop(&mut self) {
+                // All elements were transformed and written back.
+                if self.current >= self.underlying.initialized {
+                    return;
+                }
 
-        // SAFETY: Stays aligned within the allocated object.
-        unsafe { ptr.add(self.front_capacity) }
+                let ptr = self.underlying.as_mut_ptr();
+
+                for index in (0..self.underlying.initialized).filter(|&index| index != self.current)
+                {
+                    // SAFETY: index is in bounds => initialized element.
+                    let element = unsafe { ptr.add(index) };
+
+                    // SAFETY: every index except `current` is initialized.
+                    unsafe {
+                        element.drop_in_place();
+                    }
+                }
+
+                if let Some(capacity) = self
+                    .underlying
+                    .back_capacity
+                    .checked_add(self.underlying.initialized)
+                {
+                    self.underlying.back_capacity = capacity;
+                    self.underlying.initialized = 0;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+            }
+        }
+
+        let mut guard = Guard {
+            underlying: &mut self,
+            current: 0,
+        };
+
+        while guard.current < guard.underlying.initialized {
+            let ptr = guard.underlying.as_mut_ptr();
+
+            // SAFETY: index is in bounds => aligned within the allocated object.
+            let ptr = unsafe { ptr.add(guard.current) };
+
+            // SAFETY:
+            // * owned memory => pointer is valid for reads.
+            // * Underlying `T` is initialized.
+            // * The guard ensures the slot is not dropped while moved out.
+            let element = unsafe { ptr.read() };
+
+            let element = transform(element);
+
+            // SAFETY:
+            // * owned memory => pointer is valid for writes.
+            // * The previous element was moved out, so nothing is leaked.
+            unsafe {
+                ptr.write(element);
+            }
+
+            if let Some(incremented) = guard.current.checked_add(1) {
+                guard.current = incremented;
+            } else {
+                unreachable!("loop ensures index is less than `usize::MAX`");
+            }
+        }
+
+        drop(guard);
+
+        self
     }
 
-    /// Obtain a mutable pointer to the underlying contigious memory buffer.
-    ///
-    /// The pointer starts at the first initialized element.
+    /// Construct a new instance from the result of `transform` on each element.
     ///
-    /// # Safety
-    /// * `self` must outlive the pointer.
-    /// * Modifying `self` might invalidate the pointer.
+    /// Elements are transformed in order, stopping at the first error. Upon
+    /// error, both the elements already transformed and those which have yet
+    /// to be transformed are dropped.
     ///
     /// # Panics
-    /// This method has the precondition that an underlying allocation exists
-    /// to point to. Note that a dangling (but nevertheless valid) pointer will
-    /// be yielded for zero-size types despite not occupying memory.
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Errors
+    /// Yields the first error produced by `transform`.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(N) memory for the result.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::Array;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-    ///
-    /// let expected = core::ptr::from_ref(&instance[0]).cast_mut();
-    /// let actual = unsafe { instance.as_mut_ptr() };
+    /// let valid = Dynamic::from_iter(["0", "1", "2"]);
+    /// let actual = valid.try_map(str::parse::<i32>).expect("all valid");
+    /// assert!(actual.into_iter().eq([0, 1, 2]));
     ///
-    /// assert_eq!(actual, expected);
+    /// let invalid = Dynamic::from_iter(["0", "x", "2"]);
+    /// assert!(invalid.try_map(str::parse::<i32>).is_err());
     /// ```
-    #[allow(clippy::arithmetic_side_effects)]
-    fn as_mut_ptr(&mut self) -> *mut Self::Element {
-        assert!(
-            self.front_capacity + self.initialized + self.back_capacity > 0,
-            "no allocation to point to"
-        );
-
-        // `MaybeUninit<T>` has the same layout as `T`.
-        let ptr = self.buffer.cast::<T>().as_ptr();
-
-        // SAFETY: Stays aligned within the allocated object.
-        unsafe { ptr.add(self.front_capacity) }
+    pub fn try_map<U, E>(self, transform: impl FnMut(T) -> Result<U, E>) -> Result<Dynamic<U>, E> {
+        self.into_iter().map(transform).collect()
     }
-}
 
-impl<T> List for Dynamic<T> {
-    /// Insert an `element` at `index`.
+    /// Iterate over non-overlapping chunks of `size` elements from the back.
+    ///
+    /// Every chunk has exactly `size` elements except for the one containing
+    /// the first element which instead contains the remainder when the number
+    /// of elements is not a multiple of `size`. This means the last chunk
+    /// yielded (that at the front) might be shorter, just like [`slice::rchunks`].
     ///
     /// # Panics
-    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    /// This method has the precondition that `size` is not zero.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::<usize>::default();
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4]);
     ///
-    /// instance.insert(0, 1);
-    /// instance.insert(1, 3);
-    /// instance.insert(1, 2);
-    /// instance.insert(0, 0);
+    /// let mut actual = instance.rchunks(2);
     ///
-    /// assert!(instance.into_iter().eq([0, 1, 2, 3]));
+    /// assert_eq!(actual.next(), Some([3, 4].as_slice()));
+    /// assert_eq!(actual.next(), Some([1, 2].as_slice()));
+    /// assert_eq!(actual.next(), Some([0].as_slice()));
+    /// assert_eq!(actual.next(), None);
     /// ```
-    fn insert(
-        &mut self,
-        index: usize,
-        element: Self::Element,
-    ) -> Result<&mut Self::Element, Self::Element> {
-        if index > self.initialized {
-            return Err(element);
-        }
-
-        let mut ptr = self.buffer.as_ptr();
-
-        // consume front capacity.
-        if index == 0 && self.capacity_front() > 0 {
-            ptr = {
-                let Some(offset) = self.capacity_front().checked_sub(1) else {
-                    unreachable!("zero front capacity")
-                };
-
-                // SAFETY: the last uninitialized element in the front.
-                unsafe { ptr.add(offset) }
-            };
-
-            // Shift all capacity to front capacity.
-            if self.initialized == 0 {
-                if let Some(capacity) = self.front_capacity.checked_add(self.back_capacity) {
-                    self.front_capacity = capacity;
-                } else {
-                    unreachable!("allocated more than `isize::MAX` bytes");
-                };
-
-                self.back_capacity = 0;
-            }
-
-            if let Some(decremented) = self.front_capacity.checked_sub(1) {
-                self.front_capacity = decremented;
-            } else {
-                unreachable!("no front capacity to insert into");
-            };
-        }
-        // consume back capacity.
-        else if self.reserve(1).is_ok() {
-            ptr = {
-                let Some(offset) = self.front_capacity.checked_add(index) else {
-                    unreachable!("index out of bounds");
-                };
-
-                // SAFETY: the uninitialized element to insert into.
-                unsafe { self.buffer.as_ptr().add(offset) }
-            };
-
-            // SAFETY: there is back capacity to shift into.
-            unsafe {
-                self.shift_range(index.., 1);
-            }
-
-            if let Some(decrement) = self.back_capacity.checked_sub(1) {
-                self.back_capacity = decrement;
-            } else {
-                unreachable!("no back capacity to insert into");
-            };
-        } else {
-            debug_assert_eq!(self.capacity(), 0, "no capacity to insert into");
+    #[must_use]
+    pub fn rchunks(
+        &self,
+        size: usize,
+    ) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + core::iter::FusedIterator {
+        assert!(size > 0, "chunk size must be non-zero");
 
-            return Err(element);
+        // No allocation to point to => no elements to chunk.
+        if self.initialized == 0 {
+            return [].rchunks(size);
         }
 
-        if let Some(increment) = self.initialized.checked_add(1) {
-            self.initialized = increment;
-        } else {
-            unreachable!("allocated more that `isize::MAX` bytes");
-        };
-
-        // SAFETY: the `MaybeUninit<T>` is initialized even if the `T` isn't.
-        let uninit_element = unsafe { &mut *ptr };
-
-        // the underlying `T` is unutilized.
-        Ok(uninit_element.write(element))
+        self.as_slice().rchunks(size)
     }
 
-    /// Remove the element at `index`.
+    /// Iterate over non-overlapping mutable chunks of `size` from the back.
+    ///
+    /// Every chunk has exactly `size` elements except for the one containing
+    /// the first element which instead contains the remainder when the number
+    /// of elements is not a multiple of `size`. This means the last chunk
+    /// yielded (that at the front) might be shorter, just like [`slice::rchunks_mut`].
+    ///
+    /// # Panics
+    /// This method has the precondition that `size` is not zero.
     ///
     /// # Performance
-    /// This methods takes O(N) time and O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0,1,2,3,4,5]);
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4]);
     ///
-    /// instance.remove(5);
-    /// instance.remove(2);
-    /// instance.remove(0);
+    /// for chunk in instance.rchunks_mut(2) {
+    ///     chunk.reverse();
+    /// }
     ///
-    /// assert!(instance.into_iter().eq([1, 3, 4]));
+    /// assert!(instance.into_iter().eq([0, 2, 1, 4, 3]));
     /// ```
-    fn remove(&mut self, index: usize) -> Option<Self::Element> {
-        if index >= self.initialized {
-            return None;
-        }
-
-        let element = {
-            // SAFETY: index within bounds => aligned within allocated object.
-            let ptr = unsafe { self.as_ptr().add(index) };
-
-            // SAFETY:
-            // * owned memory => pointer is valid for reads.
-            // * Underlying `T` is initialized.
-            // * This takes ownership (moved out of the buffer).
-            unsafe { ptr.read() }
-        };
-
-        // Increase front capacity.
-        if index == 0 {
-            if let Some(incremented) = self.front_capacity.checked_add(1) {
-                self.front_capacity = incremented;
-            } else {
-                unreachable!("allocated more that `isize::MAX` bytes");
-            };
-        }
-        // Increase back capacity.
-        else {
-            // SAFETY: there is back capacity to shift into.
-            unsafe {
-                self.shift_range(index.saturating_add(1).., -1);
-            }
+    pub fn rchunks_mut(
+        &mut self,
+        size: usize,
+    ) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator + core::iter::FusedIterator
+    {
+        assert!(size > 0, "chunk size must be non-zero");
 
-            if let Some(incremented) = self.back_capacity.checked_add(1) {
-                self.back_capacity = incremented;
-            } else {
-                unreachable!("allocated more that `isize::MAX` bytes");
-            };
+        // No allocation to point to => no elements to chunk.
+        if self.initialized == 0 {
+            return [].rchunks_mut(size);
         }
 
-        if let Some(decremented) = self.initialized.checked_sub(1) {
-            self.initialized = decremented;
-        } else {
-            unreachable!("no initialized element to remove");
-        };
-
-        Some(element)
+        self.as_mut_slice().rchunks_mut(size)
     }
 
-    /// Optimally remove elements within `range` by-value.
+    /// Iterate over non-overlapping chunks of exactly `size` elements.
     ///
-    /// This method is more efficient than using `remove` for sequential
-    /// elements, moving elements out of the buffer as iterated and shifting
-    /// once only when the iterator has been dropped.
+    /// Unlike [`slice::chunks`], every chunk yielded has the same length and
+    /// the last `len % size` elements which do not fill a chunk are instead
+    /// available via [`core::slice::ChunksExact::remainder`]. Uniform length
+    /// allows the compiler to elide bounds checks within each chunk.
+    ///
+    /// # Panics
+    /// This method has the precondition that `size` is not zero.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(N) memory for the result.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
-    /// use rust::structure::collection::linear::List;
-    ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6, 7]);
-    ///
-    /// let mut drain = instance.drain(..2);
-    /// assert_eq!(drain.next(), Some(0));
-    /// assert_eq!(drain.next_back(), Some(1));
-    /// core::mem::drop(drain);
-    ///
-    /// let mut drain = instance.drain(0..2);
-    /// assert_eq!(drain.next(), Some(2));
-    /// assert_eq!(drain.next_back(), Some(3));
-    /// core::mem::drop(drain);
     ///
-    /// let mut drain = instance.drain(0..=1);
-    /// assert_eq!(drain.next(), Some(4));
-    /// assert_eq!(drain.next_back(), Some(5));
-    /// core::mem::drop(drain);
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4]);
     ///
-    /// let mut drain = instance.drain(0..);
-    /// assert_eq!(drain.next(), Some(6));
-    /// assert_eq!(drain.next_back(), Some(7));
-    /// core::mem::drop(drain);
+    /// let mut actual = instance.chunks_exact(2);
     ///
-    /// let mut drain = instance.drain(..);
-    /// assert_eq!(drain.next(), None);
-    /// assert_eq!(drain.next_back(), None);
+    /// assert_eq!(actual.next(), Some([0, 1].as_slice()));
+    /// assert_eq!(actual.next(), Some([2, 3].as_slice()));
+    /// assert_eq!(actual.next(), None);
+    /// assert_eq!(actual.remainder(), [4].as_slice());
     /// ```
-    fn drain(
-        &mut self,
-        range: impl core::ops::RangeBounds<usize>,
-    ) -> impl DoubleEndedIterator<Item = Self::Element> + ExactSizeIterator {
-        let start = match range.start_bound() {
-            core::ops::Bound::Included(start) => *start,
-            core::ops::Bound::Excluded(start) => start.saturating_add(1),
-            core::ops::Bound::Unbounded => 0,
-        }
-        .min(self.len());
+    pub fn chunks_exact(&self, size: usize) -> core::slice::ChunksExact<'_, T> {
+        assert!(size > 0, "chunk size must be non-zero");
 
-        let end = match range.end_bound() {
-            core::ops::Bound::Included(end) => end.saturating_add(1),
-            core::ops::Bound::Excluded(end) => *end,
-            core::ops::Bound::Unbounded => self.len(),
+        // No allocation to point to => no elements to chunk.
+        if self.initialized == 0 {
+            return [].chunks_exact(size);
         }
-        .min(self.len());
-
-        let normalized = start..end;
 
-        Drain {
-            underlying: self,
-            range: normalized.clone(),
-            next: normalized.clone(),
-        }
+        self.as_slice().chunks_exact(size)
     }
 
-    /// Remove the elements which match some `predicate`.
+    /// Iterate over every `step`-th element, starting with the first.
     ///
-    /// The `predicate` is called exactly once per each element, in order of
-    /// iteration. Elements for which the `predicate` is true are removed in
-    /// order from left to right. Elements for which the `predicate` is false
-    /// are shifted left to immediately after the previously retained element,
-    /// thereby maintaining order.
+    /// Unlike [`Iterator::step_by`] upon [`Linear::iter`] which yields each
+    /// skipped element only for it to be discarded, this advances directly
+    /// to the next element yielded via pointer arithmetic because elements
+    /// are contiguous. Nothing is done for the elements skipped.
+    ///
+    /// # Panics
+    /// This method has the precondition that `step` is not zero.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(N) memory for the result.
+    /// This method takes O(1) time and consumes O(1) memory. Each element
+    /// yielded takes O(1) time regardless of `step`.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
-    /// use rust::structure::collection::linear::List;
-    ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-    /// let mut withdraw = instance.withdraw(|element| element % 2 == 0);
-    ///
-    /// assert_eq!(withdraw.next(), Some(0));
-    /// assert_eq!(withdraw.next_back(), Some(4));
     ///
-    /// drop(withdraw);
+    /// let instance: Dynamic<_> = (0..10).collect();
     ///
-    /// assert!(instance.eq([1, 3, 5]));
+    /// assert!(instance.iter_step_by(3).eq(&[0, 3, 6, 9]));
     /// ```
-    fn withdraw(
-        &mut self,
-        predicate: impl FnMut(&T) -> bool,
-    ) -> impl DoubleEndedIterator<Item = Self::Element> {
-        let head = if self.initialized == 0 {
-            // is empty => this pointer will _NOT_ be modified or read.
-            NonNull::dangling()
-        } else {
-            // SAFETY: at least one element exist => pointer cannot be null.
-            unsafe { NonNull::new_unchecked(self.as_mut_ptr()) }
-        };
-
-        let tail = {
-            let ptr = {
-                let offset = self.initialized.saturating_sub(1);
-
-                // SAFETY: stays aligned within the allocated object.
-                unsafe { head.as_ptr().add(offset) }
-            };
-
-            // SAFETY: `head` cannot be null => pointer cannot be null.
-            unsafe { NonNull::new_unchecked(ptr) }
-        };
-
-        let remaining = self.initialized;
+    #[must_use]
+    pub fn iter_step_by(
+        &self,
+        step: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(step > 0, "step must be non-zero");
 
-        Withdraw {
-            underlying: self,
-            predicate,
-            remaining,
-            retained: head,
-            next_front: head,
-            next_back: tail,
-            trailing: 0,
+        // No allocation to point to => no elements to yield.
+        if self.initialized == 0 {
+            return [].iter().step_by(step);
         }
+
+        // The slice iterator advances via `nth` which is pointer arithmetic.
+        self.as_slice().iter().step_by(step)
     }
 
-    /// Drop all initialized elements
+    /// Iterate over the elements by mutable reference in parallel.
+    ///
+    /// This is only available with the `rayon` feature enabled. Elements are
+    /// contiguous, hence this splits the underlying slice across threads of
+    /// the global [`rayon`] thread pool. Each element is yielded to exactly
+    /// one thread, so it must be safe to send mutable references between
+    /// threads, that is `T` must be [`Send`].
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
+    /// # #[cfg(feature = "rayon")]
+    /// # {
+    /// use rayon::iter::ParallelIterator;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0,1,2,3,4,5]);
+    /// let mut instance: Dynamic<_> = (0..6).collect();
     ///
-    /// instance.clear();
+    /// instance.par_iter_mut().for_each(|element| *element *= 2);
     ///
-    /// assert_eq!(instance.len(), 0);
-    /// assert_eq!(instance.capacity(), 6);
+    /// assert!(instance.into_iter().eq([0, 2, 4, 6, 8, 10]));
+    /// # }
     /// ```
-    fn clear(&mut self) {
-        if self.initialized == 0 {
-            return;
-        }
-
-        let ptr = self.as_mut_ptr().cast::<MaybeUninit<T>>();
-
-        for index in 0..self.initialized {
-            // SAFETY: index in bounds => aligned within the allocated object.
-            let ptr = unsafe { ptr.add(index) };
-
-            // SAFETY: the `MaybeUninit<T>` is initialized.
-            let element = unsafe { &mut *ptr };
-
-            // SAFETY: the underlying `T` is initialized.
-            unsafe {
-                element.assume_init_drop();
-            }
-        }
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn par_iter_mut(&mut self) -> impl rayon::iter::IndexedParallelIterator<Item = &mut T>
+    where
+        T: Send,
+    {
+        use rayon::iter::IntoParallelIterator;
 
-        if let Some(capacity) = self.back_capacity.checked_add(self.initialized) {
-            self.back_capacity = capacity;
+        // No allocation to point to => no elements to iterate.
+        let elements: &mut [T] = if self.initialized == 0 {
+            &mut []
         } else {
-            unreachable!("allocated more than `isize::MAX` bytes");
-        }
+            self.as_mut_slice()
+        };
 
-        self.initialized = 0;
+        elements.into_par_iter()
     }
-}
 
-impl<T> super::super::Stack for Dynamic<T> {
-    /// Move an `element` on the top of the stack.
+    /// Sort the elements in ascending order whilst retaining equal order.
+    ///
+    /// This is a stable sort, elements which are equal will remain in the same
+    /// order relative to each other. See [`Self::sort_by`] for details.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(N) memory.
+    /// This method takes O(N * log N) time and consumes O(N) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::Stack;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::<usize>::default();
+    /// let mut instance = Dynamic::from_iter([0, 5, 2, 3, 1, 4]);
     ///
-    /// instance.push(5).expect("successful allocation");
-    /// instance.push(4).expect("successful allocation");
-    /// instance.push(3).expect("successful allocation");
-    /// instance.push(2).expect("successful allocation");
-    /// instance.push(1).expect("successful allocation");
-    /// instance.push(0).expect("successful allocation");
+    /// instance.sort();
     ///
-    /// assert!(instance.eq([0, 1, 2, 3, 4, 5]));
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, 4, 5]));
     /// ```
-    fn push(&mut self, element: Self::Element) -> Result<&mut Self::Element, Self::Element> {
-        self.prepend(element)
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
     }
 
-    /// Move out the element at the top of the stack.
+    /// Sort the elements via `compare` whilst retaining equal order.
+    ///
+    /// References to the elements are sorted into a scratch buffer via
+    /// [top-down merge sort](`crate::algorithm::sort::comparison::merge::top_down`),
+    /// then the elements themselves are swapped into that order. This means
+    /// elements are never moved into temporary memory, hence a panic within
+    /// `compare` will leave every element within `self`, albeit potentially
+    /// in an unspecified order.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
     ///
     /// # Performance
-    /// This method takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N * log N) time and consumes O(N) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::Stack;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut instance = Dynamic::from_iter([0, 5, 2, 3, 1, 4]);
     ///
-    /// assert_eq!(instance.pop(), Some(0));
-    /// assert_eq!(instance.pop(), Some(1));
-    /// assert_eq!(instance.pop(), Some(2));
-    /// assert_eq!(instance.pop(), Some(3));
-    /// assert_eq!(instance.pop(), Some(4));
-    /// assert_eq!(instance.pop(), Some(5));
-    /// assert_eq!(instance.pop(), None);
+    /// instance.sort_by(|left, right| right.cmp(left));
+    ///
+    /// assert!(instance.into_iter().eq([5, 4, 3, 2, 1, 0]));
     /// ```
-    fn pop(&mut self) -> Option<Self::Element> {
-        self.front()
+    pub fn sort_by(&mut self, compare: impl FnMut(&T, &T) -> core::cmp::Ordering) {
+        self.sort_via(compare, |elements| {
+            let mut auxiliary: Dynamic<_> = elements.iter().cloned().collect();
+
+            merge::top_down(elements, auxiliary.as_mut_slice());
+        });
     }
 
-    /// Query the element at the top of the stack.
+    /// Sort the elements by the `key` of each whilst retaining equal order.
+    ///
+    /// See [`Self::sort_by`] for details.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
     ///
     /// # Performance
-    /// This method takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N * log N) time and consumes O(N) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::Stack;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut instance = Dynamic::from_iter([(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')]);
     ///
-    /// assert_eq!(instance.peek(), Some(&0));
+    /// instance.sort_by_key(|element| element.0);
+    ///
+    /// assert!(instance.into_iter().eq([(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]));
     /// ```
-    fn peek(&self) -> Option<&Self::Element> {
-        self.first()
+    pub fn sort_by_key<K: Ord>(&mut self, mut key: impl FnMut(&T) -> K) {
+        self.sort_by(|left, right| key(left).cmp(&key(right)));
     }
-}
 
-impl<T> super::super::Queue for Dynamic<T> {
-    /// Move an `element` to the end of the queue.
+    /// Sort the elements in ascending order, ignoring order of equal elements.
+    ///
+    /// See [`Self::sort_unstable_by`] for details.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(N) memory.
+    /// This method takes O(N * log N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::Stack;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::<usize>::default();
+    /// let mut instance = Dynamic::from_iter([0, 5, 2, 3, 1, 4]);
     ///
-    /// instance.push(5).expect("successful allocation");
-    /// instance.push(4).expect("successful allocation");
-    /// instance.push(3).expect("successful allocation");
-    /// instance.push(2).expect("successful allocation");
-    /// instance.push(1).expect("successful allocation");
-    /// instance.push(0).expect("successful allocation");
+    /// instance.sort_unstable();
     ///
-    /// assert!(instance.eq([0, 1, 2, 3, 4, 5]));
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, 4, 5]));
     /// ```
-    fn push(&mut self, element: Self::Element) -> Result<&mut Self::Element, Self::Element> {
-        self.append(element)
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp);
     }
 
-    /// Move out the element at the front of the queue.
+    /// Sort the elements via `compare`, ignoring order of equal elements.
+    ///
+    /// Unlike [`Self::sort_by`], the elements are sorted in place via
+    /// [bottom-up heap sort](`crate::algorithm::sort::comparison::heap::heapsort_by`)
+    /// which does not require any additional memory, but elements which are
+    /// equal may be reordered relative to each other.
     ///
     /// # Performance
-    /// This method takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N * log N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::Stack;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut instance = Dynamic::from_iter([0, 5, 2, 3, 1, 4]);
     ///
-    /// assert_eq!(instance.pop(), Some(0));
-    /// assert_eq!(instance.pop(), Some(1));
-    /// assert_eq!(instance.pop(), Some(2));
-    /// assert_eq!(instance.pop(), Some(3));
-    /// assert_eq!(instance.pop(), Some(4));
-    /// assert_eq!(instance.pop(), Some(5));
-    /// assert_eq!(instance.pop(), None);
+    /// instance.sort_unstable_by(|left, right| right.cmp(left));
+    ///
+    /// assert!(instance.into_iter().eq([5, 4, 3, 2, 1, 0]));
     /// ```
-    fn pop(&mut self) -> Option<Self::Element> {
-        self.front()
+    pub fn sort_unstable_by(&mut self, compare: impl FnMut(&T, &T) -> core::cmp::Ordering) {
+        // No allocation to point to => no elements to sort.
+        if self.initialized == 0 {
+            return;
+        }
+
+        heap::heapsort_by(self.as_mut_slice(), compare);
     }
 
-    /// Query the element at the front of the queue.
+    /// Sort the elements by the `key` of each, ignoring order of equal elements.
+    ///
+    /// See [`Self::sort_unstable_by`] for details.
     ///
     /// # Performance
-    /// This method takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N * log N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::Stack;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut instance = Dynamic::from_iter([3, -5, 1, -4, 0, 2]);
     ///
-    /// assert_eq!(instance.peek(), Some(&0));
+    /// instance.sort_unstable_by_key(|element: &i32| element.abs());
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, -4, -5]));
     /// ```
-    fn peek(&self) -> Option<&Self::Element> {
-        self.first()
+    pub fn sort_unstable_by_key<K: Ord>(&mut self, mut key: impl FnMut(&T) -> K) {
+        self.sort_unstable_by(|left, right| key(left).cmp(&key(right)));
     }
-}
 
-/// [`Iterator`] to yield elements within an index range from [`Dynamic`].
-///
-/// See [`Dynamic::drain`].
-struct Drain<'a, T> {
-    /// The underlying [`Dynamic`] being drained from.
-    underlying: &'a mut Dynamic<T>,
-
-    /// The index range of elements being drained.
-    range: core::ops::Range<usize>,
-
-    /// The index range of elements being drained that have yet to be yielded.
-    next: core::ops::Range<usize>,
-}
-
-impl<T> Drop for Drain<'_, T> {
-    /// Drops remaining elements and fixes the underlying [`Dynamic`] buffer.
+    /// Lexicographically compare with `other` using `cmp` for elements.
+    ///
+    /// Elements are compared pairwise in order, the first which are not equal
+    /// determines the result. If one is a prefix of the other, then the one
+    /// with fewer elements is less, just like [`Ord`] for slices. This allows
+    /// comparing elements which are not [`Ord`] or using a custom ordering.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory.
+    /// This method takes O(N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
+    /// use core::cmp::Ordering;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6]);
-    ///
-    /// let mut drain = instance.drain(2..=4);
+    /// let left = Dynamic::from_iter([0, 1, 2]);
+    /// let right = Dynamic::from_iter([0, 2]);
     ///
-    /// drain.next();      // Consumes the element with value `2`.
-    /// drain.next_back(); // Consumes the element with value `4`.
+    /// assert_eq!(left.cmp_by(&right, Ord::cmp), Ordering::Less);
+    /// assert_eq!(left.cmp_by(&right, |left, right| right.cmp(left)), Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub fn cmp_by(
+        &self,
+        other: &Self,
+        mut cmp: impl FnMut(&T, &T) -> core::cmp::Ordering,
+    ) -> core::cmp::Ordering {
+        let mut left = self.iter();
+        let mut right = other.iter();
+
+        loop {
+            match (left.next(), right.next()) {
+                (Some(current), Some(next)) => match cmp(current, next) {
+                    core::cmp::Ordering::Equal => {}
+                    unequal @ (core::cmp::Ordering::Less | core::cmp::Ordering::Greater) => {
+                        return unequal;
+                    }
+                },
+                (Some(_), None) => return core::cmp::Ordering::Greater,
+                (None, Some(_)) => return core::cmp::Ordering::Less,
+                (None, None) => return core::cmp::Ordering::Equal,
+            }
+        }
+    }
+
+    /// Reorder the elements into the order `sort` places references to them.
     ///
-    /// core::mem::drop(drain); // Drops the element with value '3'.
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
     ///
-    /// assert!(instance.into_iter().eq([0, 1, 5, 6])); // Remaining elements.
-    /// ```
-    fn drop(&mut self) {
-        if self.underlying.initialized == 0 {
-            debug_assert_eq!(self.range, 0..0, "drained uninitialized elements");
+    /// # Performance
+    /// This method takes O(N) time and consumes O(N) memory, excluding `sort`.
+    #[allow(clippy::indexing_slicing)]
+    fn sort_via<F: FnMut(&T, &T) -> core::cmp::Ordering>(
+        &mut self,
+        compare: F,
+        sort: impl for<'a> FnOnce(&mut [Ordered<'a, T, F>]),
+    ) {
+        if self.initialized < 2 {
             return;
         }
 
-        self.for_each(drop);
-
-        if self.range.end == self.underlying.initialized {
-            if let Some(capacity) = self.underlying.back_capacity.checked_add(self.range.len()) {
-                self.underlying.back_capacity = capacity;
-            } else {
-                unreachable!("allocated more than `isize::MAX` bytes");
-            }
-        } else if self.range.start == 0 {
-            if let Some(capacity) = self.underlying.front_capacity.checked_add(self.range.len()) {
-                self.underlying.front_capacity = capacity;
-            } else {
-                unreachable!("allocated more than `isize::MAX` bytes");
-            }
-        } else {
-            let leading = self.range.start;
+        let compare = core::cell::RefCell::new(compare);
+
+        // Where each element ought to be moved from to be in sorted order.
+        let mut sources: Dynamic<usize> = {
+            let mut ordered: Dynamic<Ordered<'_, T, F>> = self
+                .iter()
+                .enumerate()
+                .map(|(index, element)| Ordered {
+                    index,
+                    element,
+                    compare: &compare,
+                })
+                .collect();
 
-            let Some(trailing) = self.underlying.initialized.checked_sub(self.range.end) else {
-                unreachable!("not enough initialized elements to remove");
-            };
+            sort(ordered.as_mut_slice());
 
-            let Ok(offset) = isize::try_from(self.range.len()) else {
-                unreachable!("allocated more than `isize::MAX` bytes");
-            };
+            ordered.into_iter().map(|ordered| ordered.index).collect()
+        };
 
-            let only_front_capacity =
-                self.underlying.front_capacity != 0 && self.underlying.back_capacity == 0;
-            let only_back_capacity =
-                self.underlying.front_capacity == 0 && self.underlying.back_capacity != 0;
+        let sources = sources.as_mut_slice();
+        let elements = self.as_mut_slice();
 
-            if only_front_capacity || (!only_back_capacity && trailing > leading) {
-                let Some(offset) = offset.checked_neg() else {
-                    unreachable!("negative amount of elements");
-                };
+        // Follow each cycle of the permutation swapping elements into place.
+        for start in 0..sources.len() {
+            let mut current = start;
 
-                let Some(end) = self.range.end.checked_add(trailing) else {
-                    unreachable!("allocated more than `isize::MAX` bytes");
-                };
+            while sources[current] != start {
+                let source = sources[current];
 
-                // SAFETY: [front capacity] [remain] [drained] [shift] [back capacity]
-                unsafe {
-                    self.underlying.shift_range(self.range.end..end, offset);
-                }
+                elements.swap(current, source);
 
-                self.underlying.back_capacity = self.range.len();
-            } else {
-                // SAFETY: [front capacity] [shift] [drained] [remain] [back capacity]
-                unsafe {
-                    self.underlying.shift_range(0..self.range.start, offset);
-                }
+                // Mark as already in sorted order.
+                sources[current] = current;
 
-                self.underlying.front_capacity = self.range.len();
+                current = source;
             }
+
+            sources[current] = current;
         }
+    }
 
-        if let Some(decreased) = self.underlying.initialized.checked_sub(self.range.len()) {
-            self.underlying.initialized = decreased;
+    /// Move the first `count` elements into the back capacity after the last.
+    ///
+    /// # Panics
+    /// This method has the precondition that `count` is neither greater than
+    /// the number of elements nor [`Self::capacity_back`].
+    ///
+    /// # Performance
+    /// This method takes O(K) time and consumes O(1) memory.
+    fn relocate_to_back(&mut self, count: usize) {
+        assert!(count <= self.initialized, "not enough elements to relocate");
+
+        let Some(back_capacity) = self.back_capacity.checked_sub(count) else {
+            panic!("not enough capacity to relocate into");
+        };
+
+        let source = self.as_mut_ptr();
+
+        // SAFETY: stays aligned within the allocated object.
+        let destination = unsafe { source.add(self.initialized) };
+
+        // SAFETY:
+        // * owned memory => source/destination valid for read/writes.
+        // * `count` <= back capacity => source and destination do not overlap.
+        // * underlying buffer is aligned => both pointers are aligned.
+        unsafe {
+            core::ptr::copy_nonoverlapping(source, destination, count);
+        }
+
+        self.back_capacity = back_capacity;
+
+        if let Some(front_capacity) = self.front_capacity.checked_add(count) {
+            self.front_capacity = front_capacity;
+        } else {
+            unreachable!("allocated more than `isize::MAX` bytes");
         }
     }
-}
 
-impl<T> Iterator for Drain<'_, T> {
-    type Item = T;
+    /// Move the last `count` elements into the front capacity before the first.
+    ///
+    /// # Panics
+    /// This method has the precondition that `count` is neither greater than
+    /// the number of elements nor [`Self::capacity_front`].
+    ///
+    /// # Performance
+    /// This method takes O(K) time and consumes O(1) memory.
+    fn relocate_to_front(&mut self, count: usize) {
+        let Some(retained) = self.initialized.checked_sub(count) else {
+            panic!("not enough elements to relocate");
+        };
 
-    /// Obtain the next element, if there are any left.
+        let Some(front_capacity) = self.front_capacity.checked_sub(count) else {
+            panic!("not enough capacity to relocate into");
+        };
+
+        let first = self.as_mut_ptr();
+
+        // SAFETY: stays aligned within the allocated object.
+        let source = unsafe { first.add(retained) };
+
+        // SAFETY: `count` <= front capacity => aligned within allocated object.
+        let destination = unsafe { first.sub(count) };
+
+        // SAFETY:
+        // * owned memory => source/destination valid for read/writes.
+        // * `count` <= front capacity => source and destination do not overlap.
+        // * underlying buffer is aligned => both pointers are aligned.
+        unsafe {
+            core::ptr::copy_nonoverlapping(source, destination, count);
+        }
+
+        self.front_capacity = front_capacity;
+
+        if let Some(back_capacity) = self.back_capacity.checked_add(count) {
+            self.back_capacity = back_capacity;
+        } else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        }
+    }
+
+    /// Apply `operation` to each overlapping window of `size` elements in order.
+    ///
+    /// Overlapping mutable windows can _NOT_ coexist, hence unlike
+    /// [`slice::windows`] this does not yield an iterator but rather
+    /// reborrows each window in turn meaning modifications made within one
+    /// window are observed by subsequent windows.
+    ///
+    /// # Panics
+    /// This method has the precondition that `size` is not zero.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-    /// let mut actual = underlying.drain(..);
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(actual.next(), Some(0));
-    /// assert_eq!(actual.next(), Some(1));
-    /// assert_eq!(actual.next(), Some(2));
-    /// assert_eq!(actual.next_back(), Some(5));
-    /// assert_eq!(actual.next_back(), Some(4));
-    /// assert_eq!(actual.next_back(), Some(3));
-    /// assert_eq!(actual.next(), None);
-    /// assert_eq!(actual.next_back(), None);
+    /// instance.for_each_window_mut(2, |window| {
+    ///     if let [first, second] = window {
+    ///         *second += *first;
+    ///     }
+    /// });
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 3, 6, 10, 15]));
     /// ```
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next.next().map_or_else(
-            || None,
-            |index| {
-                let ptr = self.underlying.as_mut_ptr().cast::<MaybeUninit<T>>();
+    pub fn for_each_window_mut(&mut self, size: usize, mut operation: impl FnMut(&mut [T])) {
+        assert!(size != 0, "window size must be non-zero");
 
-                // SAFETY: stays aligned within the allocated object.
-                let ptr = unsafe { ptr.add(index) };
+        let Some(last) = self.initialized.checked_sub(size) else {
+            return;
+        };
 
-                // SAFETY: index in bounds => aligned within the allocated object.
-                let element = unsafe { &mut *ptr };
+        let elements = self.as_mut_slice();
 
-                // SAFETY:
-                // * owned memory => pointer is valid for reads.
-                // * Underlying `T` is initialized.
-                // * This takes ownership (moved out of the buffer).
-                Some(unsafe { element.assume_init_read() })
-            },
-        )
+        for start in 0..=last {
+            let Some(window) = elements
+                .get_mut(start..)
+                .and_then(|rest| rest.get_mut(..size))
+            else {
+                unreachable!("loop ensures the window is within bounds");
+            };
+
+            operation(window);
+        }
     }
 
-    /// Query how many elements have yet to be yielded.
+    /// Reinterpret the elements as chunks of `N` elements and the remainder.
+    ///
+    /// The elements are contiguous, hence the prefix whose length is a
+    /// multiple of `N` has the same layout as an array of `[T; N]` arrays.
+    ///
+    /// # Panics
+    /// This method has the precondition that `N` is not zero.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
     /// use rust::structure::collection::linear::array::Dynamic;
-    /// use rust::structure::collection::linear::List;
     ///
-    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-    /// let mut actual = underlying.drain(..);
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6]);
     ///
-    /// assert_eq!(actual.size_hint(), (6, Some(6)));
+    /// let (chunks, remainder) = instance.as_chunks::<2>();
+    ///
+    /// assert_eq!(chunks, [[0, 1], [2, 3], [4, 5]]);
+    /// assert_eq!(remainder, [6]);
     /// ```
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.next.len(), Some(self.next.len()))
+    #[must_use]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        if self.initialized == 0 {
+            return (&[], &[]);
+        }
+
+        self.as_slice().as_chunks::<N>()
     }
-}
 
-impl<T> DoubleEndedIterator for Drain<'_, T> {
-    /// Obtain the final element, if there are any left.
+    /// Reinterpret the elements as mutable chunks of `N` elements and the remainder.
+    ///
+    /// See [`Self::as_chunks`].
+    ///
+    /// # Panics
+    /// This method has the precondition that `N` is not zero.
     ///
     /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-    /// let mut actual = underlying.drain(..);
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6]);
     ///
-    /// assert_eq!(actual.next_back(), Some(5));
-    /// assert_eq!(actual.next_back(), Some(4));
-    /// assert_eq!(actual.next_back(), Some(3));
-    /// assert_eq!(actual.next_back(), Some(2));
-    /// assert_eq!(actual.next_back(), Some(1));
-    /// assert_eq!(actual.next_back(), Some(0));
-    /// assert_eq!(actual.next_back(), None);
+    /// let (chunks, remainder) = instance.as_chunks_mut::<2>();
+    ///
+    /// chunks.iter_mut().for_each(|chunk| chunk.swap(0, 1));
+    /// remainder[0] = 7;
+    ///
+    /// assert!(instance.into_iter().eq([1, 0, 3, 2, 5, 4, 7]));
     /// ```
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.next.next_back().map_or_else(
-            || None,
-            |index| {
-                let ptr = self.underlying.as_mut_ptr().cast::<MaybeUninit<T>>();
-
-                // SAFETY: stays aligned within the allocated object.
-                let ptr = unsafe { ptr.add(index) };
+    #[must_use]
+    pub fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        assert!(N != 0, "chunk size must be non-zero");
 
-                // SAFETY: index in bounds => aligned within the allocated object.
-                let element = unsafe { &mut *ptr };
+        if self.initialized == 0 {
+            return (&mut [], &mut []);
+        }
 
-                // SAFETY:
-                // * owned memory => pointer is valid for reads.
-                // * Underlying `T` is initialized.
-                // * This takes ownership (moved out of the buffer).
-                Some(unsafe { element.assume_init_read() })
-            },
-        )
+        self.as_mut_slice().as_chunks_mut::<N>()
     }
-}
-
-impl<T> ExactSizeIterator for Drain<'_, T> {}
-
-impl<T> core::iter::FusedIterator for Drain<'_, T> {}
 
-impl<T: core::fmt::Debug> core::fmt::Debug for Drain<'_, T> {
-    /// List the elements being drained.
+    /// Reorder such that elements matching `predicate` precede those which do not.
+    ///
+    /// Elements are scanned from both ends, swapping the first which does not
+    /// match with the last which does until the scans meet. The `predicate`
+    /// is called exactly once per element. Note that this is non-stable
+    /// meaning the relative order within each group is not preserved, see
+    /// [`Self::stable_partition`] if that is required.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(N) memory.
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut list = f.debug_list();
-
-        let slice = {
-            // SAFETY: index in bounds => aligned within the allocated object.
-            let ptr = unsafe { self.underlying.as_ptr().add(self.next.start) };
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Linear;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let boundary = instance.partition(|element| element % 2 == 0);
+    ///
+    /// assert_eq!(boundary, 3);
+    /// assert!(instance.iter().take(3).all(|element| element % 2 == 0));
+    /// assert!(instance.iter().skip(3).all(|element| element % 2 == 1));
+    /// ```
+    #[allow(clippy::indexing_slicing)]
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn partition(&mut self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        if self.initialized == 0 {
+            return 0;
+        }
 
-            // SAFETY: points to yet to be yielded slice.
-            unsafe { core::slice::from_raw_parts(ptr, self.next.len()) }
-        };
+        let elements = self.as_mut_slice();
 
-        list.entries(slice).finish()
-    }
-}
+        // Elements before `front` match, elements at or after `back` do not.
+        let mut front = 0;
+        let mut back = elements.len();
 
-/// [`Iterator`] to yield elements matching a predicate from [`Dynamic`].
-///
-/// See [`Dynamic::withdraw`].
-struct Withdraw<'a, T, F: FnMut(&T) -> bool> {
-    /// The underlying [`Dynamic`] begin withdrawn from.
-    underlying: &'a mut Dynamic<T>,
+        loop {
+            while front < back && predicate(&elements[front]) {
+                front += 1;
+            }
 
-    /// The predicate based upon which elements are withdrawn.
-    predicate: F,
+            if front == back {
+                return front;
+            }
 
-    /// Where to write the next retained element to.
-    retained: NonNull<T>,
+            // The element at `front` does not match, find one to swap with.
+            back -= 1;
 
-    /// How many element are left to query with the predicate.
-    remaining: usize,
+            while front < back && !predicate(&elements[back]) {
+                back -= 1;
+            }
 
-    /// The next (front) element to query with the predicate.
-    next_front: NonNull<T>,
+            if front == back {
+                return front;
+            }
 
-    /// The next (back) element to query with the predicate.
-    next_back: NonNull<T>,
+            elements.swap(front, back);
 
-    /// The number of retained elements at the end because of `next_back`.
-    trailing: usize,
-}
+            front += 1;
+        }
+    }
 
-impl<T, F: FnMut(&T) -> bool> Drop for Withdraw<'_, T, F> {
-    /// Drops remaining elements and fixes the underlying [`Dynamic`] buffer.
+    /// Reorder such that elements matching `predicate` precede those which do
+    /// not whilst preserving relative order.
+    ///
+    /// Elements which do not match are withdrawn into a scratch [`Dynamic`]
+    /// that is then appended, thereby preserving the order of both groups.
+    /// The `predicate` is called exactly once per element, in order.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(1) memory.
+    /// This method takes O(N) time and consumes O(N) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
     /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// let mut withdraw = instance.withdraw(|element| element % 2 == 0);
+    /// let boundary = instance.stable_partition(|element| element % 2 == 0);
     ///
-    /// // Consumes the element with value `0`.
-    /// assert_eq!(withdraw.next(), Some(0));
+    /// assert_eq!(boundary, 3);
+    /// assert!(instance.into_iter().eq([0, 2, 4, 1, 3, 5]));
+    /// ```
+    pub fn stable_partition(&mut self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        let rejected: Self = self.withdraw(|element| !predicate(element)).collect();
+
+        let boundary = self.initialized;
+
+        self.extend(rejected);
+
+        boundary
+    }
+
+    /// Query if `target` is contained, assuming the elements are sorted.
     ///
-    /// // Consumes the element with value `4`.
-    /// assert_eq!(withdraw.next_back(), Some(4));
+    /// Unlike [`slice::contains`] which compares every element, this uses
+    /// [`Array::binary_search`] hence has the precondition that the elements
+    /// are sorted in ascending order, otherwise the result is unspecified.
     ///
-    /// // Drops the element with value '2'.
-    /// drop(withdraw);
+    /// # Panics
+    /// With debug assertions enabled, panics if the elements are not sorted.
     ///
-    /// // Retained elements.
-    /// assert!(instance.eq([1, 3, 5]));
+    /// # Performance
+    /// This method takes O(log N) time and consumes O(1) memory, or O(N)
+    /// time with debug assertions enabled.
+    ///
+    /// # Examples
     /// ```
-    fn drop(&mut self) {
-        // Drop all remaining elements to withdraw.
-        self.for_each(drop);
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert!(instance.contains_sorted(&3));
+    /// assert!(!instance.contains_sorted(&6));
+    /// ```
+    #[must_use]
+    pub fn contains_sorted(&self, target: &T) -> bool
+    where
+        T: Ord,
+    {
+        if self.initialized == 0 {
+            return false;
+        }
 
-        if self.trailing > 0 {
-            // SAFETY: aligned within the allocated object, or one byte past.
-            let trailing = unsafe { self.next_back.as_ptr().add(1) };
+        debug_assert!(self.as_slice().is_sorted(), "elements must be sorted");
 
-            // SAFETY:
-            // * owned memory => source/destination valid for read/writes.
-            // * no aliasing restrictions => source and destination can overlap.
-            // * underlying buffer is aligned => both pointers are aligned.
-            unsafe {
-                core::ptr::copy(trailing, self.retained.as_ptr(), self.trailing);
-            }
-        }
+        self.binary_search(target).is_ok()
     }
-}
-
-impl<T, F: FnMut(&T) -> bool> Iterator for Withdraw<'_, T, F> {
-    type Item = T;
 
-    /// Obtain the next element, if there are any left.
+    /// Insert `element` into sorted position, assuming the elements are sorted.
+    ///
+    /// The insertion point is found via binary search then the element is
+    /// inserted there via [`List::insert`], yielding the index it now
+    /// occupies. Equal elements are passed over such that `element` is
+    /// inserted after any already contained, hence repeatedly calling this
+    /// builds a stably sorted instance.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    /// With debug assertions enabled, panics if the elements are not sorted.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(1) memory.
+    /// This method takes O(log N) comparisons and O(N) time moving elements,
+    /// consuming O(1) memory unless reallocation is necessary.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-    /// let mut actual = underlying.withdraw(|element| element % 2 == 0);
+    /// let mut instance = Dynamic::from_iter([0, 1, 3, 4]);
     ///
-    /// assert_eq!(actual.next(), Some(0));
-    /// assert_eq!(actual.next(), Some(2));
-    /// assert_eq!(actual.next(), Some(4));
-    /// assert_eq!(actual.next(), None);
+    /// assert_eq!(instance.binary_insert(2), 2);
+    /// assert_eq!(instance.binary_insert(5), 5);
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, 4, 5]));
     /// ```
-    fn next(&mut self) -> Option<Self::Item> {
-        let first_retained = self.next_front;
-        let mut consecutive_retained = 0;
+    pub fn binary_insert(&mut self, element: T) -> usize
+    where
+        T: Ord,
+    {
+        let index = if self.initialized == 0 {
+            0
+        } else {
+            debug_assert!(self.as_slice().is_sorted(), "elements must be sorted");
 
-        // SAFETY:
-        // * owned memory => source/destination valid for read/writes.
-        // * no aliasing restrictions => source and destination can overlap.
-        // * underlying buffer is aligned => both pointers are aligned.
-        let shift_retained = |src: *mut T, dst: *mut T, count| unsafe {
-            // Shift the current run of retained elements to the left.
-            core::ptr::copy(src, dst, count);
+            self.as_slice()
+                .partition_point(|current| current <= &element)
         };
 
-        while self.remaining != 0 {
-            if let Some(remaining) = self.remaining.checked_sub(1) {
-                self.remaining = remaining;
-            } else {
-                unreachable!("no remaining element");
-            }
+        assert!(self.insert(index, element).is_ok(), "allocation failed");
 
-            // SAFETY: the element is initialized.
-            let current = unsafe { self.next_front.as_ref() };
+        index
+    }
 
-            self.next_front = {
-                // SAFETY: aligned within the allocated object, or one byte past.
-                let ptr = unsafe { self.next_front.as_ptr().add(1) };
+    /// Remove consecutive duplicate elements, retaining the first of each run.
+    ///
+    /// Retained elements are swapped towards the front in their original
+    /// order, then the duplicates left at the back are dropped via
+    /// [`List::drain`]. Should `PartialEq::eq` panic, every element remains
+    /// within `self`, albeit potentially in an unspecified order.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 0, 1, 2, 2, 2, 1]);
+    ///
+    /// instance.dedup();
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2, 1]));
+    /// ```
+    #[allow(clippy::indexing_slicing)]
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        if self.initialized == 0 {
+            return;
+        }
 
-                // SAFETY: `head` is not null => pointer is not null.
-                unsafe { NonNull::new_unchecked(ptr) }
-            };
+        let elements = self.as_mut_slice();
 
-            if (self.predicate)(current) {
-                // SAFETY:
-                // * owned memory => pointer is valid for reads.
-                // * Underlying `T` is initialized.
-                // * This takes ownership (moved out of the buffer).
-                let element = unsafe { core::ptr::read(current) };
+        // Elements before this index are retained.
+        let mut retained = 1;
 
-                if self.underlying.as_ptr() == current {
-                    // Will not shift, instead increasing front capacity.
-                    if let Some(incremented) = self.underlying.front_capacity.checked_add(1) {
-                        self.underlying.front_capacity = incremented;
-                    } else {
-                        unreachable!("allocated more than `isize::MAX` bytes");
-                    }
+        for current in 1..elements.len() {
+            if elements[current] != elements[retained - 1] {
+                elements.swap(retained, current);
 
-                    // The current element will be left uninitialized.
-                    self.retained = {
-                        // SAFETY: at most one byte past the allocated object.
-                        let ptr = unsafe { self.retained.as_ptr().add(1) };
+                retained += 1;
+            }
+        }
 
-                        // SAFETY: `retained` is not null => pointer is not null.
-                        unsafe { NonNull::new_unchecked(ptr) }
-                    };
-                } else {
-                    // will shift elements to increase back capacity.
-                    if let Some(incremented) = self.underlying.back_capacity.checked_add(1) {
-                        self.underlying.back_capacity = incremented;
-                    } else {
-                        unreachable!("allocated more than `isize::MAX` bytes");
+        drop(self.drain(retained..));
+    }
+
+    /// Sort the elements then remove all duplicates, leaving each unique once.
+    ///
+    /// This is a convenience for building a set from arbitrary data. The
+    /// elements are stably sorted via [`Self::sort`] then deduplicated via
+    /// [`Self::dedup`], hence the first of each group of equal elements
+    /// within the original order is the one retained.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N * log N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([3, 1, 2, 3, 1]);
+    ///
+    /// instance.dedup_sorted();
+    ///
+    /// assert!(instance.into_iter().eq([1, 2, 3]));
+    /// ```
+    pub fn dedup_sorted(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort();
+        self.dedup();
+    }
+
+    /// Remove all duplicate elements, retaining the first occurrence of each.
+    ///
+    /// Unlike [`Self::dedup_sorted`], this neither requires [`Ord`] nor
+    /// reorders the elements: the first occurrences retain their relative
+    /// order. The index of each retained element is recorded within an
+    /// open-addressing hash table (via linear probing) such that each
+    /// element need only be compared against those with the same hash.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N) time on average (O(N<sup>2</sup>) worst case)
+    /// and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([3, 1, 3, 2, 1]);
+    ///
+    /// instance.dedup_by_hash();
+    ///
+    /// assert!(instance.into_iter().eq([3, 1, 2]));
+    /// ```
+    #[allow(clippy::indexing_slicing)]
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn dedup_by_hash(&mut self)
+    where
+        T: core::hash::Hash + Eq,
+    {
+        use core::hash::BuildHasher;
+
+        if self.initialized < 2 {
+            return;
+        }
+
+        // At most half occupied so probe sequences remain short.
+        let Some(slots) = self
+            .initialized
+            .checked_mul(2)
+            .and_then(usize::checked_next_power_of_two)
+        else {
+            panic!("allocation would exceed `isize::MAX` bytes");
+        };
+
+        // The index of a retained element with the hash of that slot.
+        let mut table: Dynamic<Option<usize>> = core::iter::repeat_n(None, slots).collect();
+
+        let hasher = std::hash::RandomState::new();
+
+        let elements = self.as_mut_slice();
+
+        // Elements before this index are retained.
+        let mut retained = 0;
+
+        for current in 0..elements.len() {
+            let Ok(mut slot) = usize::try_from(hasher.hash_one(&elements[current]) % slots as u64)
+            else {
+                unreachable!("remainder is less than the number of slots");
+            };
+
+            loop {
+                match table[slot] {
+                    Some(index) if elements[index] == elements[current] => break,
+                    Some(_) => slot = (slot + 1) % slots,
+                    None => {
+                        table[slot] = Some(retained);
+
+                        elements.swap(retained, current);
+
+                        retained += 1;
+
+                        break;
                     }
                 }
+            }
+        }
 
-                shift_retained(
-                    first_retained.as_ptr(),
-                    self.retained.as_ptr(),
-                    consecutive_retained,
-                );
+        drop(self.drain(retained..));
+    }
 
-                self.retained = {
-                    // SAFETY: next uninitialized element, or one byte past.
-                    let ptr = unsafe { self.retained.as_ptr().add(consecutive_retained) };
+    /// Clone the elements contained by either `self` or `other`, assuming both
+    /// are sorted, into a new sorted instance.
+    ///
+    /// Both inputs are walked simultaneously in a single merge-style pass.
+    /// Duplicates are treated as a multiset: an element occurring `a` times in
+    /// `self` and `b` times in `other` occurs `max(a, b)` times in the result.
+    /// Hence if neither input contains duplicates, neither does the output.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N + M) time and consumes O(N + M) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let left = Dynamic::from_iter([0, 1, 2, 3]);
+    /// let right = Dynamic::from_iter([2, 3, 4, 5]);
+    ///
+    /// assert!(left.union_sorted(&right).into_iter().eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    #[must_use]
+    pub fn union_sorted(&self, other: &Self) -> Self
+    where
+        T: Ord + Clone,
+    {
+        let mut result = Self::default();
 
-                    // SAFETY: `retained` is not null => pointer is not null.
-                    unsafe { NonNull::new_unchecked(ptr) }
-                };
+        drop(result.reserve(self.initialized.saturating_add(other.initialized)));
 
-                if let Some(decremented) = self.underlying.initialized.checked_sub(1) {
-                    self.underlying.initialized = decremented;
-                } else {
-                    unreachable!("allocated more than `isize::MAX` bytes");
+        let mut left = self.iter().peekable();
+        let mut right = other.iter().peekable();
+
+        while let (Some(current), Some(next)) = (left.peek(), right.peek()) {
+            match current.cmp(next) {
+                core::cmp::Ordering::Less => result.extend(left.next()),
+                core::cmp::Ordering::Greater => result.extend(right.next()),
+                core::cmp::Ordering::Equal => {
+                    result.extend(left.next());
+                    _ = right.next();
                 }
+            }
+        }
 
-                return Some(element);
+        result.extend(left);
+        result.extend(right);
+
+        result
+    }
+
+    /// Clone the elements contained by both `self` and `other`, assuming both
+    /// are sorted, into a new sorted instance.
+    ///
+    /// Both inputs are walked simultaneously in a single merge-style pass.
+    /// Duplicates are treated as a multiset: an element occurring `a` times in
+    /// `self` and `b` times in `other` occurs `min(a, b)` times in the result.
+    /// Elements are cloned from `self`.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N + M) time and consumes O(min(N, M)) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let left = Dynamic::from_iter([0, 1, 2, 3]);
+    /// let right = Dynamic::from_iter([2, 3, 4, 5]);
+    ///
+    /// assert!(left.intersection_sorted(&right).into_iter().eq([2, 3]));
+    /// ```
+    #[must_use]
+    pub fn intersection_sorted(&self, other: &Self) -> Self
+    where
+        T: Ord + Clone,
+    {
+        let mut result = Self::default();
+
+        drop(result.reserve(self.initialized.min(other.initialized)));
+
+        let mut left = self.iter().peekable();
+        let mut right = other.iter().peekable();
+
+        while let (Some(current), Some(next)) = (left.peek(), right.peek()) {
+            match current.cmp(next) {
+                core::cmp::Ordering::Less => _ = left.next(),
+                core::cmp::Ordering::Greater => _ = right.next(),
+                core::cmp::Ordering::Equal => {
+                    result.extend(left.next());
+                    _ = right.next();
+                }
             }
+        }
 
-            if let Some(incremented) = consecutive_retained.checked_add(1) {
-                consecutive_retained = incremented;
-            } else {
-                unreachable!("allocated more than `isize::MAX` bytes")
+        result
+    }
+
+    /// Clone the elements contained by `self` but not `other`, assuming both
+    /// are sorted, into a new sorted instance.
+    ///
+    /// Both inputs are walked simultaneously in a single merge-style pass.
+    /// Duplicates are treated as a multiset: an element occurring `a` times in
+    /// `self` and `b` times in `other` occurs `a - b` times (if positive) in
+    /// the result.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N + M) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let left = Dynamic::from_iter([0, 1, 2, 3]);
+    /// let right = Dynamic::from_iter([2, 3, 4, 5]);
+    ///
+    /// assert!(left.difference_sorted(&right).into_iter().eq([0, 1]));
+    /// ```
+    #[must_use]
+    pub fn difference_sorted(&self, other: &Self) -> Self
+    where
+        T: Ord + Clone,
+    {
+        let mut result = Self::default();
+
+        drop(result.reserve(self.initialized));
+
+        let mut left = self.iter().peekable();
+        let mut right = other.iter().peekable();
+
+        while let (Some(current), Some(next)) = (left.peek(), right.peek()) {
+            match current.cmp(next) {
+                core::cmp::Ordering::Less => result.extend(left.next()),
+                core::cmp::Ordering::Greater => _ = right.next(),
+                core::cmp::Ordering::Equal => {
+                    _ = left.next();
+                    _ = right.next();
+                }
             }
         }
 
-        // The above loop will exit whenever there are no more remaining
-        // elements to query with the predicate. However, this means the loop
-        // may iterate through a string of elements to retain at the end of the
-        // buffer before exhausting elements to query. In such a circumstance,
-        // there is no element at the end to withdraw hence the loop will exit
-        // without shifting these elements to align with previously retained
-        // elements. Nevertheless, previous iterations of the loop ensure the
-        // pointer and counter denote a valid range of retained elements (if
-        // any) so they can still be shifted before returning none.
-        shift_retained(
-            first_retained.as_ptr(),
-            self.retained.as_ptr(),
-            consecutive_retained,
-        );
+        result.extend(left);
 
-        self.retained = {
-            // SAFETY: at most one byte past the allocated object.
-            let ptr = unsafe { self.retained.as_ptr().add(consecutive_retained) };
+        result
+    }
 
-            // SAFETY: `retained` is not null => pointer is not null.
-            unsafe { NonNull::new_unchecked(ptr) }
-        };
+    /// Divide the elements into up to `count` disjoint mutable slices.
+    ///
+    /// The slices are consecutive and their lengths differ by at most one,
+    /// with the longer slices first. If there are fewer elements than
+    /// `count`, then each slice contains exactly one element. Being obtained
+    /// via [`slice::split_at_mut`], the slices are disjoint hence can be
+    /// independently given to separate threads.
+    ///
+    /// # Panics
+    /// This method has the precondition that `count` is not zero. The Rust
+    /// runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(min(N, K)) time and consumes O(min(N, K)) memory
+    /// for the K requested slices.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// let slices = instance.split_mut(3);
+    ///
+    /// assert!(slices.into_iter().eq([&mut [0, 1, 2][..], &mut [3, 4][..], &mut [5, 6][..]]));
+    /// ```
+    #[must_use]
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn split_mut(&mut self, count: usize) -> Dynamic<&mut [T]> {
+        assert!(count != 0, "count must be non-zero");
 
-        None
+        if self.initialized == 0 {
+            return Dynamic::default();
+        }
+
+        let slices = count.min(self.initialized);
+
+        let shortest = self.initialized / slices;
+        let longer = self.initialized % slices;
+
+        let mut rest = self.as_mut_slice();
+
+        (0..slices)
+            .map(|index| {
+                let length = shortest + usize::from(index < longer);
+
+                let (slice, remaining) = core::mem::take(&mut rest).split_at_mut(length);
+
+                rest = remaining;
+
+                slice
+            })
+            .collect()
     }
 
-    /// Query how many elements can be yielded.
+    /// Obtain mutable references to the elements at each of `indices`.
+    ///
+    /// Multiple mutable references may only exist if they do not alias, so
+    /// this yields none unless every index is in bounds and no two indexes
+    /// are equal. The references are in the same order as `indices`.
+    ///
+    /// See also: [`slice::get_disjoint_mut`].
     ///
     /// # Performance
-    /// This method takes O(1) time and consumes O(1) memory.
+    /// This method takes O(K<sup>2</sup>) time and consumes O(K) memory for
+    /// the K requested references.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-    /// let instance = underlying.withdraw(|element| element % 2 == 0);
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.size_hint(), (0, Some(6)));
+    /// if let Some([first, last]) = instance.get_many_mut([0, 5]) {
+    ///     core::mem::swap(first, last);
+    /// }
+    ///
+    /// assert!(instance.get_many_mut([1, 1]).is_none());
+    /// assert!(instance.get_many_mut([1, 6]).is_none());
+    ///
+    /// assert!(instance.into_iter().eq([5, 1, 2, 3, 4, 0]));
     /// ```
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.remaining))
+    #[must_use]
+    pub fn get_many_mut<const K: usize>(&mut self, indices: [usize; K]) -> Option<[&mut T; K]> {
+        // No allocation to point to, but bounds must still be checked.
+        let elements: &mut [T] = if self.initialized == 0 {
+            &mut []
+        } else {
+            self.as_mut_slice()
+        };
+
+        elements.get_disjoint_mut(indices).ok()
     }
-}
 
-impl<T, F: FnMut(&T) -> bool> DoubleEndedIterator for Withdraw<'_, T, F> {
-    /// Obtain the next element, if there are any left.
+    /// Obtain the immutable pointers to the first initialized element and
+    /// one-past the last initialized element.
+    ///
+    /// Unlike [`Array::as_ptr`], this does not panic when there is no
+    /// allocation, instead both ends are the same dangling (but nevertheless
+    /// valid) pointer. Note that `self` must outlive the pointers, and that
+    /// modifying `self` might invalidate them.
     ///
     /// # Performance
-    /// This methods takes O(N) time and consumes O(1) memory.
+    /// This methods takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
-    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::Array;
     /// use rust::structure::collection::linear::array::Dynamic;
     ///
-    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-    /// let mut actual = underlying.withdraw(|element| element % 2 == 0);
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(actual.next_back(), Some(4));
-    /// assert_eq!(actual.next_back(), Some(2));
-    /// assert_eq!(actual.next_back(), Some(0));
-    /// assert_eq!(actual.next_back(), None);
+    /// let range = instance.as_ptr_range();
+    ///
+    /// assert_eq!(range.start, instance.as_ptr());
+    /// assert_eq!(unsafe { range.end.offset_from(range.start) }, 6);
     /// ```
-    fn next_back(&mut self) -> Option<Self::Item> {
-        while self.remaining != 0 {
-            if let Some(decremented) = self.remaining.checked_sub(1) {
-                self.remaining = decremented;
-            } else {
-                unreachable!("no remaining element");
+    #[must_use]
+    pub fn as_ptr_range(&self) -> core::ops::Range<*const T> {
+        // `MaybeUninit<T>` has the same layout as `T`.
+        let ptr = self.buffer.cast::<T>().as_ptr().cast_const();
+
+        // SAFETY: Stays aligned within the allocated object.
+        let start = unsafe { ptr.add(self.front_capacity) };
+
+        // SAFETY: One-past the end of the initialized elements.
+        let end = unsafe { start.add(self.initialized) };
+
+        start..end
+    }
+
+    /// Obtain the mutable pointers to the first initialized element and
+    /// one-past the last initialized element.
+    ///
+    /// Unlike [`Array::as_mut_ptr`], this does not panic when there is no
+    /// allocation, instead both ends are the same dangling (but nevertheless
+    /// valid) pointer. Note that `self` must outlive the pointers, and that
+    /// modifying `self` might invalidate them.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Array;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let range = instance.as_mut_ptr_range();
+    ///
+    /// assert_eq!(range.start, instance.as_mut_ptr());
+    /// assert_eq!(unsafe { range.end.offset_from(range.start) }, 6);
+    /// ```
+    #[must_use]
+    pub fn as_mut_ptr_range(&mut self) -> core::ops::Range<*mut T> {
+        // `MaybeUninit<T>` has the same layout as `T`.
+        let ptr = self.buffer.cast::<T>().as_ptr();
+
+        // SAFETY: Stays aligned within the allocated object.
+        let start = unsafe { ptr.add(self.front_capacity) };
+
+        // SAFETY: One-past the end of the initialized elements.
+        let end = unsafe { start.add(self.initialized) };
+
+        start..end
+    }
+
+    /// Reinterpret the initialized elements as their underlying bytes.
+    ///
+    /// The bytes are those in memory, hence in native endianness. This
+    /// enables (de)serializing numeric buffers without copying, see
+    /// [`Self::from_bytes`] for the inverse.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Array;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0x0102_u16, 0x0304]);
+    ///
+    /// let expected: Dynamic<u8> = [0x0102_u16, 0x0304]
+    ///     .iter()
+    ///     .flat_map(|element| element.to_ne_bytes())
+    ///     .collect();
+    ///
+    /// assert_eq!(instance.as_bytes(), expected.as_slice());
+    /// ```
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        if self.initialized == 0 {
+            return &[];
+        }
+
+        let elements = self.as_slice();
+
+        // SAFETY:
+        // * the elements are initialized and `Pod` => no padding bytes.
+        // * `u8` has alignment of one => any pointer is aligned.
+        // * the bytes borrow `self` => cannot be mutated or deallocated.
+        unsafe {
+            core::slice::from_raw_parts(elements.as_ptr().cast::<u8>(), size_of_val(elements))
+        }
+    }
+
+    /// Construct an instance by copying elements reinterpreted from `bytes`.
+    ///
+    /// This is the inverse of [`Self::as_bytes`], so `bytes` must be the
+    /// native endian memory representation of the elements. Moreover,
+    /// `bytes` must be aligned as `T` would be such that they can be
+    /// copied as elements rather than byte-by-byte.
+    ///
+    /// # Errors
+    /// Yields [`FromBytesError::Misaligned`] if `bytes` does not start at an
+    /// address aligned for `T`, [`FromBytesError::InvalidLength`] if the
+    /// number of bytes is not a multiple of the size of `T`, or
+    /// [`FromBytesError::FailedAllocation`] if memory allocation fails.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    /// use rust::structure::collection::linear::array::dynamic::FromBytesError;
+    ///
+    /// let expected = Dynamic::from_iter([0_u32, 1, 2, 3]);
+    ///
+    /// let bytes = expected.as_bytes();
+    ///
+    /// let actual = Dynamic::<u32>::from_bytes(bytes).expect("valid bytes");
+    /// assert_eq!(actual, expected);
+    ///
+    /// let error = Dynamic::<u32>::from_bytes(&bytes[..3]).expect_err("invalid bytes");
+    /// assert_eq!(error, FromBytesError::InvalidLength);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError>
+    where
+        T: Pod,
+    {
+        // No elements, so nothing to be aligned.
+        if bytes.is_empty() {
+            return Ok(Self::default());
+        }
+
+        // Zero-size types have no bytes to reinterpret.
+        let Some(remainder) = bytes.len().checked_rem(size_of::<T>()) else {
+            return Err(FromBytesError::InvalidLength);
+        };
+
+        if remainder != 0 {
+            return Err(FromBytesError::InvalidLength);
+        }
+
+        if bytes.as_ptr().align_offset(align_of::<T>()) != 0 {
+            return Err(FromBytesError::Misaligned);
+        }
+
+        let Some(count) = bytes.len().checked_div(size_of::<T>()) else {
+            unreachable!("not a zero-size type");
+        };
+
+        // SAFETY:
+        // * `bytes` is aligned for `T` and contains exactly `count` of them.
+        // * `Pod` => any bit pattern is a valid element.
+        // * the elements borrow `bytes` => cannot be mutated or deallocated.
+        let elements = unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast::<T>(), count) };
+
+        Self::try_from(elements).map_err(|_| FromBytesError::FailedAllocation)
+    }
+
+    /// Exactly how much back capacity to allocate to apply amortized analysis.
+    ///
+    /// See also: [amortized analysis][amortized] and [dynamic array application][dynamic].
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// [amortized]: https://en.wikipedia.org/wiki/Amortized_analysis
+    /// [dynamic]: https://en.wikipedia.org/wiki/Dynamic_array#Geometric_expansion_and_amortized_cost
+    #[must_use]
+    fn amortized(&self, capacity: usize) -> Option<usize> {
+        let Some(retained) = self.front_capacity.checked_add(self.initialized) else {
+            unreachable!("allocated more the `isize::MAX` bytes");
+        };
+
+        let total = retained.checked_add(capacity)?;
+
+        let total = total.checked_next_power_of_two()?;
+
+        total.checked_sub(retained)
+    }
+
+    /// Shift the elements within `range` left or right by `offset`.
+    ///
+    /// Note this does _NOT_ modify internal capacity state.
+    ///
+    /// # Safety
+    /// The `range` must be within bounds, even when shifted by `offset`.
+    ///
+    /// # Panics
+    /// This method has the precondition the start bound is before the end.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    #[inline]
+    unsafe fn shift_range(&mut self, range: impl core::ops::RangeBounds<usize>, offset: isize) {
+        let start = match range.start_bound() {
+            core::ops::Bound::Unbounded => 0,
+            core::ops::Bound::Included(start) => *start,
+            core::ops::Bound::Excluded(start) => start.saturating_add(1),
+        };
+
+        let end = match range.end_bound() {
+            core::ops::Bound::Unbounded => self.len(),
+            core::ops::Bound::Included(end) => end.saturating_add(1),
+            core::ops::Bound::Excluded(end) => *end,
+        };
+
+        let Some(elements) = end.checked_sub(start) else {
+            panic!("range had end index before start index")
+        };
+
+        // SAFETY: points to the where the first initialized element goes.
+        let ptr = unsafe { self.buffer.as_ptr().add(self.front_capacity) };
+
+        // SAFETY: caller promises this will stay in bounds.
+        let source = unsafe { ptr.add(start) };
+
+        // SAFETY: caller promises this will stay in bounds.
+        let destination = unsafe { source.offset(offset) };
+
+        // SAFETY:
+        // * start/end in bounds => source/destination valid for read/write.
+        // * ranges can overlap => no aliasing restrictions.
+        unsafe {
+            core::ptr::copy(source, destination, elements);
+        }
+    }
+
+    /// Convert all front capacity into back capacity if there are no elements.
+    ///
+    /// When empty, [`Self::capacity_back`] includes the front capacity since
+    /// nothing needs to be shifted to use it, so this makes the underlying
+    /// fields agree before writing into the back capacity.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn reclaim_front_capacity_if_empty(&mut self) {
+        if self.initialized != 0 {
+            return;
+        }
+
+        if let Some(total) = self.back_capacity.checked_add(self.front_capacity) {
+            self.front_capacity = 0;
+            self.back_capacity = total;
+        } else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        }
+    }
+
+    /// (Re)allocate the buffer to modify back capacity by `capacity`.
+    ///
+    /// This method will increase back capacity by `capacity` if positive,
+    /// and decrease by `capacity` if negative, (re)allocating if necessary.
+    ///
+    /// Note that failed allocation will _NOT_ modify the underlying buffer.
+    ///
+    /// # Panics
+    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    #[inline]
+    fn reallocate(&mut self, capacity: isize) -> Result<&mut Self, FailedAllocation> {
+        let capacity = self
+            .capacity_back()
+            .checked_add_signed(capacity)
+            .ok_or(FailedAllocation)?;
+
+        let Some(unchanged) = self.front_capacity.checked_add(self.initialized) else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        };
+
+        // Zero-size types do _NOT_ occupy memory, so no (re/de)allocation.
+        if size_of::<T>() == 0 {
+            let total = unchanged.checked_add(capacity).ok_or(FailedAllocation)?;
+
+            // Global allocator API limits allocation to `isize:MAX` bytes.
+            if total > isize::MAX as usize {
+                return Err(FailedAllocation);
+            }
+
+            self.back_capacity = capacity;
+
+            return Ok(self);
+        }
+
+        let new = {
+            let total = unchanged.checked_add(capacity).ok_or(FailedAllocation)?;
+
+            match core::alloc::Layout::array::<T>(total) {
+                Ok(layout) => layout,
+                Err(_) => return Err(FailedAllocation),
+            }
+        };
+
+        let Some(total) = unchanged.checked_add(self.back_capacity) else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        };
+
+        let ptr = {
+            // No previous allocation exists, so create one.
+            if total == 0 {
+                if new.size() > 0 {
+                    // SAFETY: layout has non-zero size.
+                    unsafe { alloc::alloc::alloc(new) }.cast::<T>()
+                } else {
+                    debug_assert_eq!(capacity, 0, "otherwise occupies memory");
+
+                    // empty => The pointer will _NOT_ be read/written to.
+                    NonNull::<T>::dangling().as_ptr()
+                }
+            }
+            // Modify an existing buffer allocation.
+            else {
+                let Ok(existing) = core::alloc::Layout::array::<T>(total) else {
+                    return Err(FailedAllocation);
+                };
+
+                let ptr = self.buffer.as_ptr().cast::<u8>();
+
+                // Deallocate.
+                if unchanged == 0 && capacity == 0 {
+                    // SAFETY:
+                    // * allocated using the corresponding allocator.
+                    // * `existing_layout` is currently allocated.
+                    // * `new_layout` has non-zero size.
+                    // * `Layout` => `new.size() <= isize::MAX`.
+                    unsafe {
+                        alloc::alloc::dealloc(ptr, existing);
+                    }
+
+                    // empty state => will _NOT_ be read/written to.
+                    NonNull::<T>::dangling().as_ptr()
+                }
+                // Reallocate.
+                else {
+                    // SAFETY:
+                    // * allocated using the corresponding allocator.
+                    // * `existing_layout` is currently allocated.
+                    // * `new_layout` has non-zero size.
+                    // * `Layout` => `new.size() <= isize::MAX`.
+                    unsafe { alloc::alloc::realloc(ptr, existing, new.size()) }.cast::<T>()
+                }
+            }
+        };
+
+        // `MaybeUninit<T>` has the same layout as `T`.
+        let ptr = ptr.cast::<MaybeUninit<T>>();
+
+        self.buffer = match NonNull::new(ptr) {
+            Some(ptr) => ptr,
+            None => return Err(FailedAllocation),
+        };
+
+        self.back_capacity = capacity;
+
+        Ok(self)
+    }
+}
+
+impl<T> Drop for Dynamic<T> {
+    /// Drops the elements that are initialized and deallocates memory.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// instance.pop_front(); // Consumes the element with value `0`.
+    /// instance.pop_back();  // Consumes the element with value `5`.
+    ///
+    /// core::mem::drop(instance); // Drops the elements with values `[1, 2, 3, 4]`.
+    /// ```
+    fn drop(&mut self) {
+        /// Deallocates the buffer even if the destructor of an element panics.
+        struct Deallocate<'a, T> {
+            /// The instance whose elements are being dropped.
+            underlying: &'a mut Dynamic<T>,
+        }
+
+        impl<T> Drop for Deallocate<'_, T> {
+            /// Mark every element as dropped then deallocate the buffer.
+            fn drop(&mut self) {
+                let underlying = &mut *self.underlying;
+
+                if let Some(capacity) = underlying.back_capacity.checked_add(underlying.initialized)
+                {
+                    underlying.back_capacity = capacity;
+                    underlying.initialized = 0;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+
+                let Ok(_) = underlying.shrink(None) else {
+                    unreachable!("deallocation failure");
+                };
+            }
+        }
+
+        let elements = {
+            let ptr = self.buffer.as_ptr();
+
+            // SAFETY: stays aligned within the allocated object.
+            let ptr = unsafe { ptr.add(self.front_capacity) };
+
+            core::ptr::slice_from_raw_parts_mut(ptr.cast::<T>(), self.initialized)
+        };
+
+        let guard = Deallocate { underlying: self };
+
+        // SAFETY:
+        // * the elements are initialized => safe drop.
+        // * should one panic, the remaining elements are still dropped.
+        unsafe {
+            core::ptr::drop_in_place(elements);
+        }
+
+        drop(guard);
+    }
+}
+
+impl<'a, T: 'a + Clone> TryFrom<&'a [T]> for Dynamic<T> {
+    type Error = FailedAllocation;
+
+    /// Construct by cloning elements from an existing slice.
+    ///
+    /// # Panics
+    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let expected = [0, 1, 2, 3, 4, 5];
+    /// let actual = Dynamic::try_from(expected.as_slice()).expect("successful allocation");
+    ///
+    /// assert!(actual.into_iter().eq(expected));
+    /// ```
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        let mut instance = Self::with_capacity(slice.len())?;
+
+        instance.extend(slice.iter().cloned());
+
+        Ok(instance)
+    }
+}
+
+impl<T> core::ops::Index<usize> for Dynamic<T> {
+    type Output = T;
+
+    /// Query the initialized element `index` positions from the start.
+    ///
+    /// # Panics
+    /// This method has the precondition that the `index` is within bounds.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let expected = [0, 1, 2, 3, 4, 5];
+    /// let actual = Dynamic::from_iter(expected.iter().copied());
+    ///
+    /// for index in 0..expected.len() {
+    ///     use core::ops::Index;
+    ///     assert_eq!(actual.index(index), expected.index(index));
+    /// }
+    /// ```
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.initialized, "index out of bounds");
+
+        let ptr = self.as_ptr();
+
+        // SAFETY: index within bounds => stays within the allocated object.
+        let ptr = unsafe { ptr.add(index) };
+
+        // SAFETY:
+        // * the underlying `T` is initialized.
+        // * lifetime bound to self => valid lifetime to return.
+        unsafe { &*ptr }
+    }
+}
+
+impl<T> core::ops::IndexMut<usize> for Dynamic<T> {
+    /// Obtain a reference to the element `index` positions from the start.
+    ///
+    /// # Panics
+    /// This method has the precondition that the `index` is within bounds.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut expected = [0, 1, 2, 3, 4, 5];
+    /// let mut actual = Dynamic::from_iter(expected.iter().copied());
+    ///
+    /// for index in 0..expected.len() {
+    ///     use core::ops::IndexMut;
+    ///     assert_eq!(actual.index_mut(index), expected.index_mut(index));
+    /// }
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < self.initialized, "index out of bounds");
+
+        let ptr = self.as_mut_ptr();
+
+        // SAFETY: index within bounds => stays within the allocated object.
+        let ptr = unsafe { ptr.add(index) };
+
+        // SAFETY:
+        // * the underlying `T` is initialized.
+        // * lifetime bound to self => valid lifetime to return.
+        unsafe { &mut *ptr }
+    }
+}
+
+impl<T> IntoIterator for Dynamic<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    /// Obtain an iterator that yields ownership of elements by value.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { underlying: self }
+    }
+}
+
+#[allow(clippy::into_iter_without_iter)] // Provided by `Linear::iter`.
+impl<'a, T> IntoIterator for &'a Dynamic<T> {
+    type Item = &'a T;
+
+    type IntoIter = super::Iter<'a, T>;
+
+    /// Obtain an iterator that yields references to elements in order.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// for (actual, expected) in (&instance).into_iter().zip(0..) {
+    ///     assert_eq!(*actual, expected);
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> FromIterator<T> for Dynamic<T> {
+    /// Construct by moving elements from an iterator.
+    ///
+    /// Via the standard library, this also enables collecting an iterator of
+    /// [`Result`] into `Result<Self, E>` which stops at the first error and
+    /// drops any already collected elements.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let expected = [0, 1, 2, 3, 4, 5];
+    ///
+    /// let actual: Dynamic<_> = expected.clone().into_iter().collect();
+    ///
+    /// assert!(actual.into_iter().eq(expected))
+    /// ```
+    fn from_iter<Iter: IntoIterator<Item = T>>(iter: Iter) -> Self {
+        let iter = iter.into_iter();
+
+        let mut instance = Self::default();
+
+        instance.extend(iter);
+
+        instance
+    }
+}
+
+impl<T> Extend<T> for Dynamic<T> {
+    /// Append elements of an iterator in order.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let expected = [0, 1, 2, 3, 4, 5];
+    ///
+    /// let mut instance = Dynamic::<i32>::default();
+    ///
+    /// instance.extend(expected.iter().cloned());
+    ///
+    /// assert!(instance.into_iter().eq(expected))
+    /// ```
+    fn extend<Iter: IntoIterator<Item = T>>(&mut self, iter: Iter) {
+        let iter = iter.into_iter();
+
+        // `size_hint` can _NOT_ be trusted to exact size.
+        let count = {
+            let (min, max) = iter.size_hint();
+            max.unwrap_or(min)
+        };
+
+        // Append will allocate for each realized element reserve if fails.
+        drop(self.reserve_back(count));
+
+        for element in iter {
+            assert!(self.append(element).is_ok(), "allocation failed");
+        }
+    }
+}
+
+impl<'a, T: 'a + Clone> Extend<&'a T> for Dynamic<T> {
+    /// Append clones of borrowed elements of an iterator in order.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let expected = [0, 1, 2, 3, 4, 5];
+    ///
+    /// let mut instance = Dynamic::<i32>::default();
+    ///
+    /// instance.extend(expected.iter());
+    ///
+    /// assert!(instance.into_iter().eq(expected))
+    /// ```
+    fn extend<Iter: IntoIterator<Item = &'a T>>(&mut self, iter: Iter) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<T> core::iter::Sum for Dynamic<T> {
+    /// Concatenate the instances in order (this is _NOT_ a numeric sum).
+    ///
+    /// The allocation of the first instance is reused, moving the elements of
+    /// every subsequent instance to the end of it.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instances = [Dynamic::from_iter([0, 1]), Dynamic::from_iter([2, 3])];
+    ///
+    /// let actual: Dynamic<_> = instances.into_iter().sum();
+    ///
+    /// assert!(actual.into_iter().eq([0, 1, 2, 3]));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        let Some(first) = iter.next() else {
+            return Self::default();
+        };
+
+        iter.fold(first, |mut accumulator, instance| {
+            accumulator.extend(instance);
+            accumulator
+        })
+    }
+}
+
+impl<T> Default for Dynamic<T> {
+    /// Construct an instance with no elements and no capacity/allocation.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::<()>::default();
+    ///
+    /// assert_eq!(instance.len(), 0);
+    /// assert_eq!(instance.capacity(), 0);
+    /// ```
+    fn default() -> Self {
+        Self {
+            buffer: NonNull::dangling(),
+            front_capacity: 0,
+            initialized: 0,
+            back_capacity: 0,
+        }
+    }
+}
+
+impl<T: Clone> Clone for Dynamic<T> {
+    /// Construct an instance with no elements and no capacity/allocation.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let expected = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(expected.clone(), expected)
+    /// ```
+    fn clone(&self) -> Self {
+        let mut clone = Self::default();
+
+        clone.extend(self.iter().cloned());
+
+        clone
+    }
+}
+
+impl<T: PartialEq> PartialEq for Dynamic<T> {
+    /// Query if the elements contained are the same as `other`.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let left = [0, 1, 2, 3, 4, 5];
+    /// let right = left.clone();
+    ///
+    /// let left = Dynamic::from_iter(left);
+    /// let right = Dynamic::from_iter(right);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Dynamic<T> {}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Dynamic<T> {
+    /// List the elements contained.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut expected = [0, 1, 2, 3, 4, 5];
+    /// let actual = Dynamic::from_iter(expected.iter());
+    ///
+    /// assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl core::fmt::Write for Dynamic<u8> {
+    /// Append the UTF-8 encoded bytes of `string`.
+    ///
+    /// See [`Dynamic::splice_slice`], capacity is reserved amortized.
+    ///
+    /// # Errors
+    /// Yields [`core::fmt::Error`] when memory (re)allocation fails, in
+    /// which case no bytes are appended.
+    ///
+    /// # Performance
+    /// This methods takes O(N + K) time and consumes O(N + K) memory for
+    /// the K bytes within `string`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<u8>::default();
+    ///
+    /// let name = "value";
+    ///
+    /// write!(instance, "{name}-{:02}", 7).expect("successful allocation");
+    ///
+    /// assert!(instance.into_iter().eq(*b"value-07"));
+    /// ```
+    fn write_str(&mut self, string: &str) -> core::fmt::Result {
+        match self.splice_slice(self.initialized.., string.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(core::fmt::Error),
+        }
+    }
+}
+
+impl std::io::Write for Dynamic<u8> {
+    /// Append all of `bytes`.
+    ///
+    /// See [`Dynamic::splice_slice`], capacity is reserved amortized.
+    ///
+    /// # Errors
+    /// Yields [`std::io::ErrorKind::OutOfMemory`] when memory (re)allocation
+    /// fails, in which case no bytes are appended.
+    ///
+    /// # Performance
+    /// This methods takes O(N + K) time and consumes O(N + K) memory for
+    /// the K bytes within `bytes`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Write;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<u8>::default();
+    ///
+    /// assert_eq!(instance.write(b"bytes").ok(), Some(5));
+    ///
+    /// assert!(instance.into_iter().eq(*b"bytes"));
+    /// ```
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        match self.splice_slice(self.initialized.., bytes) {
+            Ok(_) => Ok(bytes.len()),
+            Err(_) => Err(std::io::ErrorKind::OutOfMemory.into()),
+        }
+    }
+
+    /// Do nothing since bytes are written directly into the buffer.
+    ///
+    /// # Errors
+    /// This method cannot fail.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Write;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<u8>::default();
+    ///
+    /// assert!(instance.flush().is_ok());
+    /// ```
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<T> Collection for Dynamic<T> {
+    type Element = T;
+
+    /// Query the number of initialized elements contained.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let expected = [0, 1, 2, 3, 4, 5];
+    /// let instance = Dynamic::from_iter(expected.clone());
+    ///
+    /// assert_eq!(Collection::count(&instance), expected.len());
+    /// ```
+    fn count(&self) -> usize {
+        self.initialized
+    }
+
+    /// Query how many elements could be contained without reallocation.
+    ///
+    /// Unlike [`Dynamic::capacity`] which is only the capacity yet to be
+    /// used, this includes the initialized elements.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// instance.reserve_back(10).expect("successful allocation");
+    ///
+    /// assert_eq!(instance.total_capacity(), 16);
+    /// assert_eq!(instance.capacity(), 10);
+    /// ```
+    fn total_capacity(&self) -> usize {
+        self.initialized
+            .checked_add(self.capacity())
+            .unwrap_or_else(|| unreachable!("allocated more than `isize::MAX` bytes"))
+    }
+
+    /// Allocate back capacity for at least `additional` elements.
+    ///
+    /// See [`Self::reserve_back`] for details. Allocation failure is ignored
+    /// since this is only a hint, inserting will report such failure.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2]);
+    ///
+    /// Collection::extend_reserve(&mut instance, 256);
+    ///
+    /// assert!(instance.capacity_back() >= 256);
+    /// assert_eq!(Collection::count(&instance), 3);
+    /// ```
+    fn extend_reserve(&mut self, additional: usize) {
+        _ = self.reserve_back(additional);
+    }
+
+    /// Drop all initialized elements, retaining the allocation.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0,1,2,3,4,5]);
+    ///
+    /// instance.clear();
+    ///
+    /// assert_eq!(instance.len(), 0);
+    /// assert_eq!(instance.capacity(), 6);
+    /// ```
+    fn clear(&mut self) {
+        let elements = {
+            let ptr = self.buffer.as_ptr();
+
+            // SAFETY: stays aligned within the allocated object.
+            let ptr = unsafe { ptr.add(self.front_capacity) };
+
+            core::ptr::slice_from_raw_parts_mut(ptr.cast::<T>(), self.initialized)
+        };
+
+        // Mark every element as dropped beforehand such that if the
+        // destructor of one panics, none will be dropped again later.
+        if let Some(capacity) = self.back_capacity.checked_add(self.initialized) {
+            self.back_capacity = capacity;
+            self.initialized = 0;
+        } else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        }
+
+        // SAFETY:
+        // * the elements are initialized => safe drop.
+        // * should one panic, the remaining elements are still dropped.
+        unsafe {
+            core::ptr::drop_in_place(elements);
+        }
+    }
+}
+
+impl<T> Linear for Dynamic<T> {
+    /// Create an immutable iterator over the initialized elements.
+    ///
+    /// Unlike the opaque [`Iterator`] required by [`Linear`], this yields the
+    /// concrete [`Iter`](`super::Iter`) such that the elements yet to be
+    /// yielded can be obtained via [`as_slice`](`super::Iter::as_slice`).
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let expected = [0, 1, 2, 3, 4, 5];
+    /// let actual = Dynamic::from_iter(expected.clone());
+    ///
+    /// for (actual, expected) in actual.iter().zip(expected.iter()) {
+    ///     assert_eq!(actual, expected);
+    /// }
+    /// ```
+    #[allow(refining_impl_trait)]
+    fn iter(&self) -> super::Iter<'_, Self::Element> {
+        let ptr = if self.initialized > 0 {
+            // The pointer will only ever be read, no written to.
+            let ptr = self.as_ptr().cast_mut();
+
+            // SAFETY: initialized elements => `ptr` is non-null
+            unsafe { NonNull::new_unchecked(ptr) }
+        } else {
+            debug_assert_eq!(self.initialized, 0, "initialized elements");
+
+            // no initialized elements => The pointer will not be read.
+            NonNull::dangling()
+        };
+
+        // SAFETY: `ptr` is dangling if and only if no elements have been
+        // initialized, in which case the pointer will not be read.
+        unsafe { super::Iter::new(ptr, self.initialized) }
+    }
+
+    /// Create a mutable iterator over the initialized elements.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut expected = [0, 1, 2, 3, 4, 5];
+    /// let mut actual = Dynamic::from_iter(expected.clone());
+    ///
+    /// for (actual, expected) in actual.iter_mut().zip(expected.iter_mut()) {
+    ///     assert_eq!(actual, expected);
+    /// }
+    /// ```
+    fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut Self::Element>
+           + ExactSizeIterator
+           + core::iter::FusedIterator {
+        let ptr = if self.initialized > 0 {
+            let ptr = self.as_mut_ptr();
+
+            // SAFETY: initialized elements => `ptr` is non-null
+            unsafe { NonNull::new_unchecked(ptr) }
+        } else {
+            debug_assert_eq!(self.initialized, 0, "initialized elements");
+
+            // no initialized elements => The pointer will not be read.
+            NonNull::dangling()
+        };
+
+        // SAFETY: `ptr` is dangling if and only if no elements have been
+        // initialized, in which case the pointer will not be read.
+        unsafe { super::IterMut::new(ptr, self.initialized) }
+    }
+}
+
+impl<T> Array for Dynamic<T> {
+    /// Obtain an immutable pointer to the underlying contigious memory buffer.
+    ///
+    /// The pointer starts at the first initialized element.
+    ///
+    /// # Safety
+    /// * `self` must outlive the pointer.
+    /// * The pointer must never be written to.
+    /// * Modifying `self` might invalidate the pointer.
+    ///
+    /// # Panics
+    /// This method has the precondition that an underlying allocation exists
+    /// to point to. Note that a dangling (but nevertheless valid) pointer will
+    /// be yielded for zero-size types despite not occupying memory.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Array;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let expected = core::ptr::from_ref(&instance[0]);
+    /// let actual = unsafe { instance.as_ptr() };
+    ///
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[allow(clippy::arithmetic_side_effects)]
+    fn as_ptr(&self) -> *const Self::Element {
+        assert!(
+            self.front_capacity + self.initialized + self.back_capacity > 0,
+            "no allocation to point to"
+        );
+
+        // `MaybeUninit<T>` has the same layout as `T`.
+        let ptr = self.buffer.cast::<T>().as_ptr().cast_const();
+
+        // SAFETY: Stays aligned within the allocated object.
+        unsafe { ptr.add(self.front_capacity) }
+    }
+
+    /// Obtain a mutable pointer to the underlying contigious memory buffer.
+    ///
+    /// The pointer starts at the first initialized element.
+    ///
+    /// # Safety
+    /// * `self` must outlive the pointer.
+    /// * Modifying `self` might invalidate the pointer.
+    ///
+    /// # Panics
+    /// This method has the precondition that an underlying allocation exists
+    /// to point to. Note that a dangling (but nevertheless valid) pointer will
+    /// be yielded for zero-size types despite not occupying memory.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Array;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let expected = core::ptr::from_ref(&instance[0]).cast_mut();
+    /// let actual = unsafe { instance.as_mut_ptr() };
+    ///
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[allow(clippy::arithmetic_side_effects)]
+    fn as_mut_ptr(&mut self) -> *mut Self::Element {
+        assert!(
+            self.front_capacity + self.initialized + self.back_capacity > 0,
+            "no allocation to point to"
+        );
+
+        // `MaybeUninit<T>` has the same layout as `T`.
+        let ptr = self.buffer.cast::<T>().as_ptr();
+
+        // SAFETY: Stays aligned within the allocated object.
+        unsafe { ptr.add(self.front_capacity) }
+    }
+}
+
+impl<T> RandomAccess for Dynamic<T> {
+    /// Obtain an immutable reference to the `index` element, bounds checked.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.get(0), Some(&0));
+    /// assert_eq!(instance.get(5), Some(&5));
+    /// assert_eq!(instance.get(6), None);
+    /// ```
+    fn get(&self, index: usize) -> Option<&Self::Element> {
+        self.at(index)
+    }
+
+    /// Obtain a mutable reference to the `index` element, bounds checked.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.get_mut(0), Some(&mut 0));
+    /// assert_eq!(instance.get_mut(5), Some(&mut 5));
+    /// assert_eq!(instance.get_mut(6), None);
+    /// ```
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Element> {
+        self.at_mut(index)
+    }
+
+    /// Swap the element at index `first` with the element at index `second`.
+    ///
+    /// # Panics
+    /// If either `first` or `second` is out of bounds.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// instance.swap(0, 5);
+    ///
+    /// assert!(instance.iter().eq([5, 1, 2, 3, 4, 0].iter()));
+    /// ```
+    fn swap(&mut self, first: usize, second: usize) {
+        let count = self.initialized;
+
+        assert!(first < count && second < count, "index out of bounds");
+
+        self.as_mut_slice().swap(first, second);
+    }
+}
+
+impl<T> List for Dynamic<T> {
+    /// Insert an `element` at `index`.
+    ///
+    /// # Panics
+    /// The Rust runtime might panic or otherwise abort if allocation fails.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<usize>::default();
+    ///
+    /// instance.insert(0, 1);
+    /// instance.insert(1, 3);
+    /// instance.insert(1, 2);
+    /// instance.insert(0, 0);
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3]));
+    /// ```
+    fn insert(
+        &mut self,
+        index: usize,
+        element: Self::Element,
+    ) -> Result<&mut Self::Element, Self::Element> {
+        if index > self.initialized {
+            return Err(element);
+        }
+
+        let mut ptr = self.buffer.as_ptr();
+
+        // consume front capacity.
+        if index == 0 && self.capacity_front() > 0 {
+            ptr = {
+                let Some(offset) = self.capacity_front().checked_sub(1) else {
+                    unreachable!("zero front capacity")
+                };
+
+                // SAFETY: the last uninitialized element in the front.
+                unsafe { ptr.add(offset) }
+            };
+
+            // Shift all capacity to front capacity.
+            if self.initialized == 0 {
+                if let Some(capacity) = self.front_capacity.checked_add(self.back_capacity) {
+                    self.front_capacity = capacity;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+
+                self.back_capacity = 0;
+            }
+
+            if let Some(decremented) = self.front_capacity.checked_sub(1) {
+                self.front_capacity = decremented;
+            } else {
+                unreachable!("no front capacity to insert into");
+            }
+        }
+        // consume back capacity.
+        else if self.reserve(1).is_ok() {
+            ptr = {
+                let Some(offset) = self.front_capacity.checked_add(index) else {
+                    unreachable!("index out of bounds");
+                };
+
+                // SAFETY: the uninitialized element to insert into.
+                unsafe { self.buffer.as_ptr().add(offset) }
+            };
+
+            // SAFETY: there is back capacity to shift into.
+            unsafe {
+                self.shift_range(index.., 1);
+            }
+
+            if let Some(decrement) = self.back_capacity.checked_sub(1) {
+                self.back_capacity = decrement;
+            } else {
+                unreachable!("no back capacity to insert into");
+            }
+        } else {
+            debug_assert_eq!(self.capacity(), 0, "no capacity to insert into");
+
+            return Err(element);
+        }
+
+        if let Some(increment) = self.initialized.checked_add(1) {
+            self.initialized = increment;
+        } else {
+            unreachable!("allocated more that `isize::MAX` bytes");
+        }
+
+        // SAFETY: the `MaybeUninit<T>` is initialized even if the `T` isn't.
+        let uninit_element = unsafe { &mut *ptr };
+
+        // the underlying `T` is unutilized.
+        Ok(uninit_element.write(element))
+    }
+
+    /// Remove the element at `index`.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0,1,2,3,4,5]);
+    ///
+    /// instance.remove(5);
+    /// instance.remove(2);
+    /// instance.remove(0);
+    ///
+    /// assert!(instance.into_iter().eq([1, 3, 4]));
+    /// ```
+    fn remove(&mut self, index: usize) -> Option<Self::Element> {
+        if index >= self.initialized {
+            return None;
+        }
+
+        let element = {
+            // SAFETY: index within bounds => aligned within allocated object.
+            let ptr = unsafe { self.as_ptr().add(index) };
+
+            // SAFETY:
+            // * owned memory => pointer is valid for reads.
+            // * Underlying `T` is initialized.
+            // * This takes ownership (moved out of the buffer).
+            unsafe { ptr.read() }
+        };
+
+        // Increase front capacity.
+        if index == 0 {
+            if let Some(incremented) = self.front_capacity.checked_add(1) {
+                self.front_capacity = incremented;
+            } else {
+                unreachable!("allocated more that `isize::MAX` bytes");
+            }
+        }
+        // Increase back capacity.
+        else {
+            // SAFETY: there is back capacity to shift into.
+            unsafe {
+                self.shift_range(index.saturating_add(1).., -1);
+            }
+
+            if let Some(incremented) = self.back_capacity.checked_add(1) {
+                self.back_capacity = incremented;
+            } else {
+                unreachable!("allocated more that `isize::MAX` bytes");
+            }
+        }
+
+        if let Some(decremented) = self.initialized.checked_sub(1) {
+            self.initialized = decremented;
+        } else {
+            unreachable!("no initialized element to remove");
+        }
+
+        Some(element)
+    }
+
+    /// Optimally remove elements within `range` by-value.
+    ///
+    /// This method is more efficient than using `remove` for sequential
+    /// elements, moving elements out of the buffer as iterated and shifting
+    /// once only when the iterator has been dropped.
+    ///
+    /// This yields the concrete [`Drain`] which provides [`Drain::keep_rest`].
+    /// The allocation is never modified, rather the capacity of drained
+    /// elements becomes front or back capacity.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    /// use rust::structure::collection::linear::List;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// let mut drain = instance.drain(..2);
+    /// assert_eq!(drain.next(), Some(0));
+    /// assert_eq!(drain.next_back(), Some(1));
+    /// core::mem::drop(drain);
+    ///
+    /// let mut drain = instance.drain(0..2);
+    /// assert_eq!(drain.next(), Some(2));
+    /// assert_eq!(drain.next_back(), Some(3));
+    /// core::mem::drop(drain);
+    ///
+    /// let mut drain = instance.drain(0..=1);
+    /// assert_eq!(drain.next(), Some(4));
+    /// assert_eq!(drain.next_back(), Some(5));
+    /// core::mem::drop(drain);
+    ///
+    /// let mut drain = instance.drain(0..);
+    /// assert_eq!(drain.next(), Some(6));
+    /// assert_eq!(drain.next_back(), Some(7));
+    /// core::mem::drop(drain);
+    ///
+    /// let mut drain = instance.drain(..);
+    /// assert_eq!(drain.next(), None);
+    /// assert_eq!(drain.next_back(), None);
+    /// ```
+    #[allow(refining_impl_trait)]
+    fn drain(&mut self, range: impl core::ops::RangeBounds<usize>) -> Drain<'_, T> {
+        let start = match range.start_bound() {
+            core::ops::Bound::Included(start) => *start,
+            core::ops::Bound::Excluded(start) => start.saturating_add(1),
+            core::ops::Bound::Unbounded => 0,
+        }
+        .min(self.len());
+
+        let end = match range.end_bound() {
+            core::ops::Bound::Included(end) => end.saturating_add(1),
+            core::ops::Bound::Excluded(end) => *end,
+            core::ops::Bound::Unbounded => self.len(),
+        }
+        .min(self.len());
+
+        let normalized = start..end;
+
+        Drain {
+            underlying: self,
+            range: normalized.clone(),
+            next: normalized.clone(),
+        }
+    }
+
+    /// Remove the elements which match some `predicate`.
+    ///
+    /// The `predicate` is called exactly once per each element, in order of
+    /// iteration. Elements for which the `predicate` is true are removed in
+    /// order from left to right. Elements for which the `predicate` is false
+    /// are shifted left to immediately after the previously retained element,
+    /// thereby maintaining order.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    /// use rust::structure::collection::linear::List;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut withdraw = instance.withdraw(|element| element % 2 == 0);
+    ///
+    /// assert_eq!(withdraw.next(), Some(0));
+    /// assert_eq!(withdraw.next_back(), Some(4));
+    ///
+    /// drop(withdraw);
+    ///
+    /// assert!(instance.into_iter().eq([1, 3, 5]));
+    /// ```
+    fn withdraw(
+        &mut self,
+        predicate: impl FnMut(&T) -> bool,
+    ) -> impl DoubleEndedIterator<Item = Self::Element> {
+        let head = if self.initialized == 0 {
+            // is empty => this pointer will _NOT_ be modified or read.
+            NonNull::dangling()
+        } else {
+            // SAFETY: at least one element exist => pointer cannot be null.
+            unsafe { NonNull::new_unchecked(self.as_mut_ptr()) }
+        };
+
+        let tail = {
+            let ptr = {
+                let offset = self.initialized.saturating_sub(1);
+
+                // SAFETY: stays aligned within the allocated object.
+                unsafe { head.as_ptr().add(offset) }
+            };
+
+            // SAFETY: `head` cannot be null => pointer cannot be null.
+            unsafe { NonNull::new_unchecked(ptr) }
+        };
+
+        let remaining = self.initialized;
+
+        Withdraw {
+            underlying: self,
+            predicate,
+            remaining,
+            retained: head,
+            next_front: head,
+            next_back: tail,
+            trailing: 0,
+        }
+    }
+
+    /// Move the first element out, consuming front capacity.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2]);
+    ///
+    /// assert_eq!(instance.pop_front(), Some(0));
+    /// assert_eq!(instance.pop_front(), Some(1));
+    /// assert_eq!(instance.pop_front(), Some(2));
+    /// assert_eq!(instance.pop_front(), None);
+    ///
+    /// assert_eq!(instance.capacity_front(), 3);
+    /// ```
+    fn pop_front(&mut self) -> Option<Self::Element> {
+        (self.initialized > 0).then(|| {
+            let element = self.as_mut_ptr();
+
+            if let Some(decremented) = self.initialized.checked_sub(1) {
+                self.initialized = decremented;
+            } else {
+                unreachable!("no initialized element to remove");
+            }
+
+            if let Some(incremented) = self.front_capacity.checked_add(1) {
+                self.front_capacity = incremented;
+            } else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            }
+
+            // SAFETY:
+            // * owned memory => pointer is valid for reads.
+            // * Underlying `T` is initialized.
+            // * This takes ownership (moved out of the buffer).
+            unsafe { element.read() }
+        })
+    }
+
+    /// Move the last element out, consuming back capacity.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2]);
+    ///
+    /// assert_eq!(instance.pop_back(), Some(2));
+    /// assert_eq!(instance.pop_back(), Some(1));
+    /// assert_eq!(instance.pop_back(), Some(0));
+    /// assert_eq!(instance.pop_back(), None);
+    ///
+    /// assert_eq!(instance.capacity_back(), 3);
+    /// ```
+    fn pop_back(&mut self) -> Option<Self::Element> {
+        (self.initialized > 0).then(|| {
+            if let Some(decremented) = self.initialized.checked_sub(1) {
+                self.initialized = decremented;
+            } else {
+                unreachable!("no initialized element to remove");
+            }
+
+            if let Some(incremented) = self.back_capacity.checked_add(1) {
+                self.back_capacity = incremented;
+            } else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            }
+
+            let ptr = self.as_mut_ptr();
+
+            // SAFETY: final initialized element in the allocated object.
+            let element = unsafe { ptr.add(self.initialized) };
+
+            // SAFETY:
+            // * owned memory => pointer is valid for reads.
+            // * Underlying `T` is initialized.
+            // * This takes ownership (moved out of the buffer).
+            unsafe { element.read() }
+        })
+    }
+}
+
+impl<T> super::super::Stack for Dynamic<T> {
+    /// Move an `element` on the top of the stack.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Stack;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<usize>::default();
+    ///
+    /// instance.push(5).expect("successful allocation");
+    /// instance.push(4).expect("successful allocation");
+    /// instance.push(3).expect("successful allocation");
+    /// instance.push(2).expect("successful allocation");
+    /// instance.push(1).expect("successful allocation");
+    /// instance.push(0).expect("successful allocation");
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    fn push(&mut self, element: Self::Element) -> Result<&mut Self::Element, Self::Element> {
+        self.prepend(element)
+    }
+
+    /// Move out the element at the top of the stack.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Stack;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.pop(), Some(0));
+    /// assert_eq!(instance.pop(), Some(1));
+    /// assert_eq!(instance.pop(), Some(2));
+    /// assert_eq!(instance.pop(), Some(3));
+    /// assert_eq!(instance.pop(), Some(4));
+    /// assert_eq!(instance.pop(), Some(5));
+    /// assert_eq!(instance.pop(), None);
+    /// ```
+    fn pop(&mut self) -> Option<Self::Element> {
+        self.pop_front()
+    }
+
+    /// Query the element at the top of the stack.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Stack;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.peek(), Some(&0));
+    /// ```
+    fn peek(&self) -> Option<&Self::Element> {
+        self.first()
+    }
+
+    /// Move each of `elements` onto the stack, reserving front capacity
+    /// for them all at once.
+    ///
+    /// # Errors
+    /// Yields the first element which cannot be pushed, in which case those
+    /// before it remain pushed and those after it are dropped.
+    ///
+    /// # Performance
+    /// This method takes O(N + K) time and consumes O(N + K) memory for the
+    /// K pushed elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Stack;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<usize>::default();
+    ///
+    /// instance.push_all([0, 1, 2]).expect("successful allocation");
+    ///
+    /// assert!(instance.into_iter().eq([2, 1, 0]));
+    /// ```
+    fn push_all<I: IntoIterator<Item = Self::Element>>(
+        &mut self,
+        elements: I,
+    ) -> Result<(), Self::Element> {
+        let elements = elements.into_iter();
+
+        // Inserting will report failure, so this can be ignored.
+        _ = self.reserve_front(elements.size_hint().0);
+
+        for element in elements {
+            _ = self.prepend(element)?;
+        }
+
+        Ok(())
+    }
+
+    /// Move out up to `count` elements from the stack, in popped order.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(K) time and consumes O(K) memory for the K
+    /// popped elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Stack;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<usize>::default();
+    ///
+    /// instance.push_all([0, 1, 2]).expect("successful allocation");
+    ///
+    /// assert!(instance.pop_n(2).into_iter().eq([2, 1]));
+    /// assert!(instance.into_iter().eq([0]));
+    /// ```
+    fn pop_n(&mut self, count: usize) -> Dynamic<Self::Element> {
+        self.drain(..count).collect()
+    }
+}
+
+impl<T> super::super::Queue for Dynamic<T> {
+    /// Move an `element` to the end of the queue.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Stack;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<usize>::default();
+    ///
+    /// instance.push(5).expect("successful allocation");
+    /// instance.push(4).expect("successful allocation");
+    /// instance.push(3).expect("successful allocation");
+    /// instance.push(2).expect("successful allocation");
+    /// instance.push(1).expect("successful allocation");
+    /// instance.push(0).expect("successful allocation");
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    fn push(&mut self, element: Self::Element) -> Result<&mut Self::Element, Self::Element> {
+        self.append(element)
+    }
+
+    /// Move out the element at the front of the queue.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Stack;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.pop(), Some(0));
+    /// assert_eq!(instance.pop(), Some(1));
+    /// assert_eq!(instance.pop(), Some(2));
+    /// assert_eq!(instance.pop(), Some(3));
+    /// assert_eq!(instance.pop(), Some(4));
+    /// assert_eq!(instance.pop(), Some(5));
+    /// assert_eq!(instance.pop(), None);
+    /// ```
+    fn pop(&mut self) -> Option<Self::Element> {
+        self.pop_front()
+    }
+
+    /// Query the element at the front of the queue.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Stack;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.peek(), Some(&0));
+    /// ```
+    fn peek(&self) -> Option<&Self::Element> {
+        self.first()
+    }
+
+    /// Move each of `elements` onto the queue, reserving back capacity
+    /// for them all at once.
+    ///
+    /// # Errors
+    /// Yields the first element which cannot be pushed, in which case those
+    /// before it remain pushed and those after it are dropped.
+    ///
+    /// # Performance
+    /// This method takes O(N + K) time and consumes O(N + K) memory for the
+    /// K pushed elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Queue;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<usize>::default();
+    ///
+    /// instance.push_all([0, 1, 2]).expect("successful allocation");
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 2]));
+    /// ```
+    fn push_all<I: IntoIterator<Item = Self::Element>>(
+        &mut self,
+        elements: I,
+    ) -> Result<(), Self::Element> {
+        let elements = elements.into_iter();
+
+        // Inserting will report failure, so this can be ignored.
+        _ = self.reserve_back(elements.size_hint().0);
+
+        for element in elements {
+            _ = self.append(element)?;
+        }
+
+        Ok(())
+    }
+
+    /// Move out up to `count` elements from the queue, in popped order.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(K) time and consumes O(K) memory for the K
+    /// popped elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Queue;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<usize>::default();
+    ///
+    /// instance.push_all([0, 1, 2]).expect("successful allocation");
+    ///
+    /// assert!(instance.pop_n(2).into_iter().eq([0, 1]));
+    /// assert!(instance.into_iter().eq([2]));
+    /// ```
+    fn pop_n(&mut self, count: usize) -> Dynamic<Self::Element> {
+        self.drain(..count).collect()
+    }
+}
+
+/// By-value [`Iterator`] over a [`Dynamic`].
+///
+/// Elements yet to be yielded are dropped alongside the allocation when this
+/// is dropped.
+///
+/// See [`Dynamic::into_iter`].
+pub struct IntoIter<T> {
+    /// The underlying [`Dynamic`] whose elements are being moved out of.
+    underlying: Dynamic<T>,
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for IntoIter<T> {
+    /// List the elements yet to be yielded.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]).into_iter();
+    ///
+    /// // Remove some elements.
+    /// instance.next();
+    /// instance.next_back();
+    ///
+    /// assert_eq!(format!("{instance:?}"), format!("[1, 2, 3, 4]"));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.underlying.fmt(f)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    /// Obtain the first initialized element.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]).into_iter();
+    ///
+    /// assert_eq!(instance.next(), Some(0));
+    /// assert_eq!(instance.next(), Some(1));
+    /// assert_eq!(instance.next(), Some(2));
+    /// assert_eq!(instance.next(), Some(3));
+    /// assert_eq!(instance.next(), Some(4));
+    /// assert_eq!(instance.next(), Some(5));
+    /// assert_eq!(instance.next(), None);
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        self.underlying.pop_front()
+    }
+
+    /// Query how many elements have yet to be yielded.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]).into_iter();
+    ///
+    /// assert_eq!(instance.size_hint(), (6, Some(6)));
+    /// ```
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.underlying.initialized,
+            Some(self.underlying.initialized),
+        )
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    /// Obtain the last initialized element.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]).into_iter();
+    ///
+    /// assert_eq!(instance.next_back(), Some(5));
+    /// assert_eq!(instance.next_back(), Some(4));
+    /// assert_eq!(instance.next_back(), Some(3));
+    /// assert_eq!(instance.next_back(), Some(2));
+    /// assert_eq!(instance.next_back(), Some(1));
+    /// assert_eq!(instance.next_back(), Some(0));
+    /// assert_eq!(instance.next_back(), None);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.underlying.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> core::iter::FusedIterator for IntoIter<T> {}
+
+/// [`Iterator`] to yield elements within an index range from [`Dynamic`].
+///
+/// When dropped, elements yet to be yielded are dropped and the elements
+/// after the range are shifted to close the gap, unless the range reaches
+/// either end in which case no shifting is required. Either way, the
+/// allocation is unchanged meaning the capacity is preserved, only
+/// redistributed between the front and back.
+///
+/// See [`Dynamic::drain`].
+pub struct Drain<'a, T> {
+    /// The underlying [`Dynamic`] being drained from.
+    underlying: &'a mut Dynamic<T>,
+
+    /// The index range of elements being drained.
+    range: core::ops::Range<usize>,
+
+    /// The index range of elements being drained that have yet to be yielded.
+    next: core::ops::Range<usize>,
+}
+
+impl<T> Drain<'_, T> {
+    /// Stop draining, keeping elements yet to be yielded in the [`Dynamic`].
+    ///
+    /// Elements already yielded are still removed, but the remaining elements
+    /// are retained in their original order rather than dropped.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let mut drain = instance.drain(..);
+    ///
+    /// assert_eq!(drain.next(), Some(0));
+    /// assert_eq!(drain.next_back(), Some(5));
+    ///
+    /// drain.keep_rest();
+    ///
+    /// assert!(instance.into_iter().eq([1, 2, 3, 4]));
+    /// ```
+    pub fn keep_rest(mut self) {
+        let retained = self.next.len();
+
+        if retained != 0 && self.next.start != self.range.start {
+            let ptr = self.underlying.as_mut_ptr();
+
+            // SAFETY: stays aligned within the allocated object.
+            let destination = unsafe { ptr.add(self.range.start) };
+
+            // SAFETY: stays aligned within the allocated object.
+            let source = unsafe { ptr.add(self.next.start) };
+
+            // SAFETY:
+            // * both ranges are within the initialized elements.
+            // * `copy` handles the ranges overlapping.
+            // * yielded elements were moved out, so nothing is overwritten.
+            unsafe {
+                core::ptr::copy(source, destination, retained);
+            }
+        }
+
+        let Some(gap) = self.range.start.checked_add(retained) else {
+            unreachable!("retained elements are within the range");
+        };
+
+        // Drop now only needs to close the gap of yielded elements.
+        self.range.start = gap;
+        self.next = gap..gap;
+    }
+
+    /// Shift elements to close the gap left by elements within the range.
+    ///
+    /// # Safety
+    /// Every element within the range must have been moved out or dropped.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(1) memory.
+    unsafe fn close_gap(&mut self) {
+        if self.range.end == self.underlying.initialized {
+            if let Some(capacity) = self.underlying.back_capacity.checked_add(self.range.len()) {
+                self.underlying.back_capacity = capacity;
+            } else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            }
+        } else if self.range.start == 0 {
+            if let Some(capacity) = self.underlying.front_capacity.checked_add(self.range.len()) {
+                self.underlying.front_capacity = capacity;
+            } else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            }
+        } else {
+            let leading = self.range.start;
+
+            let Some(trailing) = self.underlying.initialized.checked_sub(self.range.end) else {
+                unreachable!("not enough initialized elements to remove");
+            };
+
+            let Ok(offset) = isize::try_from(self.range.len()) else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            };
+
+            let only_front_capacity =
+                self.underlying.front_capacity != 0 && self.underlying.back_capacity == 0;
+            let only_back_capacity =
+                self.underlying.front_capacity == 0 && self.underlying.back_capacity != 0;
+
+            if only_front_capacity || (!only_back_capacity && trailing > leading) {
+                let Some(offset) = offset.checked_neg() else {
+                    unreachable!("negative amount of elements");
+                };
+
+                let Some(end) = self.range.end.checked_add(trailing) else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                };
+
+                // SAFETY: [front capacity] [remain] [drained] [shift] [back capacity]
+                unsafe {
+                    self.underlying.shift_range(self.range.end..end, offset);
+                }
+
+                self.underlying.back_capacity = self.range.len();
+            } else {
+                // SAFETY: [front capacity] [shift] [drained] [remain] [back capacity]
+                unsafe {
+                    self.underlying.shift_range(0..self.range.start, offset);
+                }
+
+                self.underlying.front_capacity = self.range.len();
+            }
+        }
+
+        if let Some(decreased) = self.underlying.initialized.checked_sub(self.range.len()) {
+            self.underlying.initialized = decreased;
+        }
+    }
+}
+
+impl<T> Drop for Drain<'_, T> {
+    /// Drops remaining elements and fixes the underlying [`Dynamic`] buffer.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// let mut drain = instance.drain(2..=4);
+    ///
+    /// drain.next();      // Consumes the element with value `2`.
+    /// drain.next_back(); // Consumes the element with value `4`.
+    ///
+    /// core::mem::drop(drain); // Drops the element with value '3'.
+    ///
+    /// assert!(instance.into_iter().eq([0, 1, 5, 6])); // Remaining elements.
+    /// ```
+    fn drop(&mut self) {
+        /// Closes the gap even if the destructor of an element panics.
+        struct CloseGap<'a, 'b, T> {
+            /// The drain whose remaining elements are being dropped.
+            drain: &'a mut Drain<'b, T>,
+        }
+
+        impl<T> Drop for CloseGap<'_, '_, T> {
+            /// Fix the underlying [`Dynamic`] buffer.
+            fn drop(&mut self) {
+                // SAFETY: every element within the range was yielded or dropped.
+                unsafe {
+                    self.drain.close_gap();
+                }
+            }
+        }
+
+        if self.underlying.initialized == 0 {
+            debug_assert_eq!(self.range, 0..0, "drained uninitialized elements");
+            return;
+        }
+
+        let remaining = {
+            let ptr = self.underlying.as_mut_ptr();
+
+            // SAFETY: stays aligned within the allocated object.
+            let ptr = unsafe { ptr.add(self.next.start) };
+
+            core::ptr::slice_from_raw_parts_mut(ptr, self.next.len())
+        };
+
+        // The elements yet to be yielded are instead dropped below.
+        self.next = self.next.end..self.next.end;
+
+        let guard = CloseGap { drain: self };
+
+        // SAFETY:
+        // * the elements have yet to be yielded => initialized => safe drop.
+        // * should one panic, the remaining elements are still dropped.
+        unsafe {
+            core::ptr::drop_in_place(remaining);
+        }
+
+        drop(guard);
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    /// Obtain the next element, if there are any left.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut actual = underlying.drain(..);
+    ///
+    /// assert_eq!(actual.next(), Some(0));
+    /// assert_eq!(actual.next(), Some(1));
+    /// assert_eq!(actual.next(), Some(2));
+    /// assert_eq!(actual.next_back(), Some(5));
+    /// assert_eq!(actual.next_back(), Some(4));
+    /// assert_eq!(actual.next_back(), Some(3));
+    /// assert_eq!(actual.next(), None);
+    /// assert_eq!(actual.next_back(), None);
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.next().map_or_else(
+            || None,
+            |index| {
+                let ptr = self.underlying.as_mut_ptr().cast::<MaybeUninit<T>>();
+
+                // SAFETY: stays aligned within the allocated object.
+                let ptr = unsafe { ptr.add(index) };
+
+                // SAFETY: index in bounds => aligned within the allocated object.
+                let element = unsafe { &mut *ptr };
+
+                // SAFETY:
+                // * owned memory => pointer is valid for reads.
+                // * Underlying `T` is initialized.
+                // * This takes ownership (moved out of the buffer).
+                Some(unsafe { element.assume_init_read() })
+            },
+        )
+    }
+
+    /// Query how many elements have yet to be yielded.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    /// use rust::structure::collection::linear::List;
+    ///
+    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut actual = underlying.drain(..);
+    ///
+    /// assert_eq!(actual.size_hint(), (6, Some(6)));
+    /// ```
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.next.len(), Some(self.next.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    /// Obtain the final element, if there are any left.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut actual = underlying.drain(..);
+    ///
+    /// assert_eq!(actual.next_back(), Some(5));
+    /// assert_eq!(actual.next_back(), Some(4));
+    /// assert_eq!(actual.next_back(), Some(3));
+    /// assert_eq!(actual.next_back(), Some(2));
+    /// assert_eq!(actual.next_back(), Some(1));
+    /// assert_eq!(actual.next_back(), Some(0));
+    /// assert_eq!(actual.next_back(), None);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next.next_back().map_or_else(
+            || None,
+            |index| {
+                let ptr = self.underlying.as_mut_ptr().cast::<MaybeUninit<T>>();
+
+                // SAFETY: stays aligned within the allocated object.
+                let ptr = unsafe { ptr.add(index) };
+
+                // SAFETY: index in bounds => aligned within the allocated object.
+                let element = unsafe { &mut *ptr };
+
+                // SAFETY:
+                // * owned memory => pointer is valid for reads.
+                // * Underlying `T` is initialized.
+                // * This takes ownership (moved out of the buffer).
+                Some(unsafe { element.assume_init_read() })
+            },
+        )
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> core::iter::FusedIterator for Drain<'_, T> {}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Drain<'_, T> {
+    /// List the elements being drained.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+
+        let slice = {
+            // SAFETY: index in bounds => aligned within the allocated object.
+            let ptr = unsafe { self.underlying.as_ptr().add(self.next.start) };
+
+            // SAFETY: points to yet to be yielded slice.
+            unsafe { core::slice::from_raw_parts(ptr, self.next.len()) }
+        };
+
+        list.entries(slice).finish()
+    }
+}
+
+/// [`Iterator`] to yield elements matching a predicate from [`Dynamic`].
+///
+/// See [`Dynamic::withdraw`].
+struct Withdraw<'a, T, F: FnMut(&T) -> bool> {
+    /// The underlying [`Dynamic`] begin withdrawn from.
+    underlying: &'a mut Dynamic<T>,
+
+    /// The predicate based upon which elements are withdrawn.
+    predicate: F,
+
+    /// Where to write the next retained element to.
+    retained: NonNull<T>,
+
+    /// How many element are left to query with the predicate.
+    remaining: usize,
+
+    /// The next (front) element to query with the predicate.
+    next_front: NonNull<T>,
+
+    /// The next (back) element to query with the predicate.
+    next_back: NonNull<T>,
+
+    /// The number of retained elements at the end because of `next_back`.
+    trailing: usize,
+}
+
+impl<T, F: FnMut(&T) -> bool> Drop for Withdraw<'_, T, F> {
+    /// Drops remaining elements and fixes the underlying [`Dynamic`] buffer.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let mut withdraw = instance.withdraw(|element| element % 2 == 0);
+    ///
+    /// // Consumes the element with value `0`.
+    /// assert_eq!(withdraw.next(), Some(0));
+    ///
+    /// // Consumes the element with value `4`.
+    /// assert_eq!(withdraw.next_back(), Some(4));
+    ///
+    /// // Drops the element with value '2'.
+    /// drop(withdraw);
+    ///
+    /// // Retained elements.
+    /// assert!(instance.into_iter().eq([1, 3, 5]));
+    /// ```
+    fn drop(&mut self) {
+        /// Retains elements yet to be queried even if the destructor of a
+        /// withdrawn element panics.
+        struct Retain<'a, 'b, T, F: FnMut(&T) -> bool> {
+            /// The withdraw whose remaining elements are being dropped.
+            withdraw: &'a mut Withdraw<'b, T, F>,
+        }
+
+        impl<T, F: FnMut(&T) -> bool> Drop for Retain<'_, '_, T, F> {
+            /// Shift elements yet to be queried and trailing retained elements.
+            fn drop(&mut self) {
+                let withdraw = &mut *self.withdraw;
+
+                // Once every element is queried, this is only those trailing.
+                let Some(count) = withdraw.remaining.checked_add(withdraw.trailing) else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                };
+
+                if count > 0 {
+                    // SAFETY:
+                    // * owned memory => source/destination valid for read/writes.
+                    // * no aliasing restrictions => source and destination can overlap.
+                    // * underlying buffer is aligned => both pointers are aligned.
+                    unsafe {
+                        core::ptr::copy(
+                            withdraw.next_front.as_ptr(),
+                            withdraw.retained.as_ptr(),
+                            count,
+                        );
+                    }
+                }
+            }
+        }
+
+        let guard = Retain { withdraw: self };
+
+        // Drop all remaining elements to withdraw.
+        guard.withdraw.for_each(drop);
+
+        drop(guard);
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> Iterator for Withdraw<'_, T, F> {
+    type Item = T;
+
+    /// Obtain the next element, if there are any left.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut actual = underlying.withdraw(|element| element % 2 == 0);
+    ///
+    /// assert_eq!(actual.next(), Some(0));
+    /// assert_eq!(actual.next(), Some(2));
+    /// assert_eq!(actual.next(), Some(4));
+    /// assert_eq!(actual.next(), None);
+    /// ```
+    fn next(&mut self) -> Option<Self::Item> {
+        let first_retained = self.next_front;
+        let mut consecutive_retained = 0;
+
+        // SAFETY:
+        // * owned memory => source/destination valid for read/writes.
+        // * no aliasing restrictions => source and destination can overlap.
+        // * underlying buffer is aligned => both pointers are aligned.
+        let shift_retained = |src: *mut T, dst: *mut T, count| unsafe {
+            // Shift the current run of retained elements to the left.
+            core::ptr::copy(src, dst, count);
+        };
+
+        while self.remaining != 0 {
+            if let Some(remaining) = self.remaining.checked_sub(1) {
+                self.remaining = remaining;
+            } else {
+                unreachable!("no remaining element");
+            }
+
+            // SAFETY: the element is initialized.
+            let current = unsafe { self.next_front.as_ref() };
+
+            self.next_front = {
+                // SAFETY: aligned within the allocated object, or one byte past.
+                let ptr = unsafe { self.next_front.as_ptr().add(1) };
+
+                // SAFETY: `head` is not null => pointer is not null.
+                unsafe { NonNull::new_unchecked(ptr) }
+            };
+
+            if (self.predicate)(current) {
+                // SAFETY:
+                // * owned memory => pointer is valid for reads.
+                // * Underlying `T` is initialized.
+                // * This takes ownership (moved out of the buffer).
+                let element = unsafe { core::ptr::read(current) };
+
+                if self.underlying.as_ptr() == current {
+                    // Will not shift, instead increasing front capacity.
+                    if let Some(incremented) = self.underlying.front_capacity.checked_add(1) {
+                        self.underlying.front_capacity = incremented;
+                    } else {
+                        unreachable!("allocated more than `isize::MAX` bytes");
+                    }
+
+                    // The current element will be left uninitialized.
+                    self.retained = {
+                        // SAFETY: at most one byte past the allocated object.
+                        let ptr = unsafe { self.retained.as_ptr().add(1) };
+
+                        // SAFETY: `retained` is not null => pointer is not null.
+                        unsafe { NonNull::new_unchecked(ptr) }
+                    };
+                } else {
+                    // will shift elements to increase back capacity.
+                    if let Some(incremented) = self.underlying.back_capacity.checked_add(1) {
+                        self.underlying.back_capacity = incremented;
+                    } else {
+                        unreachable!("allocated more than `isize::MAX` bytes");
+                    }
+                }
+
+                shift_retained(
+                    first_retained.as_ptr(),
+                    self.retained.as_ptr(),
+                    consecutive_retained,
+                );
+
+                self.retained = {
+                    // SAFETY: next uninitialized element, or one byte past.
+                    let ptr = unsafe { self.retained.as_ptr().add(consecutive_retained) };
+
+                    // SAFETY: `retained` is not null => pointer is not null.
+                    unsafe { NonNull::new_unchecked(ptr) }
+                };
+
+                if let Some(decremented) = self.underlying.initialized.checked_sub(1) {
+                    self.underlying.initialized = decremented;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+
+                return Some(element);
+            }
+
+            if let Some(incremented) = consecutive_retained.checked_add(1) {
+                consecutive_retained = incremented;
+            } else {
+                unreachable!("allocated more than `isize::MAX` bytes")
+            }
+        }
+
+        // The above loop will exit whenever there are no more remaining
+        // elements to query with the predicate. However, this means the loop
+        // may iterate through a string of elements to retain at the end of the
+        // buffer before exhausting elements to query. In such a circumstance,
+        // there is no element at the end to withdraw hence the loop will exit
+        // without shifting these elements to align with previously retained
+        // elements. Nevertheless, previous iterations of the loop ensure the
+        // pointer and counter denote a valid range of retained elements (if
+        // any) so they can still be shifted before returning none.
+        shift_retained(
+            first_retained.as_ptr(),
+            self.retained.as_ptr(),
+            consecutive_retained,
+        );
+
+        self.retained = {
+            // SAFETY: at most one byte past the allocated object.
+            let ptr = unsafe { self.retained.as_ptr().add(consecutive_retained) };
+
+            // SAFETY: `retained` is not null => pointer is not null.
+            unsafe { NonNull::new_unchecked(ptr) }
+        };
+
+        None
+    }
+
+    /// Query how many elements can be yielded.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let instance = underlying.withdraw(|element| element % 2 == 0);
+    ///
+    /// assert_eq!(instance.size_hint(), (0, Some(6)));
+    /// ```
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> DoubleEndedIterator for Withdraw<'_, T, F> {
+    /// Obtain the next element, if there are any left.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::List;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let mut actual = underlying.withdraw(|element| element % 2 == 0);
+    ///
+    /// assert_eq!(actual.next_back(), Some(4));
+    /// assert_eq!(actual.next_back(), Some(2));
+    /// assert_eq!(actual.next_back(), Some(0));
+    /// assert_eq!(actual.next_back(), None);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.remaining != 0 {
+            if let Some(decremented) = self.remaining.checked_sub(1) {
+                self.remaining = decremented;
+            } else {
+                unreachable!("no remaining element");
+            }
+
+            // SAFETY: the element is initialized.
+            let current = unsafe { self.next_back.as_ref() };
+
+            // Do _NOT_ moved the pointer _before_ the allocated object.
+            if self.remaining != 0 {
+                self.next_back = {
+                    // SAFETY: aligned within the allocated object.
+                    let ptr = unsafe { self.next_back.as_ptr().sub(1) };
+
+                    // SAFETY: `retained` is not null => pointer is not null.
+                    unsafe { NonNull::new_unchecked(ptr) }
+                };
+            }
+
+            if (self.predicate)(current) {
+                // SAFETY:
+                // * owned memory => pointer is valid for reads.
+                // * Underlying `T` is initialized.
+                // * This takes ownership (moved out of the buffer).
+                let element = unsafe { core::ptr::read(current) };
+
+                if let Some(decremented) = self.underlying.initialized.checked_sub(1) {
+                    self.underlying.initialized = decremented;
+                } else {
+                    unreachable!("no initialized element to remove");
+                }
+
+                if let Some(incremented) = self.underlying.back_capacity.checked_add(1) {
+                    self.underlying.back_capacity = incremented;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+
+                let src = {
+                    let current: *const T = current;
+
+                    // SAFETY: stays aligned within the allocated object.
+                    unsafe { current.add(1) }.cast_mut()
+                };
+
+                let dst = {
+                    let current: *const T = current;
+                    current.cast_mut()
+                };
+
+                // SAFETY:
+                // * owned memory => source/destination valid for read/writes.
+                // * no aliasing restrictions => source and destination can overlap.
+                // * underlying buffer is aligned => both pointers are aligned.
+                unsafe {
+                    core::ptr::copy(src, dst, self.trailing);
+                }
+
+                return Some(element);
+            }
+
+            if let Some(incremented) = self.trailing.checked_add(1) {
+                self.trailing = incremented;
+            } else {
+                unreachable!("allocated more than `isize::MAX`");
+            }
+        }
+
+        None
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> core::iter::FusedIterator for Withdraw<'_, T, F> {}
+
+impl<T, F: FnMut(&T) -> bool> core::fmt::Debug for Withdraw<'_, T, F> {
+    /// Output what indexes are being pointed to in the underlying buffer.
+    ///
+    /// Note that these indexes are _NOT_ based on the first initialized
+    /// element, but rather absolute relative to the beginning of the
+    /// allocated object.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let origin = self.underlying.buffer.as_ptr().cast::<T>();
+
+        // SAFETY: both pointers are aligned within the allocated object.
+        let head = unsafe { self.next_front.as_ptr().offset_from(origin) };
+
+        // SAFETY: both pointers are aligned within the allocated object.
+        let retained = unsafe { self.retained.as_ptr().offset_from(origin) };
+
+        // SAFETY: both pointers are aligned within the allocated object.
+        let tail = unsafe { self.next_back.as_ptr().offset_from(origin) };
+
+        f.debug_struct("Withdraw")
+            .field("head index", &head)
+            .field("tail index", &tail)
+            .field("remaining elements", &self.remaining)
+            .field("retained index", &retained)
+            .field("trailing elements", &self.trailing)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reference to an element of a [`Dynamic`] ordered via a shared comparison.
+///
+/// This allows sorting algorithms requiring [`Ord`] to use custom comparisons.
+struct Ordered<'a, T, F: FnMut(&T, &T) -> core::cmp::Ordering> {
+    /// Where the element is located before sorting.
+    index: usize,
+
+    /// The element being ordered.
+    element: &'a T,
+
+    /// The comparison defining the order of elements.
+    compare: &'a core::cell::RefCell<F>,
+}
+
+impl<T, F: FnMut(&T, &T) -> core::cmp::Ordering> Clone for Ordered<'_, T, F> {
+    /// Copy the reference to the same element and comparison.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn clone(&self) -> Self {
+        Self {
+            index: self.index,
+            element: self.element,
+            compare: self.compare,
+        }
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> core::cmp::Ordering> PartialEq for Ordered<'_, T, F> {
+    /// Query if the elements compare equal.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory, excluding the
+    /// comparison itself.
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == core::cmp::Ordering::Equal
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> core::cmp::Ordering> Eq for Ordered<'_, T, F> {}
+
+impl<T, F: FnMut(&T, &T) -> core::cmp::Ordering> PartialOrd for Ordered<'_, T, F> {
+    /// Compare the elements via the shared comparison.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory, excluding the
+    /// comparison itself.
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> core::cmp::Ordering> Ord for Ordered<'_, T, F> {
+    /// Compare the elements via the shared comparison.
+    ///
+    /// # Panics
+    /// If the comparison itself attempts to compare.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory, excluding the
+    /// comparison itself.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.compare.borrow_mut())(self.element, other.element)
+    }
+}
+
+/// [`Iterator`] to yield elements within an index range of [`Dynamic`]
+/// which match some predicate.
+///
+/// The elements before the range are initialized as usual, but those from
+/// the start of the range onward are accounted for as back capacity until
+/// this is dropped. Retained elements are shifted left over those removed
+/// as the predicate is tested, and when dropped, those yet to be tested are
+/// shifted alongside all elements after the range.
+///
+/// See [`Dynamic::extract_if`].
+struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    /// The underlying [`Dynamic`] whose elements are being extracted.
+    underlying: &'a mut Dynamic<T>,
+
+    /// The predicate based on which to extract elements.
+    predicate: F,
+
+    /// The index of the next element to test.
+    next: usize,
+
+    /// The index one-past the last element to test.
+    end: usize,
+
+    /// The number of initialized elements before any were extracted.
+    original: usize,
+
+    /// The number of elements extracted so far.
+    removed: usize,
+}
+
+impl<T, F: FnMut(&mut T) -> bool> ExtractIf<'_, T, F> {
+    /// Obtain a pointer to the element at `index` in the original layout.
+    ///
+    /// # Safety
+    /// The `index` must be within the original initialized elements.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    unsafe fn element(&mut self, index: usize) -> *mut T {
+        let Some(offset) = self.underlying.front_capacity.checked_add(index) else {
+            unreachable!("allocated more than `isize::MAX` bytes");
+        };
+
+        // SAFETY: caller promises this stays within the allocated object.
+        unsafe { self.underlying.buffer.as_ptr().add(offset).cast::<T>() }
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> Drop for ExtractIf<'_, T, F> {
+    /// Retain the elements yet to be tested.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    fn drop(&mut self) {
+        let Some(remaining) = self.original.checked_sub(self.next) else {
+            unreachable!("tested more elements than exist");
+        };
+
+        if self.removed > 0 && remaining > 0 {
+            // SAFETY: the next element to test is initialized.
+            let source = unsafe { self.element(self.next) };
+
+            // SAFETY: at least this many elements were removed before it.
+            let destination = unsafe { source.sub(self.removed) };
+
+            // SAFETY:
+            // * both ranges are within the allocated object.
+            // * ranges may overlap => no aliasing restrictions.
+            unsafe {
+                core::ptr::copy(source, destination, remaining);
+            }
+        }
+
+        let Some(retained) = self.original.checked_sub(self.removed) else {
+            unreachable!("removed more elements than exist");
+        };
+
+        let Some(suffix) = retained.checked_sub(self.underlying.initialized) else {
+            unreachable!("elements before the range are retained");
+        };
+
+        if let Some(capacity) = self.underlying.back_capacity.checked_sub(suffix) {
+            self.underlying.back_capacity = capacity;
+        } else {
+            unreachable!("suffix was accounted for as back capacity");
+        }
+
+        self.underlying.initialized = retained;
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, T, F> {
+    type Item = T;
+
+    /// Obtain the next element within the range matching the predicate.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < self.end {
+            // SAFETY: the element is within the range, hence initialized.
+            let ptr = unsafe { self.element(self.next) };
+
+            // SAFETY: the element is initialized and not otherwise borrowed.
+            let element = unsafe { &mut *ptr };
+
+            let extracted = (self.predicate)(element);
+
+            if let Some(incremented) = self.next.checked_add(1) {
+                self.next = incremented;
+            } else {
+                unreachable!("allocated more than `isize::MAX` bytes");
+            }
+
+            if extracted {
+                if let Some(incremented) = self.removed.checked_add(1) {
+                    self.removed = incremented;
+                } else {
+                    unreachable!("allocated more than `isize::MAX` bytes");
+                }
+
+                // SAFETY:
+                // * owned memory => pointer is valid for reads.
+                // * Underlying `T` is initialized.
+                // * This takes ownership (moved out of the buffer).
+                return Some(unsafe { ptr.read() });
+            }
+
+            if self.removed > 0 {
+                // SAFETY: at least this many elements were removed before it.
+                let destination = unsafe { ptr.sub(self.removed) };
+
+                // SAFETY:
+                // * the destination was moved out of, so is uninitialized.
+                // * distinct elements => ranges do not overlap.
+                unsafe {
+                    core::ptr::copy_nonoverlapping(ptr, destination, 1);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Query how many elements could be yielded.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end.saturating_sub(self.next)))
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> core::iter::FusedIterator for ExtractIf<'_, T, F> {}
+
+/// [`Iterator`] to yield every element of [`Dynamic`] in ascending order.
+///
+/// The underlying elements are kept in min-heap order, the root of which is
+/// swapped to the back to be yielded.
+///
+/// See [`Dynamic::drain_sorted`].
+struct DrainSorted<'a, T: Ord> {
+    /// The underlying [`Dynamic`] being drained from.
+    underlying: &'a mut Dynamic<T>,
+}
+
+impl<T: Ord> Drop for DrainSorted<'_, T> {
+    /// Drop the elements yet to be yielded.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    fn drop(&mut self) {
+        self.underlying.clear();
+    }
+}
+
+impl<T: Ord> Iterator for DrainSorted<'_, T> {
+    type Item = T;
+
+    /// Obtain the least element yet to be yielded.
+    ///
+    /// # Performance
+    /// This method takes O(log N) time and consumes O(1) memory.
+    fn next(&mut self) -> Option<Self::Item> {
+        let last = self.underlying.initialized.checked_sub(1)?;
+
+        self.underlying.as_mut_slice().swap(0, last);
+
+        let element = self.underlying.pop_back();
+
+        if self.underlying.initialized != 0 {
+            heap::sift_down_by(self.underlying.as_mut_slice(), 0, |left, right| {
+                right.cmp(left)
+            });
+        }
+
+        element
+    }
+
+    /// Query how many elements have yet to be yielded.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.underlying.initialized,
+            Some(self.underlying.initialized),
+        )
+    }
+}
+
+impl<T: Ord> ExactSizeIterator for DrainSorted<'_, T> {}
+
+impl<T: Ord> core::iter::FusedIterator for DrainSorted<'_, T> {}
+
+/// Error type for recoverable allocation failure.
+#[derive(Debug, Clone, Copy)]
+pub struct FailedAllocation;
+
+impl core::fmt::Display for FailedAllocation {
+    /// Write a human-facing description of the error.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "memory allocation failed")
+    }
+}
+
+impl core::error::Error for FailedAllocation {}
+
+/// Error type for invalid index parameters.
+#[derive(Debug, Clone, Copy)]
+pub struct OutOfBounds;
+
+impl core::fmt::Display for OutOfBounds {
+    /// Write a human-facing description of the error.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "index is outside the bounds of initialized elements")
+    }
+}
+
+impl core::error::Error for OutOfBounds {}
+
+/// Error type for invalid byte representations of elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromBytesError {
+    /// The bytes do not start at an address aligned for the element type.
+    Misaligned,
+
+    /// The number of bytes is not a multiple of the element size.
+    InvalidLength,
+
+    /// Memory allocation failed.
+    FailedAllocation,
+}
+
+impl core::fmt::Display for FromBytesError {
+    /// Write a human-facing description of the error.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            FromBytesError::Misaligned => write!(f, "bytes are not aligned for the element type"),
+            FromBytesError::InvalidLength => {
+                write!(f, "number of bytes is not a multiple of the element size")
+            }
+            FromBytesError::FailedAllocation => core::fmt::Display::fmt(&FailedAllocation, f),
+        }
+    }
+}
+
+impl core::error::Error for FromBytesError {}
+
+/// Marker for "plain old data" which can be reinterpreted as bytes.
+///
+/// This enables [`Dynamic::as_bytes`] and [`Dynamic::from_bytes`] without
+/// depending upon an external crate such as `bytemuck`.
+///
+/// # Safety
+/// Implementors must have no padding bytes, and every bit pattern of the
+/// appropriate size must be a valid instance.
+pub unsafe trait Pod: Copy {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for u8 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for u16 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for u32 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for u64 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for u128 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for usize {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for i8 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for i16 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for i32 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for i64 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for i128 {}
+
+// SAFETY: integers have no padding and any bit pattern is valid.
+unsafe impl Pod for isize {}
+
+// SAFETY: floating point has no padding and any bit pattern is valid.
+unsafe impl Pod for f32 {}
+
+// SAFETY: floating point has no padding and any bit pattern is valid.
+unsafe impl Pod for f64 {}
+
+/// Error type for recoverable insertion failure, yielding the element back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryInsertError<T> {
+    /// The index was outside the bounds of initialized elements.
+    OutOfBounds(T),
+
+    /// Memory (re)allocation failed.
+    FailedAllocation(T),
+}
+
+impl<T> TryInsertError<T> {
+    /// Move out the element which could not be inserted.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let mut instance = Dynamic::<usize>::default();
+    ///
+    /// let error = instance.try_insert(1, 0).expect_err("out of bounds");
+    ///
+    /// assert_eq!(error.into_inner(), 0);
+    /// ```
+    pub fn into_inner(self) -> T {
+        match self {
+            TryInsertError::OutOfBounds(element) | TryInsertError::FailedAllocation(element) => {
+                element
+            }
+        }
+    }
+}
+
+impl<T> core::fmt::Display for TryInsertError<T> {
+    /// Write a human-facing description of the error.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            TryInsertError::OutOfBounds(_) => core::fmt::Display::fmt(&OutOfBounds, f),
+            TryInsertError::FailedAllocation(_) => core::fmt::Display::fmt(&FailedAllocation, f),
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> core::error::Error for TryInsertError<T> {
+    /// The underlying reason the element could not be inserted.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match *self {
+            TryInsertError::OutOfBounds(_) => Some(&OutOfBounds),
+            TryInsertError::FailedAllocation(_) => Some(&FailedAllocation),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::undocumented_unsafe_blocks,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::assertions_on_result_states,
+    clippy::indexing_slicing
+)]
+mod test {
+    use super::*;
+
+    /// Mock element for drop tests.
+    #[derive(Debug, Clone)]
+    struct Droppable {
+        /// A shared counter for the number of elements dropped.
+        counter: alloc::rc::Rc<core::cell::RefCell<usize>>,
+    }
+
+    impl Drop for Droppable {
+        /// Increment the shared counter upon drop.
+        fn drop(&mut self) {
+            _ = self.counter.replace_with(|old| old.wrapping_add(1));
+        }
+    }
+
+    /// Mock element for drop tests whose destructor might panic.
+    #[derive(Debug)]
+    struct PanicOnDrop {
+        /// A shared counter for the number of elements dropped.
+        counter: alloc::rc::Rc<core::cell::RefCell<usize>>,
+
+        /// If the destructor should panic after incrementing the counter.
+        panics: bool,
+    }
+
+    impl Drop for PanicOnDrop {
+        /// Increment the shared counter upon drop, then maybe panic.
+        fn drop(&mut self) {
+            _ = self.counter.replace_with(|old| old.wrapping_add(1));
+
+            assert!(!self.panics, "intentional panic");
+        }
+    }
+
+    std::thread_local! {
+        /// How many instances of [`ZeroSizeDroppable`] have been dropped.
+        static ZERO_SIZE_DROPPED: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+    }
+
+    /// Zero-size mock element for drop tests, counting drops per thread.
+    #[derive(Debug)]
+    struct ZeroSizeDroppable;
+
+    impl Drop for ZeroSizeDroppable {
+        /// Increment the thread-local counter upon drop.
+        fn drop(&mut self) {
+            ZERO_SIZE_DROPPED.set(ZERO_SIZE_DROPPED.get().wrapping_add(1));
+        }
+    }
+
+    /// Construct [`PanicOnDrop`] elements where only that at `panicking` panics.
+    fn panic_on_drop(
+        counter: &alloc::rc::Rc<core::cell::RefCell<usize>>,
+        elements: usize,
+        panicking: usize,
+    ) -> Dynamic<PanicOnDrop> {
+        (0..elements)
+            .map(|index| PanicOnDrop {
+                counter: alloc::rc::Rc::clone(counter),
+                panics: index == panicking,
+            })
+            .collect()
+    }
+
+    mod method {
+        use super::*;
+
+        mod with_capacity {
+            use super::*;
+
+            #[test]
+            fn excessive_capacity_fails_gracefully() {
+                assert!(Dynamic::<usize>::with_capacity(usize::MAX).is_err());
+                assert!(Dynamic::<usize>::with_capacity(usize::MAX / 2).is_err());
+            }
+
+            #[test]
+            fn increases_capacity() {
+                let actual = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity(), 256);
+                assert_eq!(actual.capacity_front(), 256);
+                assert_eq!(actual.capacity_back(), 256);
+            }
+
+            #[test]
+            fn allocates_memory() {
+                let actual = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                for index in 0..actual.capacity() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn does_not_initialize_elements() {
+                let actual = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                assert_eq!(actual.initialized, 0);
+            }
+
+            #[test]
+            fn zero_capacity_cannot_fail() {
+                let actual = Dynamic::<usize>::with_capacity(0);
+
+                assert!(actual.is_ok());
+            }
+
+            #[test]
+            fn zero_size_types_cannot_fail() {
+                let capacity = usize::try_from(isize::MAX).unwrap();
+
+                let actual = Dynamic::<()>::with_capacity(capacity);
+
+                assert!(actual.is_ok());
+            }
+        }
+
+        mod from_nested {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                let actual = Dynamic::<usize>::from_nested(Dynamic::<Dynamic<usize>>::default());
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn flattens_in_order() {
+                let actual = Dynamic::from_nested([0..3, 3..6]);
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn allocates_once_when_exact_size() {
+                let actual = Dynamic::from_nested([0..3, 0..0, 3..6]);
+
+                assert_eq!(actual.len(), 6);
+                assert_eq!(actual.capacity(), 0);
+            }
+
+            #[test]
+            fn inexact_size_hints() {
+                let actual = Dynamic::from_nested(
+                    [0, 1].map(|parity| (0..6).filter(move |element| element % 2 == parity)),
+                );
+
+                assert!(actual.into_iter().eq([0, 2, 4, 1, 3, 5]));
+            }
+
+            #[test]
+            fn does_not_reserve_upper_bound() {
+                let actual = Dynamic::from_nested([(0..256).filter(|element| *element == 0)]);
+
+                assert_eq!(actual.len(), 1);
+                assert!(actual.total_capacity() < 256);
+            }
+        }
+
+        mod from_elem {
+            use super::*;
+
+            /// Counts how many times it has been cloned.
+            #[derive(Debug)]
+            struct Cloned<'a>(&'a core::cell::Cell<usize>);
+
+            impl Clone for Cloned<'_> {
+                fn clone(&self) -> Self {
+                    self.0.set(self.0.get().wrapping_add(1));
+
+                    Self(self.0)
+                }
+            }
+
+            #[test]
+            fn repeats_value() {
+                let actual = Dynamic::from_elem(7, 4);
+
+                assert!(actual.into_iter().eq([7, 7, 7, 7]));
+            }
+
+            #[test]
+            fn allocates_exactly_count() {
+                let actual = Dynamic::from_elem(7, 256);
+
+                assert_eq!(actual.len(), 256);
+                assert_eq!(actual.capacity(), 0);
+            }
+
+            #[test]
+            fn empty_when_count_is_zero() {
+                let actual = Dynamic::from_elem(7, 0);
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn moves_value_into_last_element() {
+                let clones = core::cell::Cell::new(0);
+
+                let actual = Dynamic::from_elem(Cloned(&clones), 4);
+
+                assert_eq!(actual.len(), 4);
+                assert_eq!(clones.get(), 3);
+            }
+
+            #[test]
+            fn drops_value_when_count_is_zero() {
+                let value = alloc::rc::Rc::new(());
+
+                let actual = Dynamic::from_elem(alloc::rc::Rc::clone(&value), 0);
+
+                assert_eq!(actual.len(), 0);
+                assert_eq!(alloc::rc::Rc::strong_count(&value), 1);
+            }
+        }
+
+        mod repeat {
+            use super::*;
+
+            #[test]
+            fn concatenates_n_times() {
+                let actual = Dynamic::from_iter([0, 1]);
+
+                assert!(actual.repeat(3).into_iter().eq([0, 1, 0, 1, 0, 1]));
+            }
+
+            #[test]
+            fn does_not_modify_original() {
+                let actual = Dynamic::from_iter([0, 1]);
+
+                drop(actual.repeat(3));
+
+                assert!(actual.into_iter().eq([0, 1]));
+            }
+
+            #[test]
+            fn allocates_exactly_enough() {
+                let actual = Dynamic::from_iter([0, 1, 2]);
+
+                let actual = actual.repeat(256);
+
+                assert_eq!(actual.len(), 768);
+                assert_eq!(actual.capacity(), 0);
+            }
+
+            #[test]
+            fn empty_when_n_is_zero() {
+                let actual = Dynamic::from_iter([0, 1]);
+
+                assert_eq!(actual.repeat(0).len(), 0);
+            }
+
+            #[test]
+            fn empty_when_empty() {
+                let actual = Dynamic::<usize>::default();
+
+                assert_eq!(actual.repeat(usize::MAX).len(), 0);
+            }
+
+            #[test]
+            #[should_panic = "capacity overflow"]
+            fn panics_when_count_overflows() {
+                let actual = Dynamic::from_iter([(), ()]);
+
+                drop(actual.repeat(usize::MAX));
+            }
+        }
+
+        mod capacity {
+            use super::*;
+
+            #[test]
+            fn only_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity(), 256);
+            }
+
+            #[test]
+            fn only_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity(), 256);
+            }
+
+            #[test]
+            fn front_and_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity(), 512);
+            }
+
+            #[test]
+            fn does_not_invalidate_pointers_for_that_many_additions() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                let ptr = actual.buffer.as_ptr();
+
+                for index in 0..actual.capacity() {
+                    if index % 2 == 0 {
+                        _ = actual.append(index).expect("uses capacity");
+                    } else {
+                        _ = actual.prepend(index).expect("uses capacity");
+                    }
+                }
+
+                assert_eq!(ptr, actual.buffer.as_ptr());
+            }
+        }
+
+        mod capacity_front {
+            use super::*;
+
+            #[test]
+            fn is_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_front(), actual.front_capacity);
+            }
+
+            #[test]
+            fn does_not_count_back_capacity_when_not_empty() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_front(), 0);
+            }
+
+            #[test]
+            fn counts_back_capacity_when_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_front(), 256);
+            }
+
+            #[test]
+            fn does_not_invalidate_pointers_for_that_many_prepends() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                let ptr = actual.buffer.as_ptr();
+
+                for index in 0..actual.capacity_front() {
+                    _ = actual.prepend(index).expect("uses capacity");
+                }
+
+                assert_eq!(ptr, actual.buffer.as_ptr());
+            }
+        }
+
+        mod capacity_back {
+            use super::*;
+
+            #[test]
+            fn is_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_back(), actual.back_capacity);
+            }
+
+            #[test]
+            fn does_not_count_front_capacity_when_not_empty() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_back(), 0);
+            }
+
+            #[test]
+            fn counts_front_capacity_when_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_back(), 256);
+            }
+
+            #[test]
+            fn does_not_invalidate_pointers_for_that_many_appends() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                let ptr = actual.buffer.as_ptr();
+
+                for index in 0..actual.capacity_back() {
+                    _ = actual.append(index).expect("uses capacity");
+                }
+
+                assert_eq!(ptr, actual.buffer.as_ptr());
+            }
+        }
+
+        mod reserve {
+            use super::*;
+
+            #[test]
+            fn excessive_capacity_fails_gracefully() {
+                let mut actual = Dynamic::from_iter([0_usize, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(8).expect("successful allocation");
+
+                for capacity in [
+                    usize::MAX,
+                    usize::MAX - 1,
+                    usize::MAX / 2,
+                    isize::MAX as usize,
+                ] {
+                    assert!(actual.reserve(capacity).is_err());
+                }
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn excessive_zero_size_capacity_fails_gracefully() {
+                let mut actual = Dynamic::from_iter([(), ()]);
+
+                let capacity = usize::try_from(isize::MAX).unwrap();
+
+                assert!(actual.reserve_front(capacity).is_err());
+                assert!(actual.reserve_back(capacity).is_err());
+                assert!(actual.reserve(usize::MAX).is_err());
+
+                assert_eq!(actual.len(), 2);
+            }
+
+            #[test]
+            fn increases_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve(1).expect("successful allocation");
+
+                assert!(actual.capacity() >= 1);
+            }
+
+            #[test]
+            fn increases_capacity_in_powers_of_two() {
+                let mut actual = Dynamic::<()>::default();
+
+                for _ in 0..(isize::BITS - 1) {
+                    let capacity = actual.capacity() + 1;
+
+                    _ = actual.reserve(capacity).expect("successful allocation");
+
+                    let capacity = capacity.checked_next_power_of_two().unwrap();
+
+                    assert_eq!(actual.capacity(), capacity);
+                }
+            }
+
+            #[test]
+            fn does_not_decrease_capacity() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                assert!(actual.reserve(0).is_ok());
+                assert_eq!(actual.capacity(), 256);
+            }
+
+            #[test]
+            fn uses_front_capacity_before_reallocating() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                let existing_allocation = actual.buffer.as_ptr();
+
+                assert!(actual.reserve(256).is_ok());
+
+                assert_eq!(actual.buffer.as_ptr(), existing_allocation);
+            }
+
+            #[test]
+            fn uses_front_capacity_when_empty() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for _ in 0..3 {
+                    assert!(actual.pop_front().is_some());
+                    assert!(actual.pop_back().is_some());
+                }
+
+                assert!(actual.reserve(6).is_ok());
+
+                assert_eq!(actual.front_capacity, 0);
+                assert_eq!(actual.back_capacity, 6);
+            }
+
+            #[test]
+            fn allocates_memory() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve(256).expect("successful allocation");
+
+                for index in 0..actual.capacity() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn reallocates_memory() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual
+                    .reserve(actual.capacity() * 2)
+                    .expect("successful allocation");
+
+                for index in 0..actual.capacity() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn does_not_initialize_elements() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve(256).expect("successful allocation");
+
+                assert_eq!(actual.initialized, 0);
+            }
+
+            #[test]
+            fn does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.reserve(256).expect("successful allocation");
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn zero_capacity_cannot_fail() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.reserve(0).is_ok());
+            }
+
+            #[test]
+            fn zero_size_types_cannot_fail() {
+                let capacity = usize::try_from(isize::MAX).unwrap();
+
+                let mut actual = Dynamic::<()>::default();
+
+                assert!(actual.reserve(capacity).is_ok());
+            }
+        }
+
+        mod reserve_front {
+            use super::*;
+
+            #[test]
+            fn excessive_capacity_fails_gracefully() {
+                let mut actual = Dynamic::from_iter([0_usize, 1, 2, 3, 4, 5]);
+
+                for capacity in [
+                    usize::MAX,
+                    usize::MAX - 1,
+                    usize::MAX / 2,
+                    isize::MAX as usize,
+                ] {
+                    assert!(actual.reserve_front(capacity).is_err());
+                }
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn increases_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_front(), 256);
+            }
+
+            #[test]
+            fn does_not_decrease_capacity() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                assert!(actual.reserve_front(0).is_ok());
+                assert_eq!(actual.capacity_front(), 256);
+            }
+
+            #[test]
+            fn does_not_modify_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_back(), 256);
+            }
+
+            #[test]
+            fn allocates_memory() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                for index in 0..actual.capacity_front() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn reallocates_memory() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual
+                    .reserve_front(actual.capacity_front() * 2)
+                    .expect("successful allocation");
+
+                for index in 0..actual.capacity_front() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn does_not_initialize_elements() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert_eq!(actual.initialized, 0);
+            }
+
+            #[test]
+            fn does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn zero_capacity_cannot_fail() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.reserve_front(0).is_ok());
+            }
+
+            #[test]
+            fn zero_size_types_cannot_fail() {
+                let capacity = usize::try_from(isize::MAX).unwrap();
+
+                let mut actual = Dynamic::<()>::default();
+
+                assert!(actual.reserve_front(capacity).is_ok());
+            }
+        }
+
+        mod reserve_back {
+            use super::*;
+
+            #[test]
+            fn excessive_capacity_fails_gracefully() {
+                let mut actual = Dynamic::from_iter([0_usize, 1, 2, 3, 4, 5]);
+
+                for capacity in [
+                    usize::MAX,
+                    usize::MAX - 1,
+                    usize::MAX / 2,
+                    isize::MAX as usize,
+                ] {
+                    assert!(actual.reserve_back(capacity).is_err());
+                }
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn increases_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_back(), 256);
+            }
+
+            #[test]
+            fn does_not_decrease_capacity() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                assert!(actual.reserve_back(0).is_ok());
+                assert_eq!(actual.capacity_back(), 256);
+            }
+
+            #[test]
+            fn does_not_modify_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.capacity_front(), 256);
+            }
+
+            #[test]
+            fn allocates_memory() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                for index in 0..actual.capacity_back() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn reallocates_memory() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual
+                    .reserve_back(actual.capacity_back() * 2)
+                    .expect("successful allocation");
+
+                for index in 0..actual.capacity_back() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn does_not_initialize_elements() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.initialized, 0);
+            }
+
+            #[test]
+            fn does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn zero_capacity_cannot_fail() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.reserve_back(0).is_ok());
+            }
+
+            #[test]
+            fn zero_size_types_cannot_fail() {
+                let capacity = usize::try_from(isize::MAX).unwrap();
+
+                let mut actual = Dynamic::<()>::default();
+
+                assert!(actual.reserve_back(capacity).is_ok());
+            }
+        }
+
+        mod shrink {
+            use super::*;
+
+            #[test]
+            fn decreases_capacity_when_some() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual.shrink(Some(64)).expect("successful reallocation");
+
+                assert_eq!(actual.capacity(), 64);
+            }
+
+            #[test]
+            fn removes_capacity_when_none() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual.shrink(None).expect("successful reallocation");
+
+                assert_eq!(actual.capacity(), 0);
+            }
+
+            #[test]
+            fn does_not_increase_capacity() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(64).expect("successful allocation");
+
+                assert!(actual.shrink(Some(256)).is_ok());
+                assert_eq!(actual.capacity(), 64);
+            }
+
+            #[test]
+            fn shrinks_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                _ = actual.shrink(None).expect("successful reallocation");
+
+                assert_eq!(actual.capacity_front(), 0);
+            }
+
+            #[test]
+            fn shrinks_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual.shrink(None).expect("successful reallocation");
+
+                assert_eq!(actual.capacity_back(), 0);
+            }
+
+            #[test]
+            fn shrinks_front_and_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual.shrink(None).expect("successful reallocation");
+
+                assert_eq!(actual.capacity_front(), 0);
+                assert_eq!(actual.capacity_back(), 0);
+            }
+
+            #[test]
+            fn reallocates_memory() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual.shrink(Some(128)).expect("successful allocation");
+
+                for index in 0..actual.capacity() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn does_not_initialize_elements() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual.shrink(Some(128)).expect("successful reallocation");
+
+                assert_eq!(actual.initialized, 0);
+            }
+
+            #[test]
+            fn does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.shrink(None).expect("successful reallocation");
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn zero_capacity_cannot_fail() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.shrink(None).is_ok());
+            }
+
+            #[test]
+            fn zero_size_types_cannot_fail() {
+                let mut actual = Dynamic::<()>::with_capacity(256).expect("successful allocation");
+
+                assert!(actual.shrink(None).is_ok());
+            }
+        }
+
+        mod shrink_front {
+            use super::*;
+
+            #[test]
+            fn decreases_front_capacity_when_some() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful reallocation");
+
+                _ = actual
+                    .shrink_front(Some(64))
+                    .expect("successful reallocation");
+
+                assert_eq!(actual.capacity_front(), 64);
+            }
+
+            #[test]
+            fn removes_front_capacity_when_none() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful reallocation");
+
+                _ = actual.shrink_front(None).expect("successful reallocation");
+
+                assert_eq!(actual.capacity_front(), 0);
+            }
+
+            #[test]
+            fn does_not_increase_capacity() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(64).expect("successful allocation");
+
+                assert!(actual.shrink_front(Some(256)).is_ok());
+                assert_eq!(actual.capacity(), 64);
+            }
+
+            #[test]
+            fn does_not_decrease_back_capacity_when_not_empty() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual.shrink_front(None).expect("no-op");
+
+                assert_eq!(actual.capacity_back(), 256);
+            }
+
+            #[test]
+            fn decreases_back_capacity_when_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual.shrink_front(None).expect("successful deallocation");
+
+                assert_eq!(actual.capacity_back(), 0);
+            }
+
+            #[test]
+            fn reallocates_memory() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual
+                    .shrink_front(Some(128))
+                    .expect("successful allocation");
+
+                for index in 0..actual.capacity_front() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn does_not_initialize_elements() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual
+                    .shrink_front(Some(128))
+                    .expect("successful reallocation");
+
+                assert_eq!(actual.initialized, 0);
+            }
+
+            #[test]
+            fn does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.shrink_front(None).expect("successful reallocation");
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn zero_capacity_cannot_fail() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.shrink_front(None).is_ok());
+            }
+
+            #[test]
+            fn zero_size_types_cannot_fail() {
+                let mut actual = Dynamic::<()>::with_capacity(256).expect("successful allocation");
+
+                assert!(actual.shrink_front(None).is_ok());
+            }
+        }
+
+        mod shrink_back {
+            use super::*;
+
+            #[test]
+            fn decreases_back_capacity_when_some() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful reallocation");
+
+                _ = actual
+                    .shrink_back(Some(64))
+                    .expect("successful reallocation");
+
+                assert_eq!(actual.capacity_back(), 64);
+            }
+
+            #[test]
+            fn removes_back_capacity_when_none() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful reallocation");
+
+                _ = actual.shrink_back(None).expect("successful reallocation");
+
+                assert_eq!(actual.capacity_back(), 0);
+            }
+
+            #[test]
+            fn does_not_increase_capacity() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(64).expect("successful allocation");
+
+                assert!(actual.shrink_back(Some(256)).is_ok());
+                assert_eq!(actual.capacity(), 64);
+            }
+
+            #[test]
+            fn does_not_decrease_front_capacity_when_not_empty() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                _ = actual.shrink_back(None).expect("no-op");
+
+                assert_eq!(actual.capacity_front(), 256);
+            }
+
+            #[test]
+            fn decreases_front_capacity_when_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                _ = actual.shrink_back(None).expect("successful deallocation");
+
+                assert_eq!(actual.capacity_front(), 0);
+            }
+
+            #[test]
+            fn reallocates_memory() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual
+                    .shrink_back(Some(128))
+                    .expect("successful allocation");
+
+                for index in 0..actual.capacity_back() {
+                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+
+                    // Ideally, this will seg-fault if unowned memory.
+                    _ = unsafe { &mut *ptr }.write(index);
+                }
+            }
+
+            #[test]
+            fn does_not_initialize_elements() {
+                let mut actual =
+                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                _ = actual
+                    .shrink_back(Some(128))
+                    .expect("successful reallocation");
+
+                assert_eq!(actual.initialized, 0);
+            }
+
+            #[test]
+            fn does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.shrink_back(None).expect("successful reallocation");
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn zero_capacity_cannot_fail() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.shrink_back(None).is_ok());
+            }
+
+            #[test]
+            fn zero_size_types_cannot_fail() {
+                let mut actual = Dynamic::<()>::with_capacity(256).expect("successful allocation");
+
+                assert!(actual.shrink_back(None).is_ok());
+            }
+        }
+
+        mod shrink_keeping {
+            use super::*;
+
+            #[test]
+            fn decreases_capacity_at_both_ends() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual
+                    .shrink_keeping(Some(8), Some(8))
+                    .expect("successful reallocation");
+
+                assert_eq!(actual.capacity_front(), 8);
+                assert_eq!(actual.capacity_back(), 8);
+            }
+
+            #[test]
+            fn removes_capacity_when_none() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual
+                    .shrink_keeping(None, None)
+                    .expect("successful reallocation");
+
+                assert_eq!(actual.capacity_front(), 0);
+                assert_eq!(actual.capacity_back(), 0);
+            }
+
+            #[test]
+            fn retains_freed_front_capacity_at_back() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                _ = actual
+                    .shrink_keeping(Some(8), Some(8))
+                    .expect("successful reallocation");
+
+                assert_eq!(actual.capacity_front(), 8);
+                assert_eq!(actual.capacity_back(), 8);
+            }
+
+            #[test]
+            fn does_not_increase_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(4).expect("successful allocation");
+                _ = actual.reserve_back(4).expect("successful allocation");
+
+                assert!(actual.shrink_keeping(Some(8), Some(8)).is_ok());
+
+                assert_eq!(actual.capacity_front(), 4);
+                assert_eq!(actual.capacity_back(), 4);
+            }
+
+            #[test]
+            fn does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual
+                    .shrink_keeping(Some(8), Some(8))
+                    .expect("successful reallocation");
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn zero_size_types_cannot_fail() {
+                let mut actual = Dynamic::<()>::with_capacity(256).expect("successful allocation");
+
+                assert!(actual.shrink_keeping(Some(8), None).is_ok());
+            }
+        }
+
+        mod compact_back {
+            use super::*;
+
+            #[test]
+            fn moves_freed_capacity_to_back() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6, 7]);
+
+                let removed = actual.drain(3..5).count();
+
+                _ = actual.compact_back();
+
+                assert_eq!(actual.capacity_front(), 0);
+                assert_eq!(actual.capacity_back(), removed);
+            }
+
+            #[test]
+            fn moves_front_capacity_to_back() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                _ = actual.compact_back();
+
+                assert_eq!(actual.capacity_front(), 0);
+                assert_eq!(actual.capacity_back(), 512);
+            }
+
+            #[test]
+            fn does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                _ = actual.compact_back();
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn does_nothing_without_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                let pointer = actual.as_ptr();
+
+                _ = actual.compact_back();
+
+                assert_eq!(actual.as_ptr(), pointer);
+                assert_eq!(actual.capacity_back(), 256);
+            }
+
+            #[test]
+            fn when_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                _ = actual.compact_back();
+
+                assert_eq!(actual.capacity(), 0);
+            }
+        }
+
+        mod resize {
+            use super::*;
+
+            #[test]
+            fn grows_with_clones() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(actual.resize(6, 7).is_ok());
+
+                assert!(actual.into_iter().eq([0, 1, 2, 7, 7, 7]));
+            }
+
+            #[test]
+            fn grows_when_emptied_by_draining() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                drop(actual.drain(..2));
+                drop(actual.drain(..));
+
+                assert!(actual.resize(3, 9).is_ok());
+
+                assert!(actual.into_iter().eq([9, 9, 9]));
+            }
+
+            #[test]
+            fn shrinks_from_back() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.resize(2, 7).is_ok());
+
+                assert!(actual.into_iter().eq([0, 1]));
+            }
+
+            #[test]
+            fn unchanged_when_same_length() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(actual.resize(3, 7).is_ok());
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+        }
+
+        mod resize_with {
+            use super::*;
+
+            #[test]
+            fn grows_in_order() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                let mut next = 3;
+
+                assert!(actual
+                    .resize_with(6, || {
+                        next += 1;
+                        next - 1
+                    })
+                    .is_ok());
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn growing_into_reserved_back_capacity_does_not_reallocate() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(actual.reserve_back(256).is_ok());
+
+                let ptr = actual.as_ptr();
+                let back = actual.capacity_back();
+
+                assert!(actual.resize_with(128, || 0).is_ok());
+
+                assert_eq!(actual.as_ptr(), ptr);
+                assert_eq!(actual.capacity_back(), back - (128 - 3));
+            }
+
+            #[test]
+            fn growing_does_not_consume_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(actual.reserve_front(16).is_ok());
+                assert!(actual.reserve_back(16).is_ok());
+
+                let front = actual.capacity_front();
+
+                assert!(actual.resize_with(19, || 0).is_ok());
+
+                assert_eq!(actual.capacity_front(), front);
+                assert_eq!(actual.capacity_back(), 0);
+            }
+
+            #[test]
+            fn growing_when_empty_consumes_back_capacity() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.reserve_back(16).is_ok());
+
+                assert!(actual.resize_with(16, || 0).is_ok());
+
+                assert_eq!(actual.len(), 16);
+                assert_eq!(actual.capacity(), 0);
+            }
+
+            #[test]
+            fn growing_when_empty_with_only_front_capacity() {
+                let mut actual = Dynamic::from_iter([0_u64, 1, 2]);
+
+                for _ in 0..3 {
+                    assert!(actual.pop_front().is_some());
+                }
+
+                assert_eq!(actual.capacity_front(), 3);
+
+                let mut next = 0;
+
+                assert!(actual
+                    .resize_with(2, || {
+                        next += 1;
+                        next - 1
+                    })
+                    .is_ok());
+
+                assert!(actual.into_iter().eq([0, 1]));
+            }
+
+            #[test]
+            fn growing_allocates_when_insufficient_capacity() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.resize_with(256, || 0).is_ok());
+
+                assert_eq!(actual.len(), 256);
+                assert_eq!(actual.capacity_back(), 0);
+            }
+
+            #[test]
+            fn shrinking_credits_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let front = actual.capacity_front();
+                let back = actual.capacity_back();
+
+                assert!(actual.resize_with(2, || 0).is_ok());
+
+                assert!(actual.iter().eq([0, 1].iter()));
+                assert_eq!(actual.capacity_front(), front);
+                assert_eq!(actual.capacity_back(), back + 4);
+            }
+
+            #[test]
+            fn shrinking_drops_excess_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let mut actual: Dynamic<_> = core::iter::repeat_with(|| Droppable {
+                    counter: alloc::rc::Rc::clone(&dropped),
+                })
+                .take(6)
+                .collect();
+
+                assert!(actual
+                    .resize_with(2, || unreachable!("does not construct when shrinking"))
+                    .is_ok());
+
+                assert_eq!(dropped.take(), 4);
+            }
+
+            #[test]
+            fn zero_size_type() {
+                let mut actual = Dynamic::<()>::default();
+
+                assert!(actual.resize_with(256, || ()).is_ok());
+                assert_eq!(actual.len(), 256);
+
+                assert!(actual.resize_with(128, || ()).is_ok());
+                assert_eq!(actual.len(), 128);
+            }
+
+            #[test]
+            fn errors_when_allocation_fails() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.resize_with(usize::MAX, || 0).is_err());
+                assert_eq!(actual.len(), 0);
+            }
+        }
+
+        mod try_insert {
+            use super::*;
+
+            #[test]
+            fn inserts_at_index() {
+                let mut actual = Dynamic::from_iter([0, 2]);
+
+                assert_eq!(actual.try_insert(1, 1), Ok(&mut 1));
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn out_of_bounds_yields_element() {
+                let counter = alloc::rc::Rc::new(core::cell::RefCell::new(0));
+
+                let mut actual: Dynamic<_> = (0..3)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&counter),
+                    })
+                    .collect();
+
+                let element = Droppable {
+                    counter: alloc::rc::Rc::clone(&counter),
+                };
+
+                let Err(TryInsertError::OutOfBounds(rejected)) = actual.try_insert(4, element)
+                else {
+                    panic!("index is out of bounds");
+                };
+
+                assert_eq!(actual.len(), 3);
+                assert_eq!(counter.take(), 0);
+
+                drop(rejected);
+
+                assert_eq!(counter.take(), 1);
+            }
+        }
+
+        mod try_prepend {
+            use super::*;
+
+            #[test]
+            fn becomes_first() {
+                let mut actual = Dynamic::from_iter([1, 2]);
+
+                assert_eq!(actual.try_prepend(0), Ok(&mut 0));
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+        }
+
+        mod try_append {
+            use super::*;
+
+            #[test]
+            fn becomes_last() {
+                let mut actual = Dynamic::from_iter([0, 1]);
+
+                assert_eq!(actual.try_append(2), Ok(&mut 2));
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+        }
+
+        mod shift {
+            use super::*;
+
+            #[test]
+            fn left_increases_back_capacity_and_decreases_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                for _ in 0..256 {
+                    let front_capacity = actual.front_capacity;
+                    let back_capacity = actual.back_capacity;
+
+                    assert!(actual.shift(-1).is_ok());
+
+                    assert_eq!(actual.front_capacity, front_capacity - 1);
+                    assert_eq!(actual.back_capacity, back_capacity + 1);
+                }
+            }
+
+            #[test]
+            fn left_does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(expected);
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                for _ in 0..256 {
+                    assert!(actual.shift(-1).is_ok());
+
+                    assert!(actual.iter().eq(expected.iter()));
+                }
+            }
+
+            #[test]
+            fn right_increases_front_capacity_and_decreases_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                for _ in 0..256 {
+                    let front_capacity = actual.front_capacity;
+                    let back_capacity = actual.back_capacity;
+
+                    assert!(actual.shift(1).is_ok());
+
+                    assert_eq!(actual.front_capacity, front_capacity + 1);
+                    assert_eq!(actual.back_capacity, back_capacity - 1);
+                }
+            }
+
+            #[test]
+            fn right_does_not_modify_initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(expected);
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                for _ in 0..256 {
+                    assert!(actual.shift(1).is_ok());
+
+                    assert!(actual.iter().eq(expected.iter()));
+                }
+            }
+
+            #[test]
+            fn zero_cannot_fail() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.shift(0).is_ok());
+            }
+
+            #[test]
+            fn errors_when_out_of_bounds() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.shift(-1).is_err());
+                assert!(actual.shift(1).is_err());
+            }
+
+            #[test]
+            fn when_empty() {
+                let mut actual = Dynamic::<()>::default();
+
+                assert!(actual.shift(0).is_ok());
+            }
+        }
+
+        mod shift_elements {
+            use super::*;
+
+            #[test]
+            fn right() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.shift_elements(1..3, 2).is_ok());
+
+                assert!(actual.into_iter().eq([0, 3, 4, 1, 2, 5]));
+            }
+
+            #[test]
+            fn left() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.shift_elements(3..=4, -2).is_ok());
+
+                assert!(actual.into_iter().eq([0, 3, 4, 1, 2, 5]));
+            }
+
+            #[test]
+            fn to_either_end() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.shift_elements(..2, 4).is_ok());
+                assert!(actual.iter().eq([2, 3, 4, 5, 0, 1].iter()));
+
+                assert!(actual.shift_elements(4.., -4).is_ok());
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn does_not_modify_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.reserve_front(4).is_ok());
+                assert!(actual.reserve_back(4).is_ok());
+
+                let front = actual.capacity_front();
+                let back = actual.capacity_back();
+
+                assert!(actual.shift_elements(0..2, 3).is_ok());
+                assert!(actual.shift_elements(4..6, -4).is_ok());
+
+                assert_eq!(actual.capacity_front(), front);
+                assert_eq!(actual.capacity_back(), back);
+            }
+
+            #[test]
+            fn does_not_drop_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let mut actual: Dynamic<_> = core::iter::repeat_with(|| Droppable {
+                    counter: alloc::rc::Rc::clone(&dropped),
+                })
+                .take(6)
+                .collect();
+
+                assert!(actual.shift_elements(1..3, 3).is_ok());
+                assert_eq!(dropped.take(), 0);
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 6);
+            }
+
+            #[test]
+            fn zero_cannot_fail() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.shift_elements(.., 0).is_ok());
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn errors_when_out_of_bounds() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.shift_elements(4..6, 1).is_err());
+                assert!(actual.shift_elements(0..2, -1).is_err());
+                assert!(actual.shift_elements(0..7, 0).is_err());
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            #[allow(clippy::reversed_empty_ranges)]
+            fn errors_when_malformed() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.shift_elements(3..1, 1).is_err());
+            }
+
+            #[test]
+            fn when_empty() {
+                let mut actual = Dynamic::<()>::default();
+
+                assert!(actual.shift_elements(.., 0).is_ok());
+                assert!(actual.shift_elements(.., 1).is_err());
+            }
+        }
+
+        mod remove_via_front {
+            use super::*;
+
+            #[test]
+            fn yields_none_when_out_of_bounds() {
+                let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let actual = underlying.remove_via_front(underlying.len());
+
+                assert_eq!(actual, None);
+            }
+
+            #[test]
+            fn yields_element_when_in_bounds() {
+                let underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for index in 1..underlying.len() {
+                    let mut underlying = underlying.clone();
+
+                    let actual = underlying.remove_via_front(index);
+
+                    assert_eq!(actual, Some(index));
+                }
+            }
+
+            #[test]
+            fn removed_becomes_first_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.remove_via_front(3).expect("element with value '3'");
+
+                assert_eq!(actual[2], 0);
+            }
+
+            #[test]
+            fn does_not_modify_other_elements() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.remove_via_front(1);
+
+                assert!(actual.into_iter().eq([0, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn increases_front_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.remove_via_front(5);
+
+                assert_eq!(actual.capacity_front(), 1);
+            }
+
+            #[test]
+            fn when_front_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let removed = actual.remove_via_front(0);
+
+                assert_eq!(removed, Some(0));
+                assert_eq!(actual.capacity_front(), 1);
+                assert!(actual.into_iter().eq([1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn when_only_one_element() {
+                let mut actual = Dynamic::from_iter([0]);
+
+                let removed = actual.remove_via_front(0);
+
+                assert_eq!(removed, Some(0));
+                assert_eq!(actual.capacity_front(), 1);
+                assert_eq!(actual.len(), 0);
+            }
+        }
+
+        mod remove_via_back {
+            use super::*;
+
+            #[test]
+            fn yields_none_when_out_of_bounds() {
+                let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let actual = underlying.remove_via_back(underlying.len());
+
+                assert_eq!(actual, None);
+            }
+
+            #[test]
+            fn yields_element_when_in_bounds() {
+                let underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for index in 1..underlying.len() {
+                    let mut underlying = underlying.clone();
+
+                    let actual = underlying.remove_via_back(index);
+
+                    assert_eq!(actual, Some(index));
+                }
+            }
+
+            #[test]
+            fn removed_becomes_last_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.remove_via_back(3).expect("element with value '3'");
+
+                assert_eq!(actual[3], 5);
+            }
+
+            #[test]
+            fn does_not_modify_other_elements() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.remove_via_back(4);
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 5]));
+            }
+
+            #[test]
+            fn increases_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.remove_via_back(0);
+
+                assert_eq!(actual.capacity_back(), 1);
+            }
+
+            #[test]
+            fn when_back_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let removed = actual.remove_via_back(5);
+
+                assert_eq!(removed, Some(5));
+                assert_eq!(actual.capacity_back(), 1);
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4]));
+            }
+
+            #[test]
+            fn when_only_one_element() {
+                let mut actual = Dynamic::from_iter([0]);
+
+                let removed = actual.remove_via_back(0);
+
+                assert_eq!(removed, Some(0));
+                assert_eq!(actual.capacity_back(), 1);
+                assert_eq!(actual.len(), 0);
+            }
+        }
+
+        mod drain_step_by {
+            use super::*;
+
+            #[test]
+            fn every_second_element() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.drain_step_by(.., 2).eq([0, 2, 4, 6, 8]));
+                assert!(actual.into_iter().eq([1, 3, 5, 7, 9]));
+            }
+
+            #[test]
+            fn within_range() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.drain_step_by(2..8, 3).eq([2, 5]));
+                assert!(actual.into_iter().eq([0, 1, 3, 4, 6, 7, 8, 9]));
+            }
+
+            #[test]
+            fn step_of_one_is_drain() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.drain_step_by(3..=5, 1).eq([3, 4, 5]));
+                assert!(actual.into_iter().eq([0, 1, 2, 6, 7, 8, 9]));
+            }
+
+            #[test]
+            fn step_larger_than_range() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.drain_step_by(4..6, 100).eq([4]));
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 5, 6, 7, 8, 9]));
+            }
+
+            #[test]
+            fn range_is_clamped() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.drain_step_by(8..256, 2).eq([8]));
+                assert!(actual.drain_step_by(256.., 2).eq([]));
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5, 6, 7, 9]));
+            }
+
+            #[test]
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert_eq!(actual.drain_step_by(.., 2).count(), 0);
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn capacity_is_retained() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                let capacity = actual.capacity();
+
+                drop(actual.drain_step_by(.., 2));
+
+                assert_eq!(actual.len(), 5);
+                assert_eq!(actual.capacity(), capacity + 5);
+            }
+
+            #[test]
+            fn retained_elements_are_contiguous_when_dropped_early() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                let mut drain = actual.drain_step_by(1..9, 2);
+
+                assert_eq!(drain.next(), Some(1));
+
+                drop(drain);
+
+                assert!(actual.into_iter().eq([0, 2, 4, 6, 8, 9]));
+            }
+
+            #[test]
+            fn drops_elements_not_yielded() {
+                let counter = alloc::rc::Rc::new(core::cell::RefCell::new(0));
+
+                let mut actual: Dynamic<_> = (0..10)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&counter),
+                    })
+                    .collect();
+
+                drop(actual.drain_step_by(.., 2));
+
+                assert_eq!(counter.take(), 5);
+                assert_eq!(actual.len(), 5);
+
+                drop(actual);
+
+                assert_eq!(counter.take(), 5);
+            }
+
+            #[test]
+            #[should_panic = "step must be non-zero"]
+            fn panics_when_step_is_zero() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                drop(actual.drain_step_by(.., 0));
+            }
+        }
+
+        mod extract_if {
+            use super::*;
+
+            #[test]
+            fn yields_matching_elements_within_range_in_order() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual
+                    .extract_if(2..8, |element| *element % 2 == 0)
+                    .eq([2, 4, 6]));
+            }
+
+            #[test]
+            fn retains_other_elements_in_order() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                actual
+                    .extract_if(2..8, |element| *element % 2 == 0)
+                    .for_each(drop);
+
+                assert!(actual.into_iter().eq([0, 1, 3, 5, 7, 8, 9]));
+            }
+
+            #[test]
+            fn only_tests_elements_within_range() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                let mut tested = Dynamic::<usize>::default();
+
+                actual
+                    .extract_if(2..8, |element| {
+                        tested.extend([*element]);
+
+                        false
+                    })
+                    .for_each(drop);
+
+                assert!(tested.into_iter().eq(2..8));
+                assert!(actual.into_iter().eq(0..10));
+            }
+
+            #[test]
+            fn predicate_can_mutate_retained_elements() {
+                let mut actual: Dynamic<_> = (0..6).collect();
+
+                actual
+                    .extract_if(.., |element| {
+                        *element *= 10;
+
+                        *element > 30
+                    })
+                    .for_each(drop);
+
+                assert!(actual.into_iter().eq([0, 10, 20, 30]));
+            }
+
+            #[test]
+            fn retains_untested_elements_when_dropped() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                let mut extract = actual.extract_if(2..8, |element| *element % 2 == 0);
+
+                assert_eq!(extract.next(), Some(2));
+
+                drop(extract);
+
+                assert!(actual.into_iter().eq([0, 1, 3, 4, 5, 6, 7, 8, 9]));
+            }
+
+            #[test]
+            fn retains_capacity() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                actual.extract_if(.., |_| true).for_each(drop);
+
+                assert_eq!(actual.len(), 0);
+                assert_eq!(actual.capacity(), 10);
+            }
+
+            #[test]
+            fn clamps_out_of_bounds_range() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.extract_if(8..256, |_| true).eq([8, 9]));
+                assert!(actual.extract_if(256.., |_| true).eq([]));
+                assert!(actual.into_iter().eq(0..8));
+            }
+
+            #[test]
+            fn drops_only_retained_elements() {
+                let counter = alloc::rc::Rc::new(core::cell::RefCell::new(0));
+
+                let mut actual: Dynamic<_> = (0..10)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&counter),
+                    })
+                    .collect();
+
+                let mut index = 0;
+
+                let extracted: Dynamic<_> = actual
+                    .extract_if(.., |_| {
+                        index += 1;
+
+                        index % 2 == 0
+                    })
+                    .collect();
+
+                assert_eq!(counter.take(), 0);
+
+                drop(extracted);
+
+                assert_eq!(counter.take(), 5);
+
+                drop(actual);
+
+                assert_eq!(counter.take(), 5);
+            }
+
+            #[test]
+            fn retains_elements_when_predicate_panics() {
+                let mut actual: Dynamic<_> = (0..10).collect();
+
+                let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                    actual
+                        .extract_if(.., |element| {
+                            assert!(*element != 5, "panics at element five");
+
+                            *element % 2 == 0
+                        })
+                        .for_each(drop);
+                }));
+
+                assert!(result.is_err());
+                assert!(actual.into_iter().eq([1, 3, 5, 6, 7, 8, 9]));
+            }
+
+            #[test]
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(actual.extract_if(.., |_| true).next().is_none());
+                assert_eq!(actual.len(), 0);
+            }
+        }
+
+        mod drain_sorted {
+            use super::*;
+
+            #[test]
+            fn yields_in_ascending_order() {
+                let mut actual = Dynamic::from_iter([3, 1, 2]);
+
+                assert!(actual.drain_sorted().eq([1, 2, 3]));
+            }
+
+            #[test]
+            fn matches_sort() {
+                let mut expected: Dynamic<_> = (0..256_usize)
+                    .map(|index| index.wrapping_mul(97) % 64)
+                    .collect();
+
+                let mut actual = expected.clone();
+
+                expected.sort();
+
+                assert!(actual.drain_sorted().eq(expected));
+            }
+
+            #[test]
+            fn removes_every_element() {
+                let mut actual = Dynamic::from_iter([3, 1, 2]);
+
+                _ = actual.drain_sorted().count();
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn partial_consumption_removes_every_element() {
+                let mut actual = Dynamic::from_iter([5, 3, 1, 4, 0, 2]);
+
+                let mut drain = actual.drain_sorted();
+
+                assert_eq!(drain.next(), Some(0));
+                assert_eq!(drain.next(), Some(1));
+
+                drop(drain);
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn drops_yet_to_be_yielded_elements() {
+                /// Orders by nothing, only to satisfy the bound.
+                #[derive(Debug)]
+                struct Unordered(#[allow(dead_code)] Droppable);
+
+                impl PartialEq for Unordered {
+                    fn eq(&self, _: &Self) -> bool {
+                        true
+                    }
+                }
+
+                impl Eq for Unordered {}
+
+                impl PartialOrd for Unordered {
+                    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                        Some(self.cmp(other))
+                    }
+                }
+
+                impl Ord for Unordered {
+                    fn cmp(&self, _: &Self) -> core::cmp::Ordering {
+                        core::cmp::Ordering::Equal
+                    }
+                }
+
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let mut actual: Dynamic<_> = (0..6)
+                    .map(|_| {
+                        Unordered(Droppable {
+                            counter: alloc::rc::Rc::clone(&dropped),
+                        })
+                    })
+                    .collect();
+
+                let mut drain = actual.drain_sorted();
+
+                drop(drain.next());
+
+                assert_eq!(dropped.take(), 1);
+
+                drop(drain);
+
+                assert_eq!(dropped.take(), 5);
+            }
+
+            #[test]
+            fn retains_capacity() {
+                let mut actual = Dynamic::from_iter([5, 3, 1, 4, 0, 2]);
+
+                let mut drain = actual.drain_sorted();
+
+                _ = drain.next();
+                drop(drain);
+
+                assert_eq!(actual.capacity(), 6);
+            }
+
+            #[test]
+            fn is_exact_size() {
+                let mut actual = Dynamic::from_iter([5, 3, 1, 4, 0, 2]);
+
+                let mut drain = actual.drain_sorted();
+
+                assert_eq!(drain.len(), 6);
+
+                _ = drain.next();
+
+                assert_eq!(drain.len(), 5);
+            }
+
+            #[test]
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert_eq!(actual.drain_sorted().next(), None);
+            }
+        }
+
+        mod map_in_place {
+            use super::*;
+
+            #[test]
+            fn transforms_every_element() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let actual = actual.map_in_place(|element| element * 2);
+
+                assert!(actual.into_iter().eq([0, 2, 4, 6, 8, 10]));
+            }
+
+            #[test]
+            fn transforms_in_order() {
+                let mut order = Dynamic::<i32>::default();
+
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                drop(actual.map_in_place(|element| {
+                    _ = order.append(element).expect("successful allocation");
+
+                    element
+                }));
+
+                assert!(order.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn reuses_allocation() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                let ptr = actual.as_ptr();
+                let front = actual.capacity_front();
+                let back = actual.capacity_back();
+
+                let actual = actual.map_in_place(|element| element * 2);
+
+                assert_eq!(actual.as_ptr(), ptr);
+                assert_eq!(actual.capacity_front(), front);
+                assert_eq!(actual.capacity_back(), back);
+            }
+
+            #[test]
+            fn when_empty() {
+                let actual = Dynamic::<i32>::default();
+
+                let actual = actual.map_in_place(|_| unreachable!("no elements"));
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn drops_other_elements_when_transform_panics() {
+                let counter = alloc::rc::Rc::new(core::cell::RefCell::new(0));
+
+                let actual: Dynamic<_> = core::iter::repeat_with(|| Droppable {
+                    counter: alloc::rc::Rc::clone(&counter),
+                })
+                .take(6)
+                .collect();
+
+                let mut transformed = 0;
+
+                let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                    actual.map_in_place(|element| {
+                        assert!(transformed < 3, "transform panicked");
+
+                        transformed += 1;
+
+                        element
+                    })
+                }));
+
+                assert!(result.is_err());
+
+                // The element given to the panicking transform is dropped
+                // during unwinding of the closure, all others by the guard.
+                assert_eq!(counter.take(), 6);
+            }
+        }
+
+        mod try_map {
+            use super::*;
+
+            #[test]
+            fn transforms_every_element_when_successful() {
+                let actual = Dynamic::from_iter(["0", "1", "2", "3", "4", "5"]);
+
+                let actual = actual.try_map(str::parse::<i32>).expect("all valid");
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn yields_first_error() {
+                let actual = Dynamic::from_iter(["1", "2", "x", ""]);
+
+                let actual = actual.try_map(str::parse::<i32>);
+
+                assert_eq!(actual, Err("x".parse::<i32>().unwrap_err()));
+            }
+
+            #[test]
+            fn does_not_transform_after_error() {
+                let mut transformed = 0;
+
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let actual = actual.try_map(|element| {
+                    transformed += 1;
+
+                    if element == 2 {
+                        Err(element)
+                    } else {
+                        Ok(element)
+                    }
+                });
+
+                assert_eq!(actual, Err(2));
+                assert_eq!(transformed, 3);
+            }
+
+            #[test]
+            fn when_empty() {
+                let actual = Dynamic::<&str>::default();
+
+                let actual = actual.try_map(str::parse::<i32>).expect("no elements");
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn drops_every_element_upon_error() {
+                let input = alloc::rc::Rc::new(core::cell::RefCell::new(0));
+                let output = alloc::rc::Rc::new(core::cell::RefCell::new(0));
+
+                let actual: Dynamic<_> = core::iter::repeat_with(|| Droppable {
+                    counter: alloc::rc::Rc::clone(&input),
+                })
+                .take(6)
+                .collect();
+
+                let mut transformed = 0;
+
+                let actual = actual.try_map(|element| {
+                    drop(element);
+
+                    transformed += 1;
+
+                    if transformed > 3 {
+                        Err(())
+                    } else {
+                        Ok(Droppable {
+                            counter: alloc::rc::Rc::clone(&output),
+                        })
+                    }
+                });
+
+                assert!(actual.is_err());
+                assert_eq!(input.take(), 6);
+                assert_eq!(output.take(), 3);
+            }
+        }
+
+        mod rchunks {
+            use super::*;
+
+            #[test]
+            fn chunks_from_the_back() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4]);
+
+                let mut actual = actual.rchunks(2);
+
+                assert_eq!(actual.next(), Some([3, 4].as_slice()));
+                assert_eq!(actual.next(), Some([1, 2].as_slice()));
+                assert_eq!(actual.next(), Some([0].as_slice()));
+                assert_eq!(actual.next(), None);
+            }
+
+            #[test]
+            fn every_chunk_is_full_when_multiple_of_size() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for chunk in actual.rchunks(3) {
+                    assert_eq!(chunk.len(), 3);
+                }
+            }
+
+            #[test]
+            fn single_chunk_when_size_is_greater_than_count() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let mut actual = actual.rchunks(256);
+
+                assert_eq!(actual.next(), Some([0, 1, 2, 3, 4, 5].as_slice()));
+                assert_eq!(actual.next(), None);
+            }
+
+            #[test]
+            fn is_double_ended() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4]);
+
+                let mut actual = actual.rchunks(2);
+
+                assert_eq!(actual.next_back(), Some([0].as_slice()));
+                assert_eq!(actual.next(), Some([3, 4].as_slice()));
+                assert_eq!(actual.next_back(), Some([1, 2].as_slice()));
+                assert_eq!(actual.next(), None);
+            }
+
+            #[test]
+            fn matches_slice() {
+                let expected = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+                let actual = Dynamic::from_iter(expected);
+
+                for size in 1..=expected.len() {
+                    assert!(actual.rchunks(size).eq(expected.rchunks(size)));
+                }
+            }
+
+            #[test]
+            fn yields_nothing_when_empty() {
+                let actual = Dynamic::<i32>::default();
+
+                assert_eq!(actual.rchunks(2).next(), None);
+            }
+
+            #[test]
+            #[should_panic = "chunk size must be non-zero"]
+            fn panics_when_size_is_zero() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                drop(actual.rchunks(0));
+            }
+        }
+
+        mod rchunks_mut {
+            use super::*;
+
+            #[test]
+            fn chunks_from_the_back() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4]);
+
+                let mut actual = actual.rchunks_mut(2);
+
+                assert_eq!(actual.next(), Some([3, 4].as_mut_slice()));
+                assert_eq!(actual.next(), Some([1, 2].as_mut_slice()));
+                assert_eq!(actual.next(), Some([0].as_mut_slice()));
+                assert_eq!(actual.next(), None);
+            }
+
+            #[test]
+            fn single_chunk_when_size_is_greater_than_count() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let mut actual = actual.rchunks_mut(256);
+
+                assert_eq!(actual.next(), Some([0, 1, 2, 3, 4, 5].as_mut_slice()));
+                assert_eq!(actual.next(), None);
+            }
+
+            #[test]
+            fn mutation_is_visible() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4]);
+
+                for chunk in actual.rchunks_mut(2) {
+                    chunk.reverse();
+                }
+
+                assert!(actual.into_iter().eq([0, 2, 1, 4, 3]));
+            }
+
+            #[test]
+            fn yields_nothing_when_empty() {
+                let mut actual = Dynamic::<i32>::default();
+
+                assert_eq!(actual.rchunks_mut(2).next(), None);
+            }
+
+            #[test]
+            #[should_panic = "chunk size must be non-zero"]
+            fn panics_when_size_is_zero() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                drop(actual.rchunks_mut(0));
+            }
+        }
+
+        mod chunks_exact {
+            use super::*;
+
+            #[test]
+            fn every_chunk_has_exactly_size_elements() {
+                let actual: Dynamic<_> = (0..17).collect();
+
+                let mut count = 0;
+
+                for chunk in actual.chunks_exact(4) {
+                    assert_eq!(chunk.len(), 4);
+
+                    count += 1;
+                }
+
+                assert_eq!(count, 4);
+            }
+
+            #[test]
+            fn remainder_is_the_tail() {
+                let actual: Dynamic<_> = (0..17).collect();
+
+                assert_eq!(actual.chunks_exact(4).remainder(), [16].as_slice());
+            }
+
+            #[test]
+            fn remainder_is_empty_when_multiple_of_size() {
+                let actual: Dynamic<_> = (0..16).collect();
+
+                assert!(actual.chunks_exact(4).remainder().is_empty());
+            }
+
+            #[test]
+            fn chunks_and_remainder_concatenate_to_elements() {
+                let expected: Dynamic<_> = (0..17).collect();
+
+                let chunks = expected.chunks_exact(5);
+                let remainder = chunks.remainder();
+
+                let actual: Dynamic<_> = chunks.flatten().chain(remainder).copied().collect();
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn only_remainder_when_size_is_greater_than_count() {
+                let actual = Dynamic::from_iter([0, 1, 2]);
+
+                let mut actual = actual.chunks_exact(256);
+
+                assert_eq!(actual.next(), None);
+                assert_eq!(actual.remainder(), [0, 1, 2].as_slice());
+            }
+
+            #[test]
+            fn yields_nothing_when_empty() {
+                let actual = Dynamic::<i32>::default();
+
+                let mut actual = actual.chunks_exact(2);
+
+                assert_eq!(actual.next(), None);
+                assert!(actual.remainder().is_empty());
+            }
+
+            #[test]
+            #[should_panic = "chunk size must be non-zero"]
+            fn panics_when_size_is_zero() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                drop(actual.chunks_exact(0));
+            }
+        }
+
+        mod iter_step_by {
+            use super::*;
+
+            #[test]
+            fn yields_every_step_th_element() {
+                let actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.iter_step_by(3).eq(&[0, 3, 6, 9]));
+            }
+
+            #[test]
+            fn step_of_one_yields_every_element() {
+                let actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.iter_step_by(1).eq(actual.iter()));
+            }
+
+            #[test]
+            fn matches_generic_step_by() {
+                let actual: Dynamic<_> = (0..100).collect();
+
+                for step in 1..=101 {
+                    assert!(actual.iter_step_by(step).eq(actual.iter().step_by(step)));
+                }
+            }
+
+            #[test]
+            fn yields_first_when_step_exceeds_count() {
+                let actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(actual.iter_step_by(256).eq(&[0]));
+            }
+
+            #[test]
+            fn is_exact_size() {
+                let actual: Dynamic<_> = (0..10).collect();
+
+                assert_eq!(actual.iter_step_by(3).len(), 4);
+                assert_eq!(actual.iter_step_by(5).len(), 2);
+            }
+
+            #[test]
+            fn is_double_ended() {
+                let actual: Dynamic<_> = (0..10).collect();
+
+                assert!(actual.iter_step_by(3).rev().eq(&[9, 6, 3, 0]));
+            }
+
+            #[test]
+            fn yields_nothing_when_empty() {
+                let actual = Dynamic::<i32>::default();
+
+                assert_eq!(actual.iter_step_by(2).next(), None);
+            }
+
+            #[test]
+            #[should_panic = "step must be non-zero"]
+            fn panics_when_step_is_zero() {
+                let actual = Dynamic::from_iter([0, 1, 2]);
+
+                drop(actual.iter_step_by(0));
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        mod par_iter_mut {
+            use super::*;
+            use rayon::iter::IndexedParallelIterator;
+            use rayon::iter::ParallelIterator;
+
+            #[test]
+            fn matches_sequential() {
+                let mut actual: Dynamic<_> = (0..65_536_u64).collect();
+                let mut expected: Dynamic<_> = (0..65_536_u64).collect();
+
+                actual
+                    .par_iter_mut()
+                    .for_each(|element| *element = element.wrapping_mul(3));
+
+                expected
+                    .iter_mut()
+                    .for_each(|element| *element = element.wrapping_mul(3));
+
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn yields_each_element_exactly_once() {
+                let mut actual: Dynamic<_> = (0..65_536).map(|_| 0_usize).collect();
+
+                actual
+                    .par_iter_mut()
+                    .for_each(|element| *element = element.wrapping_add(1));
+
+                assert!(actual.iter().all(|element| *element == 1));
+            }
+
+            #[test]
+            fn yields_in_order() {
+                let mut actual: Dynamic<_> = (0..1024_usize).collect();
+
+                assert!(actual
+                    .par_iter_mut()
+                    .enumerate()
+                    .all(|(index, element)| index == *element));
+            }
+
+            #[test]
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert_eq!(actual.par_iter_mut().count(), 0);
+            }
+        }
+
+        mod sort {
+            use super::*;
+
+            #[test]
+            fn ascending_order() {
+                let mut actual = Dynamic::from_iter([3, 0, 5, 2, 4, 1]);
+
+                actual.sort();
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn is_stable() {
+                let mut actual = Dynamic::from_iter([
+                    (2, 'a'),
+                    (1, 'b'),
+                    (2, 'c'),
+                    (0, 'd'),
+                    (1, 'e'),
+                    (0, 'f'),
+                ]);
+
+                actual.sort_by_key(|element| element.0);
+
+                assert!(actual.into_iter().eq([
+                    (0, 'd'),
+                    (0, 'f'),
+                    (1, 'b'),
+                    (1, 'e'),
+                    (2, 'a'),
+                    (2, 'c')
+                ]));
+            }
+
+            #[test]
+            fn custom_comparison() {
+                let mut actual = Dynamic::from_iter([3, 0, 5, 2, 4, 1]);
+
+                actual.sort_by(|left, right| right.cmp(left));
+
+                assert!(actual.into_iter().eq([5, 4, 3, 2, 1, 0]));
+            }
+
+            #[test]
+            fn retains_capacity() {
+                let mut actual = Dynamic::from_iter([3, 0, 5, 2, 4, 1]);
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                actual.sort();
+
+                assert_eq!(actual.capacity_front(), 256);
+                assert_eq!(actual.capacity_back(), 256);
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn when_empty() {
+                let mut actual = Dynamic::<i32>::default();
+
+                actual.sort();
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn does_not_drop_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let mut actual: Dynamic<_> = (0..256)
+                    .rev()
+                    .map(|key| {
+                        (
+                            key,
+                            Droppable {
+                                counter: alloc::rc::Rc::clone(&dropped),
+                            },
+                        )
+                    })
+                    .collect();
+
+                actual.sort_by_key(|element| element.0);
+
+                assert_eq!(dropped.take(), 0);
+                assert!(actual.iter().map(|element| element.0).eq(0..256));
+            }
+        }
+
+        mod sort_unstable {
+            use super::*;
+
+            #[test]
+            fn ascending_order() {
+                let mut actual = Dynamic::from_iter([3, 0, 5, 2, 4, 1]);
+
+                actual.sort_unstable();
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn custom_comparison() {
+                let mut actual = Dynamic::from_iter([3, 0, 5, 2, 4, 1]);
+
+                actual.sort_unstable_by(|left, right| right.cmp(left));
+
+                assert!(actual.into_iter().eq([5, 4, 3, 2, 1, 0]));
+            }
+
+            #[test]
+            fn by_key() {
+                let mut actual = Dynamic::from_iter([-3, 0, 5, -2, 4, -1]);
+
+                actual.sort_unstable_by_key(|element: &i32| element.abs());
+
+                assert!(actual.into_iter().eq([0, -1, -2, -3, 4, 5]));
+            }
+
+            #[test]
+            fn retains_capacity() {
+                let mut actual = Dynamic::from_iter([3, 0, 5, 2, 4, 1]);
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                actual.sort_unstable();
+
+                assert_eq!(actual.capacity_front(), 256);
+                assert_eq!(actual.capacity_back(), 256);
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn when_empty() {
+                let mut actual = Dynamic::<i32>::default();
+
+                actual.sort_unstable();
+
+                assert_eq!(actual.len(), 0);
+            }
+        }
+
+        mod cmp_by {
+            use super::*;
+
+            #[test]
+            fn first_unequal_element_decides() {
+                let left = Dynamic::from_iter([0, 1, 5]);
+                let right = Dynamic::from_iter([0, 2, 3]);
+
+                assert_eq!(left.cmp_by(&right, Ord::cmp), core::cmp::Ordering::Less);
+                assert_eq!(right.cmp_by(&left, Ord::cmp), core::cmp::Ordering::Greater);
+            }
+
+            #[test]
+            fn uses_comparator() {
+                let left = Dynamic::from_iter([0, 1, 5]);
+                let right = Dynamic::from_iter([0, 2, 3]);
+
+                let actual = left.cmp_by(&right, |left, right| right.cmp(left));
+
+                assert_eq!(actual, core::cmp::Ordering::Greater);
+            }
+
+            #[test]
+            fn prefix_is_less() {
+                let left = Dynamic::from_iter([0, 1]);
+                let right = Dynamic::from_iter([0, 1, 2]);
+
+                assert_eq!(left.cmp_by(&right, Ord::cmp), core::cmp::Ordering::Less);
+                assert_eq!(right.cmp_by(&left, Ord::cmp), core::cmp::Ordering::Greater);
+            }
+
+            #[test]
+            fn equal_when_same_elements() {
+                let left = Dynamic::from_iter([0, 1, 2]);
+                let right = left.clone();
+
+                assert_eq!(left.cmp_by(&right, Ord::cmp), core::cmp::Ordering::Equal);
+            }
+
+            #[test]
+            fn empty_is_less_than_non_empty() {
+                let left = Dynamic::<usize>::default();
+                let right = Dynamic::from_iter([0]);
+
+                assert_eq!(left.cmp_by(&left, Ord::cmp), core::cmp::Ordering::Equal);
+                assert_eq!(left.cmp_by(&right, Ord::cmp), core::cmp::Ordering::Less);
+            }
+
+            #[test]
+            fn matches_slice_ordering() {
+                let inputs = [
+                    [].as_slice(),
+                    [0].as_slice(),
+                    [0, 0].as_slice(),
+                    [0, 1].as_slice(),
+                    [1].as_slice(),
+                    [1, 0, 2].as_slice(),
+                ];
+
+                for left in inputs {
+                    for right in inputs {
+                        let expected = left.cmp(right);
+
+                        let left = Dynamic::try_from(left).expect("successful allocation");
+                        let right = Dynamic::try_from(right).expect("successful allocation");
+
+                        assert_eq!(left.cmp_by(&right, Ord::cmp), expected);
+                    }
+                }
+            }
+
+            #[test]
+            fn compares_non_ord_elements() {
+                let left = Dynamic::from_iter([0.5, 1.5]);
+                let right = Dynamic::from_iter([0.5, f64::NAN]);
+
+                let actual = left.cmp_by(&right, f64::total_cmp);
+
+                assert_eq!(actual, core::cmp::Ordering::Less);
+            }
+        }
+
+        mod swap_with_slice {
+            use super::*;
+
+            #[test]
+            fn exchanges_contents() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3]);
+                let mut other = [4, 5, 6, 7];
+
+                actual.swap_with_slice(&mut other);
+
+                assert_eq!(other, [0, 1, 2, 3]);
+                assert!(actual.into_iter().eq([4, 5, 6, 7]));
+            }
+
+            #[test]
+            fn does_not_drop_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let mut actual: Dynamic<_> = core::iter::repeat_with(|| Droppable {
+                    counter: alloc::rc::Rc::clone(&dropped),
+                })
+                .take(4)
+                .collect();
+
+                let mut other: [Droppable; 4] = core::array::from_fn(|_| Droppable {
+                    counter: alloc::rc::Rc::clone(&dropped),
+                });
+
+                actual.swap_with_slice(&mut other);
+
+                assert_eq!(dropped.take(), 0);
+            }
+
+            #[test]
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                actual.swap_with_slice(&mut []);
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            #[should_panic = "lengths must be equal"]
+            fn panics_when_lengths_differ() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                actual.swap_with_slice(&mut [0, 1]);
+            }
+        }
+
+        mod copy_within {
+            use super::*;
+
+            #[test]
+            fn overlapping_forwards() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.copy_within(0..3, 2);
+
+                assert!(actual.into_iter().eq([0, 1, 0, 1, 2, 5]));
+            }
+
+            #[test]
+            fn overlapping_backwards() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.copy_within(2.., 0);
+
+                assert!(actual.into_iter().eq([2, 3, 4, 5, 4, 5]));
+            }
+
+            #[test]
+            fn disjoint() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.copy_within(..=1, 4);
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 0, 1]));
+            }
+
+            #[test]
+            fn empty_range() {
+                let mut actual = Dynamic::<usize>::default();
+
+                actual.copy_within(.., 0);
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            #[should_panic = "dest is out of bounds"]
+            fn panics_when_destination_out_of_bounds() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.copy_within(0..3, 4);
+            }
+
+            #[test]
+            #[should_panic = "out of range"]
+            fn panics_when_source_out_of_bounds() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.copy_within(4..7, 0);
+            }
+        }
+
+        mod splice_slice {
+            use super::*;
+
+            /// Replace `range` with `replacement` one element at a time.
+            fn expected(
+                original: &[usize],
+                range: core::ops::Range<usize>,
+                replacement: &[usize],
+            ) -> Dynamic<usize> {
+                let mut expected: Dynamic<_> = original.iter().copied().collect();
+
+                expected.drain(range.clone()).for_each(drop);
+
+                for (index, element) in (range.start..).zip(replacement) {
+                    _ = expected
+                        .insert(index, *element)
+                        .expect("successful allocation");
+                }
+
+                expected
+            }
+
+            #[test]
+            fn longer_replacement_when_emptied_from_both_ends() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for _ in 0..3 {
+                    assert!(actual.pop_front().is_some());
+                    assert!(actual.pop_back().is_some());
+                }
+
+                assert!(actual.splice_slice(.., &[1, 2, 3, 4]).is_ok());
+
+                assert!(actual.into_iter().eq([1, 2, 3, 4]));
+            }
+
+            #[test]
+            fn equal_length_replacement() {
+                let original = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(original);
+
+                _ = actual
+                    .splice_slice(1..4, &[7, 8, 9])
+                    .expect("successful allocation");
+
+                assert_eq!(actual, expected(&original, 1..4, &[7, 8, 9]));
+            }
+
+            #[test]
+            fn shorter_replacement() {
+                let original = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(original);
+
+                _ = actual
+                    .splice_slice(1..5, &[7])
+                    .expect("successful allocation");
+
+                assert_eq!(actual, expected(&original, 1..5, &[7]));
+            }
+
+            #[test]
+            fn longer_replacement() {
+                let original = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(original);
+
+                _ = actual
+                    .splice_slice(2..3, &[7, 8, 9, 10])
+                    .expect("successful allocation");
+
+                assert_eq!(actual, expected(&original, 2..3, &[7, 8, 9, 10]));
             }
 
-            // SAFETY: the element is initialized.
-            let current = unsafe { self.next_back.as_ref() };
+            #[test]
+            fn longer_replacement_into_front_capacity() {
+                let original = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(original);
 
-            // Do _NOT_ moved the pointer _before_ the allocated object.
-            if self.remaining != 0 {
-                self.next_back = {
-                    // SAFETY: aligned within the allocated object.
-                    let ptr = unsafe { self.next_back.as_ptr().sub(1) };
+                _ = actual.reserve_front(256).expect("successful allocation");
 
-                    // SAFETY: `retained` is not null => pointer is not null.
-                    unsafe { NonNull::new_unchecked(ptr) }
-                };
+                _ = actual
+                    .splice_slice(2..3, &[7, 8, 9, 10])
+                    .expect("successful allocation");
+
+                assert_eq!(actual.capacity_front(), 253);
+                assert_eq!(actual, expected(&original, 2..3, &[7, 8, 9, 10]));
             }
 
-            if (self.predicate)(current) {
-                // SAFETY:
-                // * owned memory => pointer is valid for reads.
-                // * Underlying `T` is initialized.
-                // * This takes ownership (moved out of the buffer).
-                let element = unsafe { core::ptr::read(current) };
+            #[test]
+            fn empty_range_inserts() {
+                let original = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(original);
 
-                if let Some(decremented) = self.underlying.initialized.checked_sub(1) {
-                    self.underlying.initialized = decremented;
-                } else {
-                    unreachable!("no initialized element to remove");
-                }
+                _ = actual
+                    .splice_slice(3..3, &[7, 8])
+                    .expect("successful allocation");
 
-                if let Some(incremented) = self.underlying.back_capacity.checked_add(1) {
-                    self.underlying.back_capacity = incremented;
-                } else {
-                    unreachable!("allocated more than `isize::MAX` bytes");
-                }
+                assert_eq!(actual, expected(&original, 3..3, &[7, 8]));
+            }
 
-                let src = {
-                    let current: *const T = current;
+            #[test]
+            fn empty_replacement_removes() {
+                let original = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(original);
 
-                    // SAFETY: stays aligned within the allocated object.
-                    unsafe { current.add(1) }.cast_mut()
-                };
+                _ = actual.splice_slice(1..4, &[]).expect("does not allocate");
 
-                let dst = {
-                    let current: *const T = current;
-                    current.cast_mut()
-                };
+                assert_eq!(actual, expected(&original, 1..4, &[]));
+            }
 
-                // SAFETY:
-                // * owned memory => source/destination valid for read/writes.
-                // * no aliasing restrictions => source and destination can overlap.
-                // * underlying buffer is aligned => both pointers are aligned.
-                unsafe {
-                    core::ptr::copy(src, dst, self.trailing);
-                }
+            #[test]
+            fn shorter_replacement_increases_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                return Some(element);
+                _ = actual.splice_slice(1..5, &[7]).expect("does not allocate");
+
+                assert_eq!(actual.capacity_back(), 3);
             }
 
-            if let Some(incremented) = self.trailing.checked_add(1) {
-                self.trailing = incremented;
-            } else {
-                unreachable!("allocated more than `isize::MAX`");
-            };
-        }
+            #[test]
+            fn clamps_out_of_bounds_range() {
+                let original = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(original);
 
-        None
-    }
-}
+                _ = actual
+                    .splice_slice(4..256, &[7])
+                    .expect("successful allocation");
 
-impl<T, F: FnMut(&T) -> bool> core::iter::FusedIterator for Withdraw<'_, T, F> {}
+                assert_eq!(actual, expected(&original, 4..6, &[7]));
+            }
 
-impl<T, F: FnMut(&T) -> bool> core::fmt::Debug for Withdraw<'_, T, F> {
-    /// Output what indexes are being pointed to in the underlying buffer.
-    ///
-    /// Note that these indexes are _NOT_ based on the first initialized
-    /// element, but rather absolute relative to the beginning of the
-    /// allocated object.
-    ///
-    /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let origin = self.underlying.buffer.as_ptr().cast::<T>();
+            #[test]
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
 
-        // SAFETY: both pointers are aligned within the allocated object.
-        let head = unsafe { self.next_front.as_ptr().offset_from(origin) };
+                _ = actual
+                    .splice_slice(.., &[0, 1, 2])
+                    .expect("successful allocation");
 
-        // SAFETY: both pointers are aligned within the allocated object.
-        let retained = unsafe { self.retained.as_ptr().offset_from(origin) };
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
 
-        // SAFETY: both pointers are aligned within the allocated object.
-        let tail = unsafe { self.next_back.as_ptr().offset_from(origin) };
+            #[test]
+            fn zero_size_types() {
+                let mut actual = Dynamic::from_iter([(), (), ()]);
 
-        f.debug_struct("Withdraw")
-            .field("head index", &head)
-            .field("tail index", &tail)
-            .field("remaining elements", &self.remaining)
-            .field("retained index", &retained)
-            .field("trailing elements", &self.trailing)
-            .finish_non_exhaustive()
-    }
-}
+                _ = actual
+                    .splice_slice(1..2, &[(), (), ()])
+                    .expect("successful allocation");
 
-/// Error type for recoverable allocation failure.
-#[derive(Debug, Clone, Copy)]
-pub struct FailedAllocation;
+                assert_eq!(actual.len(), 5);
+            }
+        }
 
-impl core::fmt::Display for FailedAllocation {
-    /// Write a human-facing description of the error.
-    ///
-    /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "memory allocation failed")
-    }
-}
+        mod rotate_left {
+            use super::*;
 
-impl std::error::Error for FailedAllocation {}
+            #[test]
+            fn matches_slice_rotation() {
+                for count in 0..=6 {
+                    let mut expected = [0, 1, 2, 3, 4, 5];
+                    expected.rotate_left(count);
 
-/// Error type for invalid index parameters.
-#[derive(Debug, Clone, Copy)]
-pub struct OutOfBounds;
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                    actual.rotate_left(count);
 
-impl core::fmt::Display for OutOfBounds {
-    /// Write a human-facing description of the error.
-    ///
-    /// # Performance
-    /// This methods takes O(1) time and consumes O(1) memory.
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "index is outside the bounds of initialized elements")
-    }
-}
+                    assert_eq!(actual, Dynamic::from_iter(expected));
+                }
+            }
 
-impl std::error::Error for OutOfBounds {}
+            #[test]
+            fn matches_slice_rotation_with_capacity() {
+                for count in 0..=6 {
+                    let mut expected = [0, 1, 2, 3, 4, 5];
+                    expected.rotate_left(count);
 
-#[cfg(test)]
-#[allow(
-    clippy::undocumented_unsafe_blocks,
-    clippy::unwrap_used,
-    clippy::expect_used,
-    clippy::assertions_on_result_states,
-    clippy::indexing_slicing
-)]
-mod test {
-    use super::*;
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                    _ = actual.reserve_front(2).expect("successful allocation");
+                    _ = actual.reserve_back(3).expect("successful allocation");
+                    actual.rotate_left(count);
 
-    /// Mock element for drop tests.
-    #[derive(Debug, Clone)]
-    struct Droppable {
-        /// A shared counter for the number of elements dropped.
-        counter: alloc::rc::Rc<core::cell::RefCell<usize>>,
-    }
+                    assert_eq!(actual, Dynamic::from_iter(expected));
+                }
+            }
 
-    impl Drop for Droppable {
-        /// Increment the shared counter upon drop.
-        fn drop(&mut self) {
-            _ = self.counter.replace_with(|old| old.wrapping_add(1));
-        }
-    }
+            #[test]
+            fn uses_back_capacity_when_available() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                _ = actual.reserve_back(256).expect("successful allocation");
 
-    mod method {
-        use super::*;
+                let buffer = actual.buffer;
 
-        mod with_capacity {
-            use super::*;
+                actual.rotate_left(2);
+
+                assert_eq!(actual.buffer, buffer);
+                assert_eq!(actual.capacity_front(), 2);
+                assert_eq!(actual.capacity_back(), 254);
+                assert!(actual.into_iter().eq([2, 3, 4, 5, 0, 1]));
+            }
 
             #[test]
-            fn increases_capacity() {
-                let actual = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn uses_front_capacity_when_available() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                _ = actual.reserve_front(256).expect("successful allocation");
 
-                assert_eq!(actual.capacity(), 256);
-                assert_eq!(actual.capacity_front(), 256);
-                assert_eq!(actual.capacity_back(), 256);
+                let buffer = actual.buffer;
+
+                actual.rotate_left(4);
+
+                assert_eq!(actual.buffer, buffer);
+                assert_eq!(actual.capacity_front(), 254);
+                assert_eq!(actual.capacity_back(), 2);
+                assert!(actual.into_iter().eq([4, 5, 0, 1, 2, 3]));
             }
 
             #[test]
-            fn allocates_memory() {
-                let actual = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn retains_capacity_when_insufficient() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                _ = actual.reserve_front(1).expect("successful allocation");
+                _ = actual.reserve_back(1).expect("successful allocation");
 
-                for index in 0..actual.capacity() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+                actual.rotate_left(3);
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
-                }
+                assert_eq!(actual.capacity_front(), 1);
+                assert_eq!(actual.capacity_back(), 1);
+                assert!(actual.into_iter().eq([3, 4, 5, 0, 1, 2]));
             }
 
             #[test]
-            fn does_not_initialize_elements() {
-                let actual = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn when_empty() {
+                let mut actual = Dynamic::<i32>::default();
 
-                assert_eq!(actual.initialized, 0);
+                actual.rotate_left(0);
+
+                assert_eq!(actual.len(), 0);
             }
 
             #[test]
-            fn zero_capacity_cannot_fail() {
-                let actual = Dynamic::<usize>::with_capacity(0);
+            fn does_not_drop_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
 
-                assert!(actual.is_ok());
+                let mut actual: Dynamic<_> = (0..6)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&dropped),
+                    })
+                    .collect();
+
+                _ = actual.reserve_back(2).expect("successful allocation");
+                _ = actual.reserve_front(2).expect("successful allocation");
+
+                actual.rotate_left(1);
+                actual.rotate_right(3);
+
+                assert_eq!(dropped.take(), 0);
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 6);
             }
 
             #[test]
-            fn zero_size_types_cannot_fail() {
-                let capacity = usize::try_from(isize::MAX).unwrap();
-
-                let actual = Dynamic::<()>::with_capacity(capacity);
+            #[should_panic = "rotation out of bounds"]
+            fn panics_when_count_out_of_bounds() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                assert!(actual.is_ok());
+                actual.rotate_left(7);
             }
         }
 
-        mod capacity {
+        mod rotate_right {
             use super::*;
 
             #[test]
-            fn only_front_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn matches_slice_rotation() {
+                for count in 0..=6 {
+                    let mut expected = [0, 1, 2, 3, 4, 5];
+                    expected.rotate_right(count);
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                    actual.rotate_right(count);
 
-                assert_eq!(actual.capacity(), 256);
+                    assert_eq!(actual, Dynamic::from_iter(expected));
+                }
             }
 
             #[test]
-            fn only_back_capacity() {
+            fn matches_slice_rotation_with_capacity() {
+                for count in 0..=6 {
+                    let mut expected = [0, 1, 2, 3, 4, 5];
+                    expected.rotate_right(count);
+
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                    _ = actual.reserve_front(3).expect("successful allocation");
+                    _ = actual.reserve_back(2).expect("successful allocation");
+                    actual.rotate_right(count);
+
+                    assert_eq!(actual, Dynamic::from_iter(expected));
+                }
+            }
+
+            #[test]
+            fn uses_front_capacity_when_available() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                _ = actual.reserve_front(256).expect("successful allocation");
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                let buffer = actual.buffer;
 
-                assert_eq!(actual.capacity(), 256);
+                actual.rotate_right(2);
+
+                assert_eq!(actual.buffer, buffer);
+                assert_eq!(actual.capacity_front(), 254);
+                assert!(actual.into_iter().eq([4, 5, 0, 1, 2, 3]));
             }
 
             #[test]
-            fn front_and_back_capacity() {
+            #[should_panic = "rotation out of bounds"]
+            fn panics_when_count_out_of_bounds() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                _ = actual.reserve_front(256).expect("successful allocation");
-                _ = actual.reserve_back(256).expect("successful allocation");
+                actual.rotate_right(7);
+            }
+        }
 
-                assert_eq!(actual.capacity(), 512);
+        mod apply_permutation {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                actual.apply_permutation(&[]);
+
+                assert_eq!(actual.len(), 0);
             }
 
             #[test]
-            fn does_not_invalidate_pointers_for_that_many_additions() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
-
-                let ptr = actual.buffer.as_ptr();
+            fn moves_elements_to_destination() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                for index in 0..actual.capacity() {
-                    if index % 2 == 0 {
-                        _ = actual.append(index).expect("uses capacity");
-                    } else {
-                        _ = actual.prepend(index).expect("uses capacity");
-                    }
-                }
+                actual.apply_permutation(&[3, 0, 4, 1, 5, 2]);
 
-                assert_eq!(ptr, actual.buffer.as_ptr());
+                assert!(actual.into_iter().eq([1, 3, 5, 0, 2, 4]));
             }
-        }
-
-        mod capacity_front {
-            use super::*;
 
             #[test]
-            fn is_front_capacity() {
+            fn identity_is_no_op() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                actual.apply_permutation(&[0, 1, 2, 3, 4, 5]);
 
-                assert_eq!(actual.capacity_front(), actual.front_capacity);
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
             }
 
             #[test]
-            fn does_not_count_back_capacity_when_not_empty() {
+            fn reversal() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                actual.apply_permutation(&[5, 4, 3, 2, 1, 0]);
 
-                assert_eq!(actual.capacity_front(), 0);
+                assert!(actual.into_iter().eq([5, 4, 3, 2, 1, 0]));
             }
 
             #[test]
-            fn counts_back_capacity_when_empty() {
-                let mut actual = Dynamic::<usize>::default();
+            fn multiple_cycles() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6]);
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                // Cycles (0 1 2), (3 4), and (5) (6).
+                actual.apply_permutation(&[1, 2, 0, 4, 3, 5, 6]);
 
-                assert_eq!(actual.capacity_front(), 256);
+                assert!(actual.into_iter().eq([2, 0, 1, 4, 3, 5, 6]));
             }
 
             #[test]
-            fn does_not_invalidate_pointers_for_that_many_prepends() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn inverse_restores_original() {
+                let permutation: [usize; 256] =
+                    core::array::from_fn(|index| index.wrapping_mul(97) % 256);
 
-                let ptr = actual.buffer.as_ptr();
+                let mut inverse = [0; 256];
 
-                for index in 0..actual.capacity_front() {
-                    _ = actual.prepend(index).expect("uses capacity");
+                for (index, &destination) in permutation.iter().enumerate() {
+                    inverse[destination] = index;
                 }
 
-                assert_eq!(ptr, actual.buffer.as_ptr());
-            }
-        }
+                let mut actual: Dynamic<_> = (0..256).collect();
 
-        mod capacity_back {
-            use super::*;
+                actual.apply_permutation(&permutation);
 
-            #[test]
-            fn is_back_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+                for (index, &destination) in permutation.iter().enumerate() {
+                    assert_eq!(actual[destination], index);
+                }
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                actual.apply_permutation(&inverse);
 
-                assert_eq!(actual.capacity_back(), actual.back_capacity);
+                assert!(actual.into_iter().eq(0..256));
             }
 
             #[test]
-            fn does_not_count_front_capacity_when_not_empty() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn does_not_drop_elements() {
+                let counter = alloc::rc::Rc::new(core::cell::RefCell::new(0));
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                let mut actual: Dynamic<_> = (0..6)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&counter),
+                    })
+                    .collect();
 
-                assert_eq!(actual.capacity_back(), 0);
+                actual.apply_permutation(&[5, 4, 3, 2, 1, 0]);
+
+                assert_eq!(counter.take(), 0);
+
+                drop(actual);
+
+                assert_eq!(counter.take(), 6);
             }
 
             #[test]
-            fn counts_front_capacity_when_empty() {
-                let mut actual = Dynamic::<usize>::default();
+            #[should_panic = "permutation contains a duplicate index"]
+            fn panics_on_duplicate_index() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3]);
 
-                _ = actual.reserve_front(256).expect("successful allocation");
-
-                assert_eq!(actual.capacity_back(), 256);
+                actual.apply_permutation(&[0, 1, 1, 3]);
             }
 
             #[test]
-            fn does_not_invalidate_pointers_for_that_many_appends() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            #[should_panic = "permutation contains an out of bounds index"]
+            fn panics_on_out_of_bounds_index() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3]);
 
-                let ptr = actual.buffer.as_ptr();
+                actual.apply_permutation(&[0, 1, 4, 2]);
+            }
 
-                for index in 0..actual.capacity_back() {
-                    _ = actual.append(index).expect("uses capacity");
-                }
+            #[test]
+            #[should_panic = "permutation must have an index for each element"]
+            fn panics_on_length_mismatch() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3]);
 
-                assert_eq!(ptr, actual.buffer.as_ptr());
+                actual.apply_permutation(&[0, 1, 2]);
             }
         }
 
-        mod reserve {
+        mod for_each_window_mut {
             use super::*;
 
             #[test]
-            fn increases_capacity() {
+            #[should_panic = "window size must be non-zero"]
+            fn panics_when_size_is_zero() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                _ = actual.reserve(1).expect("successful allocation");
-
-                assert!(actual.capacity() >= 1);
+                actual.for_each_window_mut(0, |_| {});
             }
 
             #[test]
-            fn increases_capacity_in_powers_of_two() {
-                let mut actual = Dynamic::<()>::default();
-
-                for _ in 0..(isize::BITS - 1) {
-                    let capacity = actual.capacity() + 1;
-
-                    _ = actual.reserve(capacity).expect("successful allocation");
-
-                    let capacity = capacity.checked_next_power_of_two().unwrap();
+            fn does_nothing_when_empty() {
+                let mut actual = Dynamic::<usize>::default();
 
-                    assert_eq!(actual.capacity(), capacity);
-                }
+                actual.for_each_window_mut(1, |_| unreachable!("no windows"));
             }
 
             #[test]
-            fn does_not_decrease_capacity() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn does_nothing_when_size_exceeds_length() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
 
-                assert!(actual.reserve(0).is_ok());
-                assert_eq!(actual.capacity(), 256);
+                actual.for_each_window_mut(4, |_| unreachable!("no windows"));
             }
 
             #[test]
-            fn uses_front_capacity_before_reallocating() {
+            fn yields_each_window_in_order() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                let mut windows = 0;
 
-                let existing_allocation = actual.buffer.as_ptr();
+                actual.for_each_window_mut(3, |window| {
+                    assert_eq!(window, [windows, windows + 1, windows + 2]);
 
-                assert!(actual.reserve(256).is_ok());
+                    windows += 1;
+                });
 
-                assert_eq!(actual.buffer.as_ptr(), existing_allocation);
+                assert_eq!(windows, 4);
             }
 
             #[test]
-            fn allocates_memory() {
-                let mut actual = Dynamic::<usize>::default();
+            fn single_window_when_size_is_length() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
 
-                _ = actual.reserve(256).expect("successful allocation");
+                let mut windows = 0;
 
-                for index in 0..actual.capacity() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+                actual.for_each_window_mut(3, |_| windows += 1);
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
-                }
+                assert_eq!(windows, 1);
             }
 
             #[test]
-            fn reallocates_memory() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
-
-                _ = actual
-                    .reserve(actual.capacity() * 2)
-                    .expect("successful allocation");
+            fn smoothing_modifies_in_place() {
+                let mut actual = Dynamic::from_iter([0, 9, 0, 9, 0, 9]);
 
-                for index in 0..actual.capacity() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+                actual.for_each_window_mut(3, |window| {
+                    if let &mut [previous, ref mut current, next] = window {
+                        *current = (previous + *current + next) / 3;
+                    }
+                });
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
-                }
+                // Each window observes the previously smoothed element.
+                assert!(actual.into_iter().eq([0, 3, 4, 4, 4, 9]));
             }
+        }
+
+        mod as_chunks {
+            use super::*;
 
             #[test]
-            fn does_not_initialize_elements() {
-                let mut actual = Dynamic::<usize>::default();
+            fn empty() {
+                let actual = Dynamic::<usize>::default();
 
-                _ = actual.reserve(256).expect("successful allocation");
+                let (chunks, remainder) = actual.as_chunks::<2>();
 
-                assert_eq!(actual.initialized, 0);
+                assert!(chunks.is_empty());
+                assert!(remainder.is_empty());
             }
 
             #[test]
-            fn does_not_modify_initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
+            #[should_panic = "chunk size must be non-zero"]
+            fn panics_when_size_is_zero() {
+                let actual = Dynamic::from_iter([0, 1, 2]);
 
-                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+                _ = actual.as_chunks::<0>();
+            }
 
-                _ = actual.reserve(256).expect("successful allocation");
+            #[test]
+            fn yields_chunks_and_remainder() {
+                let actual: Dynamic<_> = (0..7).collect();
+
+                let (chunks, remainder) = actual.as_chunks::<2>();
 
-                assert!(actual.eq(expected));
+                assert_eq!(chunks, [[0, 1], [2, 3], [4, 5]]);
+                assert_eq!(remainder, [6]);
             }
 
             #[test]
-            fn zero_capacity_cannot_fail() {
-                let mut actual = Dynamic::<usize>::default();
+            fn no_remainder_when_exact_multiple() {
+                let actual: Dynamic<_> = (0..6).collect();
 
-                assert!(actual.reserve(0).is_ok());
+                let (chunks, remainder) = actual.as_chunks::<3>();
+
+                assert_eq!(chunks, [[0, 1, 2], [3, 4, 5]]);
+                assert!(remainder.is_empty());
             }
 
             #[test]
-            fn zero_size_types_cannot_fail() {
-                let capacity = usize::try_from(isize::MAX).unwrap();
+            fn only_remainder_when_too_few_elements() {
+                let actual: Dynamic<_> = (0..2).collect();
 
-                let mut actual = Dynamic::<()>::default();
+                let (chunks, remainder) = actual.as_chunks::<3>();
 
-                assert!(actual.reserve(capacity).is_ok());
+                assert!(chunks.is_empty());
+                assert_eq!(remainder, [0, 1]);
+            }
+
+            #[test]
+            fn chunks_alias_elements() {
+                let actual: Dynamic<_> = (0..7).collect();
+
+                let (chunks, _) = actual.as_chunks::<2>();
+
+                assert_eq!(chunks.as_ptr().cast::<i32>(), actual.as_ptr());
             }
         }
 
-        mod reserve_front {
+        mod as_chunks_mut {
             use super::*;
 
             #[test]
-            fn increases_front_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                let (chunks, remainder) = actual.as_chunks_mut::<2>();
 
-                assert_eq!(actual.capacity_front(), 256);
+                assert!(chunks.is_empty());
+                assert!(remainder.is_empty());
             }
 
             #[test]
-            fn does_not_decrease_capacity() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            #[should_panic = "chunk size must be non-zero"]
+            fn panics_when_size_is_zero() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
 
-                assert!(actual.reserve_front(0).is_ok());
-                assert_eq!(actual.capacity_front(), 256);
+                _ = actual.as_chunks_mut::<0>();
             }
 
             #[test]
-            fn does_not_modify_back_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn modifies_underlying_elements() {
+                let mut actual: Dynamic<_> = (0..7).collect();
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                let (chunks, remainder) = actual.as_chunks_mut::<2>();
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                for chunk in chunks {
+                    chunk.reverse();
+                }
 
-                assert_eq!(actual.capacity_back(), 256);
+                remainder[0] = 7;
+
+                assert!(actual.into_iter().eq([1, 0, 3, 2, 5, 4, 7]));
             }
+        }
+
+        mod partition {
+            use super::*;
 
             #[test]
-            fn allocates_memory() {
+            fn empty() {
                 let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                assert_eq!(actual.partition(|_| true), 0);
+            }
 
-                for index in 0..actual.capacity_front() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+            #[test]
+            fn groups_matching_elements_first() {
+                let mut actual: Dynamic<_> = (0..6).collect();
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
+                let boundary = actual.partition(|element| element % 2 == 0);
+
+                assert_eq!(boundary, 3);
+
+                for (index, element) in actual.iter().enumerate() {
+                    assert_eq!(element % 2 == 0, index < boundary);
                 }
             }
 
             #[test]
-            fn reallocates_memory() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn retains_every_element() {
+                let mut actual: Dynamic<_> = (0..6).collect();
 
-                _ = actual
-                    .reserve_front(actual.capacity_front() * 2)
-                    .expect("successful allocation");
+                _ = actual.partition(|element| element % 3 == 0);
 
-                for index in 0..actual.capacity_front() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+                let mut elements = actual.as_slice().to_vec();
+                elements.sort_unstable();
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
-                }
+                assert_eq!(elements, [0, 1, 2, 3, 4, 5]);
             }
 
             #[test]
-            fn does_not_initialize_elements() {
-                let mut actual = Dynamic::<usize>::default();
+            fn yields_length_when_every_element_matches() {
+                let mut actual: Dynamic<_> = (0..6).collect();
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                assert_eq!(actual.partition(|_| true), 6);
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
 
-                assert_eq!(actual.initialized, 0);
+            #[test]
+            fn yields_zero_when_no_element_matches() {
+                let mut actual: Dynamic<_> = (0..6).collect();
+
+                assert_eq!(actual.partition(|_| false), 0);
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
             }
 
             #[test]
-            fn does_not_modify_initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
+            fn calls_predicate_once_per_element() {
+                let mut actual: Dynamic<_> = (0..256).collect();
 
-                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+                let mut calls = 0;
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.partition(|element| {
+                    calls += 1;
+
+                    element % 7 < 3
+                });
 
-                assert!(actual.eq(expected));
+                assert_eq!(calls, 256);
             }
+        }
+
+        mod stable_partition {
+            use super::*;
 
             #[test]
-            fn zero_capacity_cannot_fail() {
+            fn empty() {
                 let mut actual = Dynamic::<usize>::default();
 
-                assert!(actual.reserve_front(0).is_ok());
+                assert_eq!(actual.stable_partition(|_| true), 0);
+                assert_eq!(actual.len(), 0);
             }
 
             #[test]
-            fn zero_size_types_cannot_fail() {
-                let capacity = usize::try_from(isize::MAX).unwrap();
+            fn preserves_relative_order() {
+                let mut actual: Dynamic<_> = (0..6).collect();
 
-                let mut actual = Dynamic::<()>::default();
+                let boundary = actual.stable_partition(|element| element % 2 == 0);
 
-                assert!(actual.reserve_front(capacity).is_ok());
+                assert_eq!(boundary, 3);
+                assert!(actual.into_iter().eq([0, 2, 4, 1, 3, 5]));
             }
-        }
 
-        mod reserve_back {
-            use super::*;
+            #[test]
+            fn preserves_order_of_equivalent_elements() {
+                let mut actual: Dynamic<_> = (0..256).map(|index| (index % 3, index)).collect();
+
+                let boundary = actual.stable_partition(|&(key, _)| key == 1);
+
+                let elements = actual.as_slice();
+
+                assert!(elements[..boundary].iter().all(|&(key, _)| key == 1));
+                assert!(elements[boundary..].iter().all(|&(key, _)| key != 1));
+                assert!(elements[..boundary]
+                    .windows(2)
+                    .all(|pair| pair[0].1 < pair[1].1));
+                assert!(elements[boundary..]
+                    .windows(2)
+                    .all(|pair| pair[0].1 < pair[1].1));
+            }
 
             #[test]
-            fn increases_back_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn yields_matching_count() {
+                let mut actual: Dynamic<_> = (0..256).collect();
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                assert_eq!(actual.stable_partition(|element| element % 7 < 3), 111);
+            }
 
-                assert_eq!(actual.capacity_back(), 256);
+            #[test]
+            fn yields_length_when_every_element_matches() {
+                let mut actual: Dynamic<_> = (0..6).collect();
+
+                assert_eq!(actual.stable_partition(|_| true), 6);
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
             }
 
             #[test]
-            fn does_not_decrease_capacity() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn yields_zero_when_no_element_matches() {
+                let mut actual: Dynamic<_> = (0..6).collect();
 
-                assert!(actual.reserve_back(0).is_ok());
-                assert_eq!(actual.capacity_back(), 256);
+                assert_eq!(actual.stable_partition(|_| false), 0);
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
             }
 
             #[test]
-            fn does_not_modify_front_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn calls_predicate_once_per_element_in_order() {
+                let mut actual: Dynamic<_> = (0..256).collect();
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                let mut expected = 0;
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                _ = actual.stable_partition(|&element| {
+                    assert_eq!(element, expected);
 
-                assert_eq!(actual.capacity_front(), 256);
+                    expected += 1;
+
+                    element % 2 == 0
+                });
+
+                assert_eq!(expected, 256);
+            }
+        }
+
+        mod contains_sorted {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                let actual = Dynamic::<usize>::default();
+
+                assert!(!actual.contains_sorted(&0));
+            }
+
+            #[test]
+            fn present_elements() {
+                let actual: Dynamic<_> = (0..6).map(|element| element * 2).collect();
+
+                for element in [0, 2, 4, 6, 8, 10] {
+                    assert!(actual.contains_sorted(&element));
+                }
             }
 
             #[test]
-            fn allocates_memory() {
-                let mut actual = Dynamic::<usize>::default();
-
-                _ = actual.reserve_back(256).expect("successful allocation");
+            fn absent_elements() {
+                let actual: Dynamic<_> = (0..6).map(|element| element * 2).collect();
 
-                for index in 0..actual.capacity_back() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
-
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
+                for element in [-1, 1, 3, 5, 7, 9, 11] {
+                    assert!(!actual.contains_sorted(&element));
                 }
             }
 
             #[test]
-            fn reallocates_memory() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn duplicate_elements() {
+                let actual = Dynamic::from_iter([0, 1, 1, 1, 2]);
 
-                _ = actual
-                    .reserve_back(actual.capacity_back() * 2)
-                    .expect("successful allocation");
+                assert!(actual.contains_sorted(&1));
+            }
 
-                for index in 0..actual.capacity_back() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic = "elements must be sorted"]
+            fn panics_when_unsorted() {
+                let actual = Dynamic::from_iter([3, 1, 2]);
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
-                }
+                _ = actual.contains_sorted(&1);
             }
+        }
+
+        mod binary_insert {
+            use super::*;
 
             #[test]
-            fn does_not_initialize_elements() {
+            fn empty() {
                 let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                assert_eq!(actual.binary_insert(0), 0);
 
-                assert_eq!(actual.initialized, 0);
+                assert!(actual.into_iter().eq([0]));
             }
 
             #[test]
-            fn does_not_modify_initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
-
-                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+            fn yields_index_of_inserted_element() {
+                let mut actual = Dynamic::from_iter([0, 2, 4]);
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                assert_eq!(actual.binary_insert(3), 2);
+                assert_eq!(actual.binary_insert(5), 4);
+                assert_eq!(actual.binary_insert(-1), 0);
 
-                assert!(actual.eq(expected));
+                assert!(actual.into_iter().eq([-1, 0, 2, 3, 4, 5]));
             }
 
             #[test]
-            fn zero_capacity_cannot_fail() {
+            fn remains_sorted_after_every_insertion() {
                 let mut actual = Dynamic::<usize>::default();
 
-                assert!(actual.reserve_back(0).is_ok());
+                // Multiplying by a value coprime to the length yields a permutation.
+                for value in (0..256).map(|index: usize| index.wrapping_mul(97) % 256) {
+                    let index = actual.binary_insert(value);
+
+                    assert_eq!(actual[index], value);
+                    assert!(actual.as_slice().is_sorted());
+                }
+
+                assert!(actual.into_iter().eq(0..256));
             }
 
             #[test]
-            fn zero_size_types_cannot_fail() {
-                let capacity = usize::try_from(isize::MAX).unwrap();
-
-                let mut actual = Dynamic::<()>::default();
+            fn inserts_after_equal_elements() {
+                /// Element ordered only by `key`, identified by `tag`.
+                #[derive(Debug)]
+                struct Keyed {
+                    /// The value which defines the ordering.
+                    key: usize,
 
-                assert!(actual.reserve_back(capacity).is_ok());
-            }
-        }
+                    /// Identifies the element, but does not affect ordering.
+                    tag: char,
+                }
 
-        mod shrink {
-            use super::*;
+                impl PartialEq for Keyed {
+                    fn eq(&self, other: &Self) -> bool {
+                        self.key == other.key
+                    }
+                }
 
-            #[test]
-            fn decreases_capacity_when_some() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+                impl Eq for Keyed {}
 
-                _ = actual.shrink(Some(64)).expect("successful reallocation");
+                impl PartialOrd for Keyed {
+                    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                        Some(self.cmp(other))
+                    }
+                }
 
-                assert_eq!(actual.capacity(), 64);
-            }
+                impl Ord for Keyed {
+                    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                        self.key.cmp(&other.key)
+                    }
+                }
 
-            #[test]
-            fn removes_capacity_when_none() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+                let mut actual = Dynamic::<Keyed>::default();
 
-                _ = actual.shrink(None).expect("successful reallocation");
+                for (key, tag) in [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd'), (1, 'e')] {
+                    _ = actual.binary_insert(Keyed { key, tag });
+                }
 
-                assert_eq!(actual.capacity(), 0);
+                assert!(actual
+                    .into_iter()
+                    .map(|element| element.tag)
+                    .eq(['b', 'd', 'a', 'c', 'e']));
             }
 
             #[test]
-            fn does_not_increase_capacity() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(64).expect("successful allocation");
+            #[cfg(debug_assertions)]
+            #[should_panic = "elements must be sorted"]
+            fn panics_when_unsorted() {
+                let mut actual = Dynamic::from_iter([3, 1, 2]);
 
-                assert!(actual.shrink(Some(256)).is_ok());
-                assert_eq!(actual.capacity(), 64);
+                _ = actual.binary_insert(0);
             }
+        }
 
-            #[test]
-            fn shrinks_front_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+        mod dedup {
+            use super::*;
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+            #[test]
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.shrink(None).expect("successful reallocation");
+                actual.dedup();
 
-                assert_eq!(actual.capacity_front(), 0);
+                assert_eq!(actual.len(), 0);
             }
 
             #[test]
-            fn shrinks_back_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn removes_consecutive_duplicates() {
+                let mut actual = Dynamic::from_iter([0, 0, 1, 2, 2, 2, 1]);
 
-                _ = actual.reserve_back(256).expect("successful allocation");
-
-                _ = actual.shrink(None).expect("successful reallocation");
+                actual.dedup();
 
-                assert_eq!(actual.capacity_back(), 0);
+                assert!(actual.into_iter().eq([0, 1, 2, 1]));
             }
 
             #[test]
-            fn shrinks_front_and_back_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn all_equal() {
+                let mut actual = Dynamic::from_iter([0; 256]);
 
-                _ = actual.reserve_front(256).expect("successful allocation");
-                _ = actual.reserve_back(256).expect("successful allocation");
+                actual.dedup();
 
-                _ = actual.shrink(None).expect("successful reallocation");
+                assert!(actual.into_iter().eq([0]));
+            }
+        }
 
-                assert_eq!(actual.capacity_front(), 0);
-                assert_eq!(actual.capacity_back(), 0);
+        mod dedup_sorted {
+            use super::*;
+
+            /// Element ordered by `key` which counts when it is dropped.
+            #[derive(Debug)]
+            struct Keyed {
+                /// The value which defines equality and ordering.
+                key: usize,
+
+                /// Counts the number of elements dropped.
+                _droppable: Droppable,
             }
 
-            #[test]
-            fn reallocates_memory() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            impl PartialEq for Keyed {
+                fn eq(&self, other: &Self) -> bool {
+                    self.key == other.key
+                }
+            }
 
-                _ = actual.shrink(Some(128)).expect("successful allocation");
+            impl Eq for Keyed {}
 
-                for index in 0..actual.capacity() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+            impl PartialOrd for Keyed {
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
+            impl Ord for Keyed {
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.key.cmp(&other.key)
                 }
             }
 
             #[test]
-            fn does_not_initialize_elements() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.shrink(Some(128)).expect("successful reallocation");
+                actual.dedup_sorted();
 
-                assert_eq!(actual.initialized, 0);
+                assert_eq!(actual.len(), 0);
             }
 
             #[test]
-            fn does_not_modify_initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+            fn removes_all_duplicates() {
+                let mut actual = Dynamic::from_iter([3, 1, 2, 3, 1]);
 
-                _ = actual.shrink(None).expect("successful reallocation");
+                actual.dedup_sorted();
 
-                assert!(actual.eq(expected));
+                assert!(actual.into_iter().eq([1, 2, 3]));
             }
 
             #[test]
-            fn zero_capacity_cannot_fail() {
-                let mut actual = Dynamic::<usize>::default();
+            fn unique_sorted_is_unchanged() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                assert!(actual.shrink(None).is_ok());
+                actual.dedup_sorted();
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
             }
 
             #[test]
-            fn zero_size_types_cannot_fail() {
-                let mut actual = Dynamic::<()>::with_capacity(256).expect("successful allocation");
+            fn drops_removed_duplicates() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
 
-                assert!(actual.shrink(None).is_ok());
+                let mut actual: Dynamic<_> = [3, 1, 2, 3, 1]
+                    .into_iter()
+                    .map(|key| Keyed {
+                        key,
+                        _droppable: Droppable {
+                            counter: alloc::rc::Rc::clone(&dropped),
+                        },
+                    })
+                    .collect();
+
+                actual.dedup_sorted();
+
+                assert_eq!(dropped.take(), 2);
+                assert!(actual.iter().map(|element| element.key).eq([1, 2, 3]));
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 3);
             }
         }
 
-        mod shrink_front {
+        mod dedup_by_hash {
             use super::*;
 
             #[test]
-            fn decreases_front_capacity_when_some() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-
-                _ = actual.reserve_front(256).expect("successful reallocation");
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
 
-                _ = actual
-                    .shrink_front(Some(64))
-                    .expect("successful reallocation");
+                actual.dedup_by_hash();
 
-                assert_eq!(actual.capacity_front(), 64);
+                assert_eq!(actual.len(), 0);
             }
 
             #[test]
-            fn removes_front_capacity_when_none() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-
-                _ = actual.reserve_front(256).expect("successful reallocation");
+            fn removes_all_duplicates() {
+                let mut actual = Dynamic::from_iter([3, 1, 3, 2, 1]);
 
-                _ = actual.shrink_front(None).expect("successful reallocation");
+                actual.dedup_by_hash();
 
-                assert_eq!(actual.capacity_front(), 0);
+                assert!(actual.into_iter().eq([3, 1, 2]));
             }
 
             #[test]
-            fn does_not_increase_capacity() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(64).expect("successful allocation");
+            fn preserves_order_of_first_occurrences() {
+                let mut actual: Dynamic<_> = (0..256).rev().chain(0..256).collect();
 
-                assert!(actual.shrink_front(Some(256)).is_ok());
-                assert_eq!(actual.capacity(), 64);
+                actual.dedup_by_hash();
+
+                assert!(actual.into_iter().eq((0..256).rev()));
             }
 
             #[test]
-            fn does_not_decrease_back_capacity_when_not_empty() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-
-                _ = actual.reserve_back(256).expect("successful allocation");
+            fn unique_is_unchanged() {
+                let mut actual = Dynamic::from_iter([5, 0, 4, 1, 3, 2]);
 
-                _ = actual.shrink_front(None).expect("no-op");
+                actual.dedup_by_hash();
 
-                assert_eq!(actual.capacity_back(), 256);
+                assert!(actual.into_iter().eq([5, 0, 4, 1, 3, 2]));
             }
 
             #[test]
-            fn decreases_back_capacity_when_empty() {
-                let mut actual = Dynamic::<usize>::default();
+            fn drops_removed_duplicates_once() {
+                /// Element identified by `key` which counts when it is dropped.
+                #[derive(Debug)]
+                struct Keyed {
+                    /// The value which defines equality and hashing.
+                    key: usize,
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                    /// Counts the number of elements dropped.
+                    _droppable: Droppable,
+                }
 
-                _ = actual.shrink_front(None).expect("successful deallocation");
+                impl PartialEq for Keyed {
+                    fn eq(&self, other: &Self) -> bool {
+                        self.key == other.key
+                    }
+                }
 
-                assert_eq!(actual.capacity_back(), 0);
-            }
+                impl Eq for Keyed {}
 
-            #[test]
-            fn reallocates_memory() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+                impl core::hash::Hash for Keyed {
+                    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                        self.key.hash(state);
+                    }
+                }
 
-                _ = actual
-                    .shrink_front(Some(128))
-                    .expect("successful allocation");
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
 
-                for index in 0..actual.capacity_front() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+                let mut actual: Dynamic<_> = [3, 1, 3, 2, 1]
+                    .into_iter()
+                    .map(|key| Keyed {
+                        key,
+                        _droppable: Droppable {
+                            counter: alloc::rc::Rc::clone(&dropped),
+                        },
+                    })
+                    .collect();
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
-                }
+                actual.dedup_by_hash();
+
+                assert_eq!(dropped.take(), 2);
+                assert!(actual.iter().map(|element| element.key).eq([3, 1, 2]));
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 3);
             }
+        }
+
+        mod union_sorted {
+            use super::*;
 
             #[test]
-            fn does_not_initialize_elements() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn empty() {
+                let left = Dynamic::<usize>::default();
+                let right = Dynamic::<usize>::default();
 
-                _ = actual
-                    .shrink_front(Some(128))
-                    .expect("successful reallocation");
+                assert_eq!(left.union_sorted(&right).len(), 0);
+            }
 
-                assert_eq!(actual.initialized, 0);
+            #[test]
+            fn one_empty() {
+                let left = Dynamic::from_iter([0, 1, 2]);
+                let right = Dynamic::default();
+
+                assert!(left.union_sorted(&right).into_iter().eq([0, 1, 2]));
+                assert!(right.union_sorted(&left).into_iter().eq([0, 1, 2]));
             }
 
             #[test]
-            fn does_not_modify_initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+            fn overlapping() {
+                let left = Dynamic::from_iter([0, 2, 4, 6]);
+                let right = Dynamic::from_iter([3, 4, 5, 6, 7]);
 
-                _ = actual.shrink_front(None).expect("successful reallocation");
+                assert!(left
+                    .union_sorted(&right)
+                    .into_iter()
+                    .eq([0, 2, 3, 4, 5, 6, 7]));
+            }
 
-                assert!(actual.eq(expected));
+            #[test]
+            fn disjoint() {
+                let left = Dynamic::from_iter([0, 2, 4]);
+                let right = Dynamic::from_iter([1, 3, 5]);
+
+                assert!(left.union_sorted(&right).into_iter().eq([0, 1, 2, 3, 4, 5]));
             }
 
             #[test]
-            fn zero_capacity_cannot_fail() {
-                let mut actual = Dynamic::<usize>::default();
+            fn duplicates_occur_maximum_times() {
+                let left = Dynamic::from_iter([0, 0, 1, 2, 2, 2]);
+                let right = Dynamic::from_iter([0, 1, 1, 2]);
 
-                assert!(actual.shrink_front(None).is_ok());
+                assert!(left
+                    .union_sorted(&right)
+                    .into_iter()
+                    .eq([0, 0, 1, 1, 2, 2, 2]));
             }
 
             #[test]
-            fn zero_size_types_cannot_fail() {
-                let mut actual = Dynamic::<()>::with_capacity(256).expect("successful allocation");
+            fn does_not_modify_inputs() {
+                let left = Dynamic::from_iter([0, 2, 4]);
+                let right = Dynamic::from_iter([1, 2, 3]);
 
-                assert!(actual.shrink_front(None).is_ok());
+                drop(left.union_sorted(&right));
+
+                assert!(left.into_iter().eq([0, 2, 4]));
+                assert!(right.into_iter().eq([1, 2, 3]));
             }
         }
 
-        mod shrink_back {
+        mod intersection_sorted {
             use super::*;
 
             #[test]
-            fn decreases_back_capacity_when_some() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn empty() {
+                let left = Dynamic::<usize>::default();
+                let right = Dynamic::from_iter([0, 1, 2]);
 
-                _ = actual.reserve_back(256).expect("successful reallocation");
+                assert_eq!(left.intersection_sorted(&right).len(), 0);
+                assert_eq!(right.intersection_sorted(&left).len(), 0);
+            }
 
-                _ = actual
-                    .shrink_back(Some(64))
-                    .expect("successful reallocation");
+            #[test]
+            fn overlapping() {
+                let left = Dynamic::from_iter([0, 2, 4, 6]);
+                let right = Dynamic::from_iter([3, 4, 5, 6, 7]);
 
-                assert_eq!(actual.capacity_back(), 64);
+                assert!(left.intersection_sorted(&right).into_iter().eq([4, 6]));
             }
 
             #[test]
-            fn removes_back_capacity_when_none() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn disjoint() {
+                let left = Dynamic::from_iter([0, 2, 4]);
+                let right = Dynamic::from_iter([1, 3, 5]);
 
-                _ = actual.reserve_back(256).expect("successful reallocation");
+                assert_eq!(left.intersection_sorted(&right).len(), 0);
+            }
 
-                _ = actual.shrink_back(None).expect("successful reallocation");
+            #[test]
+            fn duplicates_occur_minimum_times() {
+                let left = Dynamic::from_iter([0, 0, 1, 2, 2, 2]);
+                let right = Dynamic::from_iter([0, 1, 1, 2, 2]);
 
-                assert_eq!(actual.capacity_back(), 0);
+                assert!(left
+                    .intersection_sorted(&right)
+                    .into_iter()
+                    .eq([0, 1, 2, 2]));
             }
+        }
+
+        mod difference_sorted {
+            use super::*;
 
             #[test]
-            fn does_not_increase_capacity() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(64).expect("successful allocation");
+            fn empty() {
+                let left = Dynamic::<usize>::default();
+                let right = Dynamic::from_iter([0, 1, 2]);
 
-                assert!(actual.shrink_back(Some(256)).is_ok());
-                assert_eq!(actual.capacity(), 64);
+                assert_eq!(left.difference_sorted(&right).len(), 0);
+                assert!(right.difference_sorted(&left).into_iter().eq([0, 1, 2]));
             }
 
             #[test]
-            fn does_not_decrease_front_capacity_when_not_empty() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn overlapping() {
+                let left = Dynamic::from_iter([0, 2, 4, 6]);
+                let right = Dynamic::from_iter([3, 4, 5, 6, 7]);
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                assert!(left.difference_sorted(&right).into_iter().eq([0, 2]));
+                assert!(right.difference_sorted(&left).into_iter().eq([3, 5, 7]));
+            }
 
-                _ = actual.shrink_back(None).expect("no-op");
+            #[test]
+            fn disjoint() {
+                let left = Dynamic::from_iter([0, 2, 4]);
+                let right = Dynamic::from_iter([1, 3, 5]);
 
-                assert_eq!(actual.capacity_front(), 256);
+                assert!(left.difference_sorted(&right).into_iter().eq([0, 2, 4]));
             }
 
             #[test]
-            fn decreases_front_capacity_when_empty() {
-                let mut actual = Dynamic::<usize>::default();
+            fn duplicates_occur_remaining_times() {
+                let left = Dynamic::from_iter([0, 0, 1, 2, 2, 2]);
+                let right = Dynamic::from_iter([0, 1, 1, 2]);
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                assert!(left.difference_sorted(&right).into_iter().eq([0, 2, 2]));
+            }
+        }
 
-                _ = actual.shrink_back(None).expect("successful deallocation");
+        mod split_mut {
+            use super::*;
 
-                assert_eq!(actual.capacity_front(), 0);
+            #[test]
+            #[should_panic = "count must be non-zero"]
+            fn panics_when_count_is_zero() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                drop(actual.split_mut(0));
             }
 
             #[test]
-            fn reallocates_memory() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
 
-                _ = actual
-                    .shrink_back(Some(128))
-                    .expect("successful allocation");
+                assert_eq!(actual.split_mut(4).len(), 0);
+            }
 
-                for index in 0..actual.capacity_back() {
-                    let ptr = unsafe { actual.buffer.as_ptr().add(index) };
+            #[test]
+            fn concatenate_to_every_element() {
+                let mut actual: Dynamic<_> = (0..256).collect();
 
-                    // Ideally, this will seg-fault if unowned memory.
-                    _ = unsafe { &mut *ptr }.write(index);
+                for count in [1, 2, 3, 7, 255, 256] {
+                    let slices = actual.split_mut(count);
+
+                    assert_eq!(slices.len(), count);
+                    assert!(slices
+                        .iter()
+                        .flat_map(|slice| slice.iter())
+                        .copied()
+                        .eq(0..256));
                 }
             }
 
             #[test]
-            fn does_not_initialize_elements() {
-                let mut actual =
-                    Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+            fn lengths_differ_by_at_most_one() {
+                let mut actual: Dynamic<_> = (0..10).collect();
 
-                _ = actual
-                    .shrink_back(Some(128))
-                    .expect("successful reallocation");
+                let slices = actual.split_mut(4);
 
-                assert_eq!(actual.initialized, 0);
+                assert!(slices.iter().map(|slice| slice.len()).eq([3, 3, 2, 2]));
             }
 
             #[test]
-            fn does_not_modify_initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+            fn one_element_per_slice_when_count_exceeds_length() {
+                let mut actual: Dynamic<_> = (0..6).collect();
 
-                _ = actual.shrink_back(None).expect("successful reallocation");
+                let slices = actual.split_mut(256);
 
-                assert!(actual.eq(expected));
+                assert_eq!(slices.len(), 6);
+                assert!(slices.iter().all(|slice| slice.len() == 1));
             }
 
             #[test]
-            fn zero_capacity_cannot_fail() {
-                let mut actual = Dynamic::<usize>::default();
+            fn writes_are_independent() {
+                let mut actual: Dynamic<_> = (0..6).collect();
 
-                assert!(actual.shrink_back(None).is_ok());
+                for (index, slice) in actual.split_mut(3).into_iter().enumerate() {
+                    for element in slice.iter_mut() {
+                        *element += index * 10;
+                    }
+                }
+
+                assert!(actual.into_iter().eq([0, 1, 12, 13, 24, 25]));
             }
 
             #[test]
-            fn zero_size_types_cannot_fail() {
-                let mut actual = Dynamic::<()>::with_capacity(256).expect("successful allocation");
+            fn slices_are_usable_from_separate_threads() {
+                let mut actual: Dynamic<_> = (0..256).collect();
 
-                assert!(actual.shrink_back(None).is_ok());
+                std::thread::scope(|scope| {
+                    for slice in actual.split_mut(4) {
+                        drop(
+                            scope.spawn(move || slice.iter_mut().for_each(|element| *element *= 2)),
+                        );
+                    }
+                });
+
+                assert!(actual.into_iter().eq((0..256).map(|element| element * 2)));
             }
         }
 
-        mod shift {
+        mod get_many_mut {
             use super::*;
 
             #[test]
-            fn left_increases_back_capacity_and_decreases_front_capacity() {
+            fn disjoint_references_are_mutable() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-                _ = actual.reserve_front(256).expect("successful allocation");
 
-                for _ in 0..256 {
-                    let front_capacity = actual.front_capacity;
-                    let back_capacity = actual.back_capacity;
+                let [first, second] = actual.get_many_mut([4, 1]).unwrap();
 
-                    assert!(actual.shift(-1).is_ok());
+                assert_eq!((*first, *second), (4, 1));
 
-                    assert_eq!(actual.front_capacity, front_capacity - 1);
-                    assert_eq!(actual.back_capacity, back_capacity + 1);
-                }
+                *first = 12345;
+                *second = 54321;
+
+                assert!(actual.into_iter().eq([0, 54321, 2, 3, 12345, 5]));
             }
 
             #[test]
-            fn left_does_not_modify_initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let mut actual = Dynamic::from_iter(expected);
-                _ = actual.reserve_front(256).expect("successful allocation");
+            fn every_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                for _ in 0..256 {
-                    assert!(actual.shift(-1).is_ok());
+                let references = actual.get_many_mut([5, 4, 3, 2, 1, 0]).unwrap();
 
-                    assert!(actual.iter().eq(expected.iter()));
+                for (index, reference) in references.into_iter().enumerate() {
+                    assert_eq!(*reference, 5 - index);
+
+                    *reference = index;
                 }
+
+                assert!(actual.into_iter().eq([5, 4, 3, 2, 1, 0]));
             }
 
             #[test]
-            fn right_increases_front_capacity_and_decreases_back_capacity() {
+            fn none_when_indexes_are_duplicated() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-                _ = actual.reserve_back(256).expect("successful allocation");
-
-                for _ in 0..256 {
-                    let front_capacity = actual.front_capacity;
-                    let back_capacity = actual.back_capacity;
-
-                    assert!(actual.shift(1).is_ok());
 
-                    assert_eq!(actual.front_capacity, front_capacity + 1);
-                    assert_eq!(actual.back_capacity, back_capacity - 1);
-                }
+                assert!(actual.get_many_mut([1, 1]).is_none());
+                assert!(actual.get_many_mut([0, 2, 0]).is_none());
             }
 
             #[test]
-            fn right_does_not_modify_initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let mut actual = Dynamic::from_iter(expected);
-                _ = actual.reserve_back(256).expect("successful allocation");
-
-                for _ in 0..256 {
-                    assert!(actual.shift(1).is_ok());
+            fn none_when_index_is_out_of_bounds() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                    assert!(actual.iter().eq(expected.iter()));
-                }
+                assert!(actual.get_many_mut([0, 6]).is_none());
+                assert!(actual.get_many_mut([usize::MAX]).is_none());
             }
 
             #[test]
-            fn zero_cannot_fail() {
+            fn no_indexes() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                assert!(actual.shift(0).is_ok());
+                assert!(actual.get_many_mut([]).is_some());
             }
 
             #[test]
-            fn errors_when_out_of_bounds() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn empty() {
+                let mut actual = Dynamic::<usize>::default();
 
-                assert!(actual.shift(-1).is_err());
-                assert!(actual.shift(1).is_err());
+                assert!(actual.get_many_mut([]).is_some());
+                assert!(actual.get_many_mut([0]).is_none());
             }
 
             #[test]
-            fn when_empty() {
-                let mut actual = Dynamic::<()>::default();
+            fn ignores_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
 
-                assert!(actual.shift(0).is_ok());
+                assert!(actual.reserve_front(4).is_ok());
+                assert!(actual.reserve_back(4).is_ok());
+
+                let [first, last] = actual.get_many_mut([0, 2]).unwrap();
+
+                assert_eq!((*first, *last), (0, 2));
+                assert!(actual.get_many_mut([0, 3]).is_none());
             }
         }
 
-        mod remove_via_front {
+        mod as_ptr_range {
             use super::*;
 
             #[test]
-            fn yields_none_when_out_of_bounds() {
-                let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-
-                let actual = underlying.remove_via_front(underlying.len());
+            fn starts_at_first_element() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                assert_eq!(actual, None);
+                assert_eq!(actual.as_ptr_range().start, actual.as_ptr());
             }
 
             #[test]
-            fn yields_element_when_in_bounds() {
-                let underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn length_is_number_of_elements() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                for index in 1..underlying.len() {
-                    let mut underlying = underlying.clone();
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
 
-                    let actual = underlying.remove_via_front(index);
+                let range = actual.as_ptr_range();
 
-                    assert_eq!(actual, Some(index));
-                }
+                let len = unsafe { range.end.offset_from(range.start) };
+
+                assert_eq!(usize::try_from(len), Ok(actual.len()));
             }
 
             #[test]
-            fn removed_becomes_first_element() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn empty_without_allocation() {
+                let actual = Dynamic::<usize>::default();
 
-                _ = actual.remove_via_front(3).expect("element with value '3'");
+                let range = actual.as_ptr_range();
 
-                assert_eq!(actual[2], 0);
+                assert_eq!(range.start, range.end);
             }
 
             #[test]
-            fn does_not_modify_other_elements() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn empty_with_allocation() {
+                let actual = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
 
-                _ = actual.remove_via_front(1);
+                let range = actual.as_ptr_range();
 
-                assert!(actual.eq([0, 2, 3, 4, 5]));
+                assert_eq!(range.start, actual.as_ptr());
+                assert_eq!(range.start, range.end);
             }
+        }
+
+        mod as_mut_ptr_range {
+            use super::*;
 
             #[test]
-            fn increases_front_capacity() {
+            fn starts_at_first_element() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                _ = actual.remove_via_front(5);
+                let expected = actual.as_mut_ptr();
 
-                assert_eq!(actual.capacity_front(), 1);
+                assert_eq!(actual.as_mut_ptr_range().start, expected);
             }
 
             #[test]
-            fn when_front_element() {
+            fn length_is_number_of_elements() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                let removed = actual.remove_via_front(0);
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
 
-                assert_eq!(removed, Some(0));
-                assert_eq!(actual.capacity_front(), 1);
-                assert!(actual.eq([1, 2, 3, 4, 5]));
+                let range = actual.as_mut_ptr_range();
+
+                let len = unsafe { range.end.offset_from(range.start) };
+
+                assert_eq!(usize::try_from(len), Ok(actual.len()));
             }
 
             #[test]
-            fn when_only_one_element() {
-                let mut actual = Dynamic::from_iter([0]);
+            fn empty_without_allocation() {
+                let mut actual = Dynamic::<usize>::default();
 
-                let removed = actual.remove_via_front(0);
+                let range = actual.as_mut_ptr_range();
 
-                assert_eq!(removed, Some(0));
-                assert_eq!(actual.capacity_front(), 1);
-                assert_eq!(actual.len(), 0);
+                assert_eq!(range.start, range.end);
             }
         }
 
-        mod remove_via_back {
+        mod as_bytes {
             use super::*;
 
             #[test]
-            fn yields_none_when_out_of_bounds() {
-                let mut underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn empty() {
+                let actual = Dynamic::<u32>::default();
 
-                let actual = underlying.remove_via_back(underlying.len());
+                assert!(actual.as_bytes().is_empty());
+            }
 
-                assert_eq!(actual, None);
+            #[test]
+            fn length_is_size_of_elements() {
+                let actual = Dynamic::from_iter([0_u32, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.as_bytes().len(), actual.len() * size_of::<u32>());
             }
 
             #[test]
-            fn yields_element_when_in_bounds() {
-                let underlying = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn native_endian_representation() {
+                let actual = Dynamic::from_iter([0x0102_0304_u32, 0x0506_0708]);
 
-                for index in 1..underlying.len() {
-                    let mut underlying = underlying.clone();
+                let expected: Dynamic<u8> = [0x0102_0304_u32, 0x0506_0708]
+                    .iter()
+                    .flat_map(|element| element.to_ne_bytes())
+                    .collect();
 
-                    let actual = underlying.remove_via_back(index);
+                assert_eq!(actual.as_bytes(), expected.as_slice());
+            }
 
-                    assert_eq!(actual, Some(index));
-                }
+            #[test]
+            fn excludes_capacity() {
+                let mut actual = Dynamic::from_iter([0_u32, 1, 2]);
+
+                assert!(actual.reserve_front(4).is_ok());
+                assert!(actual.reserve_back(4).is_ok());
+
+                assert_eq!(actual.as_bytes().len(), 3 * size_of::<u32>());
             }
+        }
+
+        mod from_bytes {
+            use super::*;
 
             #[test]
-            fn removed_becomes_last_element() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn empty() {
+                let actual = Dynamic::<u32>::from_bytes(&[]).unwrap();
 
-                _ = actual.remove_via_back(3).expect("element with value '3'");
+                assert_eq!(actual.len(), 0);
+            }
 
-                assert_eq!(actual[3], 5);
+            #[test]
+            fn round_trip() {
+                let expected = Dynamic::from_iter([0_u32, 1, u32::MAX, 0xDEAD_BEEF]);
+
+                let actual = Dynamic::<u32>::from_bytes(expected.as_bytes()).unwrap();
+
+                assert_eq!(actual, expected);
             }
 
             #[test]
-            fn does_not_modify_other_elements() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn round_trip_floating_point() {
+                let expected = Dynamic::from_iter([0.0_f64, -1.5, f64::MAX, f64::MIN_POSITIVE]);
 
-                _ = actual.remove_via_back(4);
+                let actual = Dynamic::<f64>::from_bytes(expected.as_bytes()).unwrap();
 
-                assert!(actual.eq([0, 1, 2, 3, 5]));
+                assert!(actual
+                    .iter()
+                    .map(|element| element.to_bits())
+                    .eq(expected.iter().map(|element| element.to_bits())));
             }
 
             #[test]
-            fn increases_back_capacity() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn rejects_partial_element() {
+                let elements = Dynamic::from_iter([0_u32, 1]);
 
-                _ = actual.remove_via_back(0);
+                let bytes = &elements.as_bytes()[..7];
 
-                assert_eq!(actual.capacity_back(), 1);
+                assert_eq!(
+                    Dynamic::<u32>::from_bytes(bytes),
+                    Err(FromBytesError::InvalidLength)
+                );
             }
 
             #[test]
-            fn when_back_element() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+            fn rejects_misaligned() {
+                let elements = Dynamic::from_iter([0_u32, 1, 2]);
 
-                let removed = actual.remove_via_back(5);
+                // The buffer is aligned for `u32`, so offset by one byte is not.
+                let bytes = &elements.as_bytes()[1..9];
 
-                assert_eq!(removed, Some(5));
-                assert_eq!(actual.capacity_back(), 1);
-                assert!(actual.eq([0, 1, 2, 3, 4]));
+                assert_eq!(
+                    Dynamic::<u32>::from_bytes(bytes),
+                    Err(FromBytesError::Misaligned)
+                );
             }
 
             #[test]
-            fn when_only_one_element() {
-                let mut actual = Dynamic::from_iter([0]);
+            fn bytes_have_no_alignment_requirement() {
+                let elements = Dynamic::from_iter([0_u32, 1, 2]);
 
-                let removed = actual.remove_via_back(0);
+                let bytes = &elements.as_bytes()[1..9];
 
-                assert_eq!(removed, Some(0));
-                assert_eq!(actual.capacity_back(), 1);
-                assert_eq!(actual.len(), 0);
+                assert!(Dynamic::<u8>::from_bytes(bytes)
+                    .unwrap()
+                    .into_iter()
+                    .eq(bytes.iter().copied()));
             }
         }
 
-        mod resize {
+        mod reallocate {
             use super::*;
 
             #[test]
             fn does_not_initialize_elements() {
                 let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.resize(256).expect("successful allocation");
+                _ = actual.reallocate(256).expect("successful allocation");
 
                 assert_eq!(actual.initialized, 0);
             }
@@ -3848,7 +11176,7 @@ mod test {
             fn increases_back_capacity() {
                 let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.resize(256).expect("successful allocation");
+                _ = actual.reallocate(256).expect("successful allocation");
 
                 assert_eq!(actual.back_capacity, 256);
             }
@@ -3857,7 +11185,7 @@ mod test {
             fn does_not_increase_front_capacity() {
                 let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.resize(256).expect("successful allocation");
+                _ = actual.reallocate(256).expect("successful allocation");
 
                 assert_eq!(actual.front_capacity, 0);
             }
@@ -3867,7 +11195,7 @@ mod test {
                 let mut actual =
                     Dynamic::<usize>::with_capacity(256).expect("successful allocation");
 
-                _ = actual.resize(-128).expect("successful allocation");
+                _ = actual.reallocate(-128).expect("successful allocation");
 
                 assert_eq!(actual.back_capacity, 128);
             }
@@ -3877,7 +11205,7 @@ mod test {
                 let mut actual =
                     Dynamic::<usize>::with_capacity(256).expect("successful allocation");
 
-                _ = actual.resize(-128).expect("successful allocation");
+                _ = actual.reallocate(-128).expect("successful allocation");
 
                 assert_eq!(actual.front_capacity, 0);
             }
@@ -3889,7 +11217,7 @@ mod test {
                 for elements in 1..=actual.initialized {
                     let elements = isize::try_from(elements).unwrap();
 
-                    assert!(actual.resize(-elements).is_err());
+                    assert!(actual.reallocate(-elements).is_err());
                 }
             }
 
@@ -3897,7 +11225,7 @@ mod test {
             fn allocates_memory() {
                 let mut actual = Dynamic::<usize>::default();
 
-                _ = actual.resize(256).expect("successful allocation");
+                _ = actual.reallocate(256).expect("successful allocation");
 
                 for index in 0..actual.capacity_back() {
                     let ptr = unsafe { actual.buffer.as_ptr().add(index) };
@@ -3912,7 +11240,7 @@ mod test {
                 let mut actual =
                     Dynamic::<usize>::with_capacity(256).expect("successful allocation");
 
-                _ = actual.resize(-128).expect("successful reallocation");
+                _ = actual.reallocate(-128).expect("successful reallocation");
 
                 for index in 0..actual.capacity_back() {
                     let ptr = unsafe { actual.buffer.as_ptr().add(index) };
@@ -3927,7 +11255,7 @@ mod test {
                 let expected = [0, 1, 2, 3, 4, 5];
                 let mut actual: Dynamic<_> = expected.iter().copied().collect();
 
-                _ = actual.resize(128).expect("successful reallocation");
+                _ = actual.reallocate(128).expect("successful reallocation");
 
                 for index in 0..expected.len() {
                     assert_eq!(actual[index], expected[index]);
@@ -3938,15 +11266,15 @@ mod test {
             fn zero_capacity_cannot_fail() {
                 let mut actual = Dynamic::<usize>::default();
 
-                assert!(actual.resize(0).is_ok());
+                assert!(actual.reallocate(0).is_ok());
             }
 
             #[test]
             fn zero_size_types_cannot_fail() {
                 let mut actual = Dynamic::<()>::with_capacity(256).expect("successful allocation");
 
-                assert!(actual.resize(128).is_ok());
-                assert!(actual.resize(-128).is_ok());
+                assert!(actual.reallocate(128).is_ok());
+                assert!(actual.reallocate(-128).is_ok());
             }
         }
     }
@@ -4028,6 +11356,18 @@ mod test {
 
             assert_eq!(dropped.take(), ELEMENTS);
         }
+
+        #[test]
+        fn drops_every_element_when_destructor_panics() {
+            let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+            let actual = panic_on_drop(&dropped, 6, 2);
+
+            let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| drop(actual)));
+
+            assert!(result.is_err());
+            assert_eq!(dropped.take(), 6);
+        }
     }
 
     mod try_from {
@@ -4157,28 +11497,87 @@ mod test {
                 self.data.next()
             }
 
-            fn size_hint(&self) -> (usize, Option<usize>) {
-                (usize::MAX, Some(usize::MAX))
-            }
-        }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (usize::MAX, Some(usize::MAX))
+            }
+        }
+
+        mod into {
+            use super::*;
+
+            #[test]
+            fn element_count() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let actual: Dynamic<_> = expected.iter().copied().collect();
+
+                assert_eq!(actual.into_iter().count(), expected.len());
+            }
+
+            #[test]
+            fn in_order() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let actual: Dynamic<_> = expected.iter().copied().collect();
+
+                assert!(actual.into_iter().eq(expected.into_iter()));
+            }
+
+            #[test]
+            fn for_loop() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let mut expected = 0..6;
+
+                for element in actual {
+                    assert_eq!(Some(element), expected.next());
+                }
+
+                assert_eq!(expected.next(), None);
+            }
+
+            #[test]
+            fn by_reference() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let mut expected = actual.iter();
 
-        mod into {
-            use super::*;
+                for element in &actual {
+                    assert_eq!(Some(element), expected.next());
+                }
+
+                assert_eq!(expected.next(), None);
+            }
 
             #[test]
-            fn element_count() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let actual: Dynamic<_> = expected.iter().copied().collect();
+            fn drops_yet_to_be_yielded_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
 
-                assert_eq!(actual.into_iter().count(), expected.len());
+                let actual: Dynamic<_> = (0..6)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&dropped),
+                    })
+                    .collect();
+
+                let mut actual = actual.into_iter();
+
+                drop(actual.next());
+                drop(actual.next_back());
+
+                assert_eq!(dropped.take(), 2);
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 4);
             }
 
             #[test]
-            fn in_order() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let actual: Dynamic<_> = expected.iter().copied().collect();
+            fn is_not_an_iterator_itself() {
+                let actual = Dynamic::from_iter([0, 1, 2]);
 
-                assert!(actual.into_iter().eq(expected.into_iter()));
+                // Mapping must be explicit, hence the original is not consumed.
+                let doubled: Dynamic<_> = actual.iter().map(|element| element * 2).collect();
+
+                assert_eq!(actual, Dynamic::from_iter([0, 1, 2]));
+                assert_eq!(doubled, Dynamic::from_iter([0, 2, 4]));
             }
 
             mod double_ended {
@@ -4225,7 +11624,8 @@ mod test {
 
                 #[test]
                 fn updates() {
-                    let mut actual: Dynamic<_> = [0, 1, 2, 3, 4, 5].into_iter().collect();
+                    let actual: Dynamic<_> = [0, 1, 2, 3, 4, 5].into_iter().collect();
+                    let mut actual = actual.into_iter();
 
                     for remaining in (0..actual.len()).rev() {
                         _ = actual.next();
@@ -4340,6 +11740,55 @@ mod test {
 
                 assert_eq!(actual.initialized, expected.len());
             }
+
+            #[test]
+            fn collects_ok_results() {
+                let actual: Result<Dynamic<_>, ()> =
+                    [0, 1, 2, 3, 4, 5].into_iter().map(Ok).collect();
+
+                assert!(actual.is_ok_and(|actual| actual.into_iter().eq([0, 1, 2, 3, 4, 5])));
+            }
+
+            #[test]
+            fn yields_first_error() {
+                let actual: Result<Dynamic<usize>, _> =
+                    [Ok(0), Err(1), Ok(2), Err(3)].into_iter().collect();
+
+                assert_eq!(actual.err(), Some(1));
+            }
+
+            #[test]
+            fn stops_at_first_error() {
+                let mut consumed = 0;
+
+                let actual: Result<Dynamic<usize>, _> = [Ok(0), Err(1), Ok(2), Err(3)]
+                    .into_iter()
+                    .inspect(|_| consumed += 1)
+                    .collect();
+
+                assert!(actual.is_err());
+                assert_eq!(consumed, 2);
+            }
+
+            #[test]
+            fn drops_collected_elements_upon_error() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let actual: Result<Dynamic<_>, ()> = (0..6)
+                    .map(|index| {
+                        if index < 3 {
+                            Ok(Droppable {
+                                counter: alloc::rc::Rc::clone(&dropped),
+                            })
+                        } else {
+                            Err(())
+                        }
+                    })
+                    .collect();
+
+                assert!(actual.is_err());
+                assert_eq!(dropped.take(), 3);
+            }
         }
 
         mod extend {
@@ -4445,7 +11894,7 @@ mod test {
                 let expected = [3, 4, 5];
                 actual.extend(expected.iter().copied());
 
-                for (actual, expected) in actual.skip(preexisting.len()).zip(expected) {
+                for (actual, expected) in actual.into_iter().skip(preexisting.len()).zip(expected) {
                     assert_eq!(actual, expected);
                 }
             }
@@ -4470,14 +11919,14 @@ mod test {
 
                 actual.extend(expected.iter().copied());
 
-                assert!(actual.eq(expected));
+                assert!(actual.into_iter().eq(expected));
             }
 
             #[test]
             fn from_empty_iterator() {
                 let mut actual = Dynamic::<()>::default();
 
-                actual.extend(core::iter::empty());
+                actual.extend(core::iter::empty::<()>());
 
                 assert_eq!(actual.front_capacity, 0);
                 assert_eq!(actual.initialized, 0);
@@ -4496,6 +11945,104 @@ mod test {
                 });
             }
         }
+
+        mod extend_borrowed {
+            use super::*;
+
+            #[test]
+            fn clones_elements_from_slice() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = Dynamic::<usize>::default();
+
+                actual.extend(expected.as_slice().iter());
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn clones_elements_from_collection() {
+                let source: Dynamic<_> = [3, 4, 5].into_iter().collect();
+
+                let mut actual: Dynamic<_> = [0, 1, 2].into_iter().collect();
+
+                actual.extend(source.iter());
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+                assert!(source.into_iter().eq([3, 4, 5]));
+            }
+
+            #[test]
+            fn reserves_once_for_exact_size() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = Dynamic::<usize>::default();
+
+                actual.extend(expected.iter());
+
+                assert_eq!(actual.initialized, expected.len());
+                assert_eq!(actual.back_capacity, 0);
+            }
+        }
+
+        mod sum {
+            use super::*;
+
+            #[test]
+            fn concatenates_in_order() {
+                let instances = [
+                    Dynamic::from_iter([0, 1]),
+                    Dynamic::from_iter([2]),
+                    Dynamic::from_iter([3, 4, 5]),
+                ];
+
+                let actual: Dynamic<_> = instances.into_iter().sum();
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn skips_empty_instances() {
+                let instances = [
+                    Dynamic::default(),
+                    Dynamic::from_iter([0, 1]),
+                    Dynamic::default(),
+                    Dynamic::from_iter([2]),
+                ];
+
+                let actual: Dynamic<_> = instances.into_iter().sum();
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn empty_when_no_instances() {
+                let actual: Dynamic<usize> = core::iter::empty::<Dynamic<_>>().sum();
+
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn does_not_drop_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let instances = [0, 1, 2].map(|_| -> Dynamic<_> {
+                    (0..2)
+                        .map(|_| Droppable {
+                            counter: alloc::rc::Rc::clone(&dropped),
+                        })
+                        .collect()
+                });
+
+                let actual: Dynamic<_> = instances.into_iter().sum();
+
+                assert_eq!(dropped.take(), 0);
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 6);
+            }
+        }
     }
 
     mod default {
@@ -4626,77 +12173,319 @@ mod test {
             let second: Dynamic<_> = expected.iter().copied().collect();
             let third: Dynamic<_> = expected.iter().copied().collect();
 
-            // `first == second && second == third` => `first == third`
-            assert_eq!(first, second);
-            assert_eq!(second, third);
-            assert_eq!(third, first);
+            // `first == second && second == third` => `first == third`
+            assert_eq!(first, second);
+            assert_eq!(second, third);
+            assert_eq!(third, first);
+        }
+
+        #[test]
+        fn is_reflexive() {
+            let actual = Dynamic::<()>::default();
+
+            assert_eq!(actual, actual);
+        }
+    }
+
+    mod fmt {
+        use super::*;
+
+        mod debug {
+            use super::*;
+
+            #[test]
+            fn is_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let actual: Dynamic<_> = expected.iter().copied().collect();
+
+                assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+            }
+        }
+
+        mod write {
+            use super::*;
+            use core::fmt::Write;
+
+            #[test]
+            fn appends_utf8_bytes() {
+                let mut actual = Dynamic::<u8>::default();
+
+                let text = "text";
+
+                assert!(write!(actual, "{text}, {:03}, {:.1}", 7, 1.25).is_ok());
+
+                assert!(actual.into_iter().eq(*b"text, 007, 1.2"));
+            }
+
+            #[test]
+            fn appends_after_existing_bytes() {
+                let mut actual = Dynamic::from_iter(*b"prefix ");
+
+                assert!(write!(actual, "\u{e9}").is_ok());
+
+                assert!(actual.into_iter().eq("prefix \u{e9}".bytes()));
+            }
+
+            #[test]
+            fn appends_after_emptied_from_both_ends() {
+                let mut actual = Dynamic::from_iter(*b"string");
+
+                for _ in 0..3 {
+                    assert!(actual.pop_front().is_some());
+                    assert!(actual.pop_back().is_some());
+                }
+
+                assert!(actual.write_str("text").is_ok());
+
+                assert!(actual.into_iter().eq(*b"text"));
+            }
+
+            #[test]
+            fn grows_capacity_amortized() {
+                let mut actual = Dynamic::<u8>::default();
+
+                assert!(actual.write_str("0123456789").is_ok());
+
+                let ptr = actual.as_ptr();
+
+                assert_eq!(actual.total_capacity(), 16);
+
+                assert!(actual.write_str("012345").is_ok());
+
+                assert_eq!(actual.as_ptr(), ptr);
+                assert_eq!(actual.capacity(), 0);
+            }
+        }
+    }
+
+    mod io {
+        use super::*;
+
+        mod write {
+            use super::*;
+            use std::io::Write;
+
+            #[test]
+            fn appends_bytes() {
+                let mut actual = Dynamic::<u8>::default();
+
+                assert_eq!(actual.write(b"bytes").ok(), Some(5));
+                assert!(actual.write_all(b" and more").is_ok());
+
+                assert!(actual.into_iter().eq(*b"bytes and more"));
+            }
+
+            #[test]
+            fn formats_bytes() {
+                let mut actual = Dynamic::<u8>::default();
+
+                assert!(write!(actual, "{}-{}", 0, 1).is_ok());
+
+                assert!(actual.into_iter().eq(*b"0-1"));
+            }
+
+            #[test]
+            fn appends_after_emptied_from_both_ends() {
+                let mut actual = Dynamic::from_iter(*b"string");
+
+                for _ in 0..3 {
+                    assert!(actual.pop_front().is_some());
+                    assert!(actual.pop_back().is_some());
+                }
+
+                assert!(actual.write_all(b"bytes").is_ok());
+
+                assert!(actual.into_iter().eq(*b"bytes"));
+            }
+
+            #[test]
+            fn flush_does_nothing() {
+                let mut actual = Dynamic::from_iter(*b"bytes");
+
+                assert!(actual.flush().is_ok());
+
+                assert!(actual.into_iter().eq(*b"bytes"));
+            }
+        }
+    }
+
+    mod collection {
+        use super::*;
+
+        mod count {
+            use super::*;
+
+            #[test]
+            fn initialized_elements() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let actual: Dynamic<_> = expected.iter().copied().collect();
+
+                assert_eq!(Collection::count(&actual), expected.len());
+            }
+
+            #[test]
+            fn zero_when_empty() {
+                let actual = Dynamic::<()>::default();
+
+                assert_eq!(Collection::count(&actual), 0);
+            }
+
+            #[test]
+            fn ignores_front_capacity() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.reserve_front(256).expect("successful allocation");
+
+                assert_eq!(actual.count(), expected.len());
+            }
+
+            #[test]
+            fn ignores_back_capacity() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.count(), expected.len());
+            }
+        }
+
+        mod total_capacity {
+            use super::*;
+
+            #[test]
+            fn includes_reserved_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_front(8).expect("successful allocation");
+                _ = actual.reserve_back(16).expect("successful allocation");
+
+                assert_eq!(actual.total_capacity(), 30);
+            }
+
+            #[test]
+            fn includes_elements() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.shrink(None).expect("successful reallocation");
+
+                assert_eq!(actual.total_capacity(), 6);
+            }
+
+            #[test]
+            fn without_elements() {
+                let actual = Dynamic::<usize>::with_capacity(256).expect("successful allocation");
+
+                assert_eq!(actual.total_capacity(), 256);
+            }
+
+            #[test]
+            fn empty() {
+                let actual = Dynamic::<usize>::default();
+
+                assert_eq!(actual.total_capacity(), 0);
+            }
         }
 
-        #[test]
-        fn is_reflexive() {
-            let actual = Dynamic::<()>::default();
+        mod extend_reserve {
+            use super::*;
 
-            assert_eq!(actual, actual);
-        }
-    }
+            #[test]
+            fn grows_back_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
 
-    mod fmt {
-        use super::*;
+                Collection::extend_reserve(&mut actual, 256);
 
-        mod debug {
-            use super::*;
+                assert!(actual.capacity_back() >= 256);
+            }
 
             #[test]
-            fn is_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let actual: Dynamic<_> = expected.iter().copied().collect();
+            fn does_not_add_elements() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
 
-                assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+                Collection::extend_reserve(&mut actual, 256);
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
             }
-        }
-    }
 
-    mod collection {
-        use super::*;
+            #[test]
+            fn does_not_reallocate_when_appending_reserved() {
+                let mut actual = Dynamic::<usize>::default();
 
-        mod count {
-            use super::*;
+                Collection::extend_reserve(&mut actual, 256);
+
+                let ptr = actual.as_ptr();
+
+                for element in 0..256 {
+                    _ = actual.append(element).expect("reserved capacity");
+                }
+
+                assert_eq!(actual.as_ptr(), ptr);
+            }
 
             #[test]
-            fn initialized_elements() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let actual: Dynamic<_> = expected.iter().copied().collect();
+            fn ignores_failed_allocation() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
 
-                assert_eq!(Collection::count(&actual), expected.len());
+                Collection::extend_reserve(&mut actual, usize::MAX);
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
             }
+        }
+
+        mod clear {
+            use super::*;
 
             #[test]
-            fn zero_when_empty() {
-                let actual = Dynamic::<()>::default();
+            fn drop_all_elements() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                assert_eq!(Collection::count(&actual), 0);
+                actual.clear();
+
+                assert_eq!(actual.initialized, 0);
             }
 
             #[test]
-            fn ignores_front_capacity() {
+            fn keeps_allocation() {
                 let expected = [0, 1, 2, 3, 4, 5];
+                let mut actual = Dynamic::from_iter(expected);
 
-                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+                actual.clear();
 
-                _ = actual.reserve_front(256).expect("successful allocation");
+                assert_eq!(actual.capacity(), expected.len());
+            }
 
-                assert_eq!(actual.count(), expected.len());
+            #[test]
+            fn when_already_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                // Ideally this will panic or something in case of logic error.
+                actual.clear();
             }
 
             #[test]
-            fn ignores_back_capacity() {
-                let expected = [0, 1, 2, 3, 4, 5];
+            fn drops_every_element_once_when_destructor_panics() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
 
-                let mut actual: Dynamic<_> = expected.iter().copied().collect();
+                let mut actual = panic_on_drop(&dropped, 6, 2);
 
-                _ = actual.reserve_back(256).expect("successful allocation");
+                let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                    actual.clear();
+                }));
 
-                assert_eq!(actual.count(), expected.len());
+                assert!(result.is_err());
+                assert_eq!(dropped.take(), 6);
+
+                assert_eq!(actual.len(), 0);
+                assert_eq!(actual.capacity(), 6);
+
+                // Would drop the elements again if still considered initialized.
+                drop(actual);
+
+                assert_eq!(dropped.take(), 0);
             }
         }
     }
@@ -4707,6 +12496,18 @@ mod test {
         mod iter {
             use super::*;
 
+            #[test]
+            fn nth_skips_elements() {
+                let actual: Dynamic<_> = (0..6).collect();
+
+                let mut iter = actual.iter();
+
+                assert_eq!(iter.nth(3), Some(&3));
+                assert_eq!(iter.next(), Some(&4));
+                assert_eq!(iter.nth_back(0), Some(&5));
+                assert_eq!(iter.nth(1), None);
+            }
+
             #[test]
             fn element_count() {
                 let expected = [0, 1, 2, 3, 4, 5];
@@ -4812,6 +12613,46 @@ mod test {
                     assert_eq!(actual.next_back(), None);
                 }
             }
+
+            mod as_slice {
+                use super::*;
+
+                #[test]
+                fn remaining_middle_elements() {
+                    let actual: Dynamic<_> = (0..6).collect();
+
+                    let mut iter = actual.iter();
+
+                    _ = iter.next();
+                    _ = iter.next_back();
+                    _ = iter.next_back();
+
+                    assert_eq!(iter.as_slice(), [1, 2, 3]);
+                    assert!(iter.eq([1, 2, 3].iter()));
+                }
+
+                #[test]
+                fn outlives_iterator() {
+                    let actual: Dynamic<_> = (0..6).collect();
+
+                    let remaining = {
+                        let mut iter = actual.iter();
+
+                        _ = iter.nth(2);
+
+                        iter.as_slice()
+                    };
+
+                    assert_eq!(remaining, [3, 4, 5]);
+                }
+
+                #[test]
+                fn empty() {
+                    let actual = Dynamic::<usize>::default();
+
+                    assert_eq!(actual.iter().as_slice(), []);
+                }
+            }
         }
 
         mod iter_mut {
@@ -4830,97 +12671,282 @@ mod test {
                 let mut expected = [0, 1, 2, 3, 4, 5];
                 let mut actual: Dynamic<_> = expected.iter().copied().collect();
 
-                assert!(actual.iter_mut().eq(expected.iter_mut()));
+                assert!(actual.iter_mut().eq(expected.iter_mut()));
+            }
+
+            mod double_ended {
+                use super::*;
+
+                #[test]
+                fn element_count() {
+                    let expected = [0, 1, 2, 3, 4, 5];
+                    let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                    assert_eq!(actual.iter_mut().rev().count(), expected.len());
+                }
+
+                #[test]
+                fn in_order() {
+                    let mut expected = [0, 1, 2, 3, 4, 5];
+                    let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                    assert!(actual.iter_mut().rev().eq(expected.iter_mut().rev()));
+                }
+            }
+
+            mod exact_size {
+                use super::*;
+
+                #[test]
+                fn hint() {
+                    let expected = [0, 1, 2, 3, 4, 5];
+                    let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                    assert_eq!(
+                        actual.iter_mut().size_hint(),
+                        (expected.len(), Some(expected.len()))
+                    );
+                }
+
+                #[test]
+                fn len() {
+                    let expected = [0, 1, 2, 3, 4, 5];
+                    let mut actual: Dynamic<_> = expected.iter().copied().collect();
+
+                    assert_eq!(actual.iter_mut().len(), expected.len());
+                }
+
+                #[test]
+                fn updates() {
+                    let mut actual: Dynamic<_> = [0, 1, 2, 3, 4, 5].into_iter().collect();
+                    let mut actual = actual.iter_mut();
+
+                    for remaining in (0..actual.len()).rev() {
+                        _ = actual.next();
+
+                        assert_eq!(actual.len(), remaining);
+                    }
+                }
+            }
+
+            mod fused {
+                use super::*;
+
+                #[test]
+                fn empty() {
+                    let mut actual = Dynamic::<()>::default();
+                    let mut actual = actual.iter_mut();
+
+                    // Yields `None` at least once.
+                    assert_eq!(actual.next(), None);
+                    assert_eq!(actual.next_back(), None);
+
+                    // Continues to yield `None`.
+                    assert_eq!(actual.next(), None);
+                    assert_eq!(actual.next_back(), None);
+                }
+
+                #[test]
+                fn exhausted() {
+                    let mut actual: Dynamic<_> = [()].into_iter().collect();
+                    let mut actual = actual.iter_mut();
+
+                    // Exhaust the elements.
+                    let _: &mut () = actual.next().expect("the one element");
+
+                    // Yields `None` at least once.
+                    assert_eq!(actual.next(), None);
+                    assert_eq!(actual.next_back(), None);
+
+                    // Continues to yield `None`.
+                    assert_eq!(actual.next(), None);
+                    assert_eq!(actual.next_back(), None);
+                }
+            }
+        }
+
+        mod first {
+            use super::*;
+
+            #[test]
+            fn borrows_first_element() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(Linear::first(&actual), Some(&0));
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let actual = Dynamic::<()>::default();
+
+                assert_eq!(Linear::first(&actual), None);
+            }
+        }
+
+        mod last {
+            use super::*;
+
+            #[test]
+            fn borrows_last_element() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(Linear::last(&actual), Some(&5));
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let actual = Dynamic::<()>::default();
+
+                assert_eq!(Linear::last(&actual), None);
+            }
+        }
+
+        mod enumerate_indices {
+            use super::*;
+
+            #[test]
+            fn pairs_index_with_element() {
+                let actual = Dynamic::from_iter([5, 4, 3, 2, 1, 0]);
+
+                assert!(Linear::enumerate_indices(&actual).eq([
+                    (0, &5),
+                    (1, &4),
+                    (2, &3),
+                    (3, &2),
+                    (4, &1),
+                    (5, &0)
+                ]));
+            }
+
+            #[test]
+            fn index_corresponds_to_at() {
+                let actual = Dynamic::from_iter([5, 4, 3, 2, 1, 0]);
+
+                for (index, element) in Linear::enumerate_indices(&actual) {
+                    assert_eq!(actual.at(index), Some(element));
+                }
+            }
+
+            #[test]
+            fn does_not_consume() {
+                let actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert_eq!(Linear::enumerate_indices(&actual).len(), 3);
+                assert_eq!(Collection::count(&actual), 3);
+            }
+
+            #[test]
+            fn empty() {
+                let actual = Dynamic::<()>::default();
+
+                assert_eq!(Linear::enumerate_indices(&actual).next(), None);
+            }
+        }
+
+        mod group_by {
+            use super::*;
+
+            #[test]
+            fn groups_adjacent_equal_elements() {
+                let actual = Dynamic::from_iter([1, 1, 2, 3, 3]);
+
+                let mut groups = Linear::group_by(&actual, PartialEq::eq);
+
+                assert!(groups.next().expect("first group").into_iter().eq([&1, &1]));
+                assert!(groups.next().expect("second group").into_iter().eq([&2]));
+                assert!(groups.next().expect("third group").into_iter().eq([&3, &3]));
+                assert!(groups.next().is_none());
             }
 
-            mod double_ended {
-                use super::*;
+            #[test]
+            fn compares_against_previous_element_of_group() {
+                let actual = Dynamic::from_iter([0, 1, 2, 5, 6, 9]);
 
-                #[test]
-                fn element_count() {
-                    let expected = [0, 1, 2, 3, 4, 5];
-                    let mut actual: Dynamic<_> = expected.iter().copied().collect();
+                let groups: Dynamic<Dynamic<&i32>> =
+                    Linear::group_by(&actual, |previous, next| previous + 1 == *next).collect();
 
-                    assert_eq!(actual.iter_mut().rev().count(), expected.len());
-                }
+                assert_eq!(Collection::count(&groups), 3);
 
-                #[test]
-                fn in_order() {
-                    let mut expected = [0, 1, 2, 3, 4, 5];
-                    let mut actual: Dynamic<_> = expected.iter().copied().collect();
+                let mut groups = groups.into_iter();
 
-                    assert!(actual.iter_mut().rev().eq(expected.iter_mut().rev()));
-                }
+                assert!(groups
+                    .next()
+                    .expect("first group")
+                    .into_iter()
+                    .eq([&0, &1, &2]));
+                assert!(groups
+                    .next()
+                    .expect("second group")
+                    .into_iter()
+                    .eq([&5, &6]));
+                assert!(groups.next().expect("third group").into_iter().eq([&9]));
             }
 
-            mod exact_size {
-                use super::*;
-
-                #[test]
-                fn hint() {
-                    let expected = [0, 1, 2, 3, 4, 5];
-                    let mut actual: Dynamic<_> = expected.iter().copied().collect();
+            #[test]
+            fn non_adjacent_equal_elements_are_separate() {
+                let actual = Dynamic::from_iter([0, 1, 0]);
 
-                    assert_eq!(
-                        actual.iter_mut().size_hint(),
-                        (expected.len(), Some(expected.len()))
-                    );
-                }
+                assert_eq!(Linear::group_by(&actual, PartialEq::eq).count(), 3);
+            }
 
-                #[test]
-                fn len() {
-                    let expected = [0, 1, 2, 3, 4, 5];
-                    let mut actual: Dynamic<_> = expected.iter().copied().collect();
+            #[test]
+            fn empty() {
+                let actual = Dynamic::<usize>::default();
 
-                    assert_eq!(actual.iter_mut().len(), expected.len());
-                }
+                assert!(Linear::group_by(&actual, PartialEq::eq).next().is_none());
+            }
+        }
 
-                #[test]
-                fn updates() {
-                    let mut actual: Dynamic<_> = [0, 1, 2, 3, 4, 5].into_iter().collect();
-                    let mut actual = actual.iter_mut();
+        mod zip_elements {
+            use super::*;
+            use crate::structure::collection::linear::list::Singly;
 
-                    for remaining in (0..actual.len()).rev() {
-                        _ = actual.next();
+            #[test]
+            fn pairs_elements_in_order() {
+                let left = Dynamic::from_iter([0, 1, 2]);
+                let right = Singly::from_iter(['a', 'b', 'c']);
 
-                        assert_eq!(actual.len(), remaining);
-                    }
-                }
+                assert!(Linear::zip_elements(&left, &right).eq([
+                    (&0, &'a'),
+                    (&1, &'b'),
+                    (&2, &'c')
+                ]));
             }
 
-            mod fused {
-                use super::*;
+            #[test]
+            fn stops_when_self_is_shorter() {
+                let left = Dynamic::from_iter([0, 1]);
+                let right = Singly::from_iter(['a', 'b', 'c', 'd']);
 
-                #[test]
-                fn empty() {
-                    let mut actual = Dynamic::<()>::default();
-                    let mut actual = actual.iter_mut();
+                assert!(Linear::zip_elements(&left, &right).eq([(&0, &'a'), (&1, &'b')]));
+            }
 
-                    // Yields `None` at least once.
-                    assert_eq!(actual.next(), None);
-                    assert_eq!(actual.next_back(), None);
+            #[test]
+            fn stops_when_other_is_shorter() {
+                let left = Dynamic::from_iter([0, 1, 2, 3]);
+                let right = Singly::from_iter(['a', 'b']);
 
-                    // Continues to yield `None`.
-                    assert_eq!(actual.next(), None);
-                    assert_eq!(actual.next_back(), None);
-                }
+                assert!(Linear::zip_elements(&left, &right).eq([(&0, &'a'), (&1, &'b')]));
+            }
 
-                #[test]
-                fn exhausted() {
-                    let mut actual: Dynamic<_> = [()].into_iter().collect();
-                    let mut actual = actual.iter_mut();
+            #[test]
+            fn does_not_consume() {
+                let left = Dynamic::from_iter([0, 1, 2]);
+                let right = Singly::from_iter(['a', 'b', 'c']);
 
-                    // Exhaust the elements.
-                    let _: &mut () = actual.next().expect("the one element");
+                assert_eq!(Linear::zip_elements(&left, &right).count(), 3);
+                assert_eq!(Collection::count(&left), 3);
+                assert_eq!(Collection::count(&right), 3);
+            }
 
-                    // Yields `None` at least once.
-                    assert_eq!(actual.next(), None);
-                    assert_eq!(actual.next_back(), None);
+            #[test]
+            fn empty() {
+                let left = Dynamic::<i32>::default();
+                let right = Singly::from_iter(['a', 'b', 'c']);
 
-                    // Continues to yield `None`.
-                    assert_eq!(actual.next(), None);
-                    assert_eq!(actual.next_back(), None);
-                }
+                assert!(Linear::zip_elements(&left, &right).next().is_none());
             }
         }
     }
@@ -4990,6 +13016,152 @@ mod test {
                 _ = actual.as_mut_ptr();
             }
         }
+
+        mod binary_search {
+            use super::*;
+
+            #[test]
+            fn finds_contained_element() {
+                let actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for (index, element) in (0..6).enumerate() {
+                    assert_eq!(actual.binary_search(&element), Ok(index));
+                }
+            }
+
+            #[test]
+            fn insertion_index_when_not_contained() {
+                let actual = Dynamic::from_iter([0, 2, 4]);
+
+                assert_eq!(actual.binary_search(&-1), Err(0));
+                assert_eq!(actual.binary_search(&1), Err(1));
+                assert_eq!(actual.binary_search(&3), Err(2));
+                assert_eq!(actual.binary_search(&5), Err(3));
+            }
+
+            #[test]
+            fn ignores_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+                _ = actual.reserve_front(256).expect("successful allocation");
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                assert_eq!(actual.binary_search(&2), Ok(2));
+                assert_eq!(actual.binary_search(&3), Err(3));
+            }
+
+            #[test]
+            fn when_no_allocation() {
+                let actual = Dynamic::<i32>::default();
+
+                assert_eq!(actual.binary_search(&0), Err(0));
+            }
+        }
+    }
+
+    mod random_access {
+        use super::*;
+
+        mod get {
+            use super::*;
+
+            #[test]
+            fn correct_element() {
+                let actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for index in 0..6 {
+                    assert_eq!(actual.get(index), Some(&index));
+                }
+            }
+
+            #[test]
+            fn none_when_index_out_of_bounds() {
+                let actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.get(6), None);
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let actual = Dynamic::<usize>::default();
+
+                assert_eq!(actual.get(0), None);
+            }
+        }
+
+        mod get_mut {
+            use super::*;
+
+            #[test]
+            fn correct_element() {
+                let mut actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for mut index in 0..6 {
+                    assert_eq!(actual.get_mut(index), Some(&mut index));
+                }
+            }
+
+            #[test]
+            fn is_mutable() {
+                let mut actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                *actual.get_mut(0).expect("within bounds") = 5;
+
+                assert_eq!(actual.get(0), Some(&5));
+            }
+
+            #[test]
+            fn none_when_index_out_of_bounds() {
+                let mut actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.get_mut(6), None);
+            }
+        }
+
+        mod swap {
+            use super::*;
+
+            #[test]
+            fn exchanges_elements() {
+                let mut actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.swap(1, 4);
+
+                assert!(actual.iter().eq([0, 4, 2, 3, 1, 5].iter()));
+            }
+
+            #[test]
+            fn same_index_is_unmodified() {
+                let mut actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.swap(2, 2);
+
+                assert!(actual.iter().eq([0, 1, 2, 3, 4, 5].iter()));
+            }
+
+            #[test]
+            #[should_panic = "index out of bounds"]
+            fn panics_when_index_out_of_bounds() {
+                let mut actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.swap(0, 6);
+            }
+        }
+
+        mod generic {
+            use super::*;
+
+            /// Obtain the last element via only [`RandomAccess`] methods.
+            fn last(collection: &impl RandomAccess<Element = usize>) -> Option<&usize> {
+                collection.get(collection.count().checked_sub(1)?)
+            }
+
+            #[test]
+            fn accepted_by_generic_functions() {
+                let actual = Dynamic::<usize>::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(last(&actual), Some(&5));
+            }
+        }
     }
 
     mod list {
@@ -4999,6 +13171,26 @@ mod test {
         mod insert {
             use super::*;
 
+            #[test]
+            fn appending_many_zero_size_types() {
+                const ELEMENTS: usize = 1 << 16;
+
+                let mut actual = Dynamic::<()>::default();
+
+                assert!(actual.reserve_back(isize::MAX.unsigned_abs()).is_ok());
+
+                let capacity = actual.capacity_back();
+
+                for _ in 0..ELEMENTS {
+                    assert!(actual.append(()).is_ok());
+                }
+
+                assert_eq!(actual.len(), ELEMENTS);
+                assert_eq!(actual.capacity_back(), capacity - ELEMENTS);
+                assert_eq!(actual.iter().count(), ELEMENTS);
+                assert_eq!(actual.iter().rev().count(), ELEMENTS);
+            }
+
             #[test]
             fn adds_element() {
                 let expected = [0, 1, 2, 3, 4, 5];
@@ -5183,24 +13375,156 @@ mod test {
             fn none_when_index_out_of_bounds() {
                 let mut actual = Dynamic::<()>::default();
 
-                assert!(actual.remove(0).is_none());
+                assert!(actual.remove(0).is_none());
+            }
+
+            #[test]
+            fn increases_front_capacity_if_first_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for index in 0..actual.len() {
+                    _ = actual.remove(0).expect("element to remove");
+
+                    assert_eq!(actual.capacity_front(), index + 1);
+                }
+            }
+        }
+
+        mod pop_front {
+            use super::*;
+
+            #[test]
+            fn yields_first_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for expected in 0..6 {
+                    assert_eq!(actual.pop_front(), Some(expected));
+                }
+            }
+
+            #[test]
+            fn does_not_modify_trailing_elements() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.pop_front();
+
+                assert!(actual.into_iter().eq([1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let mut actual = Dynamic::<()>::default();
+
+                assert_eq!(actual.pop_front(), None);
+            }
+
+            #[test]
+            #[allow(deprecated)]
+            fn deprecated_alias() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.front(), Some(0));
+            }
+        }
+
+        mod pop_back {
+            use super::*;
+
+            #[test]
+            fn yields_last_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                for expected in (0..6).rev() {
+                    assert_eq!(actual.pop_back(), Some(expected));
+                }
+            }
+
+            #[test]
+            fn does_not_modify_leading_elements() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.pop_back();
+
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4]));
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let mut actual = Dynamic::<()>::default();
+
+                assert_eq!(actual.pop_back(), None);
+            }
+
+            #[test]
+            fn zero_size_type() {
+                let mut actual: Dynamic<_> = core::iter::repeat_with(|| ZeroSizeDroppable)
+                    .take(256)
+                    .collect();
+
+                let mut popped = 0;
+
+                while let Some(element) = actual.pop_back() {
+                    drop(element);
+
+                    popped += 1;
+                }
+
+                assert_eq!(popped, 256);
+                assert_eq!(ZERO_SIZE_DROPPED.get(), 256);
+                assert_eq!(actual.len(), 0);
             }
 
             #[test]
-            fn increases_front_capacity_if_first_element() {
+            #[allow(deprecated)]
+            fn deprecated_alias() {
                 let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
 
-                for index in 0..actual.len() {
-                    _ = actual.remove(0).expect("element to remove");
-
-                    assert_eq!(actual.capacity_front(), index + 1);
-                }
+                assert_eq!(actual.back(), Some(5));
             }
         }
 
         mod drain {
             use super::*;
 
+            #[test]
+            fn zero_size_type_sub_range() {
+                let mut actual: Dynamic<_> = core::iter::repeat_with(|| ZeroSizeDroppable)
+                    .take(256)
+                    .collect();
+
+                let capacity = actual.capacity();
+
+                assert_eq!(actual.drain(64..192).count(), 128);
+
+                assert_eq!(ZERO_SIZE_DROPPED.get(), 128);
+                assert_eq!(actual.len(), 128);
+                assert_eq!(actual.iter().count(), 128);
+                assert_eq!(actual.capacity(), capacity + 128);
+
+                drop(actual);
+
+                assert_eq!(ZERO_SIZE_DROPPED.get(), 256);
+            }
+
+            #[test]
+            fn zero_size_type_partially_consumed() {
+                let mut actual: Dynamic<_> = core::iter::repeat_with(|| ZeroSizeDroppable)
+                    .take(256)
+                    .collect();
+
+                let mut drain = actual.drain(64..192);
+
+                drop(drain.next());
+                drop(drain.next_back());
+
+                assert_eq!(drain.len(), 126);
+
+                drop(drain);
+
+                assert_eq!(ZERO_SIZE_DROPPED.get(), 128);
+                assert_eq!(actual.len(), 128);
+            }
+
             #[test]
             fn none_out_of_bounds_range() {
                 let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
@@ -5337,6 +13661,19 @@ mod test {
                     assert_eq!(actual.capacity_back(), 3);
                 }
 
+                #[test]
+                fn preserves_allocation() {
+                    for range in [0..2, 2..4, 4..6, 1..5] {
+                        let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                        let total = actual.len() + actual.capacity();
+
+                        drop(actual.drain(range));
+
+                        assert_eq!(actual.len() + actual.capacity(), total);
+                    }
+                }
+
                 #[test]
                 fn increases_capacity_when_middle() {
                     let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
@@ -5405,11 +13742,174 @@ mod test {
                     assert!(actual.iter().eq([0, 1, 2, 5].iter()));
                 }
             }
+
+            mod unwind {
+                use super::*;
+
+                #[test]
+                fn drops_every_remaining_element_when_destructor_panics() {
+                    let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                    let mut actual = panic_on_drop(&dropped, 6, 2);
+
+                    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                        drop(actual.drain(1..5));
+                    }));
+
+                    assert!(result.is_err());
+                    assert_eq!(dropped.take(), 4);
+                }
+
+                #[test]
+                fn leaves_consistent_state_when_destructor_panics() {
+                    let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                    let mut actual = panic_on_drop(&dropped, 6, 2);
+
+                    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                        drop(actual.drain(1..5));
+                    }));
+
+                    assert!(result.is_err());
+                    assert_eq!(actual.len(), 2);
+                    assert_eq!(dropped.take(), 4);
+
+                    drop(actual);
+
+                    assert_eq!(dropped.take(), 2);
+                }
+            }
+
+            mod keep_rest {
+                use super::*;
+
+                #[test]
+                fn keeps_unconsumed_middle_elements() {
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6, 7]);
+
+                    let mut drain = actual.drain(2..6);
+
+                    assert_eq!(drain.next(), Some(2));
+                    assert_eq!(drain.next_back(), Some(5));
+
+                    drain.keep_rest();
+
+                    assert!(actual.into_iter().eq([0, 1, 3, 4, 6, 7]));
+                }
+
+                #[test]
+                fn keeps_every_element_when_none_consumed() {
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                    actual.drain(1..5).keep_rest();
+
+                    assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+                }
+
+                #[test]
+                fn keeps_no_element_when_all_consumed() {
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                    let mut drain = actual.drain(1..5);
+
+                    drain.by_ref().for_each(drop);
+
+                    drain.keep_rest();
+
+                    assert!(actual.into_iter().eq([0, 5]));
+                }
+
+                #[test]
+                fn keeps_elements_when_range_is_front() {
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                    let mut drain = actual.drain(..3);
+
+                    assert_eq!(drain.next(), Some(0));
+
+                    drain.keep_rest();
+
+                    assert!(actual.into_iter().eq([1, 2, 3, 4, 5]));
+                }
+
+                #[test]
+                fn keeps_elements_when_range_is_back() {
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                    let mut drain = actual.drain(3..);
+
+                    assert_eq!(drain.next_back(), Some(5));
+
+                    drain.keep_rest();
+
+                    assert!(actual.into_iter().eq([0, 1, 2, 3, 4]));
+                }
+
+                #[test]
+                fn does_not_drop_kept_elements() {
+                    let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                    let mut actual: Dynamic<_> = (0..6)
+                        .map(|_| Droppable {
+                            counter: alloc::rc::Rc::clone(&dropped),
+                        })
+                        .collect();
+
+                    let mut drain = actual.drain(1..5);
+
+                    drop(drain.next());
+
+                    drain.keep_rest();
+
+                    assert_eq!(actual.len(), 5);
+                    assert_eq!(dropped.take(), 1);
+                }
+
+                #[test]
+                fn preserves_allocation() {
+                    let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6, 7]);
+
+                    let total = actual.len() + actual.capacity();
+
+                    let mut drain = actual.drain(2..6);
+
+                    assert_eq!(drain.next(), Some(2));
+
+                    drain.keep_rest();
+
+                    assert_eq!(actual.len() + actual.capacity(), total);
+                }
+            }
         }
 
         mod withdraw {
             use super::*;
 
+            #[test]
+            fn zero_size_type() {
+                let mut actual: Dynamic<_> = core::iter::repeat_with(|| ZeroSizeDroppable)
+                    .take(256)
+                    .collect();
+
+                let mut toggle = false;
+
+                let withdrawn = actual
+                    .withdraw(|_| {
+                        toggle = !toggle;
+                        toggle
+                    })
+                    .count();
+
+                assert_eq!(withdrawn, 128);
+                assert_eq!(ZERO_SIZE_DROPPED.get(), 128);
+                assert_eq!(actual.len(), 128);
+                assert_eq!(actual.iter().count(), 128);
+
+                drop(actual);
+
+                assert_eq!(ZERO_SIZE_DROPPED.get(), 256);
+            }
+
             mod iterator {
                 use super::*;
 
@@ -5487,7 +13987,7 @@ mod test {
 
                         drop(actual.withdraw(|element| element == &1).rev());
 
-                        assert!(actual.eq([0, 2, 3, 4, 5]));
+                        assert!(actual.into_iter().eq([0, 2, 3, 4, 5]));
                     }
 
                     #[test]
@@ -5541,6 +14041,41 @@ mod test {
                 }
             }
 
+            mod unwind {
+                use super::*;
+
+                #[test]
+                fn retains_unqueried_elements_when_destructor_panics() {
+                    let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                    let mut actual = panic_on_drop(&dropped, 6, 2);
+
+                    let mut queried = 0;
+
+                    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                        drop(actual.withdraw(|_| {
+                            let withdraw = queried % 2 == 0;
+
+                            queried += 1;
+
+                            withdraw
+                        }));
+                    }));
+
+                    assert!(result.is_err());
+
+                    // The first two withdrawn elements, including that which panicked.
+                    assert_eq!(dropped.take(), 2);
+
+                    // The one retained element and the three yet to be queried.
+                    assert_eq!(actual.len(), 4);
+
+                    drop(actual);
+
+                    assert_eq!(dropped.take(), 4);
+                }
+            }
+
             mod drop {
                 use super::*;
 
@@ -5593,7 +14128,7 @@ mod test {
 
                     drop(actual.withdraw(|element| element == &1));
 
-                    assert!(actual.eq([0, 2, 3, 4, 5]));
+                    assert!(actual.into_iter().eq([0, 2, 3, 4, 5]));
                 }
 
                 #[test]
@@ -5630,34 +14165,31 @@ mod test {
             }
         }
 
-        mod clear {
+        mod generic {
             use super::*;
 
-            #[test]
-            fn drop_all_elements() {
-                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
-
-                actual.clear();
-
-                assert_eq!(actual.initialized, 0);
+            /// Insert `first` and `last` via only [`List`] methods.
+            fn surround(instance: &mut impl List<Element = usize>, first: usize, last: usize) {
+                assert!(instance.prepend(first).is_ok(), "prepend element");
+                assert!(instance.append(last).is_ok(), "append element");
             }
 
             #[test]
-            fn keeps_allocation() {
-                let expected = [0, 1, 2, 3, 4, 5];
-                let mut actual = Dynamic::from_iter(expected);
+            fn accepted_by_generic_functions() {
+                let mut actual = Dynamic::from_iter([1, 2, 3, 4]);
 
-                actual.clear();
+                surround(&mut actual, 0, 5);
 
-                assert_eq!(actual.capacity(), expected.len());
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
             }
 
             #[test]
-            fn when_already_empty() {
+            fn when_empty() {
                 let mut actual = Dynamic::<usize>::default();
 
-                // Ideally this will panic or something in case of logic error.
-                actual.clear();
+                surround(&mut actual, 0, 1);
+
+                assert!(actual.into_iter().eq([0, 1]));
             }
         }
     }
@@ -5715,7 +14247,7 @@ mod test {
                 let mut actual = Dynamic::<usize>::default();
 
                 assert!(actual.push(0).is_ok());
-                assert!(actual.eq([0]));
+                assert!(actual.into_iter().eq([0]));
             }
         }
 
@@ -5789,6 +14321,85 @@ mod test {
                 assert_eq!(actual.peek(), None);
             }
         }
+
+        mod push_all {
+            use super::*;
+
+            #[test]
+            fn pushes_in_order() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(<Dynamic<_> as Stack>::push_all(&mut actual, [0, 1, 2]).is_ok());
+
+                assert!(actual.into_iter().eq([2, 1, 0]));
+            }
+
+            #[test]
+            fn empty_batch() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(<Dynamic<_> as Stack>::push_all(&mut actual, []).is_ok());
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn reserves_once() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(<Dynamic<_> as Stack>::push_all(&mut actual, 0..256).is_ok());
+
+                // Amortized growth would have over-allocated.
+                assert_eq!(actual.capacity(), 0);
+            }
+        }
+
+        mod pop_n {
+            use super::*;
+
+            #[test]
+            fn pops_in_order() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(<Dynamic<_> as Stack>::push_all(&mut actual, [0, 1, 2]).is_ok());
+
+                let popped = <Dynamic<_> as Stack>::pop_n(&mut actual, 2);
+
+                assert!(popped.into_iter().eq([2, 1]));
+                assert!(actual.into_iter().eq([0]));
+            }
+
+            #[test]
+            fn matches_repeated_pop() {
+                let mut expected: Dynamic<_> = (0..6).collect();
+                let mut actual = expected.clone();
+
+                let popped = <Dynamic<_> as Stack>::pop_n(&mut actual, 4);
+
+                for element in popped {
+                    assert_eq!(Some(element), <Dynamic<_> as Stack>::pop(&mut expected));
+                }
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn at_most_every_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                let popped = <Dynamic<_> as Stack>::pop_n(&mut actual, 256);
+
+                assert_eq!(popped.len(), 3);
+                assert_eq!(Collection::count(&actual), 0);
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert_eq!(<Dynamic<_> as Stack>::pop_n(&mut actual, 2).len(), 0);
+            }
+        }
     }
 
     mod queue {
@@ -5844,7 +14455,7 @@ mod test {
                 let mut actual = Dynamic::<usize>::default();
 
                 assert!(actual.push(0).is_ok());
-                assert!(actual.eq([0]));
+                assert!(actual.into_iter().eq([0]));
             }
         }
 
@@ -5918,5 +14529,84 @@ mod test {
                 assert_eq!(actual.peek(), None);
             }
         }
+
+        mod push_all {
+            use super::*;
+
+            #[test]
+            fn pushes_in_order() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(<Dynamic<_> as Queue>::push_all(&mut actual, [0, 1, 2]).is_ok());
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn empty_batch() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(<Dynamic<_> as Queue>::push_all(&mut actual, []).is_ok());
+
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn reserves_once() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(<Dynamic<_> as Queue>::push_all(&mut actual, 0..256).is_ok());
+
+                // Amortized growth would have over-allocated.
+                assert_eq!(actual.capacity(), 0);
+            }
+        }
+
+        mod pop_n {
+            use super::*;
+
+            #[test]
+            fn pops_in_order() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert!(<Dynamic<_> as Queue>::push_all(&mut actual, [0, 1, 2]).is_ok());
+
+                let popped = <Dynamic<_> as Queue>::pop_n(&mut actual, 2);
+
+                assert!(popped.into_iter().eq([0, 1]));
+                assert!(actual.into_iter().eq([2]));
+            }
+
+            #[test]
+            fn matches_repeated_pop() {
+                let mut expected: Dynamic<_> = (0..6).collect();
+                let mut actual = expected.clone();
+
+                let popped = <Dynamic<_> as Queue>::pop_n(&mut actual, 4);
+
+                for element in popped {
+                    assert_eq!(Some(element), <Dynamic<_> as Queue>::pop(&mut expected));
+                }
+
+                assert!(actual.into_iter().eq(expected));
+            }
+
+            #[test]
+            fn at_most_every_element() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                let popped = <Dynamic<_> as Queue>::pop_n(&mut actual, 256);
+
+                assert_eq!(popped.len(), 3);
+                assert_eq!(Collection::count(&actual), 0);
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                assert_eq!(<Dynamic<_> as Queue>::pop_n(&mut actual, 2).len(), 0);
+            }
+        }
     }
 }
@@ -2,7 +2,9 @@
 
 use super::Array;
 use super::Collection;
+use super::Dynamic;
 use super::Linear;
+use super::RandomAccess;
 
 /// Fixed size (statically stack allocated) [`Array`].
 ///
@@ -17,6 +19,177 @@ pub struct Fixed<T, const N: usize> {
     data: [T; N],
 }
 
+impl<T, const N: usize> Fixed<T, N> {
+    /// Iterate over every overlapping window of `W` consecutive elements.
+    ///
+    /// Unlike [`slice::windows`], each window is a reference to an array, so
+    /// the length is retained within the type for downstream computation.
+    /// There are `N - W + 1` windows, or none if `W` is greater than `N`.
+    ///
+    /// # Panics
+    /// This method has the precondition that `W` is not zero.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let instance = Fixed::from([0, 1, 2, 3, 4]);
+    ///
+    /// let mut actual = instance.array_windows::<2>();
+    ///
+    /// assert_eq!(actual.next(), Some(&[0, 1]));
+    /// assert_eq!(actual.next(), Some(&[1, 2]));
+    /// assert_eq!(actual.next(), Some(&[2, 3]));
+    /// assert_eq!(actual.next(), Some(&[3, 4]));
+    /// assert_eq!(actual.next(), None);
+    /// ```
+    pub fn array_windows<const W: usize>(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &[T; W]> + ExactSizeIterator {
+        assert!(W != 0, "window size must be non-zero");
+
+        self.data.windows(W).map(|window| {
+            let Ok(window) = window.try_into() else {
+                unreachable!("windows have exactly `W` elements");
+            };
+
+            window
+        })
+    }
+
+    /// Iterate over non-overlapping chunks of `C` consecutive elements.
+    ///
+    /// Unlike [`slice::chunks`], each chunk is a reference to an array, so
+    /// the length is retained within the type for downstream computation.
+    /// There are `N / C` chunks, hence the last `N % C` elements (which is
+    /// known at compile-time) are not yielded, but can be obtained via
+    /// [`Array::as_slice`].
+    ///
+    /// # Panics
+    /// This method has the precondition that `C` is not zero.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Array;
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let instance = Fixed::from([0, 1, 2, 3, 4]);
+    ///
+    /// assert!(instance.array_chunks::<2>().eq([&[0, 1], &[2, 3]]));
+    /// assert_eq!(instance.as_slice()[5 - 5 % 2..], [4]);
+    /// ```
+    pub fn array_chunks<const C: usize>(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &[T; C]> + ExactSizeIterator {
+        assert!(C != 0, "chunk size must be non-zero");
+
+        let (chunks, _) = self.data.as_chunks::<C>();
+
+        chunks.iter()
+    }
+
+    /// Reorder the elements such that each moves to the index whose binary
+    /// representation is that of its current index reversed.
+    ///
+    /// For example, with `N = 8` the element at index `1` (`0b001`) swaps
+    /// with that at index `4` (`0b100`), whereas the element at index `2`
+    /// (`0b010`) remains in place. This is the reordering applied before the
+    /// iterative [Cooley-Tukey FFT][fft]. Applying it twice yields the
+    /// original order.
+    ///
+    /// # Panics
+    /// This method has the precondition that `N` is a power of two.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let mut instance = Fixed::from([0, 1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// instance.reverse_bits_permutation();
+    ///
+    /// assert_eq!(instance, Fixed::from([0, 4, 2, 6, 1, 5, 3, 7]));
+    /// ```
+    ///
+    /// [fft]: https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm#Data_reordering,_bit_reversal,_and_in-place_algorithms
+    pub fn reverse_bits_permutation(&mut self) {
+        assert!(N.is_power_of_two(), "length must be a power of two");
+
+        // The number of bits not used by any index.
+        let Some(unused) = usize::BITS.checked_sub(N.trailing_zeros()) else {
+            unreachable!("fewer trailing zeros than bits");
+        };
+
+        for index in 0..N {
+            // A single element has no bits to reverse.
+            let reversed = index.reverse_bits().checked_shr(unused).unwrap_or(0);
+
+            // Swap each pair only once, from the lower of the two indexes.
+            if index < reversed {
+                self.data.swap(index, reversed);
+            }
+        }
+    }
+
+    /// Swap the element at index `I` with that at index `J`.
+    ///
+    /// Unlike [`slice::swap`], the indexes are known at compile-time, hence
+    /// they are bounds checked at compile-time rather than at runtime.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let mut instance = Fixed::from([0, 1, 2, 3]);
+    ///
+    /// instance.swap_const::<0, 3>();
+    ///
+    /// assert_eq!(instance, Fixed::from([3, 1, 2, 0]));
+    /// ```
+    ///
+    /// Out of bounds indexes fail to compile:
+    /// ```compile_fail
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let mut instance = Fixed::from([0, 1, 2, 3]);
+    ///
+    /// instance.swap_const::<0, 4>();
+    /// ```
+    pub fn swap_const<const I: usize, const J: usize>(&mut self) {
+        const {
+            assert!(I < N, "first index out of bounds");
+            assert!(J < N, "second index out of bounds");
+        }
+
+        let ptr = self.data.as_mut_ptr();
+
+        // SAFETY: `I < N` => stays aligned within the allocated object.
+        let first = unsafe { ptr.add(I) };
+
+        // SAFETY: `J < N` => stays aligned within the allocated object.
+        let second = unsafe { ptr.add(J) };
+
+        // SAFETY:
+        // * owned memory => both pointers are valid for reads and writes.
+        // * the same index => swapping overlapping pointers is a no-op.
+        unsafe {
+            core::ptr::swap(first, second);
+        }
+    }
+}
+
 impl<T, const N: usize> From<[T; N]> for Fixed<T, N> {
     /// Construct from an existing [`array`].
     ///
@@ -38,9 +211,59 @@ impl<T, const N: usize> From<[T; N]> for Fixed<T, N> {
     }
 }
 
+impl<T, const N: usize> TryFrom<Dynamic<T>> for Fixed<T, N> {
+    type Error = Dynamic<T>;
+
+    /// Construct by moving the elements of a [`Dynamic`] with exactly `N`.
+    ///
+    /// # Errors
+    /// Yields the unmodified `dynamic` if it does not contain exactly `N`
+    /// elements, so none are lost.
+    ///
+    /// # Performance
+    /// This methods takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::array::Dynamic;
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let dynamic = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let actual = Fixed::<_, 6>::try_from(dynamic).expect("exact length");
+    ///
+    /// assert_eq!(actual, Fixed::from([0, 1, 2, 3, 4, 5]));
+    ///
+    /// let dynamic = Dynamic::from_iter([0, 1, 2]);
+    /// let actual = Fixed::<_, 6>::try_from(dynamic);
+    ///
+    /// assert!(actual.is_err_and(|original| original.into_iter().eq([0, 1, 2])));
+    /// ```
+    fn try_from(dynamic: Dynamic<T>) -> Result<Self, Self::Error> {
+        if dynamic.len() != N {
+            return Err(dynamic);
+        }
+
+        let mut elements = dynamic.into_iter();
+
+        let data = core::array::from_fn(|_| {
+            let Some(element) = elements.next() else {
+                unreachable!("contains exactly `N` elements");
+            };
+
+            element
+        });
+
+        Ok(Self { data })
+    }
+}
+
 impl<T: Default, const N: usize> Default for Fixed<T, N> {
     /// Construct with default initialized elements.
     ///
+    /// Elements are initialized in order from the front. Should any call to
+    /// [`Default::default`] panic, the elements already initialized are
+    /// dropped before unwinding rather than being leaked.
+    ///
     /// # Performance
     /// This methods takes O(N) time and consumes O(N) memory for the result.
     ///
@@ -55,20 +278,7 @@ impl<T: Default, const N: usize> Default for Fixed<T, N> {
     /// }
     /// ```
     fn default() -> Self {
-        // SAFETY: the [`MaybeUninit<T>`] is initialized even if the `T` isn't.
-        let mut uninitialized: [core::mem::MaybeUninit<T>; N] =
-            unsafe { core::mem::MaybeUninit::uninit().assume_init() };
-
-        for element in &mut uninitialized {
-            _ = element.write(Default::default());
-        }
-
-        // SAFETY:
-        // * [`MaybeUninit<T>`] has same size as `T` => arrays have same size.
-        // * [`MaybeUninit<T>`] has same alignment as `T` => elements aligned.
-        let initialized = unsafe { uninitialized.as_mut_ptr().cast::<[T; N]>().read() };
-
-        Self::from(initialized)
+        Self::from(core::array::from_fn(|_| T::default()))
     }
 }
 
@@ -146,7 +356,7 @@ impl<T, const N: usize> core::ops::IndexMut<usize> for Fixed<T, N> {
     }
 }
 
-impl<'a, T: 'a, const N: usize> IntoIterator for Fixed<T, N> {
+impl<T, const N: usize> IntoIterator for Fixed<T, N> {
     type Item = T;
 
     type IntoIter = IntoIter<T, N>;
@@ -206,7 +416,7 @@ impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for Fixed<T, N> {
     }
 }
 
-impl<'a, T: 'a, const N: usize> Collection for Fixed<T, N> {
+impl<T, const N: usize> Collection for Fixed<T, N> {
     type Element = T;
 
     /// Query how many elements are contained.
@@ -227,6 +437,33 @@ impl<'a, T: 'a, const N: usize> Collection for Fixed<T, N> {
     fn count(&self) -> usize {
         N
     }
+
+    /// Drop all elements, which is only possible if none are contained.
+    ///
+    /// The number of elements is fixed at `N`, hence none can be removed.
+    /// This method exists so [`Fixed`] can be used as any other
+    /// [`Collection`], but does nothing unless `N` is zero.
+    ///
+    /// # Panics
+    /// Panics if `N` is not zero, since the elements cannot be removed.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let mut instance = Fixed::<i32, 0>::from([]);
+    ///
+    /// instance.clear();
+    ///
+    /// assert_eq!(instance.count(), 0);
+    /// ```
+    fn clear(&mut self) {
+        assert!(N == 0, "cannot remove elements from a fixed size array");
+    }
 }
 
 impl<T, const N: usize> Linear for Fixed<T, N> {
@@ -302,9 +539,52 @@ impl<T, const N: usize> Linear for Fixed<T, N> {
         // * Points to `N` initialized instance of `T`.
         unsafe { super::IterMut::new(ptr, N) }
     }
+
+    /// Obtain an immutable reference to the element at `index`, bounds checked.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(actual.at(0), Some(&0));
+    /// assert_eq!(actual.at(5), Some(&5));
+    /// assert_eq!(actual.at(6), None);
+    /// ```
+    fn at(&self, index: usize) -> Option<&Self::Element> {
+        self.data.get(index)
+    }
+
+    /// Obtain a mutable reference to the element at `index`, bounds checked.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let mut actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+    ///
+    /// if let Some(element) = actual.at_mut(0) {
+    ///     *element = 12345;
+    /// }
+    ///
+    /// assert_eq!(actual.at_mut(0), Some(&mut 12345));
+    /// assert_eq!(actual.at_mut(6), None);
+    /// ```
+    fn at_mut(&mut self, index: usize) -> Option<&mut Self::Element> {
+        self.data.get_mut(index)
+    }
 }
 
-impl<'a, T: 'a, const N: usize> Array for Fixed<T, N> {
+impl<T, const N: usize> Array for Fixed<T, N> {
     /// Obtain an immutable pointer to the underlying contigious memory buffer.
     ///
     /// # Performance
@@ -356,6 +636,78 @@ impl<'a, T: 'a, const N: usize> Array for Fixed<T, N> {
     }
 }
 
+impl<T, const N: usize> RandomAccess for Fixed<T, N> {
+    /// Obtain an immutable reference to the `index` element, bounds checked.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let instance = Fixed::from([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.get(0), Some(&0));
+    /// assert_eq!(instance.get(5), Some(&5));
+    /// assert_eq!(instance.get(6), None);
+    /// ```
+    fn get(&self, index: usize) -> Option<&Self::Element> {
+        self.at(index)
+    }
+
+    /// Obtain a mutable reference to the `index` element, bounds checked.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let mut instance = Fixed::from([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.get_mut(0), Some(&mut 0));
+    /// assert_eq!(instance.get_mut(5), Some(&mut 5));
+    /// assert_eq!(instance.get_mut(6), None);
+    /// ```
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Element> {
+        self.at_mut(index)
+    }
+
+    /// Swap the element at index `first` with the element at index `second`.
+    ///
+    /// # Panics
+    /// If either `first` or `second` is out of bounds.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::RandomAccess;
+    /// use rust::structure::collection::linear::array::Fixed;
+    ///
+    /// let mut instance = Fixed::from([0, 1, 2, 3, 4, 5]);
+    ///
+    /// instance.swap(0, 5);
+    ///
+    /// assert!(instance.iter().eq([5, 1, 2, 3, 4, 0].iter()));
+    /// ```
+    fn swap(&mut self, first: usize, second: usize) {
+        let count = self.count();
+
+        assert!(first < count && second < count, "index out of bounds");
+
+        self.as_mut_slice().swap(first, second);
+    }
+}
+
 /// By-value [`Iterator`] over a [`Fixed`].
 pub struct IntoIter<T, const N: usize> {
     /// Ownership of the underlying array.
@@ -499,7 +851,7 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
     }
 }
 
-impl<'a, T: 'a, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
     /// Obtain the final element, if there are any left.
     ///
     /// # Performance
@@ -523,43 +875,246 @@ impl<'a, T: 'a, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
         self.next.next_back().map(|index| {
             let array = self.data.as_mut_ptr();
 
-            // SAFETY: stays aligned within the allocated object.
-            let element = unsafe { array.add(index) };
+            // SAFETY: stays aligned within the allocated object.
+            let element = unsafe { array.add(index) };
+
+            // SAFETY: this takes ownership (move).
+            let owned = unsafe { element.read() };
+
+            core::mem::ManuallyDrop::into_inner(owned)
+        })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> core::iter::FusedIterator for IntoIter<T, N> {}
+
+#[cfg(test)]
+#[allow(
+    clippy::undocumented_unsafe_blocks,
+    clippy::unwrap_used,
+    clippy::expect_used
+)]
+mod test {
+    use super::*;
+
+    extern crate alloc;
+
+    /// Mock element for drop tests.
+    #[derive(Debug, Clone)]
+    struct Droppable {
+        /// A shared counter for the number of elements dropped.
+        counter: alloc::rc::Rc<core::cell::RefCell<usize>>,
+    }
+
+    impl Drop for Droppable {
+        /// Increment the shared counter upon drop.
+        fn drop(&mut self) {
+            _ = self.counter.replace_with(|old| old.wrapping_add(1));
+        }
+    }
+
+    mod method {
+        use super::*;
+
+        mod array_windows {
+            use super::*;
+
+            #[test]
+            fn yields_every_window() {
+                let actual = Fixed::from([0, 1, 2, 3, 4]);
+
+                let windows: [Option<&[i32; 2]>; 5] = {
+                    let mut windows = actual.array_windows::<2>();
+
+                    core::array::from_fn(|_| windows.next())
+                };
+
+                assert_eq!(
+                    windows,
+                    [
+                        Some(&[0, 1]),
+                        Some(&[1, 2]),
+                        Some(&[2, 3]),
+                        Some(&[3, 4]),
+                        None
+                    ]
+                );
+            }
+
+            #[test]
+            fn exact_size() {
+                let actual = Fixed::from([0, 1, 2, 3, 4]);
+
+                assert_eq!(actual.array_windows::<1>().len(), 5);
+                assert_eq!(actual.array_windows::<2>().len(), 4);
+                assert_eq!(actual.array_windows::<5>().len(), 1);
+            }
+
+            #[test]
+            fn larger_than_elements() {
+                let actual = Fixed::from([0, 1, 2, 3, 4]);
+
+                assert_eq!(actual.array_windows::<6>().next(), None);
+            }
+
+            #[test]
+            #[should_panic = "window size must be non-zero"]
+            fn panics_when_zero() {
+                let actual = Fixed::from([0, 1, 2, 3, 4]);
+
+                drop(actual.array_windows::<0>());
+            }
+        }
+
+        mod array_chunks {
+            use super::*;
+
+            #[test]
+            fn yields_every_chunk() {
+                let actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.array_chunks::<2>().eq([&[0, 1], &[2, 3], &[4, 5]]));
+            }
+
+            #[test]
+            fn excludes_remainder() {
+                let actual = Fixed::from([0, 1, 2, 3, 4]);
+
+                assert!(actual.array_chunks::<2>().eq([&[0, 1], &[2, 3]]));
+                assert_eq!(actual.as_slice().get(5 - 5 % 2..), Some([4].as_slice()));
+            }
+
+            #[test]
+            fn larger_than_elements() {
+                let actual = Fixed::from([0, 1, 2, 3, 4]);
+
+                assert_eq!(actual.array_chunks::<6>().next(), None);
+            }
+
+            #[test]
+            #[should_panic = "chunk size must be non-zero"]
+            fn panics_when_zero() {
+                let actual = Fixed::from([0, 1, 2, 3, 4]);
+
+                drop(actual.array_chunks::<0>());
+            }
+        }
+
+        mod reverse_bits_permutation {
+            use super::*;
+
+            #[test]
+            fn single_element() {
+                let mut actual = Fixed::from([0]);
+
+                actual.reverse_bits_permutation();
+
+                assert_eq!(actual.data, [0]);
+            }
+
+            #[test]
+            fn two_elements_are_unchanged() {
+                let mut actual = Fixed::from([0, 1]);
+
+                actual.reverse_bits_permutation();
+
+                assert_eq!(actual.data, [0, 1]);
+            }
+
+            #[test]
+            fn moves_to_bit_reversed_index() {
+                let mut actual = Fixed::from([0, 1, 2, 3, 4, 5, 6, 7]);
+
+                actual.reverse_bits_permutation();
+
+                // Index `0b001` moves to index `0b100`.
+                assert_eq!(actual.data[4], 1);
+
+                assert_eq!(actual.data, [0, 4, 2, 6, 1, 5, 3, 7]);
+            }
+
+            #[test]
+            fn each_element_at_reversed_index() {
+                let mut actual = Fixed::from(core::array::from_fn::<usize, 256, _>(|index| index));
+
+                actual.reverse_bits_permutation();
+
+                for (index, element) in actual.data.iter().enumerate() {
+                    assert_eq!(index.reverse_bits() >> (usize::BITS - 8), *element);
+                }
+            }
+
+            #[test]
+            fn twice_restores_original() {
+                let expected: [usize; 64] = core::array::from_fn(|index| index.wrapping_mul(7));
+
+                let mut actual = Fixed::from(expected);
+
+                actual.reverse_bits_permutation();
+                assert_ne!(actual.data, expected);
+
+                actual.reverse_bits_permutation();
+                assert_eq!(actual.data, expected);
+            }
+
+            #[test]
+            #[should_panic = "length must be a power of two"]
+            fn panics_when_not_power_of_two() {
+                let mut actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                actual.reverse_bits_permutation();
+            }
+
+            #[test]
+            #[should_panic = "length must be a power of two"]
+            fn panics_when_empty() {
+                let mut actual = Fixed::<usize, 0>::from([]);
+
+                actual.reverse_bits_permutation();
+            }
+        }
 
-            // SAFETY: this takes ownership (move).
-            let owned = unsafe { element.read() };
+        mod swap_const {
+            use super::*;
 
-            core::mem::ManuallyDrop::into_inner(owned)
-        })
-    }
-}
+            #[test]
+            fn swaps_elements() {
+                let mut actual: Fixed<i32, 4> = Fixed::from([0, 1, 2, 3]);
 
-impl<'a, T: 'a, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+                actual.swap_const::<0, 3>();
 
-impl<'a, T: 'a, const N: usize> core::iter::FusedIterator for IntoIter<T, N> {}
+                assert_eq!(actual.data, [3, 1, 2, 0]);
+            }
 
-#[cfg(test)]
-#[allow(
-    clippy::undocumented_unsafe_blocks,
-    clippy::unwrap_used,
-    clippy::expect_used
-)]
-mod test {
-    use super::*;
+            #[test]
+            fn is_symmetric() {
+                let mut actual: Fixed<i32, 4> = Fixed::from([0, 1, 2, 3]);
 
-    extern crate alloc;
+                actual.swap_const::<3, 0>();
 
-    /// Mock element for drop tests.
-    #[derive(Debug, Clone)]
-    struct Droppable {
-        /// A shared counter for the number of elements dropped.
-        counter: alloc::rc::Rc<core::cell::RefCell<usize>>,
-    }
+                assert_eq!(actual.data, [3, 1, 2, 0]);
+            }
 
-    impl Drop for Droppable {
-        /// Increment the shared counter upon drop.
-        fn drop(&mut self) {
-            _ = self.counter.replace_with(|old| old.wrapping_add(1));
+            #[test]
+            fn same_index_does_nothing() {
+                let mut actual: Fixed<i32, 4> = Fixed::from([0, 1, 2, 3]);
+
+                actual.swap_const::<2, 2>();
+
+                assert_eq!(actual.data, [0, 1, 2, 3]);
+            }
+
+            #[test]
+            fn applying_twice_is_original() {
+                let mut actual: Fixed<i32, 4> = Fixed::from([0, 1, 2, 3]);
+
+                actual.swap_const::<1, 2>();
+                actual.swap_const::<1, 2>();
+
+                assert_eq!(actual.data, [0, 1, 2, 3]);
+            }
         }
     }
 
@@ -579,6 +1134,87 @@ mod test {
         }
     }
 
+    mod try_from {
+        use super::*;
+
+        mod dynamic {
+            use super::*;
+
+            #[test]
+            fn moves_elements_when_exact_length() {
+                let dynamic: Dynamic<_> = (0..6).collect();
+
+                let actual = Fixed::<_, 6>::try_from(dynamic).expect("exact length");
+
+                assert_eq!(actual.data, [0, 1, 2, 3, 4, 5]);
+            }
+
+            #[test]
+            fn empty_when_zero_length() {
+                let dynamic = Dynamic::<usize>::default();
+
+                let actual = Fixed::<_, 0>::try_from(dynamic).expect("exact length");
+
+                assert_eq!(actual.data, []);
+            }
+
+            #[test]
+            fn yields_original_when_too_few_elements() {
+                let dynamic: Dynamic<_> = (0..5).collect();
+
+                let actual = Fixed::<_, 6>::try_from(dynamic);
+
+                assert!(actual.is_err_and(|original| original.into_iter().eq([0, 1, 2, 3, 4])));
+            }
+
+            #[test]
+            fn yields_original_when_too_many_elements() {
+                let dynamic: Dynamic<_> = (0..7).collect();
+
+                let actual = Fixed::<_, 6>::try_from(dynamic);
+
+                assert!(
+                    actual.is_err_and(|original| original.into_iter().eq([0, 1, 2, 3, 4, 5, 6]))
+                );
+            }
+
+            #[test]
+            fn does_not_drop_elements_when_exact_length() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let dynamic: Dynamic<_> = (0..6)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&dropped),
+                    })
+                    .collect();
+
+                let actual = Fixed::<_, 6>::try_from(dynamic).expect("exact length");
+
+                assert_eq!(dropped.take(), 0);
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 6);
+            }
+
+            #[test]
+            fn does_not_drop_elements_when_mismatched_length() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let dynamic: Dynamic<_> = (0..5)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&dropped),
+                    })
+                    .collect();
+
+                let actual = Fixed::<_, 6>::try_from(dynamic);
+
+                assert_eq!(dropped.take(), 0);
+                assert!(actual.is_err_and(|original| original.len() == 5));
+            }
+        }
+    }
+
     mod index {
         use super::*;
         use core::ops::Index;
@@ -682,7 +1318,7 @@ mod test {
                     }
 
                     unsafe {
-                        std::mem::transmute::<
+                        core::mem::transmute::<
                             [core::mem::MaybeUninit<Droppable>; 256],
                             [Droppable; 256],
                         >(uninitialized)
@@ -812,6 +1448,53 @@ mod test {
                 assert_eq!(element, Value::default());
             }
         }
+
+        #[test]
+        fn is_zero_for_integers() {
+            let actual = Fixed::<i32, 4>::default();
+
+            assert_eq!(actual, Fixed::from([0, 0, 0, 0]));
+        }
+
+        #[test]
+        fn drops_initialized_elements_when_default_panics() {
+            std::thread_local! {
+                /// How many instances of [`Panicking`] have been constructed.
+                static CONSTRUCTED: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+
+                /// How many instances of [`Panicking`] have been dropped.
+                static DROPPED: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+            }
+
+            /// Mock element which panics when constructing the fourth.
+            struct Panicking;
+
+            impl Default for Panicking {
+                /// Construct an instance, panicking for specifically the fourth.
+                fn default() -> Self {
+                    let constructed = CONSTRUCTED.get();
+
+                    assert!(constructed < 3, "default panicked");
+
+                    CONSTRUCTED.set(constructed.wrapping_add(1));
+
+                    Panicking
+                }
+            }
+
+            impl Drop for Panicking {
+                /// Increment the counter of dropped instances.
+                fn drop(&mut self) {
+                    DROPPED.set(DROPPED.get().wrapping_add(1));
+                }
+            }
+
+            let result = std::panic::catch_unwind(Fixed::<Panicking, 8>::default);
+
+            assert!(result.is_err());
+            assert_eq!(CONSTRUCTED.get(), 3);
+            assert_eq!(DROPPED.get(), 3);
+        }
     }
 
     #[allow(clippy::clone_on_copy)]
@@ -931,6 +1614,36 @@ mod test {
                 assert_eq!(Collection::count(&actual), 0);
             }
         }
+
+        mod clear {
+            use super::*;
+
+            #[test]
+            fn when_empty() {
+                let mut actual = Fixed::<usize, 0>::from([]);
+
+                actual.clear();
+
+                assert_eq!(actual.count(), 0);
+            }
+
+            #[test]
+            #[should_panic = "cannot remove elements from a fixed size array"]
+            fn panics_when_not_empty() {
+                let mut actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                actual.clear();
+            }
+
+            #[test]
+            fn coerces_to_trait_object() {
+                let actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                let actual: &dyn Collection<Element = i32> = &actual;
+
+                assert_eq!(actual.count(), 6);
+            }
+        }
     }
 
     mod linear {
@@ -1159,6 +1872,84 @@ mod test {
                 }
             }
         }
+
+        mod at {
+            use super::*;
+
+            #[test]
+            fn correct_element() {
+                let expected = [0, 1, 2, 3, 4, 5];
+                let actual = Fixed::from(expected);
+
+                for (index, element) in expected.iter().enumerate() {
+                    assert_eq!(actual.at(index), Some(element));
+                }
+            }
+
+            #[test]
+            fn none_when_index_out_of_bounds() {
+                let actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.at(6).is_none());
+                assert!(actual.at(usize::MAX).is_none());
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let actual = Fixed::<(), 0>::default();
+
+                assert!(actual.at(0).is_none());
+            }
+        }
+
+        mod at_mut {
+            use super::*;
+
+            #[test]
+            fn correct_element() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+                let mut actual = Fixed::from(expected);
+
+                for (index, element) in expected.iter_mut().enumerate() {
+                    assert_eq!(actual.at_mut(index), Some(element));
+                }
+            }
+
+            #[test]
+            fn is_mutable() {
+                let mut actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                for index in 0..actual.count() {
+                    *actual.at_mut(index).expect("within bounds") = 12345;
+                }
+
+                assert_eq!(actual, Fixed::from([12345; 6]));
+            }
+
+            #[test]
+            fn none_when_index_out_of_bounds() {
+                let mut actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                assert!(actual.at_mut(6).is_none());
+                assert!(actual.at_mut(usize::MAX).is_none());
+            }
+        }
+
+        mod generic {
+            use super::*;
+
+            /// Sum the elements of any [`Linear`] from the front and back.
+            fn sum(linear: &impl Linear<Element = i32>) -> (i32, i32) {
+                (linear.iter().sum(), linear.iter().rev().sum())
+            }
+
+            #[test]
+            fn usable_through_the_trait() {
+                let actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(sum(&actual), (15, 15));
+            }
+        }
     }
 
     mod array {
@@ -1185,5 +1976,140 @@ mod test {
                 assert_eq!(actual.as_mut_ptr(), actual.data.as_mut_ptr());
             }
         }
+
+        mod binary_search {
+            use super::*;
+
+            #[test]
+            fn finds_contained_element() {
+                let actual = Fixed::from([0, 1, 2, 3, 4, 5]);
+
+                for (index, element) in (0..6).enumerate() {
+                    assert_eq!(actual.binary_search(&element), Ok(index));
+                }
+            }
+
+            #[test]
+            fn insertion_index_when_not_contained() {
+                let actual = Fixed::from([0, 2, 4]);
+
+                assert_eq!(actual.binary_search(&-1), Err(0));
+                assert_eq!(actual.binary_search(&1), Err(1));
+                assert_eq!(actual.binary_search(&5), Err(3));
+            }
+
+            #[test]
+            fn when_empty() {
+                let actual = Fixed::<i32, 0>::from([]);
+
+                assert_eq!(actual.binary_search(&0), Err(0));
+            }
+        }
+    }
+
+    mod random_access {
+        use super::*;
+
+        mod get {
+            use super::*;
+
+            #[test]
+            fn correct_element() {
+                let actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                for index in 0..6 {
+                    assert_eq!(actual.get(index), Some(&index));
+                }
+            }
+
+            #[test]
+            fn none_when_index_out_of_bounds() {
+                let actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.get(6), None);
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let actual = Fixed::<usize, 0>::from([]);
+
+                assert_eq!(actual.get(0), None);
+            }
+        }
+
+        mod get_mut {
+            use super::*;
+
+            #[test]
+            fn correct_element() {
+                let mut actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                for mut index in 0..6 {
+                    assert_eq!(actual.get_mut(index), Some(&mut index));
+                }
+            }
+
+            #[test]
+            fn is_mutable() {
+                let mut actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                *actual.get_mut(0).expect("within bounds") = 5;
+
+                assert_eq!(actual.get(0), Some(&5));
+            }
+
+            #[test]
+            fn none_when_index_out_of_bounds() {
+                let mut actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.get_mut(6), None);
+            }
+        }
+
+        mod swap {
+            use super::*;
+
+            #[test]
+            fn exchanges_elements() {
+                let mut actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                actual.swap(1, 4);
+
+                assert!(actual.iter().eq([0, 4, 2, 3, 1, 5].iter()));
+            }
+
+            #[test]
+            fn same_index_is_unmodified() {
+                let mut actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                actual.swap(2, 2);
+
+                assert!(actual.iter().eq([0, 1, 2, 3, 4, 5].iter()));
+            }
+
+            #[test]
+            #[should_panic = "index out of bounds"]
+            fn panics_when_index_out_of_bounds() {
+                let mut actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                actual.swap(0, 6);
+            }
+        }
+
+        mod generic {
+            use super::*;
+
+            /// Obtain the last element via only [`RandomAccess`] methods.
+            fn last(collection: &impl RandomAccess<Element = usize>) -> Option<&usize> {
+                collection.get(collection.count().checked_sub(1)?)
+            }
+
+            #[test]
+            fn accepted_by_generic_functions() {
+                let actual = Fixed::<usize, 6>::from([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(last(&actual), Some(&5));
+            }
+        }
     }
 }
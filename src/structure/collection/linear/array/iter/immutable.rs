@@ -4,7 +4,7 @@ use core::ptr::NonNull;
 
 /// Immutable reference [`Iterator`] over an [`Array`](`super::super::Array`).
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub(in super::super) struct Iter<'a, T> {
+pub struct Iter<'a, T> {
     /// Pointer to the hypothetical next element.
     ptr: NonNull<T>,
 
@@ -33,6 +33,37 @@ impl<'a, T: 'a> Iter<'a, T> {
             lifetime: core::marker::PhantomData,
         }
     }
+
+    /// Obtain the elements yet to be yielded as a contiguous slice.
+    ///
+    /// This allows switching from element-wise iteration to bulk processing
+    /// of the remaining elements mid-stream, equivalent to
+    /// [`core::slice::Iter::as_slice`].
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::Linear;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let mut actual = instance.iter();
+    ///
+    /// _ = actual.next();
+    /// _ = actual.next_back();
+    ///
+    /// assert_eq!(actual.as_slice(), [1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    pub fn as_slice(&self) -> &'a [T] {
+        // SAFETY:
+        // * points to `count` aligned and initialized instances of `T`.
+        // * lifetime bound to underlying input.
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.count) }
+    }
 }
 
 impl<'a, T: 'a> Iterator for Iter<'a, T> {
@@ -70,6 +101,33 @@ impl<'a, T: 'a> Iterator for Iter<'a, T> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.count, Some(self.count))
     }
+
+    /// Skip `n` elements then obtain the next element from the front.
+    ///
+    /// Elements are contiguous, hence this advances the pointer directly
+    /// rather than yielding each skipped element.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skipped = n.min(self.count);
+
+        self.ptr = {
+            // SAFETY: either within the allocated object or one byte past.
+            let ptr = unsafe { self.ptr.as_ptr().add(skipped) };
+
+            // SAFETY: `add` maintains the non-null requirement.
+            unsafe { NonNull::new_unchecked(ptr) }
+        };
+
+        self.count = self.count.saturating_sub(skipped);
+
+        if skipped == n {
+            self.next()
+        } else {
+            None
+        }
+    }
 }
 
 impl<'a, T: 'a> core::iter::FusedIterator for Iter<'a, T> {}
@@ -96,6 +154,22 @@ impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
             unsafe { &*ptr }
         })
     }
+
+    /// Skip `n` elements then obtain the next element from the back.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.count {
+            self.count = self.count.saturating_sub(n);
+
+            self.next_back()
+        } else {
+            self.count = 0;
+
+            None
+        }
+    }
 }
 
 impl<'a, T: 'a + core::fmt::Debug> core::fmt::Debug for Iter<'a, T> {
@@ -157,6 +231,59 @@ mod test {
                 assert_eq!(actual.count, expected.len());
             }
         }
+
+        mod as_slice {
+            use super::*;
+
+            #[test]
+            fn initially_every_element() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.as_slice(), expected);
+            }
+
+            #[test]
+            fn excludes_yielded_elements() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                _ = actual.next();
+                _ = actual.next_back();
+                _ = actual.nth(1);
+                _ = actual.nth_back(0);
+
+                assert_eq!(actual.as_slice(), [3]);
+            }
+
+            #[test]
+            fn empty_when_exhausted() {
+                let mut expected = [0, 1, 2];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                actual.by_ref().for_each(drop);
+
+                assert_eq!(actual.as_slice(), []);
+            }
+        }
     }
 
     mod iterator {
@@ -320,5 +447,131 @@ mod test {
                 assert_eq!(actual.next_back(), None);
             }
         }
+
+        mod nth {
+            use super::*;
+
+            #[test]
+            fn yields_element_at_offset() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.nth(3), Some(&3));
+            }
+
+            #[test]
+            fn continues_after_yielded_element() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                _ = actual.nth(3);
+
+                assert_eq!(actual.len(), 2);
+                assert_eq!(actual.next(), Some(&4));
+                assert_eq!(actual.next_back(), Some(&5));
+                assert_eq!(actual.next(), None);
+            }
+
+            #[test]
+            fn exhausts_when_out_of_bounds() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.nth(6), None);
+                assert_eq!(actual.len(), 0);
+                assert_eq!(actual.next(), None);
+                assert_eq!(actual.next_back(), None);
+            }
+
+            #[test]
+            fn does_not_yield_each_skipped_element() {
+                // Iterating this many elements would never finish.
+                let mut actual = unsafe { Iter::new(NonNull::<()>::dangling(), usize::MAX) };
+
+                assert!(actual.nth(usize::MAX - 1).is_some());
+                assert_eq!(actual.next(), None);
+            }
+        }
+
+        mod nth_back {
+            use super::*;
+
+            #[test]
+            fn yields_element_at_offset() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.nth_back(3), Some(&2));
+            }
+
+            #[test]
+            fn continues_after_yielded_element() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                _ = actual.nth_back(3);
+
+                assert_eq!(actual.len(), 2);
+                assert_eq!(actual.next_back(), Some(&1));
+                assert_eq!(actual.next(), Some(&0));
+                assert_eq!(actual.next_back(), None);
+            }
+
+            #[test]
+            fn exhausts_when_out_of_bounds() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { Iter::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.nth_back(6), None);
+                assert_eq!(actual.len(), 0);
+                assert_eq!(actual.next(), None);
+                assert_eq!(actual.next_back(), None);
+            }
+
+            #[test]
+            fn does_not_yield_each_skipped_element() {
+                // Iterating this many elements would never finish.
+                let mut actual = unsafe { Iter::new(NonNull::<()>::dangling(), usize::MAX) };
+
+                assert!(actual.nth_back(usize::MAX - 1).is_some());
+                assert_eq!(actual.next_back(), None);
+            }
+        }
     }
 }
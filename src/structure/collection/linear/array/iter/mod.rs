@@ -1,7 +1,7 @@
 //! Iterators over [`Array`](`super::Array`).
 
 mod immutable;
-pub(super) use immutable::Iter;
+pub use immutable::Iter;
 
 mod mutable;
 pub(super) use mutable::IterMut;
@@ -70,6 +70,33 @@ impl<'a, T: 'a> Iterator for IterMut<'a, T> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.count, Some(self.count))
     }
+
+    /// Skip `n` elements then obtain the next element from the front.
+    ///
+    /// Elements are contiguous, hence this advances the pointer directly
+    /// rather than yielding each skipped element.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skipped = n.min(self.count);
+
+        self.ptr = {
+            // SAFETY: either within the allocated object or one byte past.
+            let ptr = unsafe { self.ptr.as_ptr().add(skipped) };
+
+            // SAFETY: `add` maintains the non-null requirement.
+            unsafe { NonNull::new_unchecked(ptr) }
+        };
+
+        self.count = self.count.saturating_sub(skipped);
+
+        if skipped == n {
+            self.next()
+        } else {
+            None
+        }
+    }
 }
 
 impl<'a, T: 'a> core::iter::FusedIterator for IterMut<'a, T> {}
@@ -96,6 +123,22 @@ impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T> {
             unsafe { &mut *ptr }
         })
     }
+
+    /// Skip `n` elements then obtain the next element from the back.
+    ///
+    /// # Performance
+    /// This methods takes O(1) time and consumes O(1) memory.
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.count {
+            self.count = self.count.saturating_sub(n);
+
+            self.next_back()
+        } else {
+            self.count = 0;
+
+            None
+        }
+    }
 }
 
 impl<'a, T: 'a + core::fmt::Debug> core::fmt::Debug for IterMut<'a, T> {
@@ -320,5 +363,131 @@ mod test {
                 assert_eq!(actual.next_back(), None);
             }
         }
+
+        mod nth {
+            use super::*;
+
+            #[test]
+            fn yields_element_at_offset() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { IterMut::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.nth(3), Some(&mut 3));
+            }
+
+            #[test]
+            fn continues_after_yielded_element() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { IterMut::new(ptr, expected.len()) }
+                };
+
+                _ = actual.nth(3);
+
+                assert_eq!(actual.len(), 2);
+                assert_eq!(actual.next(), Some(&mut 4));
+                assert_eq!(actual.next_back(), Some(&mut 5));
+                assert_eq!(actual.next(), None);
+            }
+
+            #[test]
+            fn exhausts_when_out_of_bounds() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { IterMut::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.nth(6), None);
+                assert_eq!(actual.len(), 0);
+                assert_eq!(actual.next(), None);
+                assert_eq!(actual.next_back(), None);
+            }
+
+            #[test]
+            fn does_not_yield_each_skipped_element() {
+                // Iterating this many elements would never finish.
+                let mut actual = unsafe { IterMut::new(NonNull::<()>::dangling(), usize::MAX) };
+
+                assert!(actual.nth(usize::MAX - 1).is_some());
+                assert_eq!(actual.next(), None);
+            }
+        }
+
+        mod nth_back {
+            use super::*;
+
+            #[test]
+            fn yields_element_at_offset() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { IterMut::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.nth_back(3), Some(&mut 2));
+            }
+
+            #[test]
+            fn continues_after_yielded_element() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { IterMut::new(ptr, expected.len()) }
+                };
+
+                _ = actual.nth_back(3);
+
+                assert_eq!(actual.len(), 2);
+                assert_eq!(actual.next_back(), Some(&mut 1));
+                assert_eq!(actual.next(), Some(&mut 0));
+                assert_eq!(actual.next_back(), None);
+            }
+
+            #[test]
+            fn exhausts_when_out_of_bounds() {
+                let mut expected = [0, 1, 2, 3, 4, 5];
+
+                let mut actual = {
+                    let ptr = expected.as_mut_ptr();
+                    let ptr = unsafe { NonNull::new_unchecked(ptr) };
+
+                    unsafe { IterMut::new(ptr, expected.len()) }
+                };
+
+                assert_eq!(actual.nth_back(6), None);
+                assert_eq!(actual.len(), 0);
+                assert_eq!(actual.next(), None);
+                assert_eq!(actual.next_back(), None);
+            }
+
+            #[test]
+            fn does_not_yield_each_skipped_element() {
+                // Iterating this many elements would never finish.
+                let mut actual = unsafe { IterMut::new(NonNull::<()>::dangling(), usize::MAX) };
+
+                assert!(actual.nth_back(usize::MAX - 1).is_some());
+                assert_eq!(actual.next_back(), None);
+            }
+        }
     }
 }
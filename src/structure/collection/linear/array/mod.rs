@@ -1,7 +1,7 @@
 //! Implementations of [`Array`].
 
 mod iter;
-use iter::Iter;
+pub use iter::Iter;
 use iter::IterMut;
 
 pub mod dope;
@@ -13,6 +13,7 @@ pub use fixed::Fixed;
 pub mod dynamic;
 pub use dynamic::Dynamic;
 
+use super::super::RandomAccess;
 use super::Collection;
 use super::Linear;
 
@@ -40,4 +41,45 @@ pub trait Array: Linear {
         // SAFETY: points to count many initialized elements.
         unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.count()) }
     }
+
+    /// Search sorted elements for one equal to `element`.
+    ///
+    /// Contigious memory allows constant time access to any element, hence
+    /// this takes logarithmic time unlike searching a [`super::List`].
+    ///
+    /// # Errors
+    /// Yields the index `element` could be inserted at to maintain sorted
+    /// order if no such element is contained.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Array;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([0, 1, 2, 4, 5]);
+    ///
+    /// assert_eq!(instance.binary_search(&4), Ok(3));
+    /// assert_eq!(instance.binary_search(&3), Err(3));
+    /// ```
+    ///
+    /// Lists are not contigious hence do not provide this method:
+    /// ```compile_fail
+    /// use rust::structure::collection::linear::Array;
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// _ = instance.binary_search(&3);
+    /// ```
+    fn binary_search(&self, element: &Self::Element) -> Result<usize, usize>
+    where
+        Self::Element: Ord,
+    {
+        // An empty instance might not have an allocation to point to.
+        if self.count() == 0 {
+            return Err(0);
+        }
+
+        self.as_slice().binary_search(element)
+    }
 }
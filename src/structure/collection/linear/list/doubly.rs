@@ -7,6 +7,7 @@ use core::ptr::NonNull;
 use super::Collection;
 use super::Linear;
 use super::List;
+use super::Singly;
 
 /// Independently allocated elements connected via two links.
 ///
@@ -29,6 +30,9 @@ pub struct Doubly<T> {
 
     /// The node considered to be the last/back, if any are contained.
     tail: Option<NonNull<Node<T>>>,
+
+    /// The number of elements contained.
+    count: usize,
 }
 
 /// An independently allocated element contained within some [`Doubly`].
@@ -80,6 +84,7 @@ impl<T> Default for Doubly<T> {
         Doubly {
             head: None,
             tail: None,
+            count: 0,
         }
     }
 }
@@ -299,6 +304,12 @@ impl<T> Iterator for Doubly<T> {
                 self.tail = None;
             }
 
+            if let Some(decremented) = self.count.checked_sub(1) {
+                self.count = decremented;
+            } else {
+                unreachable!("no element to remove");
+            }
+
             removed.element
         })
     }
@@ -306,7 +317,7 @@ impl<T> Iterator for Doubly<T> {
     /// Query how many elements are contained.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
@@ -317,9 +328,7 @@ impl<T> Iterator for Doubly<T> {
     /// assert_eq!(instance.size_hint(), (6, Some(6)));
     /// ```
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let count = self.count();
-
-        (count, Some(count))
+        (self.count, Some(self.count))
     }
 }
 
@@ -364,6 +373,12 @@ impl<T> DoubleEndedIterator for Doubly<T> {
                 self.head = None;
             }
 
+            if let Some(decremented) = self.count.checked_sub(1) {
+                self.count = decremented;
+            } else {
+                unreachable!("no element to remove");
+            }
+
             removed.element
         })
     }
@@ -426,13 +441,40 @@ impl<T> FromIterator<T> for Doubly<T> {
     }
 }
 
+impl<T> From<Singly<T>> for Doubly<T> {
+    /// Construct by moving the elements of a [`Singly`], preserving order.
+    ///
+    /// Nodes of each list have differing layouts, so each element is moved
+    /// into a newly allocated node rather than relinked.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::list::Doubly;
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let singly = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let actual = Doubly::from(singly);
+    ///
+    /// assert!(actual.eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    fn from(singly: Singly<T>) -> Self {
+        singly.collect()
+    }
+}
+
 impl<T> Collection for Doubly<T> {
     type Element = T;
 
     /// Query how many elements are contained.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
@@ -444,24 +486,27 @@ impl<T> Collection for Doubly<T> {
     /// assert_eq!(instance.count(), 6);
     /// ```
     fn count(&self) -> usize {
-        let mut count: usize = 0;
-
-        let mut next = self.head;
-
-        while let Some(current) = next {
-            if let Some(incremented) = count.checked_add(1) {
-                count = incremented;
-            } else {
-                unreachable!("more elements than supported by the address space (usize::MAX)");
-            }
-
-            // SAFETY: immutable references can alias.
-            let current = unsafe { current.as_ref() };
-
-            next = current.successor;
-        }
+        self.count
+    }
 
-        count
+    /// Drop all elements, deallocating every node.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::list::Doubly;
+    ///
+    /// let mut instance = Doubly::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// instance.clear();
+    ///
+    /// assert_eq!(instance.count(), 0);
+    /// ```
+    fn clear(&mut self) {
+        self.for_each(drop);
     }
 }
 
@@ -785,6 +830,12 @@ impl<T> List for Doubly<T> {
             self.tail = Some(allocation);
         }
 
+        if let Some(incremented) = self.count.checked_add(1) {
+            self.count = incremented;
+        } else {
+            unreachable!("more elements than supported by the address space (usize::MAX)");
+        }
+
         Ok(&mut new.element)
     }
 
@@ -841,6 +892,12 @@ impl<T> List for Doubly<T> {
             self.head = removed.successor.take();
         }
 
+        if let Some(decremented) = self.count.checked_sub(1) {
+            self.count = decremented;
+        } else {
+            unreachable!("no element to remove");
+        }
+
         Some(removed.element)
     }
 
@@ -886,6 +943,12 @@ impl<T> List for Doubly<T> {
             self.tail = Some(allocation);
         }
 
+        if let Some(incremented) = self.count.checked_add(1) {
+            self.count = incremented;
+        } else {
+            unreachable!("more elements than supported by the address space (usize::MAX)");
+        }
+
         Ok(&mut new.element)
     }
 
@@ -931,6 +994,12 @@ impl<T> List for Doubly<T> {
             self.head = Some(allocation);
         }
 
+        if let Some(incremented) = self.count.checked_add(1) {
+            self.count = incremented;
+        } else {
+            unreachable!("more elements than supported by the address space (usize::MAX)");
+        }
+
         Ok(&mut new.element)
     }
 
@@ -946,15 +1015,15 @@ impl<T> List for Doubly<T> {
     ///
     /// let mut instance = Doubly::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.front(), Some(0));
-    /// assert_eq!(instance.front(), Some(1));
-    /// assert_eq!(instance.front(), Some(2));
-    /// assert_eq!(instance.front(), Some(3));
-    /// assert_eq!(instance.front(), Some(4));
-    /// assert_eq!(instance.front(), Some(5));
-    /// assert_eq!(instance.front(), None);
+    /// assert_eq!(instance.pop_front(), Some(0));
+    /// assert_eq!(instance.pop_front(), Some(1));
+    /// assert_eq!(instance.pop_front(), Some(2));
+    /// assert_eq!(instance.pop_front(), Some(3));
+    /// assert_eq!(instance.pop_front(), Some(4));
+    /// assert_eq!(instance.pop_front(), Some(5));
+    /// assert_eq!(instance.pop_front(), None);
     /// ```
-    fn front(&mut self) -> Option<Self::Element> {
+    fn pop_front(&mut self) -> Option<Self::Element> {
         let removed = self.head.take()?;
 
         // SAFETY:
@@ -974,6 +1043,12 @@ impl<T> List for Doubly<T> {
             self.tail = None;
         }
 
+        if let Some(decremented) = self.count.checked_sub(1) {
+            self.count = decremented;
+        } else {
+            unreachable!("no element to remove");
+        }
+
         Some(removed.element)
     }
 
@@ -989,15 +1064,15 @@ impl<T> List for Doubly<T> {
     ///
     /// let mut instance = Doubly::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.back(), Some(5));
-    /// assert_eq!(instance.back(), Some(4));
-    /// assert_eq!(instance.back(), Some(3));
-    /// assert_eq!(instance.back(), Some(2));
-    /// assert_eq!(instance.back(), Some(1));
-    /// assert_eq!(instance.back(), Some(0));
-    /// assert_eq!(instance.back(), None);
+    /// assert_eq!(instance.pop_back(), Some(5));
+    /// assert_eq!(instance.pop_back(), Some(4));
+    /// assert_eq!(instance.pop_back(), Some(3));
+    /// assert_eq!(instance.pop_back(), Some(2));
+    /// assert_eq!(instance.pop_back(), Some(1));
+    /// assert_eq!(instance.pop_back(), Some(0));
+    /// assert_eq!(instance.pop_back(), None);
     /// ```
-    fn back(&mut self) -> Option<Self::Element> {
+    fn pop_back(&mut self) -> Option<Self::Element> {
         let removed = self.tail.take()?;
 
         // SAFETY:
@@ -1017,6 +1092,12 @@ impl<T> List for Doubly<T> {
             self.head = None;
         }
 
+        if let Some(decremented) = self.count.checked_sub(1) {
+            self.count = decremented;
+        } else {
+            unreachable!("no element to remove");
+        }
+
         Some(removed.element)
     }
 
@@ -1116,6 +1197,7 @@ impl<T> List for Doubly<T> {
         Drain {
             front,
             back,
+            count: &mut self.count,
             remaining,
         }
     }
@@ -1142,6 +1224,7 @@ impl<T> List for Doubly<T> {
         Withdraw {
             front: &mut self.head,
             back: &mut self.tail,
+            count: &mut self.count,
             exhausted: false,
             predicate,
         }
@@ -1195,7 +1278,7 @@ impl<T> super::super::Stack for Doubly<T> {
     /// assert_eq!(instance.pop(), None);
     /// ```
     fn pop(&mut self) -> Option<Self::Element> {
-        self.front()
+        self.pop_front()
     }
 
     /// Query the element at the top of the stack.
@@ -1264,7 +1347,7 @@ impl<T> super::super::Queue for Doubly<T> {
     /// assert_eq!(instance.pop(), None);
     /// ```
     fn pop(&mut self) -> Option<Self::Element> {
-        self.front()
+        self.pop_front()
     }
 
     /// Query the element at the front of the queue
@@ -1592,6 +1675,9 @@ struct Drain<'a, T> {
     /// The next (back) element to remove, if any.
     back: &'a mut Option<NonNull<Node<T>>>,
 
+    /// The number of elements contained by the underlying [`Doubly`].
+    count: &'a mut usize,
+
     /// The maximum amount of elements yet to be yielded.
     remaining: usize,
 }
@@ -1674,6 +1760,12 @@ impl<T> Iterator for Drain<'_, T> {
             // * the node was allocated via `Box` and `into_raw`.
             let removed = unsafe { Box::from_raw(ptr.as_ptr()) };
 
+            if let Some(contained) = self.count.checked_sub(1) {
+                *self.count = contained;
+            } else {
+                unreachable!("no element to remove");
+            }
+
             Some(removed.element)
         })
     }
@@ -1752,6 +1844,12 @@ impl<T> DoubleEndedIterator for Drain<'_, T> {
             // * the node was allocated via `Box` and `into_raw`.
             let removed = unsafe { Box::from_raw(removed.as_ptr()) };
 
+            if let Some(contained) = self.count.checked_sub(1) {
+                *self.count = contained;
+            } else {
+                unreachable!("no element to remove");
+            }
+
             Some(removed.element)
         })
     }
@@ -1771,6 +1869,9 @@ struct Withdraw<'a, T, F: FnMut(&T) -> bool> {
     /// The next from the back to query with the predicate.
     back: &'a mut Option<NonNull<Node<T>>>,
 
+    /// The number of elements contained by the underlying [`Doubly`].
+    count: &'a mut usize,
+
     /// If all elements have been queried.
     exhausted: bool,
 
@@ -1854,6 +1955,12 @@ impl<T, F: FnMut(&T) -> bool> Iterator for Withdraw<'_, T, F> {
                 // * the node was allocated via `Box` and `into_raw`.
                 let removed = unsafe { Box::from_raw(removed.as_ptr()) };
 
+                if let Some(contained) = self.count.checked_sub(1) {
+                    *self.count = contained;
+                } else {
+                    unreachable!("no element to remove");
+                }
+
                 return Some(removed.element);
             }
 
@@ -1971,6 +2078,12 @@ impl<T, F: FnMut(&T) -> bool> DoubleEndedIterator for Withdraw<'_, T, F> {
                 // * the node was allocated via `Box` and `into_raw`.
                 let removed = unsafe { Box::from_raw(removed.as_ptr()) };
 
+                if let Some(contained) = self.count.checked_sub(1) {
+                    *self.count = contained;
+                } else {
+                    unreachable!("no element to remove");
+                }
+
                 return Some(removed.element);
             }
 
@@ -2470,6 +2583,42 @@ mod test {
         }
     }
 
+    mod from {
+        use super::*;
+
+        mod singly {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                let actual = Doubly::from(Singly::<()>::default());
+
+                assert!(actual.head.is_none());
+                assert!(actual.tail.is_none());
+            }
+
+            #[test]
+            fn preserves_order() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let actual = Doubly::from(Singly::from_iter(expected));
+
+                assert!(actual.eq(expected));
+            }
+
+            #[test]
+            fn supports_back_operations() {
+                let mut actual = Doubly::from(Singly::from_iter([0, 1, 2, 3, 4, 5]));
+
+                assert_eq!(actual.pop_back(), Some(5));
+                assert!(actual.append(6).is_ok());
+                assert_eq!(Linear::last(&actual), Some(&6));
+
+                assert!(actual.eq([0, 1, 2, 3, 4, 6]));
+            }
+        }
+    }
+
     mod collection {
         use super::*;
 
@@ -2482,6 +2631,78 @@ mod test {
 
                 assert_eq!(Collection::count(&actual), 6);
             }
+
+            #[test]
+            fn does_not_traverse() {
+                let mut actual = Doubly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.count = 256;
+
+                assert_eq!(Collection::count(&actual), 256);
+
+                actual.count = 6;
+            }
+
+            #[test]
+            fn maintained_through_mutation() {
+                let mut actual = Doubly::<usize>::default();
+
+                let traversed = |list: &Doubly<usize>| list.iter().count();
+
+                _ = actual.prepend(1).unwrap();
+                _ = actual.append(3).unwrap();
+                _ = actual.insert(1, 2).unwrap();
+                actual.extend([4, 5, 6, 7]);
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+                assert_eq!(Collection::count(&actual), 7);
+
+                _ = actual.remove(1).unwrap();
+                _ = actual.pop_front().unwrap();
+                _ = actual.pop_back().unwrap();
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+                assert_eq!(Collection::count(&actual), 4);
+
+                _ = actual.next().unwrap();
+                _ = actual.next_back().unwrap();
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+                assert_eq!(Collection::count(&actual), 2);
+
+                actual.extend([1, 2, 3, 4]);
+                drop(actual.drain(1..3));
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+                assert_eq!(Collection::count(&actual), 4);
+
+                actual.retain(|element| element % 2 == 0);
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+
+                actual.clear();
+                assert_eq!(Collection::count(&actual), 0);
+            }
+        }
+
+        mod clear {
+            use super::*;
+
+            #[test]
+            fn deallocates_nodes() {
+                let mut actual = Doubly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.clear();
+
+                assert!(actual.head.is_none());
+                assert!(actual.tail.is_none());
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn when_already_empty() {
+                let mut actual = Doubly::<usize>::default();
+
+                actual.clear();
+
+                assert!(actual.head.is_none());
+                assert!(actual.tail.is_none());
+            }
         }
     }
 
@@ -3192,16 +3413,24 @@ mod test {
             }
         }
 
-        mod front {
+        mod pop_front {
             use super::*;
 
+            #[test]
+            #[allow(deprecated)]
+            fn deprecated_alias() {
+                let mut actual = Doubly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.front(), Some(0));
+            }
+
             #[test]
             fn subtracts_element() {
                 let expected = [0, 1, 2, 3, 4, 5];
                 let mut actual: Doubly<_> = expected.iter().copied().collect();
 
                 for remaining in (0..expected.len()).rev() {
-                    _ = actual.front();
+                    _ = actual.pop_front();
 
                     assert_eq!(actual.len(), remaining);
                 }
@@ -3213,7 +3442,7 @@ mod test {
                 let mut actual: Doubly<_> = expected.iter().copied().collect();
 
                 for offset in 1..=expected.len() {
-                    _ = actual.front();
+                    _ = actual.pop_front();
 
                     assert!(actual.iter().eq(expected[offset..].iter()));
                 }
@@ -3228,7 +3457,7 @@ mod test {
                 let mut actual: Doubly<_> = expected.iter().copied().collect();
 
                 for element in expected {
-                    assert_eq!(actual.front(), Some(element));
+                    assert_eq!(actual.pop_front(), Some(element));
                 }
             }
 
@@ -3236,20 +3465,28 @@ mod test {
             fn none_when_empty() {
                 let mut actual = Doubly::<()>::default();
 
-                assert_eq!(actual.front(), None);
+                assert_eq!(actual.pop_front(), None);
             }
         }
 
-        mod back {
+        mod pop_back {
             use super::*;
 
+            #[test]
+            #[allow(deprecated)]
+            fn deprecated_alias() {
+                let mut actual = Doubly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.back(), Some(5));
+            }
+
             #[test]
             fn subtracts_element() {
                 let expected = [0, 1, 2, 3, 4, 5];
                 let mut actual: Doubly<_> = expected.iter().copied().collect();
 
                 for remaining in (0..expected.len()).rev() {
-                    _ = actual.back();
+                    _ = actual.pop_back();
 
                     assert_eq!(actual.len(), remaining);
                 }
@@ -3261,7 +3498,7 @@ mod test {
                 let mut actual: Doubly<_> = expected.iter().copied().collect();
 
                 for offset in (0..expected.len()).rev() {
-                    _ = actual.back();
+                    _ = actual.pop_back();
 
                     assert!(actual.iter().eq(expected[..offset].iter()));
                 }
@@ -3276,7 +3513,7 @@ mod test {
                 let mut actual: Doubly<_> = expected.iter().copied().collect();
 
                 for element in expected.into_iter().rev() {
-                    assert_eq!(actual.back(), Some(element));
+                    assert_eq!(actual.pop_back(), Some(element));
                 }
             }
 
@@ -3284,7 +3521,7 @@ mod test {
             fn none_when_empty() {
                 let mut actual = Doubly::<()>::default();
 
-                assert_eq!(actual.back(), None);
+                assert_eq!(actual.pop_back(), None);
             }
         }
 
@@ -22,8 +22,8 @@ pub use doubly::Doubly;
 ///
 /// # Removal
 ///
-/// * [First](`Linear::first`) can be removed via [`front`](`Self::front`).
-/// * [Last](`Linear::last`) can be removed via [`back`](`Self::back`).
+/// * [First](`Linear::first`) can be removed via [`pop_front`](`Self::pop_front`).
+/// * [Last](`Linear::last`) can be removed via [`pop_back`](`Self::pop_back`).
 /// * Any given index can be removed via [`remove`](`Self::remove`).
 /// * Elements can be [`retain`](`Self::retain`) or
 ///   [`withdraw`](`Self::withdraw`) given a predicate.
@@ -31,11 +31,10 @@ pub use doubly::Doubly;
 /// * All elements can be removed via [`clear`](`Self::clear`).
 pub trait List:
     Linear
-    + IntoIterator<Item = Self::Element>
-    + DoubleEndedIterator<Item = Self::Element>
-    + ExactSizeIterator
-    + core::iter::FusedIterator
-    + Extend<Self::Element>
+    + IntoIterator<
+        Item = Self::Element,
+        IntoIter: DoubleEndedIterator + ExactSizeIterator + core::iter::FusedIterator,
+    > + Extend<Self::Element>
     + FromIterator<Self::Element>
 {
     /// Insert an `element` at `index`.
@@ -59,14 +58,32 @@ pub trait List:
     /// such that they become `[index..]`.
     fn remove(&mut self, index: usize) -> Option<Self::Element>;
 
-    /// Remove the element at the front, the first element.
+    /// Move the element at the front, the first element, out.
+    ///
+    /// See also: [`Linear::first`] to borrow it instead.
+    fn pop_front(&mut self) -> Option<Self::Element> {
+        self.remove(0)
+    }
+
+    /// Move the element at the back, the last element, out.
+    ///
+    /// See also: [`Linear::last`] to borrow it instead.
+    fn pop_back(&mut self) -> Option<Self::Element> {
+        let last = self.count().checked_sub(1)?;
+
+        self.remove(last)
+    }
+
+    /// Move the element at the front, the first element, out.
+    #[deprecated(note = "ambiguous with `Linear::first`, use `pop_front` instead")]
     fn front(&mut self) -> Option<Self::Element> {
-        self.next()
+        self.pop_front()
     }
 
-    /// Remove the element at the back, the last element.
+    /// Move the element at the back, the last element, out.
+    #[deprecated(note = "ambiguous with `Linear::last`, use `pop_back` instead")]
     fn back(&mut self) -> Option<Self::Element> {
-        self.next_back()
+        self.pop_back()
     }
 
     /// Insert an element such that it becomes the first.
@@ -82,7 +99,7 @@ pub trait List:
     /// # Errors
     /// Yields the `element` when it cannot be inserted.
     fn append(&mut self, element: Self::Element) -> Result<&mut Self::Element, Self::Element> {
-        self.insert(self.len(), element)
+        self.insert(self.count(), element)
     }
 
     /// Remove the elements within a given index `range`.
@@ -105,9 +122,4 @@ pub trait List:
     fn retain(&mut self, mut predicate: impl FnMut(&Self::Element) -> bool) {
         self.withdraw(|element| !predicate(element)).for_each(drop);
     }
-
-    /// Drop all elements.
-    fn clear(&mut self) {
-        (0..self.count()).for_each(|index| drop(self.remove(index)));
-    }
 }
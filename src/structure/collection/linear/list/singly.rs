@@ -1,6 +1,7 @@
 //! Implementation of [`Singly`].
 
 use super::Collection;
+use super::Doubly;
 use super::Linear;
 use super::List;
 
@@ -22,6 +23,9 @@ use super::List;
 pub struct Singly<T> {
     /// The contained elements.
     elements: Option<Box<Node<T>>>,
+
+    /// The number of elements contained.
+    count: usize,
 }
 
 /// An independently allocated element contained within some [`Singly`].
@@ -33,6 +37,139 @@ struct Node<T> {
     next: Option<Box<Node<T>>>,
 }
 
+impl<T> Singly<T> {
+    /// Split into two lists at `index` by relinking, without reallocating.
+    ///
+    /// Afterwards, [`Self`] contains the elements before `index` whereas the
+    /// returned list contains the element at `index` and those after it.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the number of elements.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let mut instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let rest = instance.split_at(2);
+    ///
+    /// assert!(instance.eq([0, 1]));
+    /// assert!(rest.eq([2, 3, 4, 5]));
+    /// ```
+    #[must_use]
+    pub fn split_at(&mut self, index: usize) -> Self {
+        let mut next = &mut self.elements;
+
+        for _ in 0..index {
+            let &mut Some(ref mut current) = next else {
+                panic!("index out of bounds");
+            };
+
+            next = &mut current.next;
+        }
+
+        let Some(count) = self.count.checked_sub(index) else {
+            unreachable!("traversed at most as many elements as contained");
+        };
+
+        self.count = index;
+
+        Self {
+            elements: next.take(),
+            count,
+        }
+    }
+
+    /// Distribute the nodes into two lists by `predicate`, via relinking.
+    ///
+    /// Unlike [`Iterator::partition`] which moves elements into new nodes,
+    /// this reuses the existing nodes.
+    ///
+    /// The first list contains the elements for which `predicate` returns
+    /// true and the second those for which it returns false, both preserving
+    /// their relative order. No node is allocated nor element cloned.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// let (even, odd) = instance.split_by(|element| element % 2 == 0);
+    ///
+    /// assert!(even.eq([0, 2, 4]));
+    /// assert!(odd.eq([1, 3, 5]));
+    /// ```
+    #[must_use]
+    pub fn split_by(mut self, mut predicate: impl FnMut(&T) -> bool) -> (Self, Self) {
+        let mut matching = Self::default();
+        let mut remaining = Self::default();
+
+        let mut matching_last = &mut matching.elements;
+        let mut remaining_last = &mut remaining.elements;
+
+        let mut next = self.elements.take();
+
+        while let Some(mut current) = next {
+            next = current.next.take();
+
+            if predicate(&current.element) {
+                matching_last = &mut matching_last.insert(current).next;
+
+                if let Some(incremented) = matching.count.checked_add(1) {
+                    matching.count = incremented;
+                } else {
+                    unreachable!("more elements than supported by the address space (usize::MAX)");
+                }
+            } else {
+                remaining_last = &mut remaining_last.insert(current).next;
+
+                if let Some(incremented) = remaining.count.checked_add(1) {
+                    remaining.count = incremented;
+                } else {
+                    unreachable!("more elements than supported by the address space (usize::MAX)");
+                }
+            }
+        }
+
+        self.count = 0;
+
+        (matching, remaining)
+    }
+
+    /// Drop the elements after the first `len`, if there are more.
+    ///
+    /// The unlinked nodes are dropped iteratively just like [`Drop`] for
+    /// [`Singly`], so this will not overflow the call stack regardless of how
+    /// many elements are removed.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let mut instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// instance.truncate(2);
+    ///
+    /// assert!(instance.eq([0, 1]));
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.count {
+            drop(self.split_at(len));
+        }
+    }
+}
+
 impl<T> Drop for Singly<T> {
     /// Iteratively drop all contained elements.
     ///
@@ -79,7 +216,10 @@ impl<T> Default for Singly<T> {
     /// assert_eq!(instance.len(), 0);
     /// ```
     fn default() -> Self {
-        Singly { elements: None }
+        Singly {
+            elements: None,
+            count: 0,
+        }
     }
 }
 
@@ -252,13 +392,19 @@ impl<T> Iterator for Singly<T> {
 
         self.elements = removed.next;
 
+        if let Some(decremented) = self.count.checked_sub(1) {
+            self.count = decremented;
+        } else {
+            unreachable!("no element to remove");
+        }
+
         Some(removed.element)
     }
 
     /// Query how many elements are contained.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
@@ -269,9 +415,7 @@ impl<T> Iterator for Singly<T> {
     /// assert_eq!(instance.size_hint(), (6, Some(6)));
     /// ```
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let count = self.count();
-
-        (count, Some(count))
+        (self.count, Some(self.count))
     }
 }
 
@@ -306,6 +450,12 @@ impl<T> DoubleEndedIterator for Singly<T> {
             removed = current;
         }
 
+        if let Some(decremented) = self.count.checked_sub(1) {
+            self.count = decremented;
+        } else {
+            unreachable!("no element to remove");
+        }
+
         Some(removed.element)
     }
 }
@@ -351,13 +501,67 @@ impl<T> Extend<T> for Singly<T> {
             });
 
             current = &mut current.insert(element).next;
+
+            if let Some(incremented) = self.count.checked_add(1) {
+                self.count = incremented;
+            } else {
+                unreachable!("more elements than supported by the address space (usize::MAX)");
+            }
+        }
+    }
+}
+
+impl<T> core::iter::Sum for Singly<T> {
+    /// Concatenate the lists in order (this is _NOT_ a numeric sum).
+    ///
+    /// Nodes are relinked rather than moved into new allocations, hence this
+    /// only traverses each list to find its last node.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let lists = [Singly::from_iter([0, 1]), Singly::from_iter([2, 3])];
+    ///
+    /// let actual: Singly<_> = lists.into_iter().sum();
+    ///
+    /// assert!(actual.eq([0, 1, 2, 3]));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(lists: I) -> Self {
+        let mut result = Self::default();
+
+        let mut last = &mut result.elements;
+
+        for mut list in lists {
+            *last = list.elements.take();
+
+            while let &mut Some(ref mut next) = last {
+                last = &mut next.next;
+            }
+
+            if let Some(incremented) = result.count.checked_add(list.count) {
+                result.count = incremented;
+            } else {
+                unreachable!("more elements than supported by the address space (usize::MAX)");
+            }
+
+            list.count = 0;
         }
+
+        result
     }
 }
 
 impl<T> FromIterator<T> for Singly<T> {
     /// Construct an instance with `elements`.
     ///
+    /// Via the standard library, this also enables collecting an iterator of
+    /// [`Result`] into `Result<Self, E>` which stops at the first error and
+    /// drops any already collected elements.
+    ///
     /// # Panics
     /// The Rust runtime might abort if allocation fails, panics otherwise.
     ///
@@ -382,13 +586,40 @@ impl<T> FromIterator<T> for Singly<T> {
     }
 }
 
+impl<T> From<Doubly<T>> for Singly<T> {
+    /// Construct by moving the elements of a [`Doubly`], preserving order.
+    ///
+    /// Nodes of each list have differing layouts, so each element is moved
+    /// into a newly allocated node rather than relinked.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(N) memory for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::list::Doubly;
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let doubly = Doubly::from_iter([0, 1, 2, 3, 4, 5]);
+    /// let actual = Singly::from(doubly);
+    ///
+    /// assert!(actual.eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    fn from(doubly: Doubly<T>) -> Self {
+        doubly.collect()
+    }
+}
+
 impl<T> Collection for Singly<T> {
     type Element = T;
 
     /// Query how many elements are contained.
     ///
     /// # Performance
-    /// This method takes O(N) time and consumes O(1) memory.
+    /// This method takes O(1) time and consumes O(1) memory.
     ///
     /// # Examples
     /// ```
@@ -400,21 +631,29 @@ impl<T> Collection for Singly<T> {
     /// assert_eq!(instance.count(), 6);
     /// ```
     fn count(&self) -> usize {
-        let mut count: usize = 0;
-
-        let mut next = &self.elements;
-
-        while let Some(current) = next.as_deref() {
-            if let Some(incremented) = count.checked_add(1) {
-                count = incremented;
-            } else {
-                unreachable!("more elements than supported by the address space (usize::MAX)");
-            }
+        self.count
+    }
 
-            next = &current.next;
+    /// Drop all elements, deallocating every node.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let mut instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// instance.clear();
+    ///
+    /// assert_eq!(instance.count(), 0);
+    /// ```
+    fn clear(&mut self) {
+        for element in self {
+            drop(element);
         }
-
-        count
     }
 }
 
@@ -662,6 +901,12 @@ impl<T> List for Singly<T> {
             next: next.take(),
         });
 
+        if let Some(incremented) = self.count.checked_add(1) {
+            self.count = incremented;
+        } else {
+            unreachable!("more elements than supported by the address space (usize::MAX)");
+        }
+
         Ok(&mut next.insert(new).element)
     }
 
@@ -691,11 +936,17 @@ impl<T> List for Singly<T> {
             }
         }
 
-        next.take().map(|removed| {
-            *next = removed.next;
+        let removed = next.take()?;
 
-            removed.element
-        })
+        *next = removed.next;
+
+        if let Some(decremented) = self.count.checked_sub(1) {
+            self.count = decremented;
+        } else {
+            unreachable!("no element to remove");
+        }
+
+        Some(removed.element)
     }
 
     /// Move an `element` into a new node at the front to become the first.
@@ -720,6 +971,12 @@ impl<T> List for Singly<T> {
             next: self.elements.take(),
         });
 
+        if let Some(incremented) = self.count.checked_add(1) {
+            self.count = incremented;
+        } else {
+            unreachable!("more elements than supported by the address space (usize::MAX)");
+        }
+
         let new = self.elements.insert(new);
 
         Ok(&mut new.element)
@@ -753,6 +1010,12 @@ impl<T> List for Singly<T> {
             next: None,
         });
 
+        if let Some(incremented) = self.count.checked_add(1) {
+            self.count = incremented;
+        } else {
+            unreachable!("more elements than supported by the address space (usize::MAX)");
+        }
+
         let new = next.insert(new);
 
         Ok(&mut new.element)
@@ -770,19 +1033,25 @@ impl<T> List for Singly<T> {
     ///
     /// let mut instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.front(), Some(0));
-    /// assert_eq!(instance.front(), Some(1));
-    /// assert_eq!(instance.front(), Some(2));
-    /// assert_eq!(instance.front(), Some(3));
-    /// assert_eq!(instance.front(), Some(4));
-    /// assert_eq!(instance.front(), Some(5));
-    /// assert_eq!(instance.front(), None);
+    /// assert_eq!(instance.pop_front(), Some(0));
+    /// assert_eq!(instance.pop_front(), Some(1));
+    /// assert_eq!(instance.pop_front(), Some(2));
+    /// assert_eq!(instance.pop_front(), Some(3));
+    /// assert_eq!(instance.pop_front(), Some(4));
+    /// assert_eq!(instance.pop_front(), Some(5));
+    /// assert_eq!(instance.pop_front(), None);
     /// ```
-    fn front(&mut self) -> Option<Self::Element> {
+    fn pop_front(&mut self) -> Option<Self::Element> {
         let mut removed = self.elements.take()?;
 
         self.elements = removed.next.take();
 
+        if let Some(decremented) = self.count.checked_sub(1) {
+            self.count = decremented;
+        } else {
+            unreachable!("no element to remove");
+        }
+
         Some(removed.element)
     }
 
@@ -798,15 +1067,15 @@ impl<T> List for Singly<T> {
     ///
     /// let mut instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(instance.back(), Some(5));
-    /// assert_eq!(instance.back(), Some(4));
-    /// assert_eq!(instance.back(), Some(3));
-    /// assert_eq!(instance.back(), Some(2));
-    /// assert_eq!(instance.back(), Some(1));
-    /// assert_eq!(instance.back(), Some(0));
-    /// assert_eq!(instance.back(), None);
+    /// assert_eq!(instance.pop_back(), Some(5));
+    /// assert_eq!(instance.pop_back(), Some(4));
+    /// assert_eq!(instance.pop_back(), Some(3));
+    /// assert_eq!(instance.pop_back(), Some(2));
+    /// assert_eq!(instance.pop_back(), Some(1));
+    /// assert_eq!(instance.pop_back(), Some(0));
+    /// assert_eq!(instance.pop_back(), None);
     /// ```
-    fn back(&mut self) -> Option<Self::Element> {
+    fn pop_back(&mut self) -> Option<Self::Element> {
         let mut next = &mut self.elements;
 
         while let Some(current) = next.take() {
@@ -814,6 +1083,12 @@ impl<T> List for Singly<T> {
                 let current = next.insert(current);
                 next = &mut current.next;
             } else {
+                if let Some(decremented) = self.count.checked_sub(1) {
+                    self.count = decremented;
+                } else {
+                    unreachable!("no element to remove");
+                }
+
                 return Some(current.element);
             }
         }
@@ -879,7 +1154,11 @@ impl<T> List for Singly<T> {
             }
         }
 
-        Drain { next, remaining }
+        Drain {
+            next,
+            count: &mut self.count,
+            remaining,
+        }
     }
 
     /// Remove elements matching some `predicate`.
@@ -905,6 +1184,7 @@ impl<T> List for Singly<T> {
     ) -> impl DoubleEndedIterator<Item = Self::Element> {
         Withdraw {
             next: &mut self.elements,
+            count: &mut self.count,
             previous_back: core::ptr::null(),
             predicate,
         }
@@ -958,7 +1238,7 @@ impl<T> super::super::Stack for Singly<T> {
     /// assert_eq!(instance.pop(), None);
     /// ```
     fn pop(&mut self) -> Option<Self::Element> {
-        self.front()
+        self.pop_front()
     }
 
     /// Query the element at the top of the stack.
@@ -1027,7 +1307,7 @@ impl<T> super::super::Queue for Singly<T> {
     /// assert_eq!(instance.pop(), None);
     /// ```
     fn pop(&mut self) -> Option<Self::Element> {
-        self.front()
+        self.pop_front()
     }
 
     /// Query the element at the front of the queue.
@@ -1331,6 +1611,9 @@ struct Drain<'a, T> {
     /// The next element from the front to be yielded, if any.
     next: &'a mut Option<Box<Node<T>>>,
 
+    /// The number of elements contained by the underlying [`Singly`].
+    count: &'a mut usize,
+
     /// The number of elements yet to be yielded.
     remaining: usize,
 }
@@ -1387,6 +1670,12 @@ impl<'a, T: 'a> Iterator for Drain<'a, T> {
 
             *self.next = removed.next;
 
+            if let Some(contained) = self.count.checked_sub(1) {
+                *self.count = contained;
+            } else {
+                unreachable!("no element to remove");
+            }
+
             Some(removed.element)
         })
     }
@@ -1472,6 +1761,12 @@ impl<'a, T: 'a> DoubleEndedIterator for Drain<'a, T> {
 
             *predecessor = successor;
 
+            if let Some(contained) = self.count.checked_sub(1) {
+                *self.count = contained;
+            } else {
+                unreachable!("no element to remove");
+            }
+
             Some(removed.element)
         })
     }
@@ -1488,6 +1783,9 @@ struct Withdraw<'a, T, F: FnMut(&T) -> bool> {
     /// The next element to query with the predicate, if any.
     next: &'a mut Option<Box<Node<T>>>,
 
+    /// The number of elements contained by the underlying [`Singly`].
+    count: &'a mut usize,
+
     /// The previously yielded element from the back, if any.
     previous_back: *const Node<T>,
 
@@ -1541,7 +1839,7 @@ impl<T, F: FnMut(&T) -> bool> Iterator for Withdraw<'_, T, F> {
         let mut successor = removed.next.take();
         let mut predecessor = &mut *self.next;
 
-        if core::ptr::addr_eq(&*removed, self.previous_back) {
+        if core::ptr::addr_eq(&raw const *removed, self.previous_back) {
             let inserted = predecessor.insert(removed);
             inserted.next = successor;
 
@@ -1551,11 +1849,17 @@ impl<T, F: FnMut(&T) -> bool> Iterator for Withdraw<'_, T, F> {
         if (self.predicate)(&removed.element) {
             *predecessor = successor;
 
+            if let Some(contained) = self.count.checked_sub(1) {
+                *self.count = contained;
+            } else {
+                unreachable!("no element to remove");
+            }
+
             return Some(removed.element);
         }
 
         while let Some(mut current) = successor.take() {
-            if core::ptr::addr_eq(&*current, self.previous_back) {
+            if core::ptr::addr_eq(&raw const *current, self.previous_back) {
                 successor = Some(current);
 
                 break;
@@ -1568,6 +1872,12 @@ impl<T, F: FnMut(&T) -> bool> Iterator for Withdraw<'_, T, F> {
                 // SAFETY: node will outlive the lifetime of this iterator.
                 self.next = unsafe { &mut *core::ptr::from_mut(&mut inserted.next) };
 
+                if let Some(contained) = self.count.checked_sub(1) {
+                    *self.count = contained;
+                } else {
+                    unreachable!("no element to remove");
+                }
+
                 return Some(current.element);
             }
 
@@ -1643,7 +1953,7 @@ impl<T, F: FnMut(&T) -> bool> DoubleEndedIterator for Withdraw<'_, T, F> {
     /// ```
     fn next_back(&mut self) -> Option<Self::Item> {
         while let Some(mut removed) = self.next.take() {
-            if core::ptr::addr_eq(&*removed, self.previous_back) {
+            if core::ptr::addr_eq(&raw const *removed, self.previous_back) {
                 *self.next = Some(removed);
                 break;
             }
@@ -1652,7 +1962,7 @@ impl<T, F: FnMut(&T) -> bool> DoubleEndedIterator for Withdraw<'_, T, F> {
             let mut predecessor = &mut *self.next;
 
             while let Some(mut current) = successor.take() {
-                if core::ptr::addr_eq(&*current, self.previous_back) {
+                if core::ptr::addr_eq(&raw const *current, self.previous_back) {
                     successor = Some(current);
 
                     break;
@@ -1666,13 +1976,19 @@ impl<T, F: FnMut(&T) -> bool> DoubleEndedIterator for Withdraw<'_, T, F> {
             if (self.predicate)(&removed.element) {
                 *predecessor = successor;
 
+                if let Some(contained) = self.count.checked_sub(1) {
+                    *self.count = contained;
+                } else {
+                    unreachable!("no element to remove");
+                }
+
                 return Some(removed.element);
             }
 
             let inserted = predecessor.insert(removed);
             inserted.next = successor;
 
-            self.previous_back = &**inserted;
+            self.previous_back = &raw const **inserted;
         }
 
         None
@@ -1710,69 +2026,292 @@ mod test {
         }
     }
 
-    mod drop {
+    mod method {
         use super::*;
 
-        #[test]
-        fn empty() {
-            let instance = Singly::<usize>::default();
-
-            drop(instance);
-        }
+        mod split_at {
+            use super::*;
 
-        #[test]
-        fn zero_size_type() {
-            let instance: Singly<_> = [(), (), (), (), (), ()].into_iter().collect();
+            #[test]
+            fn maintains_counts() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
 
-            drop(instance);
-        }
+                let rest = actual.split_at(2);
 
-        #[test]
-        fn drops_elements() {
-            const ELEMENTS: usize = 256;
+                assert_eq!(Collection::count(&actual), 2);
+                assert_eq!(Collection::count(&rest), 4);
+            }
 
-            let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+            #[test]
+            fn retains_leading_elements() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
 
-            let mut actual = Singly::<Droppable>::default();
+                drop(actual.split_at(2));
 
-            for _ in 0..ELEMENTS {
-                _ = actual
-                    .prepend(Droppable {
-                        counter: alloc::rc::Rc::clone(&dropped),
-                    })
-                    .expect("uses capacity");
+                assert!(actual.eq([0, 1]));
             }
 
-            drop(actual);
+            #[test]
+            fn yields_trailing_elements() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
 
-            assert_eq!(dropped.take(), ELEMENTS);
-        }
-    }
+                assert!(actual.split_at(2).eq([2, 3, 4, 5]));
+            }
 
-    mod default {
-        use super::*;
+            #[test]
+            fn yields_every_element_when_zero() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
 
-        #[test]
-        fn has_no_elements() {
-            let instance = Singly::<()>::default();
+                assert!(actual.split_at(0).eq([0, 1, 2, 3, 4, 5]));
+                assert!(actual.elements.is_none());
+            }
 
-            assert!(instance.elements.is_none());
-        }
-    }
+            #[test]
+            fn yields_no_element_when_length() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
 
-    mod clone {
-        use super::*;
+                assert!(actual.split_at(6).elements.is_none());
+                assert!(actual.eq([0, 1, 2, 3, 4, 5]));
+            }
 
-        #[test]
-        fn has_elements() {
-            let expected = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+            #[test]
+            #[should_panic = "index out of bounds"]
+            fn panics_when_out_of_bounds() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
 
-            let actual = expected.clone();
+                drop(actual.split_at(7));
+            }
 
-            assert_eq!(actual.len(), expected.len());
-        }
+            #[test]
+            fn does_not_drop_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
 
-        #[test]
+                let mut actual: Singly<_> = (0..6)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&dropped),
+                    })
+                    .collect();
+
+                let rest = actual.split_at(3);
+
+                assert_eq!(dropped.take(), 0);
+
+                drop(rest);
+
+                assert_eq!(dropped.take(), 3);
+            }
+        }
+
+        mod split_by {
+            use super::*;
+
+            #[test]
+            fn maintains_counts() {
+                let actual = Singly::from_iter([0, 1, 2, 3, 4]);
+
+                let (even, odd) = actual.split_by(|element| element % 2 == 0);
+
+                assert_eq!(Collection::count(&even), 3);
+                assert_eq!(Collection::count(&odd), 2);
+            }
+
+            #[test]
+            fn empty() {
+                let actual = Singly::<usize>::default();
+
+                let (matching, remaining) = actual.split_by(|_| true);
+
+                assert!(matching.elements.is_none());
+                assert!(remaining.elements.is_none());
+            }
+
+            #[test]
+            fn preserves_relative_order() {
+                let actual: Singly<_> = (0..6).collect();
+
+                let (even, odd) = actual.split_by(|element| element % 2 == 0);
+
+                assert!(even.eq([0, 2, 4]));
+                assert!(odd.eq([1, 3, 5]));
+            }
+
+            #[test]
+            fn moves_nodes() {
+                let actual: Singly<_> = (0..6).collect();
+
+                let addresses: Vec<*const i32> = actual.iter().map(core::ptr::from_ref).collect();
+
+                let (even, odd) = actual.split_by(|element| element % 2 == 0);
+
+                for (index, element) in even.iter().enumerate() {
+                    assert_eq!(core::ptr::from_ref(element), addresses[index * 2]);
+                }
+
+                for (index, element) in odd.iter().enumerate() {
+                    assert_eq!(core::ptr::from_ref(element), addresses[index * 2 + 1]);
+                }
+            }
+
+            #[test]
+            fn does_not_require_clone() {
+                /// Element which does not implement [`Clone`].
+                #[derive(Debug, PartialEq)]
+                struct Unique(usize);
+
+                let actual: Singly<_> = (0..6).map(Unique).collect();
+
+                let (matching, remaining) = actual.split_by(|element| element.0 < 2);
+
+                assert!(matching.eq([Unique(0), Unique(1)]));
+                assert!(remaining.eq([Unique(2), Unique(3), Unique(4), Unique(5)]));
+            }
+        }
+
+        mod truncate {
+            use super::*;
+
+            #[test]
+            fn retains_first_len_elements() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.truncate(2);
+
+                assert!(actual.eq([0, 1]));
+            }
+
+            #[test]
+            fn updates_count() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.truncate(2);
+
+                assert_eq!(Collection::count(&actual), 2);
+            }
+
+            #[test]
+            fn does_nothing_when_len_is_at_least_count() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.truncate(6);
+                actual.truncate(256);
+
+                assert!(actual.eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn removes_every_element_when_zero() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.truncate(0);
+
+                assert!(actual.elements.is_none());
+                assert_eq!(Collection::count(&actual), 0);
+            }
+
+            #[test]
+            fn drops_removed_elements_once() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let mut actual: Singly<_> = (0..6)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&dropped),
+                    })
+                    .collect();
+
+                actual.truncate(2);
+
+                assert_eq!(dropped.take(), 4);
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 2);
+            }
+
+            #[test]
+            fn does_not_overflow_the_stack() {
+                const ELEMENTS: usize = 1 << 20;
+
+                let mut actual: Singly<_> = core::iter::repeat_n((), ELEMENTS).collect();
+
+                actual.truncate(3);
+
+                assert_eq!(Collection::count(&actual), 3);
+            }
+        }
+    }
+
+    mod drop {
+        use super::*;
+
+        #[test]
+        fn empty() {
+            let instance = Singly::<usize>::default();
+
+            drop(instance);
+        }
+
+        #[test]
+        fn zero_size_type() {
+            let instance: Singly<_> = [(), (), (), (), (), ()].into_iter().collect();
+
+            drop(instance);
+        }
+
+        #[test]
+        fn drops_elements() {
+            const ELEMENTS: usize = 256;
+
+            let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+            let mut actual = Singly::<Droppable>::default();
+
+            for _ in 0..ELEMENTS {
+                _ = actual
+                    .prepend(Droppable {
+                        counter: alloc::rc::Rc::clone(&dropped),
+                    })
+                    .expect("uses capacity");
+            }
+
+            drop(actual);
+
+            assert_eq!(dropped.take(), ELEMENTS);
+        }
+
+        #[test]
+        fn does_not_overflow_the_stack() {
+            const ELEMENTS: usize = 1 << 20;
+
+            let instance: Singly<_> = core::iter::repeat_n((), ELEMENTS).collect();
+
+            drop(instance);
+        }
+    }
+
+    mod default {
+        use super::*;
+
+        #[test]
+        fn has_no_elements() {
+            let instance = Singly::<()>::default();
+
+            assert!(instance.elements.is_none());
+        }
+    }
+
+    mod clone {
+        use super::*;
+
+        #[test]
+        fn has_elements() {
+            let expected = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+            let actual = expected.clone();
+
+            assert_eq!(actual.len(), expected.len());
+        }
+
+        #[test]
         fn is_equivalent() {
             let expected = Singly::from_iter([0, 1, 2, 3, 4, 5]);
 
@@ -2088,6 +2627,55 @@ mod test {
 
                 assert_eq!(actual.len(), expected.len());
             }
+
+            #[test]
+            fn collects_ok_results() {
+                let actual: Result<Singly<_>, ()> =
+                    [0, 1, 2, 3, 4, 5].into_iter().map(Ok).collect();
+
+                assert!(actual.is_ok_and(|actual| actual.eq([0, 1, 2, 3, 4, 5])));
+            }
+
+            #[test]
+            fn yields_first_error() {
+                let actual: Result<Singly<usize>, _> =
+                    [Ok(0), Err(1), Ok(2), Err(3)].into_iter().collect();
+
+                assert_eq!(actual.err(), Some(1));
+            }
+
+            #[test]
+            fn stops_at_first_error() {
+                let mut consumed = 0;
+
+                let actual: Result<Singly<usize>, _> = [Ok(0), Err(1), Ok(2), Err(3)]
+                    .into_iter()
+                    .inspect(|_| consumed += 1)
+                    .collect();
+
+                assert!(actual.is_err());
+                assert_eq!(consumed, 2);
+            }
+
+            #[test]
+            fn drops_collected_elements_upon_error() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let actual: Result<Singly<_>, ()> = (0..6)
+                    .map(|index| {
+                        if index < 3 {
+                            Ok(Droppable {
+                                counter: alloc::rc::Rc::clone(&dropped),
+                            })
+                        } else {
+                            Err(())
+                        }
+                    })
+                    .collect();
+
+                assert!(actual.is_err());
+                assert_eq!(dropped.take(), 3);
+            }
         }
 
         mod extend {
@@ -2152,6 +2740,100 @@ mod test {
                 });
             }
         }
+
+        mod sum {
+            use super::*;
+
+            #[test]
+            fn concatenates_in_order() {
+                let lists = [
+                    Singly::from_iter([0, 1]),
+                    Singly::from_iter([2]),
+                    Singly::from_iter([3, 4, 5]),
+                ];
+
+                let actual: Singly<_> = lists.into_iter().sum();
+
+                assert!(actual.eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn counts_every_element() {
+                let lists = [
+                    Singly::default(),
+                    Singly::from_iter([0, 1]),
+                    Singly::default(),
+                    Singly::from_iter([2]),
+                ];
+
+                let actual: Singly<_> = lists.into_iter().sum();
+
+                assert_eq!(Collection::count(&actual), 3);
+                assert!(actual.eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn empty_when_no_lists() {
+                let actual: Singly<usize> = core::iter::empty::<Singly<_>>().sum();
+
+                assert!(actual.elements.is_none());
+                assert_eq!(Collection::count(&actual), 0);
+            }
+
+            #[test]
+            fn does_not_drop_elements() {
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let lists = [0, 1, 2].map(|_| -> Singly<_> {
+                    (0..2)
+                        .map(|_| Droppable {
+                            counter: alloc::rc::Rc::clone(&dropped),
+                        })
+                        .collect()
+                });
+
+                let actual: Singly<_> = lists.into_iter().sum();
+
+                assert_eq!(dropped.take(), 0);
+
+                drop(actual);
+
+                assert_eq!(dropped.take(), 6);
+            }
+        }
+    }
+
+    mod from {
+        use super::*;
+
+        mod doubly {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                let actual = Singly::from(Doubly::<()>::default());
+
+                assert!(actual.elements.is_none());
+            }
+
+            #[test]
+            fn preserves_order() {
+                let expected = [0, 1, 2, 3, 4, 5];
+
+                let actual = Singly::from(Doubly::from_iter(expected));
+
+                assert!(actual.eq(expected));
+            }
+
+            #[test]
+            fn round_trip_yields_equal_instance() {
+                let expected = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                let actual = Singly::from(Doubly::from(expected.clone()));
+
+                assert_eq!(actual, expected);
+            }
+        }
     }
 
     mod collection {
@@ -2166,6 +2848,147 @@ mod test {
 
                 assert_eq!(Collection::count(&actual), 6);
             }
+
+            #[test]
+            fn does_not_traverse() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.count = 256;
+
+                assert_eq!(Collection::count(&actual), 256);
+
+                actual.count = 6;
+            }
+
+            #[test]
+            fn maintained_through_mutation() {
+                let mut actual = Singly::<usize>::default();
+
+                let traversed = |list: &Singly<usize>| list.iter().count();
+
+                _ = actual.prepend(1).unwrap();
+                _ = actual.append(3).unwrap();
+                _ = actual.insert(1, 2).unwrap();
+                actual.extend([4, 5, 6, 7]);
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+                assert_eq!(Collection::count(&actual), 7);
+
+                _ = actual.remove(1).unwrap();
+                _ = actual.pop_front().unwrap();
+                _ = actual.pop_back().unwrap();
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+                assert_eq!(Collection::count(&actual), 4);
+
+                _ = actual.next().unwrap();
+                _ = actual.next_back().unwrap();
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+                assert_eq!(Collection::count(&actual), 2);
+
+                actual.extend([1, 2, 3, 4]);
+                drop(actual.drain(1..3));
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+                assert_eq!(Collection::count(&actual), 4);
+
+                actual.retain(|element| element % 2 == 0);
+                assert_eq!(Collection::count(&actual), traversed(&actual));
+
+                actual.clear();
+                assert_eq!(Collection::count(&actual), 0);
+            }
+        }
+
+        mod total_capacity {
+            use super::*;
+
+            #[test]
+            fn is_number_of_elements() {
+                let actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.total_capacity(), 6);
+            }
+
+            #[test]
+            fn does_not_preallocate() {
+                let mut actual = Singly::from_iter([0, 1, 2]);
+
+                Collection::extend_reserve(&mut actual, 256);
+
+                assert_eq!(actual.total_capacity(), 3);
+            }
+
+            #[test]
+            fn empty() {
+                let actual = Singly::<usize>::default();
+
+                assert_eq!(actual.total_capacity(), 0);
+            }
+        }
+
+        mod extend_reserve {
+            use super::*;
+
+            #[test]
+            fn does_not_modify_elements() {
+                let mut actual = Singly::from_iter([0, 1, 2]);
+
+                Collection::extend_reserve(&mut actual, 256);
+
+                assert_eq!(Collection::count(&actual), 3);
+                assert!(actual.eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn when_empty() {
+                let mut actual = Singly::<usize>::default();
+
+                Collection::extend_reserve(&mut actual, usize::MAX);
+
+                assert_eq!(Collection::count(&actual), 0);
+            }
+        }
+
+        mod clear {
+            use super::*;
+
+            #[test]
+            fn drop_all_elements() {
+                const ELEMENTS: usize = 256;
+
+                let dropped = alloc::rc::Rc::new(core::cell::RefCell::new(usize::default()));
+
+                let mut actual = Singly::<Droppable>::default();
+
+                for _ in 0..ELEMENTS {
+                    _ = actual
+                        .prepend(Droppable {
+                            counter: alloc::rc::Rc::clone(&dropped),
+                        })
+                        .expect("uses capacity");
+                }
+
+                actual.clear();
+
+                assert_eq!(dropped.take(), ELEMENTS);
+            }
+
+            #[test]
+            fn deallocates_nodes() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                actual.clear();
+
+                assert!(actual.elements.is_none());
+                assert_eq!(actual.len(), 0);
+            }
+
+            #[test]
+            fn when_already_empty() {
+                let mut actual = Singly::<usize>::default();
+
+                actual.clear();
+
+                assert!(actual.elements.is_none());
+            }
         }
     }
 
@@ -2473,6 +3296,25 @@ mod test {
 
                 assert!(actual.at_mut(0).is_none());
             }
+
+            #[test]
+            fn none_when_index_is_length() {
+                let mut actual: Singly<_> = [0, 1, 2, 3, 4, 5].into_iter().collect();
+
+                assert!(actual.at_mut(6).is_none());
+                assert!(actual.at_mut(usize::MAX).is_none());
+            }
+
+            #[test]
+            fn same_bounds_as_at() {
+                let mut actual: Singly<_> = [0, 1, 2, 3, 4, 5].into_iter().collect();
+
+                for index in 0..=8 {
+                    let expected = actual.at(index).copied();
+
+                    assert_eq!(actual.at_mut(index).map(|element| *element), expected);
+                }
+            }
         }
 
         mod first {
@@ -2614,6 +3456,145 @@ mod test {
                 assert_eq!(actual.last_mut(), None);
             }
         }
+
+        mod enumerate_indices {
+            use super::*;
+
+            #[test]
+            fn pairs_index_with_element() {
+                let actual = Singly::from_iter([5, 4, 3, 2, 1, 0]);
+
+                assert!(Linear::enumerate_indices(&actual).eq([
+                    (0, &5),
+                    (1, &4),
+                    (2, &3),
+                    (3, &2),
+                    (4, &1),
+                    (5, &0)
+                ]));
+            }
+
+            #[test]
+            fn index_corresponds_to_at() {
+                let actual = Singly::from_iter([5, 4, 3, 2, 1, 0]);
+
+                for (index, element) in Linear::enumerate_indices(&actual) {
+                    assert_eq!(actual.at(index), Some(element));
+                }
+            }
+
+            #[test]
+            fn does_not_consume() {
+                let actual = Singly::from_iter([0, 1, 2]);
+
+                assert_eq!(Linear::enumerate_indices(&actual).len(), 3);
+                assert_eq!(Collection::count(&actual), 3);
+            }
+
+            #[test]
+            fn empty() {
+                let actual = Singly::<()>::default();
+
+                assert_eq!(Linear::enumerate_indices(&actual).next(), None);
+            }
+        }
+
+        mod group_by {
+            use super::*;
+            use crate::structure::collection::linear::array::Dynamic;
+
+            #[test]
+            fn groups_adjacent_equal_elements() {
+                let actual = Singly::from_iter([1, 1, 2, 3, 3]);
+
+                let mut groups = Linear::group_by(&actual, PartialEq::eq);
+
+                assert!(groups.next().expect("first group").into_iter().eq([&1, &1]));
+                assert!(groups.next().expect("second group").into_iter().eq([&2]));
+                assert!(groups.next().expect("third group").into_iter().eq([&3, &3]));
+                assert!(groups.next().is_none());
+            }
+
+            #[test]
+            fn compares_against_previous_element_of_group() {
+                let actual = Singly::from_iter([0, 1, 2, 5, 6, 9]);
+
+                let groups: Dynamic<Dynamic<&i32>> =
+                    Linear::group_by(&actual, |previous, next| previous + 1 == *next).collect();
+
+                assert_eq!(Collection::count(&groups), 3);
+
+                let mut groups = groups.into_iter();
+
+                assert!(groups
+                    .next()
+                    .expect("first group")
+                    .into_iter()
+                    .eq([&0, &1, &2]));
+                assert!(groups
+                    .next()
+                    .expect("second group")
+                    .into_iter()
+                    .eq([&5, &6]));
+                assert!(groups.next().expect("third group").into_iter().eq([&9]));
+            }
+
+            #[test]
+            fn non_adjacent_equal_elements_are_separate() {
+                let actual = Singly::from_iter([0, 1, 0]);
+
+                assert_eq!(Linear::group_by(&actual, PartialEq::eq).count(), 3);
+            }
+
+            #[test]
+            fn empty() {
+                let actual = Singly::<usize>::default();
+
+                assert!(Linear::group_by(&actual, PartialEq::eq).next().is_none());
+            }
+        }
+
+        mod zip_elements {
+            use super::*;
+            use crate::structure::collection::linear::array::Dynamic;
+
+            #[test]
+            fn pairs_elements_in_order() {
+                let left = Singly::from_iter(['a', 'b', 'c']);
+                let right = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(Linear::zip_elements(&left, &right).eq([
+                    (&'a', &0),
+                    (&'b', &1),
+                    (&'c', &2)
+                ]));
+            }
+
+            #[test]
+            fn stops_at_shorter_length() {
+                let left = Singly::from_iter(['a', 'b', 'c', 'd']);
+                let right = Dynamic::from_iter([0, 1]);
+
+                assert!(Linear::zip_elements(&left, &right).eq([(&'a', &0), (&'b', &1)]));
+            }
+
+            #[test]
+            fn empty() {
+                let left = Singly::<char>::default();
+                let right = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(Linear::zip_elements(&left, &right).next().is_none());
+            }
+
+            #[test]
+            fn callable_as_method_without_consuming() {
+                let left = Singly::from_iter(['a', 'b']);
+                let right = Dynamic::from_iter([0, 1]);
+
+                assert!(left.zip_elements(&right).eq([(&'a', &0), (&'b', &1)]));
+                assert_eq!(Collection::count(&left), 2);
+            }
+        }
     }
 
     mod list {
@@ -2872,16 +3853,24 @@ mod test {
             }
         }
 
-        mod front {
+        mod pop_front {
             use super::*;
 
+            #[test]
+            #[allow(deprecated)]
+            fn deprecated_alias() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.front(), Some(0));
+            }
+
             #[test]
             fn subtracts_element() {
                 let expected = [0, 1, 2, 3, 4, 5];
                 let mut actual: Singly<_> = expected.iter().copied().collect();
 
                 for remaining in (0..expected.len()).rev() {
-                    _ = actual.front();
+                    _ = actual.pop_front();
 
                     assert_eq!(actual.len(), remaining);
                 }
@@ -2893,7 +3882,7 @@ mod test {
                 let mut actual: Singly<_> = expected.iter().copied().collect();
 
                 for offset in 1..=expected.len() {
-                    _ = actual.front();
+                    _ = actual.pop_front();
 
                     assert!(actual.iter().eq(expected[offset..].iter()));
                 }
@@ -2907,7 +3896,7 @@ mod test {
                 let mut actual: Singly<_> = expected.iter().copied().collect();
 
                 for element in expected {
-                    assert_eq!(actual.front(), Some(element));
+                    assert_eq!(actual.pop_front(), Some(element));
                 }
             }
 
@@ -2915,20 +3904,28 @@ mod test {
             fn none_when_empty() {
                 let mut actual = Singly::<()>::default();
 
-                assert_eq!(actual.front(), None);
+                assert_eq!(actual.pop_front(), None);
             }
         }
 
-        mod back {
+        mod pop_back {
             use super::*;
 
+            #[test]
+            #[allow(deprecated)]
+            fn deprecated_alias() {
+                let mut actual = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+
+                assert_eq!(actual.back(), Some(5));
+            }
+
             #[test]
             fn subtracts_element() {
                 let expected = [0, 1, 2, 3, 4, 5];
                 let mut actual: Singly<_> = expected.iter().copied().collect();
 
                 for remaining in (0..expected.len()).rev() {
-                    _ = actual.back();
+                    _ = actual.pop_back();
 
                     assert_eq!(actual.len(), remaining);
                 }
@@ -2940,7 +3937,7 @@ mod test {
                 let mut actual: Singly<_> = expected.iter().copied().collect();
 
                 for offset in (0..expected.len()).rev() {
-                    _ = actual.back();
+                    _ = actual.pop_back();
 
                     assert!(actual.iter().eq(expected[..offset].iter()));
                 }
@@ -2954,7 +3951,7 @@ mod test {
                 let mut actual: Singly<_> = expected.iter().copied().collect();
 
                 for element in expected.into_iter().rev() {
-                    assert_eq!(actual.back(), Some(element));
+                    assert_eq!(actual.pop_back(), Some(element));
                 }
             }
 
@@ -2962,7 +3959,7 @@ mod test {
             fn none_when_empty() {
                 let mut actual = Singly::<()>::default();
 
-                assert_eq!(actual.back(), None);
+                assert_eq!(actual.pop_back(), None);
             }
         }
 
@@ -3299,6 +4296,34 @@ mod test {
                 }
             }
         }
+
+        mod generic {
+            use super::*;
+
+            /// Insert `first` and `last` via only [`List`] methods.
+            fn surround(instance: &mut impl List<Element = usize>, first: usize, last: usize) {
+                assert!(instance.prepend(first).is_ok(), "prepend element");
+                assert!(instance.append(last).is_ok(), "append element");
+            }
+
+            #[test]
+            fn accepted_by_generic_functions() {
+                let mut actual = Singly::from_iter([1, 2, 3, 4]);
+
+                surround(&mut actual, 0, 5);
+
+                assert!(actual.eq([0, 1, 2, 3, 4, 5]));
+            }
+
+            #[test]
+            fn when_empty() {
+                let mut actual = Singly::<usize>::default();
+
+                surround(&mut actual, 0, 1);
+
+                assert!(actual.eq([0, 1]));
+            }
+        }
     }
 
     mod stack {
@@ -3428,6 +4453,75 @@ mod test {
                 assert_eq!(actual.peek(), None);
             }
         }
+
+        mod push_all {
+            use super::*;
+
+            #[test]
+            fn pushes_in_order() {
+                let mut actual = Singly::<usize>::default();
+
+                assert!(<Singly<_> as Stack>::push_all(&mut actual, [0, 1, 2]).is_ok());
+
+                assert!(actual.eq([2, 1, 0]));
+            }
+
+            #[test]
+            fn empty_batch() {
+                let mut actual = Singly::from_iter([0, 1, 2]);
+
+                assert!(<Singly<_> as Stack>::push_all(&mut actual, []).is_ok());
+
+                assert!(actual.eq([0, 1, 2]));
+            }
+        }
+
+        mod pop_n {
+            use super::*;
+
+            #[test]
+            fn pops_in_order() {
+                let mut actual = Singly::<usize>::default();
+
+                assert!(<Singly<_> as Stack>::push_all(&mut actual, [0, 1, 2]).is_ok());
+
+                let popped = <Singly<_> as Stack>::pop_n(&mut actual, 2);
+
+                assert!(popped.into_iter().eq([2, 1]));
+                assert!(actual.eq([0]));
+            }
+
+            #[test]
+            fn matches_repeated_pop() {
+                let mut expected: Singly<_> = (0..6).collect();
+                let mut actual = expected.clone();
+
+                let popped = <Singly<_> as Stack>::pop_n(&mut actual, 4);
+
+                for element in popped {
+                    assert_eq!(Some(element), <Singly<_> as Stack>::pop(&mut expected));
+                }
+
+                assert!(actual.eq(expected));
+            }
+
+            #[test]
+            fn at_most_every_element() {
+                let mut actual = Singly::from_iter([0, 1, 2]);
+
+                let popped = <Singly<_> as Stack>::pop_n(&mut actual, 256);
+
+                assert_eq!(popped.len(), 3);
+                assert_eq!(Collection::count(&actual), 0);
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let mut actual = Singly::<usize>::default();
+
+                assert_eq!(<Singly<_> as Stack>::pop_n(&mut actual, 2).len(), 0);
+            }
+        }
     }
 
     mod queue {
@@ -3557,5 +4651,74 @@ mod test {
                 assert_eq!(actual.peek(), None);
             }
         }
+
+        mod push_all {
+            use super::*;
+
+            #[test]
+            fn pushes_in_order() {
+                let mut actual = Singly::<usize>::default();
+
+                assert!(<Singly<_> as Queue>::push_all(&mut actual, [0, 1, 2]).is_ok());
+
+                assert!(actual.eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn empty_batch() {
+                let mut actual = Singly::from_iter([0, 1, 2]);
+
+                assert!(<Singly<_> as Queue>::push_all(&mut actual, []).is_ok());
+
+                assert!(actual.eq([0, 1, 2]));
+            }
+        }
+
+        mod pop_n {
+            use super::*;
+
+            #[test]
+            fn pops_in_order() {
+                let mut actual = Singly::<usize>::default();
+
+                assert!(<Singly<_> as Queue>::push_all(&mut actual, [0, 1, 2]).is_ok());
+
+                let popped = <Singly<_> as Queue>::pop_n(&mut actual, 2);
+
+                assert!(popped.into_iter().eq([0, 1]));
+                assert!(actual.eq([2]));
+            }
+
+            #[test]
+            fn matches_repeated_pop() {
+                let mut expected: Singly<_> = (0..6).collect();
+                let mut actual = expected.clone();
+
+                let popped = <Singly<_> as Queue>::pop_n(&mut actual, 4);
+
+                for element in popped {
+                    assert_eq!(Some(element), <Singly<_> as Queue>::pop(&mut expected));
+                }
+
+                assert!(actual.eq(expected));
+            }
+
+            #[test]
+            fn at_most_every_element() {
+                let mut actual = Singly::from_iter([0, 1, 2]);
+
+                let popped = <Singly<_> as Queue>::pop_n(&mut actual, 256);
+
+                assert_eq!(popped.len(), 3);
+                assert_eq!(Collection::count(&actual), 0);
+            }
+
+            #[test]
+            fn none_when_empty() {
+                let mut actual = Singly::<usize>::default();
+
+                assert_eq!(<Singly<_> as Queue>::pop_n(&mut actual, 2).len(), 0);
+            }
+        }
     }
 }
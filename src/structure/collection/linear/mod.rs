@@ -114,4 +114,87 @@ pub trait Linear: Collection + core::ops::IndexMut<usize, Output = Self::Element
     fn last_mut(&mut self) -> Option<&mut Self::Element> {
         self.at_mut(self.count().saturating_sub(1))
     }
+
+    /// Iterate over the elements alongside their index, by immutable reference.
+    ///
+    /// Unlike [`Iterator::enumerate`], this borrows rather than consumes
+    /// implementors which are themselves an [`Iterator`], and the index is
+    /// always that used by [`at`](`Self::at`).
+    #[must_use]
+    fn enumerate_indices(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (usize, &Self::Element)> + ExactSizeIterator {
+        self.iter().enumerate()
+    }
+
+    /// Iterate over runs of adjacent elements considered equal by `eq`.
+    ///
+    /// Each run is collected into a [`Dynamic`](`array::Dynamic`) of
+    /// references in order, starting a new run whenever `eq` yields false for
+    /// the last element of the current run and the next element. Hence every
+    /// element is within exactly one run, none of which are empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Linear;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    ///
+    /// let instance = Dynamic::from_iter([1, 1, 2, 3, 3]);
+    ///
+    /// let mut groups = Linear::group_by(&instance, |previous, next| previous == next);
+    ///
+    /// assert!(groups.next().is_some_and(|group| group.into_iter().eq([&1, &1])));
+    /// assert!(groups.next().is_some_and(|group| group.into_iter().eq([&2])));
+    /// assert!(groups.next().is_some_and(|group| group.into_iter().eq([&3, &3])));
+    /// assert!(groups.next().is_none());
+    /// ```
+    #[must_use]
+    fn group_by(
+        &self,
+        mut eq: impl FnMut(&Self::Element, &Self::Element) -> bool,
+    ) -> impl Iterator<Item = array::Dynamic<&Self::Element>> {
+        let mut elements = self.iter().peekable();
+
+        core::iter::from_fn(move || {
+            let first = elements.next()?;
+
+            let mut group = array::Dynamic::from_iter([first]);
+
+            let mut previous = first;
+
+            while let Some(next) = elements.next_if(|next| eq(previous, next)) {
+                group.extend([next]);
+
+                previous = next;
+            }
+
+            Some(group)
+        })
+    }
+
+    /// Iterate over pairs of elements from this and `other` by immutable
+    /// reference, stopping when either runs out of elements.
+    ///
+    /// Unlike [`Iterator::zip`], this borrows rather than consumes
+    /// implementors which are themselves an [`Iterator`], and `other` may be
+    /// any [`Linear`] collection, not necessarily of the same kind.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::linear::Linear;
+    /// use rust::structure::collection::linear::array::Dynamic;
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let left = Dynamic::from_iter([0, 1, 2]);
+    /// let right = Singly::from_iter(['a', 'b']);
+    ///
+    /// assert!(Linear::zip_elements(&left, &right).eq([(&0, &'a'), (&1, &'b')]));
+    /// ```
+    #[must_use]
+    fn zip_elements<'a, O: Linear<Element = U> + 'a, U: 'a>(
+        &'a self,
+        other: &'a O,
+    ) -> impl Iterator<Item = (&'a Self::Element, &'a U)> {
+        self.iter().zip(other.iter())
+    }
 }
@@ -1,5 +1,6 @@
 //! Implementation of [`Stack`].
 
+use super::array::Dynamic;
 use super::Collection;
 use super::Linear;
 
@@ -20,4 +21,26 @@ pub trait Stack: Collection + Linear {
     /// Query which element would next be popped.
     #[must_use]
     fn peek(&self) -> Option<&Self::Element>;
+
+    /// Push each of `elements` in order, such that the last is at the top.
+    ///
+    /// # Errors
+    /// Yields the first element which cannot be pushed, in which case those
+    /// before it remain pushed and those after it are dropped.
+    fn push_all<I: IntoIterator<Item = Self::Element>>(
+        &mut self,
+        elements: I,
+    ) -> Result<(), Self::Element> {
+        for element in elements {
+            _ = self.push(element)?;
+        }
+
+        Ok(())
+    }
+
+    /// Pop up to `count` elements, in the order they are popped.
+    #[must_use]
+    fn pop_n(&mut self, count: usize) -> Dynamic<Self::Element> {
+        core::iter::from_fn(|| self.pop()).take(count).collect()
+    }
 }
@@ -1,7 +1,11 @@
 //! Implementations of [`Collection`].
 
+pub mod bit_set;
 pub mod linear;
+pub mod prelude;
+pub mod tree;
 
+pub use bit_set::BitSet;
 pub use linear::Linear;
 
 /// A data structure which stores multiple elements of a single type.
@@ -10,6 +14,10 @@ pub trait Collection {
     type Element;
 
     /// Query the number of elements.
+    ///
+    /// Implementors are expected to answer in O(1) time, maintaining the
+    /// number of elements as they are added and removed rather than
+    /// traversing them, so callers may query this freely.
     #[must_use]
     fn count(&self) -> usize;
 
@@ -18,4 +26,129 @@ pub trait Collection {
     fn is_empty(&self) -> bool {
         self.count() == 0
     }
+
+    /// Drop all elements such that none are contained.
+    ///
+    /// Implementors which preallocate memory are expected to retain it for
+    /// reuse, whereas node-based implementors deallocate every node.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let mut instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// instance.clear();
+    ///
+    /// assert!(instance.is_empty());
+    /// ```
+    fn clear(&mut self);
+
+    /// Query how many elements could be contained without reallocation.
+    ///
+    /// By default this is [`count`](`Self::count`) since node-based
+    /// implementors allocate each element individually, hence have no spare
+    /// capacity. Implementors which preallocate memory should override this
+    /// to include that space, such that generic code can query it uniformly.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::list::Singly;
+    ///
+    /// let instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(instance.total_capacity(), 6);
+    /// ```
+    #[must_use]
+    fn total_capacity(&self) -> usize {
+        self.count()
+    }
+
+    /// Hint that `additional` elements are about to be added.
+    ///
+    /// Implementors which can preallocate memory should do so, such that
+    /// generic code building into any collection can avoid repeated
+    /// reallocation where supported. This is only a hint, so by default it
+    /// does nothing, and implementors may ignore failure to allocate.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::linear::List;
+    ///
+    /// fn build<L: List<Element = usize>>(instance: &mut L, count: usize) {
+    ///     instance.extend_reserve(count);
+    ///
+    ///     for element in 0..count {
+    ///         _ = instance.append(element).expect("successful allocation");
+    ///     }
+    /// }
+    ///
+    /// let mut instance = rust::structure::collection::linear::list::Singly::default();
+    ///
+    /// build(&mut instance, 6);
+    ///
+    /// assert!(instance.eq([0, 1, 2, 3, 4, 5]));
+    /// ```
+    fn extend_reserve(&mut self, additional: usize) {
+        _ = additional;
+    }
+}
+
+/// A [`Linear`] [`Collection`] providing constant time access to any element.
+///
+/// Unlike [lists](`linear::List`) which must traverse preceding elements to
+/// reach a given index, implementors of this trait can access any element
+/// directly hence algorithms such as binary search which repeatedly access
+/// arbitrary indexes can be bounded by this trait to ensure efficiency.
+///
+/// # Examples
+/// ```
+/// use rust::structure::collection::RandomAccess;
+/// use rust::structure::collection::linear::array::Dynamic;
+///
+/// fn reverse(collection: &mut impl RandomAccess) {
+///     let count = rust::structure::Collection::count(collection);
+///
+///     for index in 0..count / 2 {
+///         collection.swap(index, count - index - 1);
+///     }
+/// }
+///
+/// let mut instance = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+///
+/// reverse(&mut instance);
+///
+/// assert!(instance.into_iter().eq([5, 4, 3, 2, 1, 0]));
+/// ```
+///
+/// Lists do not provide constant time access, hence do not implement this:
+/// ```compile_fail
+/// use rust::structure::collection::RandomAccess;
+/// use rust::structure::collection::linear::list::Singly;
+///
+/// fn first(collection: &impl RandomAccess<Element = i32>) -> Option<&i32> {
+///     collection.get(0)
+/// }
+///
+/// let instance = Singly::from_iter([0, 1, 2, 3, 4, 5]);
+///
+/// _ = first(&instance);
+/// ```
+pub trait RandomAccess: Linear {
+    /// Obtain an immutable reference to the `index` element, bounds checked.
+    #[must_use]
+    fn get(&self, index: usize) -> Option<&Self::Element>;
+
+    /// Obtain a mutable reference to the `index` element, bounds checked.
+    #[must_use]
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Element>;
+
+    /// Swap the element at index `first` with the element at index `second`.
+    ///
+    /// # Panics
+    /// If either `first` or `second` is out of bounds.
+    fn swap(&mut self, first: usize, second: usize);
 }
@@ -0,0 +1,22 @@
+//! Re-exports of every [`Collection`] trait for convenient glob importing.
+//!
+//! Note that [`Stack`] and [`Queue`] share method names, hence calling those
+//! methods requires fully qualified syntax when both are in scope. Likewise
+//! for [`Collection::count`] on types which are also an [`Iterator`].
+//!
+//! # Examples
+//! ```
+//! use rust::structure::collection::prelude::*;
+//! use rust::structure::collection::linear::array::Dynamic;
+//!
+//! let mut instance = Dynamic::from_iter([1, 2, 3, 4, 5]);
+//!
+//! assert!(Stack::push(&mut instance, 0).is_ok());
+//!
+//! assert_eq!(Collection::count(&instance), 6);
+//! assert!(instance.iter().eq([0, 1, 2, 3, 4, 5].iter()));
+//! assert_eq!(instance.as_slice(), [0, 1, 2, 3, 4, 5]);
+//! ```
+
+pub use super::linear::{Array, List, Queue, Stack};
+pub use super::{Collection, Linear, RandomAccess};
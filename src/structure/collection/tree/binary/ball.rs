@@ -0,0 +1,729 @@
+//! Implementation of [`Ball`].
+
+use super::super::super::linear::array::Array;
+use super::super::super::linear::array::Dynamic;
+use super::super::super::linear::List;
+use super::super::super::Collection;
+use super::super::super::Linear;
+
+/// Points partitioned into nested hyperspheres for nearest-neighbour search.
+///
+/// Each node of the tree contains a point which is the center of a ball
+/// (hypersphere) whose radius is exactly large enough to enclose every point
+/// within the subtree rooted at that node. The descendants of a node are
+/// divided into two children by the median along the dimension with the
+/// greatest spread (difference between the minimum and maximum coordinate),
+/// hence the tree is balanced with height O(log N).
+///
+/// ```text
+///                  +-----------------+
+///                  | center, radius  |
+///                  +-----------------+
+///                   /               \
+///     +-----------------+       +-----------------+
+///     | center, radius  |       | center, radius  |
+///     +-----------------+       +-----------------+
+///        /         \               /         \
+///      ...         ...           ...         ...
+/// ```
+///
+/// Queries traverse the tree depth-first, skipping any ball which cannot
+/// possibly contain a point closer than those already found. Doing so
+/// requires the `distance` function to be a [metric][metric], most notably
+/// satisfying the triangle inequality, otherwise results may be incorrect.
+///
+/// See also: [Wikipedia](https://en.wikipedia.org/wiki/Ball_tree).
+///
+/// [metric]: https://en.wikipedia.org/wiki/Metric_space
+pub struct Ball<T, F> {
+    /// The node at the top of the tree, if any points are contained.
+    root: Option<Box<Node<T>>>,
+
+    /// The number of points contained.
+    count: usize,
+
+    /// The metric used to measure the distance between two points.
+    distance: F,
+}
+
+/// An independently allocated point contained within some [`Ball`].
+struct Node<T> {
+    /// The underlying contained point, the center of this ball.
+    element: T,
+
+    /// The distance to the furthest point within this subtree.
+    radius: f64,
+
+    /// The subtree containing points before the median.
+    left: Option<Box<Node<T>>>,
+
+    /// The subtree containing points after the median.
+    right: Option<Box<Node<T>>>,
+}
+
+impl<T: AsRef<[f64]>, F: Fn(&T, &T) -> f64> Ball<T, F> {
+    /// Construct a tree containing `elements` measured via `distance`.
+    ///
+    /// The coordinates of each point are given by [`AsRef<[f64]>`](`AsRef`).
+    /// Points are only partitioned along the dimensions which every point
+    /// has coordinates for.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N * log N) time and consumes O(N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::tree::binary::Ball;
+    ///
+    /// let points = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]];
+    ///
+    /// let distance = |left: &[f64; 2], right: &[f64; 2]| {
+    ///     f64::hypot(left[0] - right[0], left[1] - right[1])
+    /// };
+    ///
+    /// let instance = Ball::new(points, distance);
+    ///
+    /// assert_eq!(instance.nearest(&[1.5, 1.75]), Some(&[2.0, 2.0]));
+    /// ```
+    pub fn new<I: IntoIterator<Item = T>>(elements: I, distance: F) -> Self {
+        let elements: Dynamic<T> = elements.into_iter().collect();
+
+        let count = elements.len();
+
+        let root = Self::construct(elements, &distance);
+
+        Ball {
+            root,
+            count,
+            distance,
+        }
+    }
+
+    /// Query the contained point which is closest to `query`.
+    ///
+    /// If multiple points are equally close, which one is yielded is
+    /// unspecified.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(log N) memory in the worst
+    /// case, but is expected to take O(log N) time for evenly spaced points.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::tree::binary::Ball;
+    ///
+    /// let points = [[0.0, 0.0], [0.0, 4.0], [4.0, 0.0], [4.0, 4.0]];
+    ///
+    /// let distance = |left: &[f64; 2], right: &[f64; 2]| {
+    ///     f64::hypot(left[0] - right[0], left[1] - right[1])
+    /// };
+    ///
+    /// let instance = Ball::new(points, distance);
+    ///
+    /// assert_eq!(instance.nearest(&[3.0, 1.0]), Some(&[4.0, 0.0]));
+    /// assert_eq!(instance.nearest(&[1.0, 3.0]), Some(&[0.0, 4.0]));
+    /// ```
+    #[must_use]
+    pub fn nearest(&self, query: &T) -> Option<&T> {
+        self.k_nearest(query, 1).into_iter().next()
+    }
+
+    /// Query the `k` contained points closest to `query`, nearest first.
+    ///
+    /// If fewer than `k` points are contained, then all are yielded. If
+    /// multiple points are equally distant, which are yielded is unspecified.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N * K) time and consumes O(K + log N) memory in the
+    /// worst case, but is expected to take O(K * log N) time for evenly
+    /// spaced points.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::tree::binary::Ball;
+    ///
+    /// let points = [[0.0, 0.0], [1.5, 0.0], [3.0, 0.0], [6.0, 0.0]];
+    ///
+    /// let distance = |left: &[f64; 2], right: &[f64; 2]| {
+    ///     f64::hypot(left[0] - right[0], left[1] - right[1])
+    /// };
+    ///
+    /// let instance = Ball::new(points, distance);
+    ///
+    /// let mut actual = instance.k_nearest(&[2.0, 0.0], 3).into_iter();
+    ///
+    /// assert_eq!(actual.next(), Some(&[1.5, 0.0]));
+    /// assert_eq!(actual.next(), Some(&[3.0, 0.0]));
+    /// assert_eq!(actual.next(), Some(&[0.0, 0.0]));
+    /// assert_eq!(actual.next(), None);
+    /// ```
+    #[must_use]
+    pub fn k_nearest(&self, query: &T, k: usize) -> Dynamic<&T> {
+        let mut nearest = Dynamic::<(f64, &T)>::default();
+
+        if k == 0 {
+            return Dynamic::default();
+        }
+
+        // Only up to one more than `k` will ever exist simultaneously.
+        drop(nearest.reserve(k.min(self.count).saturating_add(1)));
+
+        if let Some(root) = self.root.as_deref() {
+            self.search(root, query, k, &mut nearest);
+        }
+
+        nearest.into_iter().map(|(_, element)| element).collect()
+    }
+
+    /// Construct the subtree containing `elements`.
+    ///
+    /// The median along the dimension of greatest spread becomes the root,
+    /// and the elements before/after that median recursively become the
+    /// left/right subtrees respectively.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N * log N) time and consumes O(N) memory.
+    fn construct(mut elements: Dynamic<T>, distance: &F) -> Option<Box<Node<T>>> {
+        if Collection::is_empty(&elements) {
+            return None;
+        }
+
+        let dimension = Self::widest(elements.as_slice());
+
+        let middle = elements.len() / 2;
+
+        _ = elements
+            .as_mut_slice()
+            .select_nth_unstable_by(middle, |left, right| {
+                match (left.as_ref().get(dimension), right.as_ref().get(dimension)) {
+                    (Some(left), Some(right)) => left.total_cmp(right),
+                    (None, _) | (_, None) => core::cmp::Ordering::Equal,
+                }
+            });
+
+        let Some(after) = middle.checked_add(1) else {
+            unreachable!("median is an index, so less than `usize::MAX`");
+        };
+
+        let right: Dynamic<T> = elements.drain(after..).collect();
+
+        let Some(element) = elements.pop_back() else {
+            unreachable!("the median is the last element remaining");
+        };
+
+        let left = elements;
+
+        let radius = left
+            .iter()
+            .chain(right.iter())
+            .map(|other| distance(&element, other))
+            .fold(0.0_f64, f64::max);
+
+        Some(Box::new(Node {
+            element,
+            radius,
+            left: Self::construct(left, distance),
+            right: Self::construct(right, distance),
+        }))
+    }
+
+    /// Query which dimension has the greatest spread of coordinates.
+    ///
+    /// # Performance
+    /// This method takes O(N * D) time and consumes O(1) memory.
+    fn widest(elements: &[T]) -> usize {
+        let dimensions = elements
+            .iter()
+            .map(|element| element.as_ref().len())
+            .min()
+            .unwrap_or(0);
+
+        let mut widest = 0;
+        let mut greatest = f64::NEG_INFINITY;
+
+        for dimension in 0..dimensions {
+            let (min, max) = elements
+                .iter()
+                .filter_map(|element| element.as_ref().get(dimension))
+                .fold(
+                    (f64::INFINITY, f64::NEG_INFINITY),
+                    |(min, max), &coordinate| (min.min(coordinate), max.max(coordinate)),
+                );
+
+            if max - min > greatest {
+                widest = dimension;
+                greatest = max - min;
+            }
+        }
+
+        widest
+    }
+
+    /// Insert the `k` closest points within the subtree into `nearest`.
+    ///
+    /// The points within `nearest` are ordered from nearest to furthest, and
+    /// those already present are the closest found thus far.
+    ///
+    /// # Panics
+    /// The Rust runtime might abort if allocation fails, panics otherwise.
+    ///
+    /// # Performance
+    /// This method takes O(N * K) time and consumes O(log N) memory.
+    fn search<'a>(
+        &self,
+        node: &'a Node<T>,
+        query: &T,
+        k: usize,
+        nearest: &mut Dynamic<(f64, &'a T)>,
+    ) {
+        let distance = (self.distance)(query, &node.element);
+
+        // Every point within this ball is at least this far from the query.
+        if nearest.len() == k {
+            if let Some(&(furthest, _)) = Linear::last(&*nearest) {
+                if distance - node.radius > furthest {
+                    return;
+                }
+            }
+        }
+
+        let position = nearest
+            .iter()
+            .position(|&(other, _)| distance < other)
+            .unwrap_or_else(|| nearest.len());
+
+        if position < k {
+            if nearest.len() == k {
+                _ = nearest.pop_back();
+            }
+
+            assert!(
+                nearest.insert(position, (distance, &node.element)).is_ok(),
+                "allocation failed"
+            );
+        }
+
+        // The least distance possible for a point within the child's ball.
+        let bound = |child: &Node<T>| (self.distance)(query, &child.element) - child.radius;
+
+        match (node.left.as_deref(), node.right.as_deref()) {
+            (Some(left), Some(right)) => {
+                // Exploring the closer ball first allows pruning the other.
+                if bound(left) <= bound(right) {
+                    self.search(left, query, k, nearest);
+                    self.search(right, query, k, nearest);
+                } else {
+                    self.search(right, query, k, nearest);
+                    self.search(left, query, k, nearest);
+                }
+            }
+            (Some(child), None) | (None, Some(child)) => self.search(child, query, k, nearest),
+            (None, None) => {}
+        }
+    }
+}
+
+impl<T: core::fmt::Debug, F> core::fmt::Debug for Ball<T, F> {
+    /// List the points contained in pre-order (centers before subtrees).
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(log N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::collection::tree::binary::Ball;
+    ///
+    /// let distance = |left: &[f64; 1], right: &[f64; 1]| (left[0] - right[0]).abs();
+    ///
+    /// let instance = Ball::new([[0.0], [1.0], [2.0]], distance);
+    ///
+    /// assert_eq!(format!("{instance:?}"), "[[1.0], [0.0], [2.0]]");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        /// Add the points within the subtree rooted at `node` to `list`.
+        fn entries<T: core::fmt::Debug>(
+            list: &mut core::fmt::DebugList<'_, '_>,
+            node: Option<&Node<T>>,
+        ) {
+            if let Some(node) = node {
+                _ = list.entry(&node.element);
+
+                entries(list, node.left.as_deref());
+                entries(list, node.right.as_deref());
+            }
+        }
+
+        let mut list = f.debug_list();
+
+        entries(&mut list, self.root.as_deref());
+
+        list.finish()
+    }
+}
+
+impl<T, F> Collection for Ball<T, F> {
+    type Element = T;
+
+    /// Query the number of points contained.
+    ///
+    /// # Performance
+    /// This method takes O(1) time and consumes O(1) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::tree::binary::Ball;
+    ///
+    /// let distance = |left: &[f64; 1], right: &[f64; 1]| (left[0] - right[0]).abs();
+    ///
+    /// let instance = Ball::new([[0.0], [1.0], [2.0]], distance);
+    ///
+    /// assert_eq!(instance.count(), 3);
+    /// ```
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Drop all points, deallocating every node.
+    ///
+    /// # Performance
+    /// This method takes O(N) time and consumes O(log N) memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust::structure::Collection;
+    /// use rust::structure::collection::tree::binary::Ball;
+    ///
+    /// let distance = |left: &[f64; 1], right: &[f64; 1]| (left[0] - right[0]).abs();
+    ///
+    /// let mut instance = Ball::new([[0.0], [1.0], [2.0]], distance);
+    ///
+    /// instance.clear();
+    ///
+    /// assert_eq!(instance.count(), 0);
+    /// assert_eq!(instance.nearest(&[1.0]), None);
+    /// ```
+    fn clear(&mut self) {
+        self.root = None;
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::undocumented_unsafe_blocks,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::assertions_on_result_states,
+    clippy::indexing_slicing
+)]
+mod test {
+    use super::*;
+
+    /// Euclidean distance between two points in two dimensions.
+    fn euclidean(left: &[f64; 2], right: &[f64; 2]) -> f64 {
+        f64::hypot(left[0] - right[0], left[1] - right[1])
+    }
+
+    /// Deterministic pseudo-random points scattered within `[0, 100)`.
+    fn points() -> Dynamic<[f64; 2]> {
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+
+        let mut next = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+
+            #[allow(clippy::cast_precision_loss)]
+            let random = (state >> 11) as f64 / (1_u64 << 53) as f64;
+
+            random * 100.0
+        };
+
+        (0..64).map(|_| [next(), next()]).collect()
+    }
+
+    /// Points to query the nearest of, both within and around [`points`].
+    const QUERIES: [[f64; 2]; 8] = [
+        [0.0, 0.0],
+        [50.0, 50.0],
+        [99.9, 0.1],
+        [12.3, 87.6],
+        [-25.0, 40.0],
+        [150.0, 150.0],
+        [33.3, 66.6],
+        [71.0, 29.0],
+    ];
+
+    /// Sort `points` by ascending distance from `query` via brute force.
+    fn brute_force(points: &[[f64; 2]], query: &[f64; 2]) -> Dynamic<[f64; 2]> {
+        let mut sorted: Dynamic<[f64; 2]> = points.iter().copied().collect();
+
+        sorted
+            .as_mut_slice()
+            .sort_by(|left, right| euclidean(left, query).total_cmp(&euclidean(right, query)));
+
+        sorted
+    }
+
+    mod method {
+        use super::*;
+
+        mod new {
+            use super::*;
+
+            #[test]
+            fn empty() {
+                let actual = Ball::new(Dynamic::<[f64; 2]>::default(), euclidean);
+
+                assert_eq!(actual.count(), 0);
+            }
+
+            #[test]
+            fn contains_every_point() {
+                let expected = points();
+
+                let actual = Ball::new(expected.clone(), euclidean);
+
+                assert_eq!(actual.count(), expected.len());
+
+                for point in expected.iter() {
+                    assert_eq!(actual.nearest(point), Some(point));
+                }
+            }
+
+            #[test]
+            fn radius_encloses_descendants() {
+                /// Assert every descendant of `node` is within its radius.
+                fn check(node: &Node<[f64; 2]>) -> Dynamic<[f64; 2]> {
+                    let mut descendants = Dynamic::<[f64; 2]>::default();
+
+                    for child in [node.left.as_deref(), node.right.as_deref()]
+                        .into_iter()
+                        .flatten()
+                    {
+                        descendants.extend(check(child));
+                    }
+
+                    for descendant in descendants.iter() {
+                        assert!(
+                            euclidean(&node.element, descendant) <= node.radius,
+                            "descendant outside of the ball"
+                        );
+                    }
+
+                    _ = descendants
+                        .append(node.element)
+                        .expect("successful allocation");
+
+                    descendants
+                }
+
+                let actual = Ball::new(points(), euclidean);
+
+                assert_eq!(check(actual.root.as_deref().unwrap()).len(), 64);
+            }
+
+            #[test]
+            #[allow(clippy::float_cmp)]
+            fn splits_along_dimension_of_greatest_spread() {
+                let points = [
+                    [0.0, 0.0],
+                    [1.0, 10.0],
+                    [2.0, 20.0],
+                    [3.0, 30.0],
+                    [4.0, 40.0],
+                ];
+
+                let actual = Ball::new(points, euclidean);
+
+                let root = actual.root.as_deref().unwrap();
+
+                assert_eq!(root.element, [2.0, 20.0]);
+                assert!(
+                    root.left.as_deref().unwrap().element[1] < 20.0,
+                    "before the median"
+                );
+                assert!(
+                    root.right.as_deref().unwrap().element[1] > 20.0,
+                    "after the median"
+                );
+            }
+        }
+
+        mod nearest {
+            use super::*;
+
+            #[test]
+            fn none_when_empty() {
+                let actual = Ball::new(Dynamic::<[f64; 2]>::default(), euclidean);
+
+                assert_eq!(actual.nearest(&[0.0, 0.0]), None);
+            }
+
+            #[test]
+            fn single_point() {
+                let actual = Ball::new([[1.0, 2.0]], euclidean);
+
+                assert_eq!(actual.nearest(&[100.0, -100.0]), Some(&[1.0, 2.0]));
+            }
+
+            #[test]
+            fn matches_brute_force() {
+                let points = points();
+
+                let actual = Ball::new(points.clone(), euclidean);
+
+                for query in &QUERIES {
+                    let expected = brute_force(points.as_slice(), query);
+
+                    assert_eq!(actual.nearest(query), expected.first());
+                }
+            }
+        }
+
+        mod k_nearest {
+            use super::*;
+
+            #[test]
+            fn empty_when_zero() {
+                let actual = Ball::new(points(), euclidean);
+
+                assert_eq!(actual.k_nearest(&[50.0, 50.0], 0).len(), 0);
+            }
+
+            #[test]
+            fn empty_when_no_points() {
+                let actual = Ball::new(Dynamic::<[f64; 2]>::default(), euclidean);
+
+                assert_eq!(actual.k_nearest(&[50.0, 50.0], 4).len(), 0);
+            }
+
+            #[test]
+            fn every_point_when_more_than_count() {
+                let points = points();
+
+                let actual = Ball::new(points.clone(), euclidean);
+
+                let query = [50.0, 50.0];
+
+                let expected = brute_force(points.as_slice(), &query);
+
+                assert!(actual
+                    .k_nearest(&query, 128)
+                    .into_iter()
+                    .eq(expected.iter()));
+            }
+
+            #[test]
+            fn matches_brute_force() {
+                let points = points();
+
+                let actual = Ball::new(points.clone(), euclidean);
+
+                for query in &QUERIES {
+                    let expected = brute_force(points.as_slice(), query);
+
+                    for k in [1, 2, 3, 5, 8, 13] {
+                        assert!(actual
+                            .k_nearest(query, k)
+                            .into_iter()
+                            .eq(expected.iter().take(k)));
+                    }
+                }
+            }
+        }
+    }
+
+    mod fmt {
+        use super::*;
+
+        mod debug {
+            use super::*;
+
+            #[test]
+            fn is_empty_list_when_empty() {
+                let actual = Ball::new(Dynamic::<[f64; 2]>::default(), euclidean);
+
+                assert_eq!(format!("{actual:?}"), "[]");
+            }
+
+            #[test]
+            fn lists_center_before_children() {
+                let actual = Ball::new([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]], euclidean);
+
+                assert_eq!(
+                    format!("{actual:?}"),
+                    "[[1.0, 1.0], [0.0, 0.0], [2.0, 2.0]]"
+                );
+            }
+        }
+    }
+
+    mod collection {
+        use super::*;
+
+        mod count {
+            use super::*;
+
+            #[test]
+            fn zero_when_empty() {
+                let actual = Ball::new(Dynamic::<[f64; 2]>::default(), euclidean);
+
+                assert_eq!(Collection::count(&actual), 0);
+            }
+
+            #[test]
+            fn number_of_points() {
+                let actual = Ball::new(points(), euclidean);
+
+                assert_eq!(Collection::count(&actual), 64);
+            }
+        }
+
+        mod clear {
+            use super::*;
+
+            #[test]
+            fn deallocates_nodes() {
+                let mut actual = Ball::new(points(), euclidean);
+
+                actual.clear();
+
+                assert!(actual.root.is_none());
+                assert_eq!(actual.count(), 0);
+            }
+
+            #[test]
+            fn no_nearest_point() {
+                let mut actual = Ball::new(points(), euclidean);
+
+                actual.clear();
+
+                assert_eq!(actual.nearest(&[50.0, 50.0]), None);
+            }
+
+            #[test]
+            fn when_already_empty() {
+                let mut actual = Ball::new(Dynamic::<[f64; 2]>::default(), euclidean);
+
+                actual.clear();
+
+                assert!(actual.root.is_none());
+                assert_eq!(actual.count(), 0);
+            }
+        }
+    }
+}
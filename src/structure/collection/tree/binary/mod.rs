@@ -0,0 +1,7 @@
+//! Implementations of trees where each node has at most two children.
+//!
+//! See also: [Wikipedia](https://en.wikipedia.org/wiki/Binary_tree).
+
+pub mod ball;
+
+pub use ball::Ball;
@@ -0,0 +1,10 @@
+//! Implementations of hierarchical [collections](`super::Collection`).
+//!
+//! Unlike [`super::Linear`] collections which relate elements one after
+//! another, elements of a tree are related by a parent-child hierarchy. Each
+//! element (referred to as a node) has exactly one parent except for the root
+//! which has no parent, and each node can have any number of children.
+//!
+//! See also: [Wikipedia](https://en.wikipedia.org/wiki/Tree_(data_structure)).
+
+pub mod binary;
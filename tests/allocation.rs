@@ -0,0 +1,443 @@
+//! Tests which require memory allocation to fail.
+//!
+//! Doing so requires replacing the global allocator, which would otherwise
+//! affect every unit test within the library's test binary, hence these are
+//! isolated within their own.
+
+extern crate alloc;
+
+// A dependency of the library rather than these tests.
+#[cfg(feature = "rayon")]
+use rayon as _;
+
+std::thread_local! {
+    /// If [`Failing`] should fail allocation requests from this thread.
+    static FAIL_ALLOCATION: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Global allocator which fails requests for threads that opt in.
+///
+/// Allocation is permitted whilst panicking so the panic can unwind.
+#[derive(Debug)]
+struct Failing;
+
+impl Failing {
+    /// If allocation requests from this thread should fail.
+    fn fails() -> bool {
+        FAIL_ALLOCATION.get() && !std::thread::panicking()
+    }
+}
+
+// SAFETY: delegates to the system allocator, or signals failure.
+unsafe impl core::alloc::GlobalAlloc for Failing {
+    /// Allocate via the system allocator unless failing this thread.
+    unsafe fn alloc(&self, layout: core::alloc::Layout) -> *mut u8 {
+        if Self::fails() {
+            core::ptr::null_mut()
+        } else {
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc`.
+            unsafe { std::alloc::System.alloc(layout) }
+        }
+    }
+
+    /// Deallocate via the system allocator.
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: core::alloc::Layout) {
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { std::alloc::System.dealloc(ptr, layout) }
+    }
+
+    /// Reallocate via the system allocator unless failing this thread.
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: core::alloc::Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        if Self::fails() {
+            core::ptr::null_mut()
+        } else {
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::realloc`.
+            unsafe { std::alloc::System.realloc(ptr, layout, new_size) }
+        }
+    }
+}
+
+/// Every test uses [`Failing`], which allocates unless told otherwise.
+#[global_allocator]
+static ALLOCATOR: Failing = Failing;
+
+/// Run `operation` whilst every allocation from this thread fails.
+///
+/// Allocation succeeds again afterwards, even if `operation` panics.
+fn failing_allocation<R>(operation: impl FnOnce() -> R) -> R {
+    /// Resets [`FAIL_ALLOCATION`] when dropped, including when unwinding.
+    struct Reset;
+
+    impl Drop for Reset {
+        /// Allow allocation from this thread to succeed.
+        fn drop(&mut self) {
+            FAIL_ALLOCATION.set(false);
+        }
+    }
+
+    FAIL_ALLOCATION.set(true);
+
+    let _reset = Reset;
+
+    operation()
+}
+
+#[cfg(test)]
+mod failing {
+    use super::*;
+
+    #[test]
+    fn fails_allocation() {
+        let result = failing_allocation(|| {
+            rust::structure::collection::linear::array::Dynamic::<usize>::with_capacity(1)
+        });
+
+        assert!(result.is_err(), "allocation should fail");
+    }
+
+    #[test]
+    fn allocates_afterwards() {
+        failing_allocation(|| ());
+
+        assert!(!FAIL_ALLOCATION.get(), "allocation should succeed");
+    }
+
+    #[test]
+    fn allocates_afterwards_when_operation_panics() {
+        let result = std::panic::catch_unwind(|| {
+            failing_allocation(|| panic!("intentional panic"));
+        });
+
+        assert!(result.is_err(), "operation should panic");
+        assert!(!FAIL_ALLOCATION.get(), "allocation should succeed");
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::assertions_on_result_states,
+    clippy::indexing_slicing
+)]
+mod dynamic {
+    use super::*;
+
+    use rust::structure::collection::linear::array::dynamic::TryInsertError;
+    use rust::structure::collection::linear::array::Dynamic;
+    use rust::structure::collection::linear::Array;
+    use rust::structure::collection::linear::List;
+
+    /// Mock element for drop tests.
+    #[derive(Debug)]
+    struct Droppable {
+        /// A shared counter for the number of elements dropped.
+        counter: alloc::rc::Rc<core::cell::RefCell<usize>>,
+    }
+
+    impl Drop for Droppable {
+        /// Increment the shared counter upon drop.
+        fn drop(&mut self) {
+            _ = self.counter.replace_with(|old| old.wrapping_add(1));
+        }
+    }
+
+    mod method {
+        use super::*;
+
+        mod compact_back {
+            use super::*;
+
+            #[test]
+            fn appending_freed_capacity_does_not_reallocate() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5, 6, 7]);
+
+                let removed = actual.drain(1..4).count();
+
+                let pointer = actual.compact_back().as_ptr();
+
+                failing_allocation(|| {
+                    for element in 0..removed {
+                        assert!(actual.append(element).is_ok());
+                    }
+                });
+
+                assert_eq!(actual.as_ptr(), pointer);
+                assert_eq!(actual.capacity(), 0);
+            }
+        }
+
+        mod repeat {
+            use super::*;
+
+            #[test]
+            fn panics_when_allocation_fails() {
+                let instance = Dynamic::from_iter([0, 1, 2]);
+
+                let result = std::panic::catch_unwind(|| {
+                    drop(failing_allocation(|| instance.repeat(2)));
+                });
+
+                assert!(result.is_err(), "should panic");
+            }
+        }
+
+        mod try_insert {
+            use super::*;
+
+            #[test]
+            fn failed_allocation_yields_element() {
+                let counter = alloc::rc::Rc::new(core::cell::RefCell::new(0));
+
+                let mut actual: Dynamic<_> = (0..3)
+                    .map(|_| Droppable {
+                        counter: alloc::rc::Rc::clone(&counter),
+                    })
+                    .collect();
+
+                assert!(actual.shrink(None).is_ok());
+
+                let element = Droppable {
+                    counter: alloc::rc::Rc::clone(&counter),
+                };
+
+                let result = failing_allocation(|| actual.try_insert(1, element).map(|_| ()));
+
+                let Err(TryInsertError::FailedAllocation(rejected)) = result else {
+                    panic!("allocation failed");
+                };
+
+                assert_eq!(actual.len(), 3);
+                assert_eq!(counter.take(), 0);
+
+                drop(rejected);
+
+                assert_eq!(counter.take(), 1);
+            }
+
+            #[test]
+            fn failed_allocation_leaves_instance_unmodified() {
+                let mut actual = Dynamic::from_iter([0, 1, 2]);
+
+                assert!(actual.shrink(None).is_ok());
+
+                let ptr = actual.as_ptr();
+
+                let result = failing_allocation(|| actual.try_insert(1, 3).map(|_| ()));
+
+                assert_eq!(result, Err(TryInsertError::FailedAllocation(3)));
+                assert_eq!(actual.as_ptr(), ptr);
+                assert_eq!(actual.capacity(), 0);
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn failed_allocation_when_empty() {
+                let mut actual = Dynamic::<usize>::default();
+
+                let result = failing_allocation(|| actual.try_insert(0, 0).map(|_| ()));
+
+                assert_eq!(result, Err(TryInsertError::FailedAllocation(0)));
+                assert_eq!(actual.len(), 0);
+                assert_eq!(actual.capacity(), 0);
+            }
+
+            #[test]
+            fn does_not_allocate_when_there_is_capacity() {
+                let mut actual = Dynamic::from_iter([0, 2]);
+
+                assert!(actual.reserve(1).is_ok());
+
+                let result = failing_allocation(|| actual.try_insert(1, 1).map(|_| ()));
+
+                assert_eq!(result, Ok(()));
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn zero_size_type() {
+                let mut actual = Dynamic::<()>::default();
+
+                assert!(failing_allocation(|| actual.try_insert(0, ()).is_ok()));
+                assert_eq!(actual.len(), 1);
+            }
+        }
+
+        mod try_prepend {
+            use super::*;
+
+            #[test]
+            fn consumes_front_capacity_without_allocating() {
+                let mut actual = Dynamic::from_iter([1, 2]);
+
+                assert!(actual.reserve_front(1).is_ok());
+
+                let result = failing_allocation(|| actual.try_prepend(0).map(|_| ()));
+
+                assert_eq!(result, Ok(()));
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn failed_allocation_leaves_instance_unmodified() {
+                let mut actual = Dynamic::from_iter([1, 2]);
+
+                assert!(actual.shrink(None).is_ok());
+
+                let result = failing_allocation(|| actual.try_prepend(0).map(|_| ()));
+
+                assert_eq!(result, Err(TryInsertError::FailedAllocation(0)));
+                assert_eq!(actual.capacity(), 0);
+                assert!(actual.into_iter().eq([1, 2]));
+            }
+        }
+
+        mod try_append {
+            use super::*;
+
+            #[test]
+            fn consumes_back_capacity_without_allocating() {
+                let mut actual = Dynamic::from_iter([0, 1]);
+
+                assert!(actual.reserve_back(1).is_ok());
+
+                let result = failing_allocation(|| actual.try_append(2).map(|_| ()));
+
+                assert_eq!(result, Ok(()));
+                assert!(actual.into_iter().eq([0, 1, 2]));
+            }
+
+            #[test]
+            fn failed_allocation_leaves_instance_unmodified() {
+                let mut actual = Dynamic::from_iter([0, 1]);
+
+                assert!(actual.shrink(None).is_ok());
+
+                let result = failing_allocation(|| actual.try_append(2).map(|_| ()));
+
+                assert_eq!(result, Err(TryInsertError::FailedAllocation(2)));
+                assert_eq!(actual.capacity(), 0);
+                assert!(actual.into_iter().eq([0, 1]));
+            }
+        }
+
+        mod splice_slice {
+            use super::*;
+
+            #[test]
+            fn does_not_reallocate_when_enough_capacity() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.reserve_back(256).expect("successful allocation");
+
+                let ptr = actual.as_ptr();
+
+                let result =
+                    failing_allocation(|| actual.splice_slice(1..2, &[7, 8, 9]).map(|_| ()));
+
+                assert!(result.is_ok());
+                assert_eq!(actual.as_ptr(), ptr);
+                assert_eq!(actual.capacity_back(), 254);
+            }
+
+            #[test]
+            fn does_not_modify_elements_when_allocation_fails() {
+                let mut actual = Dynamic::from_iter([0, 1, 2, 3, 4, 5]);
+
+                _ = actual.shrink(None).expect("successful reallocation");
+
+                let result =
+                    failing_allocation(|| actual.splice_slice(1..2, &[7, 8, 9]).map(|_| ()));
+
+                assert!(result.is_err());
+                assert!(actual.into_iter().eq([0, 1, 2, 3, 4, 5]));
+            }
+        }
+    }
+
+    mod fmt {
+        use super::*;
+
+        mod write {
+            use super::*;
+            use core::fmt::Write;
+
+            #[test]
+            fn does_not_append_when_allocation_fails() {
+                let mut actual = Dynamic::from_iter(*b"text");
+
+                _ = actual.shrink(None).expect("successful reallocation");
+
+                let result = failing_allocation(|| actual.write_str("more"));
+
+                assert!(result.is_err());
+                assert!(actual.into_iter().eq(*b"text"));
+            }
+        }
+    }
+
+    mod io {
+        use super::*;
+
+        mod write {
+            use super::*;
+            use std::io::Write;
+
+            #[test]
+            fn errors_when_allocation_fails() {
+                let mut actual = Dynamic::<u8>::default();
+
+                let result = failing_allocation(|| actual.write(b"bytes"));
+
+                assert!(result.is_err_and(|error| error.kind() == std::io::ErrorKind::OutOfMemory));
+                assert_eq!(actual.len(), 0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod sort {
+    use super::*;
+    use rust::algorithm::sort::comparison::block::{block_sort, sym_merge};
+
+    /// Elements in no particular order, with many but not all distinct.
+    fn input() -> Vec<usize> {
+        (0..10_000_usize)
+            .map(|index| index.wrapping_mul(7_919) % 1_000)
+            .collect()
+    }
+
+    mod block_sort {
+        use super::*;
+
+        #[test]
+        fn does_not_allocate() {
+            let mut elements = input();
+
+            // Allocating would abort the process, failing the test.
+            failing_allocation(|| block_sort(&mut elements));
+
+            assert!(elements.is_sorted());
+        }
+    }
+
+    mod sym_merge {
+        use super::*;
+
+        #[test]
+        fn does_not_allocate() {
+            let mut elements = input();
+
+            // Allocating would abort the process, failing the test.
+            failing_allocation(|| sym_merge(&mut elements));
+
+            assert!(elements.is_sorted());
+        }
+    }
+}